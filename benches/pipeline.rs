@@ -0,0 +1,124 @@
+use book_batch_rust::batch::book::create_default_filter_chain;
+use book_batch_rust::batch::{chunk_with_owned, Filter};
+use book_batch_rust::item::{Book, Raw, RawValue, Site};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::hint::black_box;
+
+/// 벤치마크에 사용할 도서 개수
+const BOOK_COUNT: usize = 100_000;
+
+/// 벤치마크 청크 사이즈 (배치잡 기본 청크 사이즈와 동일)
+const CHUNK_SIZE: usize = 500;
+
+fn create_raw(i: usize) -> Raw {
+    let mut raw = Raw::new();
+    raw.insert("title".to_owned(), RawValue::from(format!("도서 {}", i).as_str()));
+    raw.insert("author".to_owned(), RawValue::from("저자"));
+    raw.insert("priceSales".to_owned(), RawValue::from(15000));
+    raw.insert("series".to_owned(), RawValue::from(vec!["1권", "2권", "3권"]));
+    raw
+}
+
+/// 벤치마크용 도서 목록을 생성한다.
+/// 중복 ISBN 필터의 비용을 측정하기 위해 10%는 중복된 ISBN을, 1%는 빈 ISBN을 가지도록 한다.
+fn create_books(count: usize) -> Vec<Book> {
+    (0..count)
+        .map(|i| {
+            let isbn = if i % 100 == 0 {
+                String::new()
+            } else if i % 10 == 0 {
+                format!("979{:010}", i - 1)
+            } else {
+                format!("979{:010}", i)
+            };
+            Book::builder()
+                .isbn(isbn)
+                .title(format!("도서 {}", i))
+                .publisher_id(1)
+                .add_original(Site::Aladin, create_raw(i))
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn bench_chunk_with_owned(c: &mut Criterion) {
+    let books = create_books(BOOK_COUNT);
+    c.bench_function("chunk_with_owned 100k books", |b| {
+        b.iter_batched(
+            || books.clone(),
+            |books| black_box(chunk_with_owned(books, CHUNK_SIZE)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn bench_filter_chain(c: &mut Criterion) {
+    let books = create_books(BOOK_COUNT);
+    let filter = create_default_filter_chain();
+    c.bench_function("default filter chain 100k books", |b| {
+        b.iter_batched(
+            || books.clone(),
+            |books| black_box(filter.do_filter(books)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn bench_book_merge(c: &mut Criterion) {
+    let db_book = Book::builder()
+        .id(1)
+        .isbn("9791234567890".to_owned())
+        .title("저장된 도서".to_owned())
+        .publisher_id(1)
+        .add_original(Site::NLGO, create_raw(1))
+        .build()
+        .unwrap();
+    let new_book = Book::builder()
+        .isbn("9791234567890".to_owned())
+        .title("수집된 도서".to_owned())
+        .scheduled_pub_date(chrono::NaiveDate::from_ymd_opt(2025, 5, 1).unwrap())
+        .add_original(Site::Aladin, create_raw(2))
+        .build()
+        .unwrap();
+
+    c.bench_function("Book::merge", |b| {
+        b.iter(|| black_box(db_book.merge(black_box(&new_book))))
+    });
+}
+
+fn bench_raw_value_conversion(c: &mut Criterion) {
+    let json = serde_json::json!({
+        "title": "도서",
+        "author": "저자",
+        "priceSales": 15000,
+        "priceStandard": 16500.5,
+        "stock": true,
+        "series": [{"title": "1권", "isbn": "9791234567890"}, {"title": "2권", "isbn": "9791234567891"}],
+    });
+    let raw_value = RawValue::from(json.clone());
+
+    c.bench_function("serde_json::Value -> RawValue", |b| {
+        b.iter_batched(
+            || json.clone(),
+            |json| black_box(RawValue::from(json)),
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("RawValue -> serde_json::Value", |b| {
+        b.iter_batched(
+            || raw_value.clone(),
+            |raw| black_box(serde_json::Value::from(raw)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_chunk_with_owned,
+    bench_filter_chain,
+    bench_book_merge,
+    bench_raw_value_conversion
+);
+criterion_main!(benches);
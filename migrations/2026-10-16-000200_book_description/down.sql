@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+alter table books.book drop column if exists description;
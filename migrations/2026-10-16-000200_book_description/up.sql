@@ -0,0 +1 @@
+alter table books.book add column if not exists description text;
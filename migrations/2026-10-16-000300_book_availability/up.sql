@@ -0,0 +1,11 @@
+create table if not exists books.book_availability(
+    book_id bigint not null,
+    site varchar(32) not null,
+    status varchar(32) not null,
+    raw_status varchar(64) not null default '',
+    checked_at timestamp not null default now(),
+    changed_at timestamp not null default now(),
+
+    foreign key (book_id) references books.book(id),
+    primary key (book_id, site)
+);
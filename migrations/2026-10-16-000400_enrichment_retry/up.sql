@@ -0,0 +1,13 @@
+create table if not exists books.enrichment_retry(
+    site varchar(32) not null,
+    isbn varchar(13) not null,
+    error_type varchar(32) not null,
+    message text not null default '',
+    attempt integer not null default 1,
+    next_retry_at timestamp not null,
+    last_failed_at timestamp not null,
+
+    primary key (site, isbn)
+);
+
+create index if not exists enrichment_retry_next_retry_at_idx on books.enrichment_retry(site, next_retry_at);
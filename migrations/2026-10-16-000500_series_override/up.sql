@@ -0,0 +1,8 @@
+create table if not exists books.series_override(
+    isbn varchar(13) not null primary key,
+    series_id bigint null references books.series(id),
+    note text null,
+    registered_at timestamp not null default now()
+);
+
+comment on column books.series_override.series_id is '운영자가 지정한 시리즈 아이디, null일 경우 어떤 시리즈에도 속하지 않는 단독 도서';
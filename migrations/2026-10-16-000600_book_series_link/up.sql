@@ -0,0 +1,16 @@
+create table if not exists books.book_series_link(
+    book_id bigint not null,
+    series_id bigint not null,
+    method varchar(32) not null,
+    score double precision,
+    linked_at timestamp not null default now(),
+
+    foreign key (book_id) references books.book(id),
+    foreign key (series_id) references books.series(id),
+    primary key (book_id)
+);
+
+create index if not exists book_series_link_score_idx on books.book_series_link(score);
+
+comment on column books.book_series_link.method is 'isbn-exact, similarity, llm-confirmed, manual, created';
+comment on column books.book_series_link.score is '시리즈 연결 당시의 제목 유사도 (0 ~ 1, 유사도를 사용하지 않은 연결은 null)';
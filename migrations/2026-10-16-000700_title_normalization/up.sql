@@ -0,0 +1,14 @@
+create table if not exists books.title_normalization(
+    book_id bigint not null,
+    source_title varchar(512) not null,
+    normalized_title varchar(512) not null,
+    reason text not null default '',
+    prompt_version varchar(64),
+    normalized_at timestamp not null default now(),
+
+    foreign key (book_id) references books.book(id),
+    primary key (book_id)
+);
+
+comment on column books.title_normalization.source_title is '정규화 당시의 도서 제목, 도서 제목이 바뀌었는지 확인하는데 사용한다.';
+comment on column books.title_normalization.reason is 'LLM이 설명한 제목에서 제거된 요소';
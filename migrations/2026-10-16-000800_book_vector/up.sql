@@ -0,0 +1,10 @@
+create table if not exists books.book_vector(
+    book_id bigint not null,
+    vec vector(1024) not null,
+    modified_at timestamp not null default now(),
+
+    foreign key (book_id) references books.book(id),
+    primary key (book_id)
+);
+
+comment on column books.book_vector.vec is '정규화된 도서 제목의 임베딩 백터';
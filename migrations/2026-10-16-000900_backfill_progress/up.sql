@@ -0,0 +1,11 @@
+create table if not exists books.backfill_progress(
+    site varchar(32) not null,
+    publisher_id bigint not null,
+    completed_through date not null,
+    updated_at timestamp not null default now(),
+
+    foreign key (publisher_id) references books.publisher(id),
+    primary key (site, publisher_id)
+);
+
+comment on column books.backfill_progress.completed_through is '수집을 마친 마지막 날짜, 다음 실행은 이 날짜의 다음 달부터 수집한다.';
@@ -0,0 +1,10 @@
+create table if not exists books.provider_quota(
+    site varchar(32) not null,
+    quota_date date not null,
+    used integer not null default 0,
+    modified_at timestamp not null default now(),
+
+    primary key (site, quota_date)
+);
+
+comment on column books.provider_quota.used is '해당 날짜에 사이트 API로 보낸 요청 수';
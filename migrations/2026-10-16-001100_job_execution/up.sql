@@ -0,0 +1,13 @@
+create table if not exists books.job_execution(
+    id bigserial primary key,
+    job_name varchar(32) not null,
+    parameters text not null,
+    status varchar(16) not null,
+    started_at timestamp not null default now(),
+    finished_at timestamp,
+    audit_path text
+);
+
+create index if not exists job_execution_job_name_idx on books.job_execution(job_name, started_at);
+
+comment on column books.job_execution.audit_path is '실행 중 변경된 내역(새 도서, 수정된 속성, 새 시리즈/연결)을 내보낸 JSON 파일 경로';
@@ -0,0 +1,9 @@
+create table if not exists books.isbn_set(
+    name varchar(128) not null,
+    isbn varchar(13) not null,
+    registered_at timestamp not null default now(),
+
+    primary key (name, isbn)
+);
+
+comment on table books.isbn_set is '잡 사이에 전달할 이름 있는 ISBN 집합 (ex: nlgo:2024-06-01:new)';
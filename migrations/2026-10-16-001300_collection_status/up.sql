@@ -0,0 +1,12 @@
+create table if not exists books.collection_status(
+    site varchar(32) not null,
+    publisher_id bigint not null,
+    collected_at timestamp not null default now(),
+    result_count integer not null default 0,
+
+    foreign key (publisher_id) references books.publisher(id),
+    primary key (site, publisher_id)
+);
+
+comment on column books.collection_status.collected_at is '마지막으로 수집에 성공한 시각';
+comment on column books.collection_status.result_count is '마지막 수집에서 조회된 도서 수';
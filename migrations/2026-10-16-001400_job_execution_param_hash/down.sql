@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+drop index if exists books.job_execution_param_hash_idx;
+alter table books.job_execution drop column if exists param_hash;
@@ -0,0 +1,5 @@
+alter table books.job_execution add column if not exists param_hash varchar(64);
+
+create index if not exists job_execution_param_hash_idx on books.job_execution(param_hash, status);
+
+comment on column books.job_execution.param_hash is '잡 이름, 정규화된 파라미터, 실행 날짜의 SHA-256 해시 (같은 날 같은 실행을 찾는데 사용)';
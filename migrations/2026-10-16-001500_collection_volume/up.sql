@@ -0,0 +1,15 @@
+create table if not exists books.collection_volume(
+    id bigserial primary key,
+    job_name varchar(32) not null,
+    publisher_id bigint not null,
+    executed_at timestamp not null default now(),
+    read_count integer not null default 0,
+    write_count integer not null default 0,
+
+    foreign key (publisher_id) references books.publisher(id)
+);
+
+create index if not exists collection_volume_job_publisher_idx on books.collection_volume(job_name, publisher_id, executed_at);
+
+comment on column books.collection_volume.read_count is '실행에서 조회된 출판사의 도서 수';
+comment on column books.collection_volume.write_count is '실행에서 새로 저장된 출판사의 도서 수';
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+drop index if exists books.book_origin_data_content_hash_idx;
+alter table books.book_origin_data drop column if exists content_hash;
@@ -0,0 +1,5 @@
+alter table books.book_origin_data add column if not exists content_hash varchar(64);
+
+create unique index if not exists book_origin_data_content_hash_idx on books.book_origin_data(book_id, site, content_hash);
+
+comment on column books.book_origin_data.content_hash is '원본 데이터 내용의 SHA-256 해시, 같은 도서와 사이트에 같은 내용의 원본 데이터가 중복 저장되지 않도록 한다.';
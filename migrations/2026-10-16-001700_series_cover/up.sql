@@ -0,0 +1,3 @@
+alter table books.series add column if not exists cover varchar(1024);
+
+comment on column books.series.cover is '시리즈 대표 이미지 URL, 시리즈에 속한 도서(1권 우선)의 썸네일 중에서 선택된다.';
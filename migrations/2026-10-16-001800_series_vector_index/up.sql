@@ -0,0 +1,3 @@
+create index if not exists series_vec_hnsw_idx on books.series using hnsw (vec vector_cosine_ops);
+
+comment on index books.series_vec_hnsw_idx is '시리즈 유사도 근사 검색(ANN)용 HNSW 인덱스, 검색 방식이 exact인 경우 사용하지 않는다.';
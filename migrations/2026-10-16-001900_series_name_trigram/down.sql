@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+drop index if exists books.series_name_trgm_idx;
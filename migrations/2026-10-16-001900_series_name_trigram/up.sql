@@ -0,0 +1,5 @@
+create extension if not exists pg_trgm;
+
+create index if not exists series_name_trgm_idx on books.series using gin (name gin_trgm_ops);
+
+comment on index books.series_name_trgm_idx is '시리즈 연결 후보를 제목 유사도(trigram)로 먼저 조회하기 위한 인덱스';
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+comment on column books.book_series_link.method is 'isbn-exact, similarity, llm-confirmed, manual, created';
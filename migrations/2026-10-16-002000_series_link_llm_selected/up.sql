@@ -0,0 +1 @@
+comment on column books.book_series_link.method is 'isbn-exact, similarity, llm-confirmed, llm-selected, manual, created';
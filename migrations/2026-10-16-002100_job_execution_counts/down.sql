@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+alter table books.job_execution drop column if exists skipped_count;
+alter table books.job_execution drop column if exists written_count;
+alter table books.job_execution drop column if exists processed_count;
+alter table books.job_execution drop column if exists read_count;
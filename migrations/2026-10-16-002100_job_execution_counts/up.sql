@@ -0,0 +1,9 @@
+alter table books.job_execution add column if not exists read_count int8;
+alter table books.job_execution add column if not exists processed_count int8;
+alter table books.job_execution add column if not exists written_count int8;
+alter table books.job_execution add column if not exists skipped_count int8;
+
+comment on column books.job_execution.read_count is '리더가 읽은 아이템 수';
+comment on column books.job_execution.processed_count is '프로세서가 처리에 성공한 아이템 수';
+comment on column books.job_execution.written_count is '라이터가 저장에 성공한 아이템 수';
+comment on column books.job_execution.skipped_count is '처리, 저장에 실패하여 건너뛴 아이템 수';
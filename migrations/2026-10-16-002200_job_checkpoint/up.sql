@@ -0,0 +1,9 @@
+create table if not exists books.job_checkpoint(
+    checkpoint_key varchar(64) primary key,
+    job_name varchar(32) not null,
+    chunk_index bigint not null,
+    updated_at timestamp not null default now()
+);
+
+comment on column books.job_checkpoint.checkpoint_key is '잡 이름, 정규화된 파라미터의 SHA-256 해시 (--resume으로 다시 실행할 때 같은 실행을 찾는데 사용)';
+comment on column books.job_checkpoint.chunk_index is '마지막으로 저장을 마친 청크의 순번 (0부터 시작)';
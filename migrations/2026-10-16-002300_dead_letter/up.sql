@@ -0,0 +1,12 @@
+create table if not exists books.dead_letter(
+    job_name varchar(32) not null,
+    isbn varchar(64) not null,
+    error_class varchar(128) not null,
+    attempts int not null default 1,
+    first_failed_at timestamp not null default now(),
+    last_failed_at timestamp not null default now(),
+    primary key (job_name, isbn, error_class)
+);
+
+comment on column books.dead_letter.error_class is '저장 실패 사유에서 숫자 등 아이템마다 다른 값을 제외한 에러 유형';
+comment on column books.dead_letter.attempts is '같은 에러 유형으로 저장에 실패하여 격리된 횟수';
@@ -1,15 +1,50 @@
 pub mod error;
 pub mod book;
 pub mod series;
+pub mod file;
+pub mod normalize;
+pub mod availability;
+pub mod metrics;
+pub mod report;
+pub mod audit;
+pub mod follow_up;
+pub mod status;
+pub mod cancel;
+pub mod spill;
+pub mod duplicate;
+pub mod volume;
+pub mod cover;
+pub mod trace;
+pub mod quarantine;
+pub mod glossary;
+pub mod purge;
+pub mod snapshot;
+pub mod profile;
+pub mod retry;
+pub mod checkpoint;
+pub mod dead_letter;
 
-use crate::batch::error::{JobProcessFailed, JobReadFailed, JobRuntimeError, JobWriteFailed};
+use crate::batch::cancel::CancellationToken;
+use crate::batch::checkpoint::{Checkpoint, CheckpointRun};
+use crate::batch::dead_letter::DeadLetter;
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobRuntimeError, JobWriteFailed};
+use crate::batch::retry::RetryPolicy;
+use crate::batch::spill::SpillQueue;
+use crate::item::JobExecutionCounts;
+use crate::{JobName, PARAM_NAME_CHUNK_SIZE, PARAM_NAME_DRY_RUN, PARAM_NAME_SKIP_LIMIT, PARAM_NAME_SPILL_THRESHOLD};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::collections::HashMap;
-use tracing::{error, warn};
+use std::path::Path;
+use tracing::{debug, error, info, warn};
 
 pub type JobParameter = HashMap<String, String>;
 
+/// 리더가 페이지 단위로 읽은 아이템의 이터레이터, 페이지를 읽는 중 실패한 경우 에러를 반환하고 더 이상 페이지를 읽지 않는다.
+pub type ReadPages<'a, T> = Box<dyn Iterator<Item = Result<Vec<T>, JobReadFailed>> + 'a>;
+
 /// 배치잡 아이템 리더 트레이트 정해진 데이터를 API, 데이터베이스 등 특정 위치에서 조회하거나 검색한다.
-/// 현재는 페이징을 지원하지 않기 때문에 잡 1회당 한번만 호출 됨으로 처리에 필요한 데이터들을 모두 로드해야한다.
+/// 잡은 [`Reader::do_read_pages`]로 페이지를 하나씩 읽어 처리하며, 페이지를 나누지 않는 리더는 `do_read`로 처리에 필요한 데이터들을 모두 로드한다.
 ///
 /// # Type
 /// - `Item`: 읽어올 데이터 타입
@@ -17,9 +52,63 @@ pub trait Reader {
     type Item;
 
     fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed>;
+
+    /// 아이템을 페이지 단위로 읽는 이터레이터를 반환한다.
+    ///
+    /// # Description
+    /// 잡은 이전 페이지의 아이템을 모두 처리한 후 다음 페이지를 요청하므로, 이터레이터가 페이지를 지연 조회(lazy)할 경우 메모리에는 전체 데이터가 아닌 페이지 분량의 아이템만 유지된다.
+    /// 기본 구현은 `do_read`로 읽은 모든 아이템을 하나의 페이지로 반환한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::error::JobReadFailed;
+    /// use book_batch_rust::batch::{collect_pages, JobParameter, Reader};
+    ///
+    /// struct NumberReader;
+    /// impl Reader for NumberReader {
+    ///     type Item = i32;
+    ///
+    ///     fn do_read(&self, _: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+    ///         Ok(vec![1, 2, 3])
+    ///     }
+    /// }
+    ///
+    /// let params = JobParameter::new();
+    /// let pages = NumberReader.do_read_pages(&params).unwrap();
+    /// assert_eq!(collect_pages(pages).unwrap(), vec![1, 2, 3]);
+    /// ```
+    fn do_read_pages<'a>(&'a self, params: &'a JobParameter) -> Result<ReadPages<'a, Self::Item>, JobReadFailed>
+    where
+        Self::Item: 'a,
+    {
+        let items = self.do_read(params)?;
+        Ok(Box::new(std::iter::once(Ok(items))))
+    }
+}
+
+/// 페이지 단위로 읽은 아이템을 모두 읽어 하나의 `Vec`로 반환한다. 페이지를 읽는 중 실패한 경우 에러를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::collect_pages;
+/// use book_batch_rust::batch::error::JobReadFailed;
+///
+/// let pages = vec![Ok(vec![1, 2]), Ok(vec![]), Ok(vec![3])];
+/// assert_eq!(collect_pages(Box::new(pages.into_iter())).unwrap(), vec![1, 2, 3]);
+///
+/// let pages = vec![Ok(vec![1, 2]), Err(JobReadFailed::UnknownError("timeout".to_owned()))];
+/// assert!(collect_pages(Box::new(pages.into_iter())).is_err());
+/// ```
+pub fn collect_pages<T>(pages: ReadPages<'_, T>) -> Result<Vec<T>, JobReadFailed> {
+    let mut items = Vec::new();
+    for page in pages {
+        items.extend(page?);
+    }
+    Ok(items)
 }
 
 /// 배치잡 필터 트레이트 정해진 데이터를 `Vec`로 받아 유효한 데이터들만 반환한다.
+/// 리더가 여러 페이지로 아이템을 반환하는 경우 페이지마다 호출 되므로, 여러 페이지에 걸친 검사(중복 제거 등)는 리더에서 처리해야 한다.
 ///
 /// # Type
 /// - `Item`: 필터링할 데이터 타입
@@ -112,6 +201,18 @@ pub trait Processor {
     type Out;
 
     fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>>;
+
+    /// 한 청크의 데이터를 한번에 변환한다.
+    ///
+    /// # Description
+    /// 기본 구현은 [`Processor::do_process`]를 데이터마다 순서대로 호출하며 처음 실패한 데이터의 에러를 반환한다.
+    /// 외부 API에 여러 데이터를 한번에 요청하는 등 청크 단위로 처리하는 것이 효율적인 프로세서는 이 함수를 재정의 한다.
+    /// 반환하는 데이터는 입력된 순서와 동일한 순서여야 한다.
+    fn do_process_chunk(&self, items: Vec<Self::In>) -> Result<Vec<Self::Out>, JobProcessFailed<Self::In>> {
+        items.into_iter()
+            .map(|item| self.do_process(item))
+            .collect()
+    }
 }
 
 /// 두 개의 프로세서를 하나의 체인으로 결합하는 체인 프로세서 객체
@@ -190,6 +291,12 @@ impl <I, O, R> Processor for ProcessorChain<I, O, R> {
         self.second.do_process(first)
             .map_err(|err| JobProcessFailed::new_empty(err.to_string()))
     }
+
+    fn do_process_chunk(&self, items: Vec<Self::In>) -> Result<Vec<Self::Out>, JobProcessFailed<Self::In>> {
+        let first = self.first.do_process_chunk(items)?;
+        self.second.do_process_chunk(first)
+            .map_err(|err| JobProcessFailed::new_empty(err.to_string()))
+    }
 }
 
 /// 입력 타입과 출력 타입이 동일한 프로세서
@@ -223,6 +330,8 @@ impl<I> Processor for PhantomProcessor<I> {
 
 /// `Reader`, `Filter`, `Processor` 작업 이후 완성된 데이터들을 최종적으로 외부 저장소에 저장하는 트레이트
 /// `do_writer` 함수는 여러번 실행 될 수 있으며 각 실행은 독립적인 트랜잭션으로 실행 되어야 한다.
+/// 저장에 실패한 경우 에러([`JobWriteFailed`])에는 전달 받은 아이템 중 저장하지 못한 아이템을 모두 담아야 한다.
+/// 잡은 에러의 아이템을 저장되지 않은 아이템으로 보고 건너뛰기, 격리, 재시도에 사용한다.
 ///
 /// # Type
 /// - `Item`: 전달 받을 데이터 타입
@@ -232,7 +341,215 @@ pub trait Writer {
     fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>>;
 }
 
-const DEF_CHUNK_SIZE: usize = 500;
+/// 아이템을 저장하지 않는 라이터
+///
+/// # Description
+/// `--dry-run`으로 실행한 잡의 라이터를 대신하여([`Job::with_dry_run`]) 전달 받은 아이템의 개수만 로그로 남기고 저장에 성공한 것으로 처리한다.
+///
+/// # Type
+/// - `T`: 전달 받을 데이터 타입
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::{DryRunWriter, Writer};
+///
+/// let writer = DryRunWriter::<i32>::new();
+/// assert!(writer.do_write(vec![1, 2, 3]).is_ok());
+/// ```
+pub struct DryRunWriter<T> {
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T> DryRunWriter<T> {
+    pub fn new() -> Self {
+        DryRunWriter {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> Default for DryRunWriter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Writer for DryRunWriter<T> {
+    type Item = T;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        info!("{} items are not written (dry run)", items.len());
+        Ok(())
+    }
+}
+
+/// 잡의 기본 청크 사이즈
+pub const DEF_CHUNK_SIZE: usize = 500;
+
+/// [`JobParameter`]에서 `chunk_size`를 키로 사용하여 청크 사이즈를 얻어온다.
+/// 만약 `JobParameter`에 청크 사이즈가 없을 경우 [`None`]을 반환한다.
+///
+/// 청크 사이즈는 1 이상의 숫자여야 하며 그렇지 않을 경우 `JobBuildError` 에러를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::{retrieve_chunk_size_in_parameter, JobParameter};
+///
+/// let mut parameter = JobParameter::new();
+/// assert_eq!(retrieve_chunk_size_in_parameter(&parameter).unwrap(), None);
+///
+/// parameter.insert("chunk_size".to_owned(), "100".to_owned());
+/// assert_eq!(retrieve_chunk_size_in_parameter(&parameter).unwrap(), Some(100));
+///
+/// parameter.insert("chunk_size".to_owned(), "0".to_owned());
+/// assert!(retrieve_chunk_size_in_parameter(&parameter).is_err());
+/// ```
+pub fn retrieve_chunk_size_in_parameter(params: &JobParameter) -> Result<Option<usize>, JobBuildError> {
+    let chunk_size = match params.get(PARAM_NAME_CHUNK_SIZE) {
+        Some(chunk_size) => chunk_size,
+        None => return Ok(None),
+    };
+
+    let chunk_size = chunk_size.trim().parse::<usize>()
+        .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_CHUNK_SIZE, e)))?;
+    if chunk_size == 0 {
+        return Err(JobBuildError::InvalidParameter(format!("{} must be greater than 0", PARAM_NAME_CHUNK_SIZE)));
+    }
+
+    Ok(Some(chunk_size))
+}
+
+/// [`JobParameter`]에서 `spill_threshold`를 키로 사용하여 디스크로 옮길 아이템 개수 기준을 얻어온다.
+/// 만약 `JobParameter`에 기준이 없을 경우 [`None`]을 반환한다.
+///
+/// 기준은 1 이상의 숫자여야 하며 그렇지 않을 경우 `JobBuildError` 에러를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::{retrieve_spill_threshold_in_parameter, JobParameter};
+///
+/// let mut parameter = JobParameter::new();
+/// assert_eq!(retrieve_spill_threshold_in_parameter(&parameter).unwrap(), None);
+///
+/// parameter.insert("spill_threshold".to_owned(), "10000".to_owned());
+/// assert_eq!(retrieve_spill_threshold_in_parameter(&parameter).unwrap(), Some(10000));
+///
+/// parameter.insert("spill_threshold".to_owned(), "0".to_owned());
+/// assert!(retrieve_spill_threshold_in_parameter(&parameter).is_err());
+/// ```
+pub fn retrieve_spill_threshold_in_parameter(params: &JobParameter) -> Result<Option<usize>, JobBuildError> {
+    let threshold = match params.get(PARAM_NAME_SPILL_THRESHOLD) {
+        Some(threshold) => threshold,
+        None => return Ok(None),
+    };
+
+    let threshold = threshold.trim().parse::<usize>()
+        .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_SPILL_THRESHOLD, e)))?;
+    if threshold == 0 {
+        return Err(JobBuildError::InvalidParameter(format!("{} must be greater than 0", PARAM_NAME_SPILL_THRESHOLD)));
+    }
+
+    Ok(Some(threshold))
+}
+
+/// [`JobParameter`]에서 처리, 저장에 실패한 아이템을 건너뛸 최대 개수(`skip_limit`)를 얻는다. 파라미터가 없을 경우 `None`을 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::{retrieve_skip_limit_in_parameter, JobParameter};
+///
+/// let mut parameter = JobParameter::new();
+/// assert_eq!(retrieve_skip_limit_in_parameter(&parameter).unwrap(), None);
+///
+/// parameter.insert("skip_limit".to_owned(), "10".to_owned());
+/// assert_eq!(retrieve_skip_limit_in_parameter(&parameter).unwrap(), Some(10));
+///
+/// parameter.insert("skip_limit".to_owned(), "ten".to_owned());
+/// assert!(retrieve_skip_limit_in_parameter(&parameter).is_err());
+/// ```
+pub fn retrieve_skip_limit_in_parameter(params: &JobParameter) -> Result<Option<usize>, JobBuildError> {
+    params.get(PARAM_NAME_SKIP_LIMIT)
+        .map(|limit| limit.trim().parse::<usize>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_SKIP_LIMIT, e))))
+        .transpose()
+}
+
+/// [`JobParameter`]에 `dry_run`이 `true`로 설정 되어 있는지 여부
+/// 미리보기 실행의 경우 잡의 라이터를 저장하지 않는 라이터([`DryRunWriter`])로 바꾸며, 도서, 시리즈 저장소는 읽기 전용으로 사용한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::{is_dry_run, JobParameter};
+///
+/// let mut parameter = JobParameter::new();
+/// assert!(!is_dry_run(&parameter));
+///
+/// parameter.insert("dry_run".to_owned(), "true".to_owned());
+/// assert!(is_dry_run(&parameter));
+/// ```
+pub fn is_dry_run(params: &JobParameter) -> bool {
+    params.get(PARAM_NAME_DRY_RUN)
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"))
+}
+
+/// 잡 실행 결과
+///
+/// # Description
+/// 건너뛰기가 설정된 잡([`Job::with_skip_limit`])에서 처리, 저장에 실패하여 건너뛴 아이템의 에러를 담는다.
+/// 처리 실패 에러에는 실패한 입력 아이템이, 저장 실패 에러에는 저장 되지 않은 아이템과 격리 파일 경로(격리가 설정된 경우)가 담겨 있으므로 이를 이용해 다시 실행한다.
+pub struct JobResult<I, O> {
+    /// 처리에 실패하여 건너뛴 아이템의 에러
+    pub process_skipped: Vec<JobProcessFailed<I>>,
+
+    /// 저장에 실패하여 건너뛴 청크의 에러
+    pub write_skipped: Vec<JobWriteFailed<O>>,
+}
+
+impl<I, O> Default for JobResult<I, O> {
+    fn default() -> Self {
+        Self { process_skipped: Vec::new(), write_skipped: Vec::new() }
+    }
+}
+
+impl<I, O> JobResult<I, O> {
+
+    /// 건너뛴 아이템이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.process_skipped.is_empty() && self.write_skipped.is_empty()
+    }
+
+    /// 건너뛴 아이템의 개수, 저장에 실패한 청크는 에러에 담긴 아이템 수(최소 1개)로 센다.
+    pub fn skip_count(&self) -> usize {
+        self.process_skipped.len() + self.write_skipped.iter().map(write_skip_count).sum::<usize>()
+    }
+
+    /// 처리에 실패하여 건너뛴 입력 아이템과 저장에 실패하여 건너뛴 아이템을 반환한다.
+    ///
+    /// # Note
+    /// 실패한 아이템을 담지 않은 처리 실패 에러([`JobProcessFailed::new_empty`])의 아이템은 포함 되지 않는다.
+    pub fn into_skipped_items(self) -> (Vec<I>, Vec<O>) {
+        let processed = self.process_skipped.into_iter()
+            .filter_map(|e| e.into_item())
+            .collect();
+        let written = self.write_skipped.into_iter()
+            .flat_map(|e| e.into_item())
+            .collect();
+        (processed, written)
+    }
+}
+
+impl<I, O> std::fmt::Debug for JobResult<I, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JobResult")
+            .field("process_skipped", &self.process_skipped)
+            .field("write_skipped", &self.write_skipped)
+            .finish()
+    }
+}
+
+fn write_skip_count<O>(e: &JobWriteFailed<O>) -> usize {
+    e.item().len().max(1)
+}
 
 pub struct Job<I, O> {
     reader: Box<dyn Reader<Item = I>>,
@@ -248,6 +565,49 @@ pub struct Job<I, O> {
     /// # Note
     /// 이 값이 0 아하로 설정된 상태에서 `run`함수 호출시 패닉이 발생함으로 반드시 1 이상 값으로 설정해야 한다.
     chunk_size: usize,
+
+    /// 읽은 아이템을 처리 전 디스크로 옮기는 함수
+    ///
+    /// # Description
+    /// 설정된 경우 `reader`로 읽고 `filter`를 거친 아이템을 전달하여 청크 처리에 사용할 이터레이터를 얻는다.
+    /// [`Job::with_spill_threshold`]로 설정한다.
+    spill: Option<Box<dyn Fn(Vec<I>) -> Box<dyn Iterator<Item = I>>>>,
+
+    /// 아이템의 추적 아이디를 만들 ISBN을 반환하는 함수
+    ///
+    /// # Description
+    /// 설정된 경우 아이템마다 추적 아이디([`trace::traced`])를 부여하여 처리한다. [`Job::with_trace_key`]로 설정한다.
+    trace_key: Option<Box<dyn Fn(&I) -> String>>,
+
+    /// 저장에 실패한 청크의 아이템들을 격리 파일로 기록하고 파일 경로를 반환하는 함수
+    ///
+    /// # Description
+    /// 설정된 경우 `writer`가 실패한 청크를 격리 파일에 기록하고 파일 경로를 에러에 담아 반환한다. [`Job::with_quarantine`]로 설정한다.
+    quarantine: Option<Box<dyn Fn(&JobWriteFailed<O>) -> Option<String>>>,
+
+    /// 처리, 저장 실패 재시도 정책과 재시도할 때 입력 아이템을 복제하는 함수
+    ///
+    /// # Description
+    /// 설정된 경우 `processor`와 `writer`가 재시도 가능한 에러로 실패하면 정책에 따라 대기 후 다시 시도한다. [`Job::with_retry_policy`]로 설정한다.
+    retry: Option<(RetryPolicy, fn(&I) -> I)>,
+
+    /// 처리, 저장에 실패한 아이템을 건너뛸 최대 개수 (0인 경우 건너뛰지 않고 잡을 실패시킨다.)
+    ///
+    /// # Description
+    /// [`Job::with_skip_limit`]로 설정하며 `skip_limit` 파라미터가 입력된 경우 파라미터를 우선한다.
+    skip_limit: usize,
+
+    /// 저장을 마친 청크의 순번을 기록하는 체크포인트
+    ///
+    /// # Description
+    /// 설정된 경우 `resume` 파라미터로 중단된 실행을 이어서 실행할 수 있다. [`Job::with_checkpoint`]로 설정한다.
+    checkpoint: Option<Checkpoint>,
+
+    /// 격리 전 아이템의 격리 횟수를 기록하고 최대 횟수를 넘은 아이템을 격리 대상에서 제외하는 함수
+    ///
+    /// # Description
+    /// 격리가 설정된 경우에만 사용하며 [`Job::with_dead_letter`]로 설정한다.
+    dead_letter: Option<Box<dyn Fn(JobWriteFailed<O>) -> JobWriteFailed<O>>>,
 }
 
 impl<I, O> Job<I, O>  {
@@ -256,36 +616,438 @@ impl<I, O> Job<I, O>  {
         self
     }
 
-    pub fn run(&self, params: &JobParameter) -> Result<(), JobRuntimeError<I, O>> {
-        let items = self.reader.do_read(params)
+    /// 아이템의 ISBN을 반환하는 함수를 설정하여 아이템마다 추적 아이디를 부여한다.
+    ///
+    /// # Description
+    /// 아이템은 추적 아이디의 스팬 안에서 하나씩 `processor`로 처리되며, 저장 후에는 추적 아이디별로 저장 완료 로그를 남긴다.
+    /// 처리나 저장에 실패한 경우 실패한 아이템의 추적 아이디를 에러 로그로 남긴다.
+    ///
+    /// # Note
+    /// 아이템을 하나씩 처리하므로 [`Processor::do_process_chunk`]를 재정의 한 프로세서를 사용하는 잡에는 설정하지 않는다.
+    pub fn with_trace_key<F: Fn(&I) -> String + 'static>(mut self, trace_key: F) -> Self {
+        self.trace_key = Some(Box::new(trace_key));
+        self
+    }
+
+    /// 처리, 저장에 실패한 아이템을 `limit`개 까지 건너뛰도록 설정한다.
+    ///
+    /// # Description
+    /// `processor`가 실패한 아이템은 경고 로그를 남기고 청크에서 제외하며, `writer`가 실패한 청크는 격리(설정된 경우)한 후 다음 청크를 처리한다.
+    /// 건너뛴 아이템의 합계가 `limit`를 넘는 경우 마지막 에러로 잡을 실패시키며, 건너뛴 아이템은 잡 실행 결과([`JobResult`])로 반환한다.
+    ///
+    /// # Note
+    /// 실패한 아이템을 구분하기 위해 아이템을 하나씩 처리하므로 [`Processor::do_process_chunk`]를 재정의 한 프로세서는 청크 단위로 처리하지 않는다.
+    pub fn with_skip_limit(mut self, limit: usize) -> Self {
+        self.skip_limit = limit;
+        self
+    }
+
+    /// `writer`를 아이템을 저장하지 않는 라이터([`DryRunWriter`])로 바꾼다.
+    ///
+    /// # Description
+    /// `--dry-run`으로 실행한 잡에 설정하여 읽기, 필터, 처리는 그대로 실행하고 저장소는 변경하지 않는다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::cancel::CancellationToken;
+    /// use book_batch_rust::batch::error::{JobReadFailed, JobWriteFailed};
+    /// use book_batch_rust::batch::{job_builder, JobParameter, Reader, Writer};
+    ///
+    /// struct NumberReader;
+    /// impl Reader for NumberReader {
+    ///     type Item = i32;
+    ///
+    ///     fn do_read(&self, _: &JobParameter) -> Result<Vec<i32>, JobReadFailed> {
+    ///         Ok(vec![1, 2, 3])
+    ///     }
+    /// }
+    ///
+    /// struct PanicWriter;
+    /// impl Writer for PanicWriter {
+    ///     type Item = i32;
+    ///
+    ///     fn do_write(&self, _: Vec<i32>) -> Result<(), JobWriteFailed<i32>> {
+    ///         panic!("must not be written")
+    ///     }
+    /// }
+    ///
+    /// let job = job_builder()
+    ///     .reader(Box::new(NumberReader))
+    ///     .writer(Box::new(PanicWriter))
+    ///     .build()
+    ///     .with_dry_run();
+    /// assert!(job.run(&JobParameter::new(), &CancellationToken::new()).is_ok());
+    /// ```
+    pub fn with_dry_run(mut self) -> Self
+    where
+        O: 'static,
+    {
+        self.writer = Box::new(DryRunWriter::new());
+        self
+    }
+
+    /// 청크의 저장을 마칠 때 마다 체크포인트에 청크의 순번을 기록하도록 설정한다.
+    ///
+    /// # Description
+    /// `resume` 파라미터가 `true`인 경우 같은 파라미터로 실행했던 이전 실행의 체크포인트까지의 청크는 처리하지 않고 건너뛰며, 정상 종료된 경우 체크포인트를 삭제한다.
+    /// 건너뛰기가 설정된 경우 저장에 실패하여 건너뛴 청크도 저장을 마친 것으로 기록한다.
+    /// 청크 사이즈 보다 작은 청크는 페이지를 읽는 중 실패하여 잘린 청크일 수 있으므로 기록하지 않는다.
+    ///
+    /// # Note
+    /// 청크의 순번으로 이어서 실행하므로 같은 파라미터로 실행했을 때 `reader`와 `filter`가 같은 아이템을 같은 순서로 반환하는 잡에만 설정한다.
+    /// [`Job::run_items`]로 실행하는 경우 체크포인트를 사용하지 않는다.
+    pub fn with_checkpoint(mut self, checkpoint: Checkpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// 잡을 실행한다.
+    ///
+    /// # Description
+    /// `reader`로 아이템을 읽기 전과 청크를 처리하기 전 마다 `cancel`의 취소 여부를 확인하고,
+    /// 취소된 경우 남은 아이템을 처리하지 않고 [`JobRuntimeError::Cancelled`]를 반환한다.
+    /// `skip_limit` 파라미터가 입력된 경우 잡에 설정된 건너뛰기 개수 대신 사용한다. ([`Job::with_skip_limit`])
+    /// 읽고, 처리하고, 저장하고, 건너뛴 아이템 수는 실행 기록에 남기기 위해 [`metrics::record_item_counts`]로 기록한다.
+    /// 체크포인트가 설정된 경우 `resume` 파라미터로 이전 실행에서 저장을 마친 청크를 건너뛴다. ([`Job::with_checkpoint`])
+    ///
+    /// 아이템은 `reader`에서 페이지 단위로 읽으며([`Reader::do_read_pages`]) 이전 페이지의 아이템을 청크로 모두 처리한 후 다음 페이지를 읽는다.
+    /// `filter`는 페이지마다 실행하며, 페이지를 읽는 중 실패한 경우 이전 페이지까지 처리한 후 [`JobRuntimeError::ReadFailed`]를 반환한다.
+    /// 디스크로 옮길 기준이 설정된 경우([`Job::with_spill_threshold`]) 모든 페이지를 읽은 후 처리한다.
+    pub fn run(&self, params: &JobParameter, cancel: &CancellationToken) -> Result<JobResult<I, O>, JobRuntimeError<I, O>> {
+        if cancel.is_cancelled() {
+            return Err(JobRuntimeError::Cancelled);
+        }
+        let skip_limit = retrieve_skip_limit_in_parameter(params)
+            .map_err(|e| JobRuntimeError::ReadFailed(JobReadFailed::InvalidArguments(e.to_string())))?
+            .unwrap_or(self.skip_limit);
+
+        let pages = self.reader.do_read_pages(params)
             .map_err(|e| JobRuntimeError::ReadFailed(e))?;
+        let checkpoint = self.checkpoint.as_ref().map(|checkpoint| checkpoint.start(params));
+
+        if let Some(spill) = &self.spill {
+            let items = collect_pages(pages)
+                .map_err(|e| JobRuntimeError::ReadFailed(e))?;
+            metrics::record_item_counts(&JobExecutionCounts { read: items.len(), ..Default::default() });
 
-        let items: Vec<I> = if let Some(filter) = &self.filter {
+            let result = self.run_chunks(spill(self.filter_page(items)), cancel, skip_limit, checkpoint.as_ref())?;
+            if let Some(checkpoint) = &checkpoint {
+                checkpoint.clear();
+            }
+            return Ok(result);
+        }
+
+        let mut read = 0;
+        let mut read_failed = None;
+        let items = pages
+            .map_while(|page| page.map_err(|e| read_failed = Some(e)).ok())
+            .inspect(|page| read += page.len())
+            .flat_map(|page| self.filter_page(page));
+        let result = self.run_chunks(items, cancel, skip_limit, checkpoint.as_ref());
+        metrics::record_item_counts(&JobExecutionCounts { read, ..Default::default() });
+
+        let result = result?;
+        if let Some(e) = read_failed {
+            error!("Failed to read next page after {} item(s) read: {}", read, e);
+            return Err(JobRuntimeError::ReadFailed(e));
+        }
+        if let Some(checkpoint) = &checkpoint {
+            checkpoint.clear();
+        }
+        Ok(result)
+    }
+
+    fn filter_page(&self, items: Vec<I>) -> Vec<I> {
+        if let Some(filter) = &self.filter {
             filter.do_filter(items)
         } else {
             items
-        };
+        }
+    }
 
-        if self.chunk_size == 1 {
-            items.into_iter()
-                .try_for_each(|item| self.run_task(vec![item]))
-        } else {
-            chunk_with_owned(items, self.chunk_size).into_iter()
-                .try_for_each(|chunk| self.run_task(chunk))
+    /// 전달 받은 아이템들을 청크 단위로 `processor`, `writer`에 전달한다.
+    ///
+    /// # Description
+    /// 아이템은 이터레이터에서 청크 사이즈 만큼씩 꺼내어 처리하며, 한 청크의 처리 결과를 `writer`에 전달한 후 다음 청크를 꺼낸다.
+    /// 따라서 이터레이터가 아이템을 지연 생성(lazy)할 경우 메모리에는 최대 한 청크 분량의 아이템과 처리 결과만 유지된다.
+    ///
+    /// # Note
+    /// 이 함수는 `reader`와 `filter`를 사용하지 않음으로 필요한 경우 호출하는 쪽에서 미리 수행해야 한다.
+    pub fn run_items<T>(&self, items: T, cancel: &CancellationToken) -> Result<JobResult<I, O>, JobRuntimeError<I, O>>
+    where
+        T: IntoIterator<Item = I>,
+    {
+        let mut read = 0;
+        let result = self.run_chunks(items.into_iter().inspect(|_| read += 1), cancel, self.skip_limit, None);
+        metrics::record_item_counts(&JobExecutionCounts { read, ..Default::default() });
+        result
+    }
+
+    fn run_chunks<T>(&self, items: T, cancel: &CancellationToken, skip_limit: usize, checkpoint: Option<&CheckpointRun>) -> Result<JobResult<I, O>, JobRuntimeError<I, O>>
+    where
+        T: IntoIterator<Item = I>,
+    {
+        if self.chunk_size == 0 {
+            panic!("chunk size must be greater than 0");
         }
+
+        let mut result = JobResult::default();
+        let mut items = items.into_iter().peekable();
+        let mut index = 0;
+        let mut resumed = 0;
+        while items.peek().is_some() {
+            if cancel.is_cancelled() {
+                warn!("Job cancelled, remaining items are skipped");
+                return Err(JobRuntimeError::Cancelled);
+            }
+            if checkpoint.is_some_and(|checkpoint| checkpoint.is_written(index)) {
+                resumed += items.by_ref().take(self.chunk_size).count();
+                index += 1;
+                continue;
+            }
+            let chunk: Vec<I> = items.by_ref().take(self.chunk_size).collect();
+            let is_full = chunk.len() == self.chunk_size;
+            self.run_task(chunk.into_iter(), &mut result, skip_limit)?;
+
+            // 청크 사이즈 보다 작은 청크는 마지막 청크거나 페이지를 읽는 중 실패하여 잘린 청크이므로 기록하지 않는다.
+            if let Some(checkpoint) = checkpoint.filter(|_| is_full) {
+                checkpoint.save(index);
+            }
+            index += 1;
+        }
+        if resumed > 0 {
+            info!("{} item(s) of chunks written before checkpoint are skipped", resumed);
+        }
+        if !result.is_empty() {
+            warn!("{} item(s) skipped (limit: {})", result.skip_count(), skip_limit);
+        }
+        Ok(result)
     }
 
-    fn run_task(&self, items: Vec<I>) -> Result<(), JobRuntimeError<I, O>> {
+    fn run_task<T>(&self, items: T, result: &mut JobResult<I, O>, skip_limit: usize) -> Result<(), JobRuntimeError<I, O>>
+    where
+        T: Iterator<Item = I>,
+    {
+        // 추적 아이디와 건너뛰기를 사용하지 않는 경우 청크 단위로 처리한다.
+        if self.trace_key.is_none() && skip_limit == 0 {
+            let targets = self.process_chunk(items.collect())
+                .map_err(|e| JobRuntimeError::ProcessFailed(e))?;
+            metrics::record_item_counts(&JobExecutionCounts { processed: targets.len(), ..Default::default() });
+            return self.write_or_skip(targets, Vec::new(), result, skip_limit);
+        }
+
+        let mut trace_ids = Vec::new();
         let mut targets = Vec::new();
         for item in items {
-            let target = self.processor.do_process(item)
-                .map_err(|e| JobRuntimeError::ProcessFailed(e))?;
-            targets.push(target);
+            let isbn = self.trace_key.as_ref().map(|trace_key| trace_key(&item));
+            let processed = match &isbn {
+                Some(isbn) => trace::traced(isbn, || {
+                    debug!("Item read");
+                    self.process_item(item)
+                        .inspect_err(|e| error!("Item process failed: {}", e))
+                }),
+                None => self.process_item(item),
+            };
+            match processed {
+                Ok(target) => {
+                    if let Some(isbn) = isbn {
+                        trace_ids.push(trace::item_trace_id(&isbn));
+                    }
+                    targets.push(target);
+                }
+                Err(e) if result.skip_count() < skip_limit => {
+                    warn!("Item process skipped({}/{}): {}", result.skip_count() + 1, skip_limit, e);
+                    metrics::record_item_counts(&JobExecutionCounts { skipped: 1, ..Default::default() });
+                    result.process_skipped.push(e);
+                }
+                Err(e) => return Err(JobRuntimeError::ProcessFailed(e)),
+            }
+        }
+        metrics::record_item_counts(&JobExecutionCounts { processed: targets.len(), ..Default::default() });
+
+        self.write_or_skip(targets, trace_ids, result, skip_limit)
+    }
+
+    /// 청크를 저장한다. 저장에 실패한 경우 건너뛸 수 있으면 실패한 청크를 실행 결과에 담고, 그렇지 않은 경우 에러를 반환한다.
+    fn write_or_skip(&self, targets: Vec<O>, trace_ids: Vec<String>, result: &mut JobResult<I, O>, skip_limit: usize) -> Result<(), JobRuntimeError<I, O>> {
+        let size = targets.len();
+        if let Err(e) = self.write_chunk(targets) {
+            if !trace_ids.is_empty() {
+                error!("Chunk write failed({}), trace ids: {}", e.message(), trace_ids.join(","));
+            }
+            let e = self.quarantine_failed(e);
+            let skip_count = result.skip_count() + write_skip_count(&e);
+            if skip_count > skip_limit {
+                return Err(JobRuntimeError::WriteFailed(e));
+            }
+            warn!("Chunk write skipped({}/{}): {}", skip_count, skip_limit, e);
+            metrics::record_item_counts(&JobExecutionCounts { skipped: write_skip_count(&e), ..Default::default() });
+            result.write_skipped.push(e);
+            return Ok(());
+        }
+        metrics::record_item_counts(&JobExecutionCounts { written: size, ..Default::default() });
+        for trace_id in trace_ids {
+            debug!(trace_id = %trace_id, "Item written");
         }
-        self.writer.do_write(targets)
-            .map_err(|e| JobRuntimeError::WriteFailed(e))?;
         Ok(())
     }
+
+    /// 청크를 `processor`로 처리한다. 재시도 정책이 설정된 경우 시도할 때 마다 아이템을 복제하여 전달한다.
+    fn process_chunk(&self, items: Vec<I>) -> Result<Vec<O>, JobProcessFailed<I>> {
+        match &self.retry {
+            Some((policy, clone)) => policy.retry("Chunk process", || {
+                self.processor.do_process_chunk(items.iter().map(clone).collect())
+            }),
+            None => self.processor.do_process_chunk(items),
+        }
+    }
+
+    /// 아이템을 `processor`로 처리한다. 재시도 정책이 설정된 경우 시도할 때 마다 아이템을 복제하여 전달한다.
+    fn process_item(&self, item: I) -> Result<O, JobProcessFailed<I>> {
+        match &self.retry {
+            Some((policy, clone)) => policy.retry("Item process", || self.processor.do_process(clone(&item))),
+            None => self.processor.do_process(item),
+        }
+    }
+
+    /// 청크를 `writer`로 저장한다. 재시도 정책이 설정된 경우 에러에 담긴 아이템들로 다시 저장한다.
+    fn write_chunk(&self, targets: Vec<O>) -> Result<(), JobWriteFailed<O>> {
+        let policy = match &self.retry {
+            Some((policy, _)) => policy,
+            None => return self.writer.do_write(targets),
+        };
+
+        let mut targets = targets;
+        let mut attempt = 1;
+        loop {
+            match self.writer.do_write(targets) {
+                Err(e) if !e.item().is_empty() && policy.should_retry(attempt, e.message()) => {
+                    policy.wait("Chunk write", attempt, e.message());
+                    targets = e.into_item();
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// 격리가 설정된 경우 저장에 실패한 청크의 아이템들을 격리하고 격리 파일 경로를 에러에 담는다.
+    /// 격리 횟수 기록이 설정된 경우 최대 횟수를 넘은 아이템을 제외하고 격리하며, 제외 후 남은 아이템이 없는 경우 격리 파일을 만들지 않는다.
+    fn quarantine_failed(&self, e: JobWriteFailed<O>) -> JobWriteFailed<O> {
+        let Some(quarantine) = &self.quarantine else {
+            return e;
+        };
+        let e = match &self.dead_letter {
+            Some(dead_letter) => {
+                let e = dead_letter(e);
+                if e.item().is_empty() {
+                    return e;
+                }
+                e
+            }
+            None => e,
+        };
+        match quarantine(&e) {
+            Some(path) => e.with_quarantine(path),
+            None => e,
+        }
+    }
+}
+
+impl<I: Clone, O> Job<I, O> {
+
+    /// 처리, 저장 실패 재시도 정책을 설정한다.
+    ///
+    /// # Description
+    /// `processor`가 재시도 가능한 에러로 실패한 경우 입력 아이템을 복제하여 다시 처리하고,
+    /// `writer`가 실패한 경우 에러에 담긴 아이템들([`JobWriteFailed::item`])로 다시 저장한다. 최대 시도 횟수를 모두 사용한 경우 마지막 에러로 잡을 실패시킨다.
+    ///
+    /// # Note
+    /// 청크의 일부만 저장된 후 실패할 수 있으므로 재시도 정책은 같은 아이템을 여러번 저장해도 결과가 같은(멱등) `writer`를 사용하는 잡에만 설정한다.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some((policy, I::clone));
+        self
+    }
+}
+
+impl<I, O: Serialize + DeserializeOwned + 'static> Job<I, O> {
+
+    /// `writer`가 저장에 실패한 청크의 아이템들을 격리 파일([`quarantine::quarantine`])로 기록하도록 설정한다.
+    ///
+    /// # Description
+    /// 격리 파일의 경로는 실패 에러와 잡 실행 기록의 변경 내역에 남으며, 원인을 해결한 후 [`Job::replay`]로 다시 저장한다.
+    pub fn with_quarantine(mut self, job: JobName) -> Self {
+        self.quarantine = Some(Box::new(move |failed: &JobWriteFailed<O>| quarantine::quarantine(&job, failed)));
+        self
+    }
+
+    /// 격리 전 저장에 실패한 아이템의 격리 횟수를 기록하여 여러 실행에서 같은 아이템이 같은 에러로 반복해서 격리 되지 않도록 설정한다.
+    ///
+    /// # Description
+    /// 아이템의 ISBN을 반환하는 함수(`key`)로 잡, ISBN, 에러 유형별 격리 횟수를 기록하며, 최대 횟수를 넘은 아이템은 격리 파일에 기록하지 않는다. ([`DeadLetter::filter`])
+    /// 격리([`Job::with_quarantine`])가 설정된 잡에서만 사용한다.
+    pub fn with_dead_letter<F: Fn(&O) -> String + 'static>(mut self, dead_letter: DeadLetter, key: F) -> Self {
+        self.dead_letter = Some(Box::new(move |failed: JobWriteFailed<O>| dead_letter.filter(failed, &key)));
+        self
+    }
+
+    /// 격리 파일에 기록된 아이템들을 `reader`, `processor`를 거치지 않고 청크 단위로 `writer`에 다시 전달한다.
+    ///
+    /// # Description
+    /// 청크를 전달하기 전 마다 `cancel`의 취소 여부를 확인한다.
+    /// 다시 저장에 실패한 경우 새로운 격리 파일을 만들지 않고 에러를 반환하므로 같은 격리 파일로 다시 시도할 수 있다.
+    ///
+    /// # Errors
+    /// 격리 파일을 읽을 수 없는 경우 [`JobRuntimeError::ReadFailed`]를 반환한다.
+    pub fn replay(&self, path: &Path, cancel: &CancellationToken) -> Result<JobResult<I, O>, JobRuntimeError<I, O>> {
+        let (header, items) = quarantine::read::<O>(path)
+            .map_err(|e| JobRuntimeError::ReadFailed(JobReadFailed::InvalidArguments(format!("{}: {}", path.display(), e))))?;
+        info!("{} => Replaying {} quarantined items (run: {}, reason: {})",
+            header.job, items.len(), header.run_id.as_deref().unwrap_or("unknown"), header.message);
+        for (index, cause) in header.causes.iter().enumerate() {
+            info!("{} => Quarantined item #{} failed: {}", header.job, index, cause);
+        }
+
+        for chunk in owned_chunks(items, self.chunk_size) {
+            if cancel.is_cancelled() {
+                warn!("Replay cancelled, remaining items are skipped");
+                return Err(JobRuntimeError::Cancelled);
+            }
+            self.writer.do_write(chunk)
+                .map_err(|e| JobRuntimeError::WriteFailed(e))?;
+        }
+        Ok(JobResult::default())
+    }
+}
+
+impl<I: Serialize + DeserializeOwned + 'static, O> Job<I, O> {
+
+    /// 읽은 아이템의 개수가 `threshold`를 넘을 경우 아이템을 디스크의 임시 큐([`SpillQueue`])로 옮긴 후 처리하도록 설정한다.
+    ///
+    /// # Description
+    /// 기간이 매우 긴 수집 등 읽은 아이템이 많을 때 처리가 끝날 때 까지 모든 아이템을 메모리에 유지하지 않도록 하여,
+    /// 메모리가 작은 배치 서버에서도 처리 중 사용하는 메모리를 청크 사이즈 수준으로 제한한다.
+    ///
+    /// # Note
+    /// 디스크로 옮기지 못한 경우 경고 로그를 남기고 메모리에서 그대로 처리한다.
+    pub fn with_spill_threshold(mut self, threshold: usize) -> Self {
+        self.spill = Some(Box::new(move |items: Vec<I>| -> Box<dyn Iterator<Item = I>> {
+            if items.len() <= threshold {
+                return Box::new(items.into_iter());
+            }
+            match SpillQueue::write(&items) {
+                Ok(queue) => {
+                    info!("{} items exceeded spill threshold({}), items are spilled to disk", queue.len(), threshold);
+                    Box::new(queue.into_iter())
+                }
+                Err(e) => {
+                    warn!("Failed to spill items to disk, items are processed in memory: {}", e);
+                    Box::new(items.into_iter())
+                }
+            }
+        }));
+        self
+    }
 }
 
 /// 백터를 지정된 크기의 청크들로 분활 한다.
@@ -302,18 +1064,62 @@ impl<I, O> Job<I, O>  {
 /// let chunks = chunk_with_owned(vec, 2);
 /// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
 /// ```
-pub fn chunk_with_owned<T>(mut vec: Vec<T>, size: usize) -> Vec<Vec<T>> {
-    if size <= 0 {
+pub fn chunk_with_owned<T>(vec: Vec<T>, size: usize) -> Vec<Vec<T>> {
+    owned_chunks(vec, size).collect()
+}
+
+/// 전달 받은 데이터를 지정된 크기의 청크로 나누어 반환하는 이터레이터를 생성한다.
+///
+/// # Description
+/// [`chunk_with_owned`]와 동일하게 각 청크가 요소들의 소유권을 가지지만 모든 청크를 미리 만들어 두지 않고
+/// 이터레이터가 진행될 때마다 하나의 청크를 만든다. 원본 데이터는 앞에서부터 이동(move)만 하기 때문에
+/// 청크를 만들 때 남은 요소들을 앞으로 당기는 복사가 발생하지 않는다.
+///
+/// # Panic
+/// - `size`가 0보다 작거나 같을 경우
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::owned_chunks;
+///
+/// let mut chunks = owned_chunks(vec![1, 2, 3, 4, 5], 2);
+/// assert_eq!(chunks.next(), Some(vec![1, 2]));
+/// assert_eq!(chunks.next(), Some(vec![3, 4]));
+/// assert_eq!(chunks.next(), Some(vec![5]));
+/// assert_eq!(chunks.next(), None);
+/// ```
+pub fn owned_chunks<I: IntoIterator>(items: I, size: usize) -> OwnedChunks<I::IntoIter> {
+    if size == 0 {
         panic!("size must be greater than 0");
     }
 
-    let mut chunks = Vec::new();
-    while vec.len() > 0 {
-        let size = std::cmp::min(size, vec.len());
-        let chunk = vec.drain(..size).collect::<Vec<_>>();
-        chunks.push(chunk);
+    OwnedChunks { iter: items.into_iter(), size }
+}
+
+/// 소유권을 가진 청크를 순서대로 반환하는 이터레이터
+///
+/// # Description
+/// [`owned_chunks`] 함수로 생성하며 내부 이터레이터에서 `size` 개수 만큼 요소를 꺼내 하나의 청크로 반환한다.
+/// 마지막 청크는 `size`보다 작을 수 있다.
+pub struct OwnedChunks<I: Iterator> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for OwnedChunks<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let capacity = std::cmp::min(self.size, self.iter.size_hint().0);
+        let mut chunk = Vec::with_capacity(capacity);
+        chunk.extend(self.iter.by_ref().take(self.size));
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
     }
-    chunks
 }
 
 pub fn job_builder<I>() -> ReaderBuildStep<I> {
@@ -410,6 +1216,13 @@ impl <I, O> WriterBuildStep<I, O> {
             processor: self.processor,
             writer: self.writer,
             chunk_size: DEF_CHUNK_SIZE,
+            spill: None,
+            trace_key: None,
+            quarantine: None,
+            retry: None,
+            skip_limit: 0,
+            checkpoint: None,
+            dead_letter: None,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,392 @@
+use crate::clock::system_clock;
+use crate::item::{Book, Series, SeriesLink};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::{env, fs};
+use std::path::{Path, PathBuf};
+use tracing::{error, info};
+
+/// 변경 내역별로 기록할 최대 샘플 수
+pub const AUDIT_SAMPLE_LIMIT: usize = 20;
+
+/// 변경 내역 파일을 저장할 기본 디렉토리
+pub const DEFAULT_AUDIT_DIR: &str = "audit";
+
+thread_local! {
+    /// 현재 실행 중인 잡의 변경 내역
+    static CURRENT: RefCell<JobAudit> = RefCell::new(JobAudit::default());
+}
+
+/// 변경 건수와 일부 샘플
+///
+/// # Description
+/// 변경 건수는 모두 세지만 샘플은 최대 [`AUDIT_SAMPLE_LIMIT`]개 까지만 보관한다.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditSection<T> {
+    pub count: usize,
+    pub samples: Vec<T>,
+}
+
+impl<T> Default for AuditSection<T> {
+    fn default() -> Self {
+        Self { count: 0, samples: Vec::new() }
+    }
+}
+
+impl<T> AuditSection<T> {
+    pub fn push(&mut self, sample: T) {
+        self.count += 1;
+        if self.samples.len() < AUDIT_SAMPLE_LIMIT {
+            self.samples.push(sample);
+        }
+    }
+}
+
+/// 새로 저장된 도서
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreatedBook {
+    pub isbn: String,
+    pub title: String,
+}
+
+/// 속성 하나의 변경 전/후 값
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// 수정된 도서와 변경된 속성 리스트
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpdatedBook {
+    pub isbn: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// 새로 생성된 시리즈
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreatedSeries {
+    pub id: u64,
+    pub title: Option<String>,
+}
+
+/// 새로 저장된 도서-시리즈 연결
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreatedLink {
+    pub book_id: u64,
+    pub series_id: u64,
+    pub method: String,
+    pub score: Option<f64>,
+}
+
+/// 삭제된 도서
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeletedBook {
+    pub id: u64,
+    pub isbn: String,
+    pub title: String,
+}
+
+/// 소속 도서가 모두 삭제되어 함께 삭제된 시리즈
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeletedSeries {
+    pub id: u64,
+}
+
+/// 출판사별 출간 예정일 지연 통계
+///
+/// # Description
+/// 도서가 수정될 때 변경 전/후의 출간 예정일(`scheduled_pub_date`)과 실제 출간일(`actual_pub_date`)을 비교하여 집계한다.
+/// 샘플과 달리 모든 수정을 집계하며, 출간 계획 수립 시 출판사별 출간 예정일의 신뢰도를 확인하는데 사용한다.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct PubDateSlip {
+    /// 출간 예정일이 있는 상태에서 실제 출간일이 새로 확인된 도서 수
+    pub landed: usize,
+
+    /// 실제 출간일이 출간 예정일 보다 늦은 도서 수
+    pub slipped: usize,
+
+    /// 지연된 도서들의 지연 일수 합계
+    pub slip_days: i64,
+
+    /// 실제 출간일이 확인 되기 전 출간 예정일이 더 늦은 날짜로 변경된 횟수
+    pub rescheduled: usize,
+}
+
+impl PubDateSlip {
+
+    /// 수정된 도서의 변경 전/후 출간일을 집계한다. 집계된 변경이 있는 경우 `true`를 반환한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::audit::PubDateSlip;
+    /// use book_batch_rust::item::Book;
+    /// use chrono::NaiveDate;
+    ///
+    /// let book = |scheduled: Option<&str>, actual: Option<&str>| {
+    ///     let mut builder = Book::builder().isbn("9791136202093".to_owned()).title("도서".to_owned());
+    ///     if let Some(date) = scheduled {
+    ///         builder = builder.scheduled_pub_date(NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap());
+    ///     }
+    ///     if let Some(date) = actual {
+    ///         builder = builder.actual_pub_date(NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap());
+    ///     }
+    ///     builder.build().unwrap()
+    /// };
+    ///
+    /// let mut slip = PubDateSlip::default();
+    /// assert!(slip.observe(&book(Some("2025-01-10"), None), &book(Some("2025-01-20"), None)));
+    /// assert!(slip.observe(&book(Some("2025-01-20"), None), &book(None, Some("2025-01-25"))));
+    /// assert!(slip.observe(&book(Some("2025-02-01"), None), &book(None, Some("2025-02-01"))));
+    /// assert!(!slip.observe(&book(None, None), &book(None, Some("2025-02-01"))));
+    ///
+    /// assert_eq!(slip, PubDateSlip { landed: 2, slipped: 1, slip_days: 5, rescheduled: 1 });
+    /// assert_eq!(slip.slip_rate(), Some(0.5));
+    /// assert_eq!(slip.average_slip_days(), Some(5.0));
+    /// ```
+    pub fn observe(&mut self, before: &Book, after: &Book) -> bool {
+        let scheduled = match before.scheduled_pub_date() {
+            Some(scheduled) if before.actual_pub_date().is_none() => scheduled,
+            _ => return false,
+        };
+
+        match (after.actual_pub_date(), after.scheduled_pub_date()) {
+            (Some(actual), _) => {
+                self.landed += 1;
+                if actual > scheduled {
+                    self.slipped += 1;
+                    self.slip_days += (actual - scheduled).num_days();
+                }
+                true
+            }
+            (None, Some(rescheduled)) if rescheduled > scheduled => {
+                self.rescheduled += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// 실제 출간일이 확인된 도서 중 지연된 도서의 비율, 확인된 도서가 없는 경우 `None`
+    pub fn slip_rate(&self) -> Option<f64> {
+        (self.landed > 0).then(|| self.slipped as f64 / self.landed as f64)
+    }
+
+    /// 지연된 도서의 평균 지연 일수, 지연된 도서가 없는 경우 `None`
+    pub fn average_slip_days(&self) -> Option<f64> {
+        (self.slipped > 0).then(|| self.slip_days as f64 / self.slipped as f64)
+    }
+}
+
+/// 잡 실행 한 번의 변경 내역(감사 기록)
+///
+/// # Description
+/// 라이터들이 저장소에 반영한 변경(새 도서, 수정된 도서의 속성, 새 시리즈, 새 도서-시리즈 연결, 삭제된 도서와 시리즈)을 실행 중에 모아
+/// 잡이 끝난 후 JSON 파일로 내보낸다. 내보낸 파일의 경로는 잡 실행 기록에 함께 저장된다.
+///
+/// # Note
+/// 변경 내역은 스레드별로 하나만 유지 되며 [`take`]를 호출하면 지금까지의 기록을 반환하고 초기화 한다.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct JobAudit {
+    pub created_books: AuditSection<CreatedBook>,
+    pub updated_books: AuditSection<UpdatedBook>,
+    pub created_series: AuditSection<CreatedSeries>,
+    pub created_links: AuditSection<CreatedLink>,
+    pub deleted_books: AuditSection<DeletedBook>,
+    pub deleted_series: AuditSection<DeletedSeries>,
+
+    /// 새로 저장된 모든 도서의 ISBN (후속 잡의 파라미터를 만드는데 사용하며 파일로 내보내지 않는다.)
+    #[serde(skip)]
+    pub created_isbn: Vec<String>,
+
+    /// 출판사별 새로 저장된 도서 수 (수집량 이상 확인에 사용하며 파일로 내보내지 않는다.)
+    #[serde(skip)]
+    pub created_by_publisher: BTreeMap<u64, usize>,
+
+    /// 출판사별 출간 예정일 지연 통계 ([`PubDateSlip`])
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub pub_date_slips: BTreeMap<u64, PubDateSlip>,
+
+    /// 새로 저장된 도서의 ISBN을 저장한 ISBN 집합 이름
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_isbn_set: Option<String>,
+
+    /// 저장에 실패하여 격리된 청크의 파일 경로 ([`crate::batch::quarantine`])
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub quarantined_files: Vec<String>,
+}
+
+impl JobAudit {
+
+    /// 변경 내역이 하나도 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.created_books.count == 0
+            && self.updated_books.count == 0
+            && self.created_series.count == 0
+            && self.created_links.count == 0
+            && self.deleted_books.count == 0
+            && self.deleted_series.count == 0
+    }
+
+    /// 변경 내역을 `dir` 디렉토리 아래 `file_name`으로 내보내고 파일 경로를 반환한다.
+    pub fn export(&self, dir: &Path, file_name: &str) -> Result<PathBuf, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+        let path = dir.join(file_name);
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(&path, json).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+/// 새로 저장된 도서를 기록한다.
+pub fn record_created_books(books: &[Book]) {
+    CURRENT.with(|audit| {
+        let mut audit = audit.borrow_mut();
+        for book in books {
+            audit.created_books.push(CreatedBook { isbn: book.isbn().to_owned(), title: book.title().to_owned() });
+            audit.created_isbn.push(book.isbn().to_owned());
+            *audit.created_by_publisher.entry(book.publisher_id()).or_insert(0) += 1;
+        }
+    });
+}
+
+/// 수정된 도서의 변경 전/후를 비교하여 기록한다. 변경된 속성이 없을 경우 기록하지 않는다.
+///
+/// 출간 예정일과 실제 출간일의 변경은 출판사별 출간 예정일 지연 통계([`PubDateSlip`])로도 집계한다.
+pub fn record_updated_book(before: &Book, after: &Book) {
+    let changes = book_field_changes(before, after);
+    if changes.is_empty() {
+        return;
+    }
+    CURRENT.with(|audit| {
+        let mut audit = audit.borrow_mut();
+        let mut slip = audit.pub_date_slips.get(&after.publisher_id()).cloned().unwrap_or_default();
+        if slip.observe(before, after) {
+            audit.pub_date_slips.insert(after.publisher_id(), slip);
+        }
+        audit.updated_books.push(UpdatedBook { isbn: after.isbn().to_owned(), changes });
+    });
+}
+
+/// 새로 생성된 시리즈를 기록한다.
+pub fn record_created_series(series: &Series) {
+    CURRENT.with(|audit| {
+        audit.borrow_mut().created_series.push(CreatedSeries { id: series.id(), title: series.title().clone() });
+    });
+}
+
+/// 새로 저장된 도서-시리즈 연결을 기록한다.
+pub fn record_created_links(links: &[SeriesLink]) {
+    CURRENT.with(|audit| {
+        let mut audit = audit.borrow_mut();
+        for link in links {
+            audit.created_links.push(CreatedLink {
+                book_id: link.book_id(),
+                series_id: link.series_id(),
+                method: link.confidence().method().to_owned(),
+                score: link.confidence().score(),
+            });
+        }
+    });
+}
+
+/// 삭제된 도서를 기록한다.
+pub fn record_deleted_books(books: &[Book]) {
+    CURRENT.with(|audit| {
+        let mut audit = audit.borrow_mut();
+        for book in books {
+            audit.deleted_books.push(DeletedBook { id: book.id(), isbn: book.isbn().to_owned(), title: book.title().to_owned() });
+        }
+    });
+}
+
+/// 삭제된 시리즈를 기록한다.
+pub fn record_deleted_series(series_id: &[u64]) {
+    CURRENT.with(|audit| {
+        let mut audit = audit.borrow_mut();
+        for id in series_id {
+            audit.deleted_series.push(DeletedSeries { id: *id });
+        }
+    });
+}
+
+/// 저장에 실패하여 격리된 청크의 파일 경로를 기록한다.
+pub fn record_quarantined(path: &str) {
+    CURRENT.with(|audit| audit.borrow_mut().quarantined_files.push(path.to_owned()));
+}
+
+/// 지금까지 기록된 변경 내역을 반환하고 초기화 한다.
+pub fn take() -> JobAudit {
+    CURRENT.with(|audit| audit.take())
+}
+
+/// 변경 내역을 환경 변수 `AUDIT_DIR`(기본값 `audit`) 디렉토리에 JSON 파일로 내보내고 파일 경로를 반환한다.
+///
+/// # Description
+/// 파일명은 `<잡 이름>_<실행 시각>_<실행 기록 아이디>.json` 형식을 사용한다.
+/// 내보내기에 실패한 경우 에러 로그를 남기고 `None`을 반환한다.
+pub fn export(audit: &JobAudit, job_name: &str, execution_id: Option<u64>) -> Option<String> {
+    info!("{} => Audit created books: {}, updated books: {}, created series: {}, created links: {}, deleted books: {}, deleted series: {}", job_name,
+        audit.created_books.count, audit.updated_books.count, audit.created_series.count, audit.created_links.count,
+        audit.deleted_books.count, audit.deleted_series.count);
+    for (publisher_id, slip) in &audit.pub_date_slips {
+        info!("{} => Pub date slip publisher: {}, landed: {}, slipped: {}, slip rate: {}, average slip days: {}, rescheduled: {}", job_name,
+            publisher_id, slip.landed, slip.slipped,
+            slip.slip_rate().map(|rate| format!("{:.1}%", rate * 100.0)).unwrap_or_else(|| "-".to_owned()),
+            slip.average_slip_days().map(|days| format!("{:.1}", days)).unwrap_or_else(|| "-".to_owned()),
+            slip.rescheduled);
+    }
+
+    let dir = env::var("AUDIT_DIR").unwrap_or_else(|_| DEFAULT_AUDIT_DIR.to_owned());
+    let file_name = format!("{}_{}_{}.json",
+        job_name.to_lowercase(),
+        system_clock().now().format("%Y%m%d%H%M%S"),
+        execution_id.map(|id| id.to_string()).unwrap_or_else(|| "unknown".to_owned()));
+
+    match audit.export(Path::new(&dir), &file_name) {
+        Ok(path) => Some(path.to_string_lossy().into_owned()),
+        Err(err) => {
+            error!("Failed to export audit: {}", err);
+            None
+        }
+    }
+}
+
+/// 두 도서의 속성을 비교하여 변경된 속성의 변경 전/후 값을 반환한다.
+///
+/// # Note
+/// 판매처별 원본 데이터와 상세 설명은 비교하지 않는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::audit::{book_field_changes, FieldChange};
+/// use book_batch_rust::item::Book;
+///
+/// let before = Book::builder().isbn("9791136202093".to_owned()).title("이전 제목".to_owned()).build().unwrap();
+/// let after = Book::builder().isbn("9791136202093".to_owned()).title("새 제목".to_owned()).build().unwrap();
+///
+/// assert_eq!(book_field_changes(&before, &after), vec![FieldChange {
+///     field: "title".to_owned(),
+///     before: Some("이전 제목".to_owned()),
+///     after: Some("새 제목".to_owned()),
+/// }]);
+/// assert!(book_field_changes(&before, &before).is_empty());
+/// ```
+pub fn book_field_changes(before: &Book, after: &Book) -> Vec<FieldChange> {
+    let fields = [
+        ("title", Some(before.title().to_owned()), Some(after.title().to_owned())),
+        ("normalized_title", before.normalized_title().map(|t| t.to_owned()), after.normalized_title().map(|t| t.to_owned())),
+        ("publisher_id", Some(before.publisher_id().to_string()), Some(after.publisher_id().to_string())),
+        ("series_id", before.series_id().map(|id| id.to_string()), after.series_id().map(|id| id.to_string())),
+        ("scheduled_pub_date", before.scheduled_pub_date().map(|d| d.to_string()), after.scheduled_pub_date().map(|d| d.to_string())),
+        ("actual_pub_date", before.actual_pub_date().map(|d| d.to_string()), after.actual_pub_date().map(|d| d.to_string())),
+    ];
+
+    fields.into_iter()
+        .filter(|(_, before, after)| before != after)
+        .map(|(field, before, after)| FieldChange { field: field.to_owned(), before, after })
+        .collect()
+}
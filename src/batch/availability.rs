@@ -0,0 +1,171 @@
+use crate::batch::book::{retrieve_from_to_in_parameter, retrieve_isbn_in_parameter};
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Processor, Reader, Writer, DEF_CHUNK_SIZE};
+use crate::clock::{system_clock, SharedClock};
+use crate::item::{raw_utils, Availability, Book, SharedAvailabilityRepository, SharedBookRepository, Site};
+use std::collections::HashMap;
+use tracing::warn;
+
+/// 판매 상태를 확인할 도서를 검색하는 리더
+///
+/// # Description
+/// `isbn` 파라미터가 있을 경우 해당 ISBN의 도서들을, 없을 경우 `from/to` 기간에 출판(예정)된 도서들을 원본 데이터와 함께 조회한다.
+pub struct AvailabilityBookReader {
+    book_repo: SharedBookRepository,
+}
+
+impl AvailabilityBookReader {
+    pub fn new(book_repo: SharedBookRepository) -> Self {
+        Self { book_repo }
+    }
+}
+
+impl Reader for AvailabilityBookReader {
+    type Item = Book;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let isbn_vec = retrieve_isbn_in_parameter(params)?;
+        if !isbn_vec.is_empty() {
+            let isbn_vec = isbn_vec.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+            return Ok(self.book_repo.find_by_isbn(&isbn_vec));
+        }
+
+        let (from, to) = retrieve_from_to_in_parameter(params)?;
+        Ok(self.book_repo.find_by_pub_between(&from, &to))
+    }
+}
+
+/// 도서와 도서의 사이트별 판매 상태
+#[derive(Debug)]
+pub struct BookAvailability {
+    pub book: Book,
+    pub records: Vec<Availability>,
+}
+
+/// 판매 상태 변환 프로세서
+///
+/// # Description
+/// 도서의 사이트별 원본 데이터에서 판매(재고) 상태 문구를 찾아 사이트별 판매 상태 기록으로 변환한다.
+/// 판매 상태를 제공하지 않는 사이트(네이버, 국립중앙도서관 등)의 원본 데이터는 무시한다.
+pub struct AvailabilityProcessor {
+    /// 판매 상태 확인 시각에 사용할 시계
+    clock: SharedClock,
+}
+
+impl AvailabilityProcessor {
+    pub fn new() -> Self {
+        Self { clock: system_clock() }
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Default for AvailabilityProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Processor for AvailabilityProcessor {
+    type In = Book;
+    type Out = BookAvailability;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let checked_at = self.clock.now();
+
+        let mut records = item.originals().iter()
+            .filter_map(|(site, raw)| {
+                let dict = raw_utils::load_site_dict(site);
+                raw_utils::retrieve_sale_status_from_raw(&dict, raw)
+                    .map(|status| Availability::new(item.id(), site.clone(), status, checked_at))
+            })
+            .collect::<Vec<_>>();
+        records.sort_by_key(|r| r.site().to_string());
+
+        Ok(BookAvailability { book: item, records })
+    }
+}
+
+/// 판매 상태 저장 라이터
+///
+/// # Description
+/// 사이트별 판매 상태를 저장하고, 판매 상태가 이전과 같을 경우 판매 상태 변경 시각은 이전 기록의 값을 유지한다.
+///
+/// # Note
+/// 이전에 하나 이상의 사이트에서 구매 가능한 상태였던 도서가 판매 상태를 확인한 모든 사이트에서 구매할 수 없는 상태(품절, 절판 등)가
+/// 되었을 경우 해당 도서를 경고 로그로 알린다. 이전 기록이 없는 도서는 알리지 않는다.
+pub struct AvailabilityWriter {
+    repo: SharedAvailabilityRepository,
+}
+
+impl AvailabilityWriter {
+    pub fn new(repo: SharedAvailabilityRepository) -> Self {
+        Self { repo }
+    }
+}
+
+impl Writer for AvailabilityWriter {
+    type Item = BookAvailability;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let book_ids = items.iter()
+            .map(|item| item.book.id())
+            .collect::<Vec<_>>();
+
+        let mut previous: HashMap<(u64, Site), Availability> = self.repo.find_by_book_id(&book_ids).into_iter()
+            .map(|a| ((a.book_id(), a.site().clone()), a))
+            .collect();
+
+        let mut records = Vec::new();
+        for item in &items {
+            let before = item.records.iter()
+                .filter_map(|r| previous.remove(&(r.book_id(), r.site().clone())))
+                .collect::<Vec<_>>();
+
+            for record in &item.records {
+                let mut record = record.clone();
+                if let Some(prev) = before.iter().find(|p| p.site() == record.site() && p.status() == record.status()) {
+                    record.set_changed_at(prev.changed_at());
+                }
+                records.push(record);
+            }
+
+            if is_gone_out_of_stock(&before, &item.records) {
+                let statuses = item.records.iter()
+                    .map(|r| format!("{}={}", r.site(), r.status()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                warn!("Book went out of stock in all sites: {} ({})", item.book.isbn(), statuses);
+            }
+        }
+
+        self.repo.save_availability(&records);
+        Ok(())
+    }
+}
+
+/// 이전 판매 상태 중 하나 이상이 구매 가능한 상태였고, 현재 모든 사이트에서 구매할 수 없는 상태인지 확인한다.
+fn is_gone_out_of_stock(before: &[Availability], after: &[Availability]) -> bool {
+    !after.is_empty()
+        && after.iter().all(|r| !r.status().is_available())
+        && before.iter().any(|r| r.status().is_available())
+}
+
+pub fn create_job(
+    book_repo: SharedBookRepository,
+    availability_repo: SharedAvailabilityRepository,
+    params: &JobParameter,
+) -> Result<Job<Book, BookAvailability>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+
+    let job = job_builder()
+        .reader(Box::new(AvailabilityBookReader::new(book_repo)))
+        .processor(Box::new(AvailabilityProcessor::new()))
+        .writer(Box::new(AvailabilityWriter::new(availability_repo)))
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size))
+}
@@ -2,14 +2,25 @@ pub mod nlgo;
 pub mod naver;
 pub mod aladin;
 pub mod kyobo;
+pub mod kyobo_search;
+pub mod fetch;
+pub mod retry;
+pub mod backfill;
+pub mod quota;
+pub mod recheck;
 
-use crate::batch::error::{JobReadFailed, JobWriteFailed};
-use crate::batch::{Filter, FilterChain, JobParameter, Reader, Writer};
-use crate::item::{Book, BookBuilder, Publisher, SharedBookRepository, SharedFilterRepository, SharedPublisherRepository, Site};
-use crate::{PARAM_NAME_FROM, PARAM_NAME_ISBN, PARAM_NAME_PUBLISHER_ID, PARAM_NAME_TO};
-use chrono::NaiveDate;
-use std::collections::{HashMap, HashSet};
-use tracing::warn;
+use crate::batch::audit;
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::status;
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::{collect_pages, Filter, FilterChain, JobParameter, Processor, ReadPages, Reader, Writer};
+use crate::item::{raw_utils, Book, BookBuilder, Originals, Publisher, SharedBookRepository, SharedFilterRepository, SharedIsbnSetRepository, SharedPublisherRepository, Site};
+use crate::{PARAM_NAME_DESCRIPTION_MAX_LENGTH, PARAM_NAME_DESCRIPTION_MIN_LENGTH, PARAM_NAME_DESCRIPTION_SITE, PARAM_NAME_FILTER_SITE, PARAM_NAME_FROM, PARAM_NAME_ISBN, PARAM_NAME_ISBN_SET, PARAM_NAME_PUBLISHER_ID, PARAM_NAME_SKIP_FILTER, PARAM_NAME_TO};
+use chrono::{Days, NaiveDate};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tracing::{info, warn};
 
 /// [`JobParameter`]에서 `시작일`과 `종료일`을 얻어 [`NaiveDate`]로 반환한다.
 /// 시작일의 키는 `from_dt` 종료일의 키는 `to_dt`를 사용한다. 시작일과 종료일은 `%Y-%m-%d` 포멧으로 파싱하며
@@ -46,6 +57,48 @@ pub fn retrieve_from_to_in_parameter(params: &JobParameter) -> Result<(NaiveDate
     Ok((from, to))
 }
 
+/// 시작일(`from`)부터 종료일(`to`)까지의 기간을 `step_days`일 단위의 구간으로 나누어 반환한다.
+///
+/// # Description
+/// 각 구간은 `(구간 시작일, 구간 종료일)`로 양 끝을 모두 포함하며 마지막 구간의 종료일은 `to`를 넘지 않는다.
+/// 넓은 기간을 한 번에 조회하면 응답 시간이 초과되거나 결과가 잘려서 반환되는 API에서 기간을 나누어 조회할 때 사용한다.
+///
+/// # Note
+/// - `from`이 `to`보다 이후일 경우 빈 이터레이터를 반환한다.
+/// - `step_days`가 0일 경우 1일 단위로 나눈다.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use book_batch_rust::batch::book::date_windows;
+///
+/// let from = NaiveDate::from_ymd_opt(2025, 5, 1).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2025, 5, 25).unwrap();
+///
+/// let windows: Vec<(NaiveDate, NaiveDate)> = date_windows(from, to, 10).collect();
+/// assert_eq!(windows, vec![
+///     (NaiveDate::from_ymd_opt(2025, 5, 1).unwrap(), NaiveDate::from_ymd_opt(2025, 5, 10).unwrap()),
+///     (NaiveDate::from_ymd_opt(2025, 5, 11).unwrap(), NaiveDate::from_ymd_opt(2025, 5, 20).unwrap()),
+///     (NaiveDate::from_ymd_opt(2025, 5, 21).unwrap(), NaiveDate::from_ymd_opt(2025, 5, 25).unwrap()),
+/// ]);
+///
+/// // 시작일이 종료일보다 이후일 경우 빈 이터레이터를 반환한다.
+/// assert_eq!(date_windows(to, from, 10).count(), 0);
+/// ```
+pub fn date_windows(from: NaiveDate, to: NaiveDate, step_days: u64) -> impl Iterator<Item=(NaiveDate, NaiveDate)> {
+    let step = step_days.max(1);
+    let window = move |start: NaiveDate| {
+        let end = start.checked_add_days(Days::new(step - 1)).unwrap_or(to).min(to);
+        (start, end)
+    };
+
+    std::iter::successors(Some(from).filter(|f| *f <= to).map(window), move |(_, end)| {
+        end.succ_opt()
+            .filter(|next| *next <= to)
+            .map(window)
+    })
+}
+
 /// [`JobParameter`]에서 `publisher`를 키로 사용하여 출판사 아이디를 얻어온다.
 /// 만약 `JobParameter`에 출판사 아이디가 없을 경우 빈 `Vec`를 반환한다.
 ///
@@ -101,6 +154,63 @@ pub fn retrieve_isbn_in_parameter(params: &JobParameter) -> Result<Vec<String>,
     Ok(isbn_str)
 }
 
+/// [`JobParameter`]의 `isbn_set`에 해당하는 ISBN 집합을 불러와 `isbn` 파라미터에 추가한 파라미터를 반환한다.
+///
+/// # Description
+/// `isbn` 파라미터를 사용하는 리더들이 다른 잡에서 저장한 ISBN 집합을 처리할 수 있도록 잡 실행 전에 호출한다.
+/// `isbn_set` 파라미터가 없을 경우 파라미터를 그대로 반환하며, 이미 `isbn` 파라미터가 있을 경우 중복을 제외하고 뒤에 추가한다.
+///
+/// # Note
+/// ISBN 집합이 없거나 비어 있을 경우 `JobBuildError::InvalidParameter` 에러를 반환한다.
+pub fn resolve_isbn_set_in_parameter(params: &JobParameter, repo: &SharedIsbnSetRepository) -> Result<JobParameter, JobBuildError> {
+    let set_name = match params.get(PARAM_NAME_ISBN_SET) {
+        Some(set_name) => set_name,
+        None => return Ok(params.clone()),
+    };
+
+    let set_isbn = repo.find_isbn(set_name);
+    if set_isbn.is_empty() {
+        return Err(JobBuildError::InvalidParameter(format!("{}: {} is empty or not found", PARAM_NAME_ISBN_SET, set_name)));
+    }
+
+    let mut visited = HashSet::new();
+    let isbn_vec = retrieve_isbn_in_parameter(params)
+        .map_err(|e| JobBuildError::InvalidParameter(format!("{:?}", e)))?
+        .into_iter()
+        .chain(set_isbn)
+        .filter(|isbn| visited.insert(isbn.clone()))
+        .collect::<Vec<_>>();
+
+    let mut resolved = params.clone();
+    resolved.remove(PARAM_NAME_ISBN_SET);
+    resolved.insert(PARAM_NAME_ISBN.to_owned(), isbn_vec.join(","));
+    Ok(resolved)
+}
+
+/// 한 번의 실행에서 출판사 키워드로 요청한 조회 조건 (사이트, 키워드, 조회 기간)
+///
+/// # Description
+/// 여러 출판사가 같은 키워드를 사용하는 경우 같은 조건으로 중복 요청하지 않도록 [`ByPublisher::read_books`]에서 사용한다.
+/// 키워드는 앞뒤 공백을 제거하고 대소문자를 구분하지 않는다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct KeywordRequest {
+    site: Site,
+    keyword: String,
+    from: Option<String>,
+    to: Option<String>,
+}
+
+impl KeywordRequest {
+    fn new(site: Site, keyword: &str, params: &JobParameter) -> Self {
+        Self {
+            site,
+            keyword: keyword.trim().to_lowercase(),
+            from: params.get(PARAM_NAME_FROM).cloned(),
+            to: params.get(PARAM_NAME_TO).cloned(),
+        }
+    }
+}
+
 pub trait ByPublisher: Reader<Item=Book> {
 
     fn site(&self) -> &Site;
@@ -119,31 +229,84 @@ pub trait ByPublisher: Reader<Item=Book> {
         Ok(publisher)
     }
 
+    /// 출판사별 키워드로 도서를 조회한다.
+    ///
+    /// # Description
+    /// 같은 실행에서 이미 요청한 조건(사이트, 키워드, 조회 기간)은 다시 요청하지 않고 처음 요청한 결과를 재사용한다.
+    /// 재사용한 도서는 처음 요청한 출판사의 도서로만 반환하여 같은 도서가 한 번의 실행에 중복으로 들어가지 않도록 하며,
+    /// 수집 현황에는 재사용한 도서 수를 함께 기록한다.
     fn read_books(&self, params: &JobParameter) -> Result<Vec<Book>, JobReadFailed> {
+        collect_pages(self.read_pages(params)?)
+    }
+
+    /// 출판사별 키워드로 도서를 조회하여 출판사 단위의 페이지로 반환한다.
+    ///
+    /// # Description
+    /// 출판사의 키워드는 잡이 페이지를 요청할 때 조회하므로 메모리에는 한 출판사 분량의 도서만 유지된다.
+    /// 재사용 규칙은 [`ByPublisher::read_books`]와 같으며, 여러 출판사의 키워드에서 조회된 같은 ISBN의 도서는 [`drop_read_isbn`]으로 제외한다.
+    fn read_pages<'a>(&'a self, params: &'a JobParameter) -> Result<ReadPages<'a, Book>, JobReadFailed> {
         let publishers = self.load_publisher(params)?;
-        let mut results = Vec::new();
+        let mut requested: HashMap<KeywordRequest, usize> = HashMap::new();
 
-        for publisher in publishers {
+        let pages = publishers.into_iter().map(move |publisher| -> Result<Vec<Book>, JobReadFailed> {
+            let mut results = Vec::new();
             match publisher.keywords().get(self.site()) {
                 Some(keywords) => {
+                    let mut count = 0;
                     for keyword in keywords {
+                        let request = KeywordRequest::new(*self.site(), keyword, params);
+                        if let Some(reused) = requested.get(&request) {
+                            info!("{:?} => Keyword {} already requested for site {:?}, {} books reused", publisher.name(), keyword, self.site(), reused);
+                            count += reused;
+                            continue;
+                        }
+
                         let books = self.by_publisher_keyword(keyword, params)?;
                         let books: Vec<Book> = books.into_iter()
                             .map(|book| book.publisher_id(publisher.id()).build().unwrap())
                             .collect();
 
+                        requested.insert(request, books.len());
+                        count += books.len();
                         results.extend(books);
                     }
+                    status::record_collected(*self.site(), publisher.id(), count);
                 },
                 None => {
                     warn!("{:?} => No keywords for site {:?}", publisher.name(), self.site())
                 },
             }
-        }
-        Ok(results)
+            Ok(results)
+        });
+        Ok(Box::new(pages))
     }
 }
 
+/// 페이지 단위로 읽은 도서 중 이전 페이지나 같은 페이지에서 이미 읽은 ISBN의 도서를 제외한다.
+///
+/// # Description
+/// 필터는 페이지마다 실행 되므로([`Filter`]) 여러 페이지에 걸친 중복은 [`DropDuplicateIsbnFilter`] 대신 이 함수로 제외한다.
+/// 메모리에는 읽은 도서의 ISBN만 유지하며, ISBN이 없는 도서는 제외하지 않는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::book::drop_read_isbn;
+/// use book_batch_rust::batch::collect_pages;
+/// use book_batch_rust::item::Book;
+///
+/// let book = |isbn: &str| Book::builder().isbn(isbn.to_owned()).title("도서".to_owned()).build().unwrap();
+/// let pages = vec![Ok(vec![book("1"), book("2")]), Ok(vec![book("2"), book("3"), book("3")])];
+///
+/// let books = collect_pages(drop_read_isbn(Box::new(pages.into_iter()))).unwrap();
+/// assert_eq!(books.iter().map(|book| book.isbn()).collect::<Vec<_>>(), vec!["1", "2", "3"]);
+/// ```
+pub fn drop_read_isbn(pages: ReadPages<'_, Book>) -> ReadPages<'_, Book> {
+    let mut isbn_set: HashSet<String> = HashSet::new();
+    Box::new(pages.map(move |page| page.map(|books| books.into_iter()
+        .filter(|book| book.isbn().is_empty() || isbn_set.insert(book.isbn().to_owned()))
+        .collect())))
+}
+
 pub struct EmptyIsbnFilter;
 
 pub fn new_empty_isbn_filter() -> EmptyIsbnFilter {
@@ -184,59 +347,433 @@ impl Filter for DropDuplicateIsbnFilter {
     }
 }
 
+/// 사이트별 원본 데이터 필터
+///
+/// # Description
+/// 저장소에 등록된 사이트별 필터 규칙으로 도서의 원본 데이터를 검사하여 모든 규칙을 통과한 도서만 반환한다.
+/// 여러 사이트가 설정된 경우 도서는 설정된 모든 사이트의 규칙을 통과해야 하며, 원본 데이터가 없는 사이트의 규칙은 검사하지 않는다.
+/// 출판사 프로필([`PublisherProfiles`])에서 필터를 적용하지 않도록 설정한 출판사의 도서는 검사하지 않는다.
 pub struct OriginalDataFilter {
     repository: SharedFilterRepository,
-    site: Site
+    sites: Vec<Site>,
+    profiles: PublisherProfiles,
 }
 
 impl OriginalDataFilter {
     pub fn new(repository: SharedFilterRepository, site: Site) -> OriginalDataFilter {
+        Self::with_sites(repository, vec![site])
+    }
+
+    pub fn with_sites(repository: SharedFilterRepository, sites: Vec<Site>) -> OriginalDataFilter {
         OriginalDataFilter {
             repository,
-            site
+            sites,
+            profiles: PublisherProfiles::new(),
         }
     }
+
+    /// 도서의 출판사별로 필터 적용 여부를 결정할 출판사 프로필을 설정한다.
+    pub fn with_profiles(mut self, profiles: PublisherProfiles) -> Self {
+        self.profiles = profiles;
+        self
+    }
 }
 
 impl Filter for OriginalDataFilter {
     type Item = Book;
 
     fn do_filter(&self, items: Vec<Self::Item>) -> Vec<Self::Item> {
-        let mut filters = self.repository.find_by_site(&self.site).into_iter()
-            .map(|rule| rule.to_predicate());
+        let filters = self.sites.iter()
+            .map(|site| {
+                let predicates = self.repository.find_by_site(site).into_iter()
+                    .map(|rule| rule.to_predicate())
+                    .collect::<Vec<_>>();
+                (site, predicates)
+            })
+            .collect::<Vec<_>>();
 
         items.into_iter()
             .filter(|book| {
-                book.originals().get(&self.site)
-                    .map(|o| filters.all(|f| f.test(o)))
-                    .unwrap_or(true)
+                self.profiles.is_filter_skipped(book) || filters.iter().all(|(site, predicates)| {
+                    book.originals().get(site)
+                        .map(|o| predicates.iter().all(|f| f.test(o)))
+                        .unwrap_or(true)
+                })
             })
             .collect()
     }
 }
 
+/// [`JobParameter`]의 `skip_filter`와 `filter_site`를 이용하여 [`OriginalDataFilter`]를 생성한다.
+///
+/// - `skip_filter`가 `true`일 경우 필터를 생성하지 않고 [`None`]을 반환한다.
+/// - `filter_site`가 있을 경우 콤마(,)로 구분된 사이트들의 규칙을 사용하고, 없을 경우 `default_site`의 규칙을 사용한다.
+///
+/// `skip_filter`를 `bool`로 파싱 할 수 없거나 알 수 없는 사이트가 입력된 경우 `JobBuildError` 에러를 반환한다.
+pub fn create_original_data_filter(
+    repository: SharedFilterRepository,
+    default_site: Site,
+    params: &JobParameter
+) -> Result<Option<OriginalDataFilter>, JobBuildError> {
+    let skip_filter = params.get(PARAM_NAME_SKIP_FILTER)
+        .map(|v| v.trim().parse::<bool>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_SKIP_FILTER, e))))
+        .transpose()?
+        .unwrap_or(false);
+    if skip_filter {
+        warn!("Original data filter is skipped");
+        return Ok(None);
+    }
+
+    let sites = match params.get(PARAM_NAME_FILTER_SITE) {
+        Some(sites) => sites.split(',')
+            .map(|s| Site::try_from(s.trim())
+                .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_FILTER_SITE, e))))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => vec![default_site],
+    };
+
+    Ok(Some(OriginalDataFilter::with_sites(repository, sites)))
+}
+
 pub fn create_default_filter_chain() -> FilterChain<Book> {
     FilterChain::new()
         .add_filter(Box::new(new_empty_isbn_filter()))
         .add_filter(Box::new(new_drop_duplicate_isbn_filter()))
 }
 
+/// 도서 소개글을 선택할 사이트 우선순위 기본값
+const DEFAULT_DESCRIPTION_SITES: [Site; 4] = [Site::KyoboBook, Site::Aladin, Site::Naver, Site::NLGO];
+
+/// 도서 소개글 최소 길이 기본값
+const DEFAULT_DESCRIPTION_MIN_LENGTH: usize = 10;
+
+/// 도서 소개글 최대 길이 기본값
+const DEFAULT_DESCRIPTION_MAX_LENGTH: usize = 2000;
+
+/// 도서 소개글 선택 프로세서
+///
+/// # Description
+/// 사이트별 원본 데이터에 있는 소개글 중 설정된 사이트 우선순위에 따라 가장 적합한 소개글을 하나 선택하여 도서에 설정한다.
+/// 선택된 소개글은 [`raw_utils::sanitize_html`]로 정리한 후 최대 길이를 넘는 부분은 잘라낸다.
+///
+/// # Note
+/// - 최소 길이보다 짧은 소개글은 건너뛰고 다음 우선순위 사이트의 소개글을 사용한다.
+/// - 소개글을 선택할 수 없는 경우 도서의 기존 소개글을 그대로 유지한다.
+/// - 길이는 바이트가 아닌 문자(char) 단위로 계산한다.
+/// - 출판사 프로필([`PublisherProfiles`])에 사이트 우선순위가 설정된 출판사의 도서는 프로필의 우선순위를 사용한다.
+pub struct BookDescriptionProcessor {
+    sites: Vec<Site>,
+    profiles: PublisherProfiles,
+
+    /// 소개글 최소 길이
+    pub min_length: usize,
+
+    /// 소개글 최대 길이
+    pub max_length: usize,
+}
+
+impl BookDescriptionProcessor {
+    pub fn new(sites: Vec<Site>) -> Self {
+        Self {
+            sites,
+            profiles: PublisherProfiles::new(),
+            min_length: DEFAULT_DESCRIPTION_MIN_LENGTH,
+            max_length: DEFAULT_DESCRIPTION_MAX_LENGTH,
+        }
+    }
+
+    /// 도서의 출판사별로 소개글 사이트 우선순위를 결정할 출판사 프로필을 설정한다.
+    pub fn with_profiles(mut self, profiles: PublisherProfiles) -> Self {
+        self.profiles = profiles;
+        self
+    }
+
+    fn select(&self, book: &Book) -> Option<String> {
+        let sites = self.profiles.find_by_book(book)
+            .and_then(|profile| profile.description_site.as_ref())
+            .unwrap_or(&self.sites);
+        sites.iter()
+            .filter_map(|site| {
+                let raw = book.originals().get(site)?;
+                let dict = raw_utils::load_site_dict(site);
+                raw_utils::retrieve_description_from_raw(&dict, raw)
+            })
+            .map(|description| raw_utils::sanitize_html(&description))
+            .find(|description| description.chars().count() >= self.min_length)
+            .map(|description| description.chars().take(self.max_length).collect())
+    }
+}
+
+impl Processor for BookDescriptionProcessor {
+    type In = Book;
+    type Out = Book;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let mut item = item;
+        if let Some(description) = self.select(&item) {
+            item.set_description(description);
+        }
+        Ok(item)
+    }
+}
+
+/// [`JobParameter`]의 `description_site`, `description_min_length`, `description_max_length`를 이용하여
+/// [`BookDescriptionProcessor`]를 생성한다.
+///
+/// - `description_site`가 있을 경우 콤마(,)로 구분된 사이트 순서를 우선순위로 사용하고, 없을 경우 교보문고, 알라딘, 네이버, 국립중앙도서관 순서를 사용한다.
+/// - `description_min_length`, `description_max_length`가 없을 경우 각각 10, 2000을 사용한다.
+///
+/// 알 수 없는 사이트가 입력 되었거나 길이를 숫자로 파싱 할 수 없는 경우, 최소 길이가 최대 길이보다 큰 경우 `JobBuildError` 에러를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::book::create_description_processor;
+/// use book_batch_rust::batch::{JobParameter, Processor};
+/// use book_batch_rust::item::{Book, Raw, Site};
+///
+/// let mut params = JobParameter::new();
+/// params.insert("description_site".to_owned(), "naver,aladin".to_owned());
+/// params.insert("description_max_length".to_owned(), "10".to_owned());
+/// let processor = create_description_processor(&params).unwrap();
+///
+/// let mut aladin = Raw::new();
+/// aladin.insert("description".to_owned(), "알라딘 도서 소개글 입니다.".into());
+/// let mut naver = Raw::new();
+/// naver.insert("description".to_owned(), "<b>네이버</b> 도서   소개글 입니다.".into());
+///
+/// let book = Book::builder()
+///     .isbn("9791136202093".to_owned())
+///     .title("도서".to_owned())
+///     .add_original(Site::Aladin, aladin)
+///     .add_original(Site::Naver, naver)
+///     .build()
+///     .unwrap();
+///
+/// let book = processor.do_process(book).unwrap();
+/// assert_eq!(book.description(), Some("네이버 도서 소개글"));
+///
+/// params.insert("description_min_length".to_owned(), "100".to_owned());
+/// assert!(create_description_processor(&params).is_err());
+/// ```
+pub fn create_description_processor(params: &JobParameter) -> Result<BookDescriptionProcessor, JobBuildError> {
+    let sites = match params.get(PARAM_NAME_DESCRIPTION_SITE) {
+        Some(sites) => sites.split(',')
+            .map(|s| Site::try_from(s.trim())
+                .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_DESCRIPTION_SITE, e))))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => DEFAULT_DESCRIPTION_SITES.to_vec(),
+    };
+
+    let mut processor = BookDescriptionProcessor::new(sites);
+    if let Some(min_length) = retrieve_length_in_parameter(params, PARAM_NAME_DESCRIPTION_MIN_LENGTH)? {
+        processor.min_length = min_length;
+    }
+    if let Some(max_length) = retrieve_length_in_parameter(params, PARAM_NAME_DESCRIPTION_MAX_LENGTH)? {
+        processor.max_length = max_length;
+    }
+
+    if processor.min_length > processor.max_length {
+        return Err(JobBuildError::InvalidParameter(format!(
+            "{} must be less than or equal to {}", PARAM_NAME_DESCRIPTION_MIN_LENGTH, PARAM_NAME_DESCRIPTION_MAX_LENGTH
+        )));
+    }
+    Ok(processor)
+}
+
+fn retrieve_length_in_parameter(params: &JobParameter, name: &str) -> Result<Option<usize>, JobBuildError> {
+    params.get(name)
+        .map(|v| v.trim().parse::<usize>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", name, e))))
+        .transpose()
+}
+
+/// 도서 테이블(`books.book`) ISBN 컬럼의 최대 길이
+const ISBN_MAX_LENGTH: usize = 13;
+
+/// 도서 테이블(`books.book`) 제목, 정규화된 제목 컬럼의 최대 길이
+const TITLE_MAX_LENGTH: usize = 512;
+
+/// 도서를 저장할 수 없는 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookViolation {
+    /// ISBN이 비어 있거나 컬럼의 최대 길이를 넘음 (ISBN 길이)
+    IsbnLength(usize),
+
+    /// 제목이 비어 있거나 컬럼의 최대 길이를 넘음 (제목 길이)
+    TitleLength(usize),
+
+    /// 정규화된 제목이 컬럼의 최대 길이를 넘음 (정규화된 제목 길이)
+    NormalizedTitleLength(usize),
+
+    /// 저장소에 없는 출판사 (출판사 아이디)
+    PublisherNotFound(u64),
+}
+
+impl std::fmt::Display for BookViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookViolation::IsbnLength(length) => write!(f, "isbn length {} (1 ~ {})", length, ISBN_MAX_LENGTH),
+            BookViolation::TitleLength(length) => write!(f, "title length {} (1 ~ {})", length, TITLE_MAX_LENGTH),
+            BookViolation::NormalizedTitleLength(length) => write!(f, "normalized title length {} (max {})", length, TITLE_MAX_LENGTH),
+            BookViolation::PublisherNotFound(publisher_id) => write!(f, "publisher {} not found", publisher_id),
+        }
+    }
+}
+
+/// 라이터가 도서를 저장하기 전 데이터베이스 제약 조건을 검증하는 검증기
+///
+/// # Description
+/// 한 도서의 제약 조건 위반으로 청크 전체의 일괄 저장(insert)이 알 수 없는 SQL 에러로 실패하지 않도록
+/// 저장 전 도서마다 ISBN, 제목, 정규화된 제목의 길이와 출판사 존재 여부([`BookValidator::with_publisher_repo`]로 설정한 경우)를 확인한다.
+///
+/// # Note
+/// PostgreSQL의 `varchar(n)` 길이 제한은 바이트가 아닌 문자 수 이므로 문자 수로 비교한다.
+#[derive(Clone, Default)]
+pub struct BookValidator {
+    pub_repo: Option<SharedPublisherRepository>,
+}
+
+impl BookValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 도서의 출판사가 저장소에 있는지 확인하도록 설정한다.
+    pub fn with_publisher_repo(mut self, pub_repo: SharedPublisherRepository) -> Self {
+        self.pub_repo = Some(pub_repo);
+        self
+    }
+
+    /// 도서 테이블의 컬럼 길이 제약 조건을 위반한 사유를 반환한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::book::{BookValidator, BookViolation};
+    /// use book_batch_rust::item::Book;
+    ///
+    /// let book = |isbn: &str, title: String| Book::builder().isbn(isbn.to_owned()).title(title).build().unwrap();
+    ///
+    /// assert!(BookValidator::column_violations(&book("9791136202093", "도서".to_owned())).is_empty());
+    /// assert_eq!(BookValidator::column_violations(&book("97911362020931", "가".repeat(512))), vec![BookViolation::IsbnLength(14)]);
+    /// assert_eq!(BookValidator::column_violations(&book("9791136202093", "가".repeat(513))), vec![BookViolation::TitleLength(513)]);
+    /// ```
+    pub fn column_violations(book: &Book) -> Vec<BookViolation> {
+        let mut violations = Vec::new();
+        let isbn_length = book.isbn().chars().count();
+        if isbn_length == 0 || isbn_length > ISBN_MAX_LENGTH {
+            violations.push(BookViolation::IsbnLength(isbn_length));
+        }
+        let title_length = book.title().chars().count();
+        if title_length == 0 || title_length > TITLE_MAX_LENGTH {
+            violations.push(BookViolation::TitleLength(title_length));
+        }
+        if let Some(normalized_title) = book.normalized_title() {
+            let normalized_length = normalized_title.chars().count();
+            if normalized_length > TITLE_MAX_LENGTH {
+                violations.push(BookViolation::NormalizedTitleLength(normalized_length));
+            }
+        }
+        violations
+    }
+
+    /// 도서들을 검증하여 저장할 수 있는 도서와 위반 사유가 있는 도서로 나눈다.
+    pub fn validate(&self, books: Vec<Book>) -> (Vec<Book>, Vec<(Book, Vec<BookViolation>)>) {
+        let publishers = self.pub_repo.as_ref().map(|repo| {
+            let publisher_id = books.iter().map(|b| b.publisher_id()).collect::<HashSet<_>>().into_iter().collect::<Vec<_>>();
+            repo.find_by_id(&publisher_id).into_iter()
+                .map(|p| p.id())
+                .collect::<HashSet<_>>()
+        });
+
+        let mut valid = Vec::new();
+        let mut invalid = Vec::new();
+        for book in books {
+            let mut violations = Self::column_violations(&book);
+            if publishers.as_ref().is_some_and(|ids| !ids.contains(&book.publisher_id())) {
+                violations.push(BookViolation::PublisherNotFound(book.publisher_id()));
+            }
+            if violations.is_empty() {
+                valid.push(book);
+            } else {
+                invalid.push((book, violations));
+            }
+        }
+        (valid, invalid)
+    }
+}
+
+/// 저장 결과에 검증에 실패한 도서를 더한다.
+///
+/// # Description
+/// 검증에 실패한 도서가 있는 경우 도서마다 위반 사유를 경고 로그로 남기고, 도서별 위반 사유를 담은 에러를 반환하여 잡의 건너뛰기와 격리 대상이 되도록 한다.
+/// 저장에 실패한 경우 저장에 실패한 도서와 검증에 실패한 도서를 각각의 사유와 함께 담는다.
+fn with_invalid_books(written: Result<(), JobWriteFailed<Book>>, invalid: Vec<(Book, Vec<BookViolation>)>) -> Result<(), JobWriteFailed<Book>> {
+    if invalid.is_empty() {
+        return written;
+    }
+
+    let invalid_count = invalid.len();
+    let failures = invalid.into_iter()
+        .map(|(book, violations)| {
+            let reason = violations.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+            warn!("Invalid book {}: {}", book.isbn(), reason);
+            (book, reason)
+        })
+        .collect();
+    with_failed_books(written, failures, &format!("{} invalid books", invalid_count))
+}
+
+/// 저장을 요청한 도서 중 저장소가 저장한 도서(`wrote`)에 없는 도서를 사유와 함께 반환한다.
+fn unwritten_books(requested: Vec<Book>, wrote: &[Book], cause: &str) -> Vec<(Book, String)> {
+    let wrote = wrote.iter().map(|book| book.isbn()).collect::<HashSet<_>>();
+    requested.into_iter()
+        .filter(|book| !wrote.contains(book.isbn()))
+        .map(|book| (book, cause.to_owned()))
+        .collect()
+}
+
+/// 저장 결과에 저장하지 못한 도서와 사유를 더한다. 저장에 실패한 경우 에러의 도서와 사유 뒤에 이어 붙이고 `message`를 에러 메시지에 덧붙인다.
+fn with_failed_books(written: Result<(), JobWriteFailed<Book>>, failures: Vec<(Book, String)>, message: &str) -> Result<(), JobWriteFailed<Book>> {
+    if failures.is_empty() {
+        return written;
+    }
+    match written {
+        Ok(()) => Err(JobWriteFailed::new_with_causes(failures, message)),
+        Err(e) => {
+            let message = format!("{}, {}", e.message(), message);
+            let mut causes = e.into_causes();
+            causes.extend(failures);
+            Err(JobWriteFailed::new_with_causes(causes, &message))
+        }
+    }
+}
+
 pub struct OnlyNewBooksWriter {
     repo: SharedBookRepository,
+    validator: BookValidator,
 }
 
 impl OnlyNewBooksWriter {
     pub fn new(repo: SharedBookRepository) -> Self {
         Self {
             repo,
+            validator: BookValidator::new(),
         }
     }
+
+    /// 저장 전 도서의 출판사가 저장소에 있는지 확인하도록 설정한다.
+    pub fn with_publisher_repo(mut self, pub_repo: SharedPublisherRepository) -> Self {
+        self.validator = self.validator.with_publisher_repo(pub_repo);
+        self
+    }
 }
 
 impl Writer for OnlyNewBooksWriter {
     type Item = Book;
 
     fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let (items, invalid) = self.validator.validate(items);
         let exists_in_db = retrieve_exists_book_in_db(&self.repo, &items);
 
         let new_books = items.into_iter()
@@ -247,50 +784,337 @@ impl Writer for OnlyNewBooksWriter {
         if wrote.len() > 0 {
             warn!("No new books to write");
         }
-        Ok(())
+        audit::record_created_books(&wrote);
+        with_invalid_books(Ok(()), invalid)
+    }
+}
+
+/// 도서의 내용(속성과 원본 데이터) 해시를 반환한다.
+///
+/// # Description
+/// 제목, 정규화된 제목, 출판사, 출판일, 도서 설명과 사이트별 원본 데이터의 내용 해시를 이어 SHA-256으로 해시한다.
+/// 아이디, 시리즈, 등록/수정 시각은 저장 과정에서 정해지는 값이므로 해시에 포함하지 않는다.
+/// 라이터는 저장소의 도서와 병합한 도서의 해시가 같은 경우 바뀐 내용이 없는 것으로 보고 저장하지 않는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::book::content_hash;
+/// use book_batch_rust::item::{Book, Raw, RawValue, Site};
+///
+/// let mut raw = Raw::new();
+/// raw.insert("salePrice".to_owned(), RawValue::Text("12000".to_owned()));
+/// let book = |id: u64, raw: Raw| Book::builder()
+///     .id(id)
+///     .isbn("9791133478410".to_owned())
+///     .title("원피스 1".to_owned())
+///     .publisher_id(1)
+///     .add_original(Site::Naver, raw)
+///     .build()
+///     .unwrap();
+///
+/// // 아이디는 해시에 포함하지 않는다.
+/// let hash = content_hash(&book(1, raw.clone()));
+/// assert_eq!(hash, content_hash(&book(2, raw.clone())));
+///
+/// raw.insert("salePrice".to_owned(), RawValue::Text("13000".to_owned()));
+/// assert_ne!(hash, content_hash(&book(1, raw.clone())));
+///
+/// // 저장소의 도서를 같은 내용의 도서와 병합하면 해시가 바뀌지 않는다. (출판일 포함)
+/// let db = book(1, raw).to_builder()
+///     .scheduled_pub_date(chrono::NaiveDate::from_ymd_opt(2025, 6, 1).unwrap())
+///     .build()
+///     .unwrap();
+/// assert_eq!(content_hash(&db), content_hash(&db.merge(&db.clone())));
+/// ```
+pub fn content_hash(book: &Book) -> String {
+    let mut hasher = Sha256::new();
+    let fields = [
+        Some(book.title().to_owned()),
+        book.normalized_title().map(|t| t.to_owned()),
+        Some(book.publisher_id().to_string()),
+        book.scheduled_pub_date().map(|d| d.to_string()),
+        book.actual_pub_date().map(|d| d.to_string()),
+        book.description().map(|d| d.to_owned()),
+    ];
+    for field in fields {
+        hasher.update(field.as_deref().unwrap_or("\0").as_bytes());
+        hasher.update(b"\n");
+    }
+
+    // 원본 데이터는 사이트 순서로 정렬하여 사이트별 내용 해시를 이어 붙인다.
+    let originals = book.originals().iter()
+        .map(|(site, raw)| (site.to_string(), raw_utils::content_hash(raw)))
+        .collect::<BTreeMap<_, _>>();
+    for (site, hash) in originals {
+        hasher.update(site.as_bytes());
+        hasher.update(b"=");
+        hasher.update(hash.as_bytes());
+        hasher.update(b"\n");
     }
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
 }
 
+/// 도서 저장(upsert) 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpsertMode {
+    /// 청크의 도서를 순서대로 업데이트 하고 새 도서를 한번에 저장한다.
+    #[default]
+    Combined,
+
+    /// 청크의 새 도서를 먼저 저장(insert)한 후 기존 도서를 업데이트(update) 한다. 각 단계는 별도의 트랜잭션으로 실행된다.
+    ///
+    /// 읽기 전용 복제본의 복제 지연에 민감한 환경에서 새 도서가 업데이트 보다 먼저 커밋 되도록 할 때 사용한다.
+    TwoPhase,
+}
+
+/// 새 도서는 저장하고 이미 저장된 도서는 병합하여 업데이트 하는 라이터
+///
+/// # Note
+/// 저장소의 도서와 병합한 도서의 내용 해시([`content_hash`])가 같은 도서는 바뀐 내용이 없으므로 업데이트 하지 않는다.
+/// 저장 전 [`BookValidator`]로 검증하여 검증에 실패한 도서는 저장하지 않고 에러로 반환한다.
 pub struct UpsertBookWriter {
     repo: SharedBookRepository,
+    mode: UpsertMode,
+    validator: BookValidator,
 }
 
 impl UpsertBookWriter {
     pub fn new(repo: SharedBookRepository) -> Self {
         Self {
             repo,
+            mode: UpsertMode::default(),
+            validator: BookValidator::new(),
         }
     }
-}
 
-impl Writer for UpsertBookWriter {
-    type Item = Book;
+    pub fn with_mode(mut self, mode: UpsertMode) -> Self {
+        self.mode = mode;
+        self
+    }
 
-    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
-        let exists_in_db = retrieve_exists_book_in_db(&self.repo, &items);
+    /// 저장 전 도서의 출판사가 저장소에 있는지 확인하도록 설정한다.
+    pub fn with_publisher_repo(mut self, pub_repo: SharedPublisherRepository) -> Self {
+        self.validator = self.validator.with_publisher_repo(pub_repo);
+        self
+    }
+}
 
+impl UpsertBookWriter {
+    /// 새 도서를 저장하고 기존 도서를 업데이트 한다.
+    ///
+    /// # Note
+    /// 업데이트에 실패한 도서가 있더라도 나머지 도서는 저장하며, 저장하지 못한 도서를 모두 사유와 함께 에러로 반환한다.
+    fn write_combined(&self, items: Vec<Book>, exists_in_db: HashMap<String, Book>) -> Result<(), JobWriteFailed<Book>> {
         let mut new_books = Vec::new();
+        let mut failures = Vec::new();
+        let mut unchanged = 0;
         for book in items {
             if !exists_in_db.contains_key(book.isbn()) {
                 new_books.push(book);
             } else {
                 let db_book = exists_in_db.get(book.isbn()).unwrap();
                 let merged_book = db_book.merge(&book);
+                if content_hash(db_book) == content_hash(&merged_book) {
+                    unchanged += 1;
+                    continue;
+                }
                 let updated_count = self.repo.update_book(&merged_book);
-                if updated_count <= 0 {
-                    return Err(JobWriteFailed::new(vec![merged_book], "Failed to update book"));
+                if updated_count == 0 {
+                    failures.push((merged_book, "Failed to update book".to_owned()));
+                    continue;
                 }
+                audit::record_updated_book(db_book, &merged_book);
             }
         }
 
+        if unchanged > 0 {
+            info!("{} books are unchanged, update skipped", unchanged);
+        }
+
         let wrote = self.repo.save_books(&new_books);
         if wrote.len() == 0 {
             warn!("No new books to write")
         }
+        audit::record_created_books(&wrote);
+        failures.extend(unwritten_books(new_books, &wrote, "Failed to insert book"));
+
+        let failed_count = failures.len();
+        with_failed_books(Ok(()), failures, &format!("{} books failed to write", failed_count))
+    }
+
+    /// 새 도서를 저장하는 단계와 기존 도서를 업데이트 하는 단계를 나누어 실행하고 단계별 결과를 로그로 남긴다.
+    ///
+    /// # Note
+    /// 저장 단계가 실패하면 업데이트 단계를 실행하지 않으며, 업데이트 단계는 하나라도 실패할 경우 청크의 업데이트 전체를 실패로 처리한다.
+    /// 에러에는 저장하지 못한 도서(저장 단계가 실패한 경우 업데이트 하지 않은 기존 도서 포함)를 모두 담는다.
+    fn write_two_phase(&self, items: Vec<Book>, exists_in_db: HashMap<String, Book>) -> Result<(), JobWriteFailed<Book>> {
+        let (new_books, exists_books): (Vec<_>, Vec<_>) = items.into_iter()
+            .partition(|book| !exists_in_db.contains_key(book.isbn()));
+
+        let wrote = if new_books.is_empty() {
+            Vec::new()
+        } else {
+            self.repo.save_books(&new_books)
+        };
+        info!("Upsert insert phase: {} / {} books inserted", wrote.len(), new_books.len());
+        audit::record_created_books(&wrote);
+        if wrote.len() < new_books.len() {
+            let mut failures = unwritten_books(new_books, &wrote, "Failed to insert book");
+            failures.extend(exists_books.into_iter().map(|book| (book, "Update skipped, insert phase failed".to_owned())));
+            return Err(JobWriteFailed::new_with_causes(failures, "Failed to insert books"));
+        }
+
+        let merged_books = exists_books.iter()
+            .map(|book| exists_in_db.get(book.isbn()).unwrap().merge(book))
+            .filter(|merged| content_hash(exists_in_db.get(merged.isbn()).unwrap()) != content_hash(merged))
+            .collect::<Vec<_>>();
+        let updated_count = self.repo.update_books(&merged_books);
+        info!("Upsert update phase: {} / {} books updated, {} books unchanged", updated_count, merged_books.len(), exists_books.len() - merged_books.len());
+        if updated_count < merged_books.len() {
+            return Err(JobWriteFailed::new(merged_books, "Failed to update books"));
+        }
+        for merged_book in merged_books.iter() {
+            audit::record_updated_book(exists_in_db.get(merged_book.isbn()).unwrap(), merged_book);
+        }
         Ok(())
     }
 }
 
+impl Writer for UpsertBookWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let (items, invalid) = self.validator.validate(items);
+        let exists_in_db = retrieve_exists_book_in_db(&self.repo, &items);
+
+        let written = match self.mode {
+            UpsertMode::Combined => self.write_combined(items, exists_in_db),
+            UpsertMode::TwoPhase => self.write_two_phase(items, exists_in_db),
+        };
+        with_invalid_books(written, invalid)
+    }
+}
+
+/// 도서 행은 수정하지 않고 원본 데이터만 교체하는 라이터
+///
+/// # Description
+/// KYOBO, NAVER 처럼 이미 저장된 도서의 원본 데이터를 보강하는 잡에서 [`UpsertBookWriter`] 대신 사용한다.
+/// 저장소의 도서와 병합한 결과 도서의 속성(제목, 출판일 등)이 바뀌지 않은 경우 바뀐 사이트의 원본 데이터만 교체하여
+/// 도서 행의 `modified_at` 갱신과 불필요한 행 재작성을 줄인다. 원본 데이터도 바뀌지 않은 도서는 저장하지 않는다.
+/// 새 도서와 도서의 속성이 바뀐 도서는 [`UpsertBookWriter`]로 저장한다.
+///
+/// # Note
+/// 도서 설명 등 파생 속성의 변경은 기본적으로 도서의 속성 변경으로 판단한다.
+/// [`OriginOnlyWriter::with_derived_fields`]로 파생 속성의 변경을 무시하도록 설정할 수 있다.
+/// 원본 데이터 교체에 실패한 도서가 있더라도 청크의 나머지 도서는 저장하며, 저장하지 못한 도서를 모두 사유와 함께 에러로 반환한다.
+pub struct OriginOnlyWriter {
+    repo: SharedBookRepository,
+    upsert: UpsertBookWriter,
+
+    /// 파생 속성(도서 설명)의 변경을 도서의 속성 변경으로 판단할지 여부
+    derived_fields: bool,
+}
+
+impl OriginOnlyWriter {
+    pub fn new(repo: SharedBookRepository) -> Self {
+        Self {
+            upsert: UpsertBookWriter::new(repo.clone()),
+            repo,
+            derived_fields: true,
+        }
+    }
+
+    /// 새 도서와 속성이 바뀐 도서를 저장할 때 사용할 업서트 방식을 설정한다.
+    pub fn with_mode(mut self, mode: UpsertMode) -> Self {
+        self.upsert = self.upsert.with_mode(mode);
+        self
+    }
+
+    /// 새 도서와 속성이 바뀐 도서를 저장하기 전 도서의 출판사가 저장소에 있는지 확인하도록 설정한다.
+    pub fn with_publisher_repo(mut self, pub_repo: SharedPublisherRepository) -> Self {
+        self.upsert = self.upsert.with_publisher_repo(pub_repo);
+        self
+    }
+
+    /// 파생 속성(도서 설명)의 변경을 도서의 속성 변경으로 판단할지 여부를 설정한다.
+    /// `false`인 경우 파생 속성만 바뀐 도서도 원본 데이터만 교체한다.
+    pub fn with_derived_fields(mut self, derived_fields: bool) -> Self {
+        self.derived_fields = derived_fields;
+        self
+    }
+
+    fn has_book_changes(&self, before: &Book, merged: &Book) -> bool {
+        // 병합된 도서는 시리즈 아이디를 가지지 않으며 업데이트 시에도 시리즈 아이디는 변경되지 않으므로 비교에서 제외한다.
+        let core_changed = audit::book_field_changes(before, merged).iter()
+            .any(|change| change.field != "series_id");
+        core_changed || (self.derived_fields && before.description() != merged.description())
+    }
+}
+
+impl Writer for OriginOnlyWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let exists_in_db = retrieve_exists_book_in_db(&self.repo, &items);
+
+        let origin_update_enabled = self.repo.is_origin_update_enabled();
+        let mut upsert_books = Vec::new();
+        let mut failures = Vec::new();
+        let mut origin_updated = 0;
+        let mut origin_skipped = 0;
+        let mut unchanged = 0;
+        for book in items {
+            let (db_book, merged) = match exists_in_db.get(book.isbn()) {
+                Some(db_book) => (db_book, db_book.merge(&book)),
+                None => {
+                    upsert_books.push(book);
+                    continue;
+                }
+            };
+            if content_hash(db_book) == content_hash(&merged) {
+                unchanged += 1;
+                continue;
+            }
+            if self.has_book_changes(db_book, &merged) {
+                upsert_books.push(book);
+                continue;
+            }
+
+            let changed_originals = book.originals().iter()
+                .filter(|(site, raw)| db_book.originals().get(site) != Some(raw))
+                .map(|(site, raw)| (site.clone(), raw.clone()))
+                .collect::<Originals>();
+            if changed_originals.is_empty() {
+                unchanged += 1;
+                continue;
+            }
+            // 원본 데이터 수정을 사용하지 않는 저장소는 업서트 하더라도 원본 데이터를 교체하지 않으므로 바뀐 내용이 없는 것으로 본다.
+            if !origin_update_enabled {
+                origin_skipped += 1;
+                continue;
+            }
+            if self.repo.update_origins(db_book.id(), &changed_originals) == 0 {
+                failures.push((book, "Failed to update origins".to_owned()));
+                continue;
+            }
+            origin_updated += 1;
+        }
+        info!("{} books origins updated, {} books unchanged, {} books upserted", origin_updated, unchanged, upsert_books.len());
+        if origin_skipped > 0 {
+            warn!("Origin update is disabled, origins of {} books are not updated", origin_skipped);
+        }
+
+        let written = if upsert_books.is_empty() {
+            Ok(())
+        } else {
+            self.upsert.do_write(upsert_books)
+        };
+        let failed_count = failures.len();
+        with_failed_books(written, failures, &format!("{} books failed to update origins", failed_count))
+    }
+}
+
 fn retrieve_exists_book_in_db(repo: &SharedBookRepository, books: &[Book]) -> HashMap<String, Book> {
     let books_isbn = books.iter().map(|b| b.as_ref().isbn()).collect::<Vec<_>>();
     repo.find_by_isbn(&books_isbn).into_iter()
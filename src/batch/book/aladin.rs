@@ -1,10 +1,17 @@
-use crate::batch::book::{create_default_filter_chain, ByPublisher, OriginalDataFilter, UpsertBookWriter};
-use crate::batch::error::JobReadFailed;
-use crate::batch::{job_builder, Job, JobParameter, Reader};
-use crate::item::{Book, BookBuilder, BookRepository, FilterRepository, PublisherRepository, SharedPublisherRepository, Site};
-use crate::provider;
-use crate::provider::api::{aladin, Client};
+use crate::batch::book::quota::DailyQuota;
+use crate::batch::book::{create_default_filter_chain, create_description_processor, create_original_data_filter, drop_read_isbn, ByPublisher, UpsertBookWriter, UpsertMode};
+use crate::batch::error::{JobBuildError, JobReadFailed};
+use crate::batch::file::{retrieve_input_reader_in_parameter, retrieve_output_writer_in_parameter};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::{collect_pages, job_builder, retrieve_chunk_size_in_parameter, retrieve_spill_threshold_in_parameter, Job, JobParameter, ReadPages, Reader, DEF_CHUNK_SIZE, trace};
+use crate::item::{Book, BookBuilder, BookRepository, FilterRepository, Publisher, PublisherRepository, RawValue, SharedPublisherRepository, SharedQuotaRepository, Site};
+use crate::provider::api::aladin::{ItemListRequest, SearchRequest, QUERY_TYPE_ITEM_NEW_SPECIAL};
+use crate::provider::api::{Client, ItemListClient};
+use crate::{JobName, PARAM_NAME_ITEM_LIST};
+use std::collections::HashMap;
+use std::iter;
 use std::rc::Rc;
+use tracing::{info, warn};
 
 const PAGE_SIZE: usize = 50;
 
@@ -12,14 +19,96 @@ const PAGE_SIZE: usize = 50;
 /// 신간 도서가 200건 보다 많아도 200건 까지만 조회 가능하고 그 이후 부터는 1페이지 부터 응답이 반복 된다.
 const MAX_RESULT: usize = 200;
 
+/// 알라딘 출판사별 신간 도서 리더
+///
+/// # Note
+/// 일일 요청 한도([`DailyQuota`])를 넘을 경우 남은 출판사 키워드는 요청하지 않는다.
+/// 알라딘은 항상 최근 출판된 도서부터 조회하므로 남은 출판사는 다음 실행에서 다시 조회된다.
+///
+/// 상품 리스트 클라이언트([`ItemListClient`])가 설정된 경우 출판사 키워드 검색 결과에 주목할 만한 신간 리스트의 도서를 합친다.
+/// 신간 리스트의 도서는 출판사명이 출판사 키워드와 일치하는 도서만 해당 출판사의 도서로 수집한다.
 pub struct AladinReader {
-    client: Rc<aladin::Client>,
+    client: Rc<dyn Client<Request = SearchRequest>>,
+    item_list_client: Option<Rc<dyn ItemListClient<Request = ItemListRequest>>>,
     pub_repo: SharedPublisherRepository,
+    quota: DailyQuota,
 }
 
 impl AladinReader {
-    pub fn new(client: Rc<aladin::Client>, pub_repo: SharedPublisherRepository) -> Self {
-        Self { client, pub_repo }
+    pub fn new(client: Rc<dyn Client<Request = SearchRequest>>, pub_repo: SharedPublisherRepository, quota_repo: SharedQuotaRepository) -> Self {
+        Self { client, item_list_client: None, pub_repo, quota: DailyQuota::with_env(quota_repo, Site::Aladin) }
+    }
+
+    /// 주목할 만한 신간 리스트를 조회할 상품 리스트 클라이언트를 설정한다.
+    pub fn with_item_list(mut self, item_list_client: Rc<dyn ItemListClient<Request = ItemListRequest>>) -> Self {
+        self.item_list_client = Some(item_list_client);
+        self
+    }
+
+    /// 출판사 키워드 검색 결과를 출판사 단위의 페이지로 반환하고, 상품 리스트 클라이언트가 설정된 경우 주목할 만한 신간 리스트의 도서를 마지막 페이지로 반환한다.
+    /// 모든 페이지를 읽은 후 일일 요청 한도의 사용량을 로그로 남긴다.
+    fn read_pages_with_item_list<'a>(&'a self, params: &'a JobParameter) -> Result<ReadPages<'a, Book>, JobReadFailed> {
+        let pages = <Self as ByPublisher>::read_pages(self, params)?;
+        let item_list_page = iter::once_with(move || {
+            let books = self.read_item_list_page(params);
+            self.quota.log();
+            books
+        });
+        Ok(Box::new(pages.chain(item_list_page)))
+    }
+
+    fn read_item_list_page(&self, params: &JobParameter) -> Result<Vec<Book>, JobReadFailed> {
+        let Some(item_list_client) = self.item_list_client.as_ref() else {
+            return Ok(Vec::new());
+        };
+        let publishers = self.load_publisher(params)?;
+        let item_list_books = self.read_item_list(item_list_client.as_ref(), &publishers)?;
+        info!("{:?} => {} book(s) found in {}", Site::Aladin, item_list_books.len(), QUERY_TYPE_ITEM_NEW_SPECIAL);
+        Ok(item_list_books)
+    }
+
+    fn read_item_list(&self, item_list_client: &dyn ItemListClient<Request = ItemListRequest>, publishers: &[Publisher]) -> Result<Vec<Book>, JobReadFailed> {
+        let publisher_by_keyword = publishers.iter()
+            .flat_map(|publisher| publisher.keywords().get(&Site::Aladin)
+                .into_iter()
+                .flatten()
+                .map(|keyword| (keyword.trim().to_owned(), publisher.id())))
+            .collect::<HashMap<_, _>>();
+
+        let mut result = Vec::new();
+        let mut current_fetch_size = 0;
+        let mut next_request = Some(ItemListRequest::builder(QUERY_TYPE_ITEM_NEW_SPECIAL)
+            .size(PAGE_SIZE as u32)
+            .build());
+        while current_fetch_size < MAX_RESULT && self.quota.try_acquire() {
+            let Some(request) = next_request.take() else {
+                break;
+            };
+
+            let response = item_list_client.item_list(&request)
+                .map_err(JobReadFailed::from)?;
+            current_fetch_size += response.books.len();
+            next_request = response.next_page_request(&request);
+
+            for builder in response.books {
+                let book = match builder.build() {
+                    Ok(book) => book,
+                    Err(_) => continue,
+                };
+                if let Some(publisher_id) = item_publisher(&book).and_then(|name| publisher_by_keyword.get(name.trim())) {
+                    result.push(book.to_builder().publisher_id(*publisher_id).build().unwrap());
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// 알라딘 원본 데이터의 출판사명을 반환한다.
+fn item_publisher(book: &Book) -> Option<&str> {
+    match book.originals().get(&Site::Aladin)?.get("publisher")? {
+        RawValue::Text(name) => Some(name.as_str()),
+        _ => None,
     }
 }
 
@@ -27,7 +116,14 @@ impl Reader for AladinReader {
     type Item = Book;
 
     fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
-        <Self as ByPublisher>::read_books(self, params)
+        collect_pages(self.read_pages_with_item_list(params)?)
+    }
+
+    fn do_read_pages<'a>(&'a self, params: &'a JobParameter) -> Result<ReadPages<'a, Self::Item>, JobReadFailed>
+    where
+        Self::Item: 'a,
+    {
+        Ok(drop_read_isbn(self.read_pages_with_item_list(params)?))
     }
 }
 
@@ -43,38 +139,79 @@ impl ByPublisher for AladinReader {
     fn by_publisher_keyword(&self, keyword: &str, _: &JobParameter) -> Result<Vec<BookBuilder>, JobReadFailed> {
         let mut result = Vec::new();
         let mut current_fetch_size = 0;
-        let mut current_page = 1;
-        loop {
-            let request = provider::api::Request::builder()
-                .page(current_page).size(PAGE_SIZE as i32)
-                .query(keyword.to_owned())
-                .build().unwrap();
-
-            let response = self.client.get_books(&request).unwrap();
-            if !response.books.is_empty() && current_fetch_size < MAX_RESULT {
-                current_fetch_size += response.books.len();
-                current_page += 1;
-
-                response.books.into_iter().for_each(|b| result.push(b));
-            } else {
-                break Ok(result);
-            }
+        let mut next_request = Some(SearchRequest::builder(keyword)
+            .size(PAGE_SIZE as u32)
+            .build());
+        while current_fetch_size < MAX_RESULT && self.quota.try_acquire() {
+            let Some(request) = next_request.take() else {
+                break;
+            };
+
+            let response = self.client.get_books(&request)
+                .inspect_err(|e| warn!("{} => Search failed: {:?}", keyword, e))
+                .map_err(JobReadFailed::from)?;
+            current_fetch_size += response.books.len();
+            next_request = response.next_page_request(&request);
+
+            response.books.into_iter().for_each(|b| result.push(b));
         }
+        Ok(result)
     }
 }
 
+/// [`JobParameter`]에서 주목할 만한 신간 리스트 수집 여부(`item_list`)를 얻는다. 파라미터가 없을 경우 `false`를 반환한다.
+fn retrieve_item_list_in_parameter(params: &JobParameter) -> Result<bool, JobBuildError> {
+    params.get(PARAM_NAME_ITEM_LIST)
+        .map(|v| v.trim().parse::<bool>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_ITEM_LIST, e))))
+        .transpose()
+        .map(|enabled| enabled.unwrap_or(false))
+}
+
 pub fn create_job(
-    client: Rc<aladin::Client>,
+    client: Rc<dyn Client<Request = SearchRequest>>,
+    item_list_client: Rc<dyn ItemListClient<Request = ItemListRequest>>,
     publisher_repo: Rc<Box<dyn PublisherRepository>>,
     book_repo: Rc<Box<dyn BookRepository>>,
     filter_repo: Rc<Box<dyn FilterRepository>>,
-) -> Job<Book, Book> {
-    let filter_chain = create_default_filter_chain()
-        .add_filter(Box::new(OriginalDataFilter::new(filter_repo.clone(), Site::Aladin)));
+    quota_repo: SharedQuotaRepository,
+    upsert_mode: UpsertMode,
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<Job<Book, Book>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+    let reader = match retrieve_input_reader_in_parameter(params) {
+        Some(reader) => reader,
+        None => {
+            let reader = AladinReader::new(client.clone(), publisher_repo.clone(), quota_repo.clone());
+            if retrieve_item_list_in_parameter(params)? {
+                Box::new(reader.with_item_list(item_list_client.clone()))
+            } else {
+                Box::new(reader)
+            }
+        }
+    };
+    let writer = match retrieve_output_writer_in_parameter(params)? {
+        Some(writer) => writer,
+        None => Box::new(UpsertBookWriter::new(book_repo.clone()).with_mode(upsert_mode).with_publisher_repo(publisher_repo.clone())),
+    };
 
-    job_builder()
-        .reader(Box::new(AladinReader::new(client.clone(), publisher_repo.clone())))
+    let mut filter_chain = create_default_filter_chain();
+    if let Some(filter) = create_original_data_filter(filter_repo.clone(), Site::Aladin, params)? {
+        filter_chain = filter_chain.add_filter(Box::new(filter.with_profiles(profiles.clone())));
+    }
+
+    let job = job_builder()
+        .reader(reader)
         .filter(Box::new(filter_chain))
-        .writer(Box::new(UpsertBookWriter::new(book_repo.clone())))
-        .build()
+        .processor(Box::new(create_description_processor(params)?.with_profiles(profiles.clone())))
+        .writer(writer)
+        .build();
+
+    let job = job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key)
+        .with_quarantine(JobName::ALADIN);
+    match retrieve_spill_threshold_in_parameter(params)? {
+        Some(threshold) => Ok(job.with_spill_threshold(threshold)),
+        None => Ok(job),
+    }
 }
\ No newline at end of file
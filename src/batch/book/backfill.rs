@@ -0,0 +1,182 @@
+use crate::batch::book::{nlgo as nlgo_job, retrieve_publisher_id_in_parameter};
+use crate::batch::cancel::CancellationToken;
+use crate::batch::error::{JobBuildError, JobReadFailed, JobRuntimeError};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::{is_dry_run, JobParameter};
+use crate::clock::{system_clock, SharedClock};
+use crate::item::{BackfillProgress, Book, Publisher, SharedBackfillRepository, SharedBookRepository, SharedFilterRepository, SharedPublisherRepository, Site};
+use crate::provider::api::nlgo::SearchRequest;
+use crate::provider::api::Client;
+use crate::{PARAM_NAME_FROM, PARAM_NAME_INPUT, PARAM_NAME_OUTPUT, PARAM_NAME_PUBLISHER_ID, PARAM_NAME_START_YEAR, PARAM_NAME_TO};
+use chrono::{Datelike, Months, NaiveDate};
+use std::env;
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// 한 달치 수집을 마친 후 다음 달 수집을 시작하기 전 기다릴 기본 간격(밀리초)
+const DEFAULT_BACKFILL_DELAY_MILLIS: u64 = 1000;
+
+/// 국립중앙도서관 과거 도서 수집(백필) 잡
+///
+/// # Description
+/// 출판사별로 시작 연도의 1월부터 현재까지 한 달씩 국립중앙도서관 수집 잡([`nlgo_job::create_job`])을 실행한다.
+/// 한 달치 수집을 마칠 때마다 진행 기록([`BackfillProgress`])을 저장하므로 중단된 경우 다시 실행하면 수집을 마친 다음 날짜부터 이어서 수집한다.
+/// - `start_year`: (필수) 수집을 시작할 연도
+/// - `publisher_id`: 수집할 출판사 아이디, 입력하지 않을 경우 국립중앙도서관 검색 키워드가 있는 모든 출판사를 수집한다.
+///
+/// # Rate limit
+/// 한 달치 수집을 마친 후 환경 변수 `BACKFILL_DELAY_MS`(기본값 1000)에 설정된 시간 만큼 기다린 후 다음 달을 수집한다.
+///
+/// # Note
+/// 백필은 항상 저장소에 저장하므로 `input`, `output` 파라미터는 사용하지 않는다.
+/// `dry_run` 파라미터가 `true`인 경우 도서와 진행 기록을 저장하지 않는다.
+pub struct BackfillJob {
+    client: Rc<dyn Client<Request = SearchRequest>>,
+    pub_repo: SharedPublisherRepository,
+    book_repo: SharedBookRepository,
+    filter_repo: SharedFilterRepository,
+    backfill_repo: SharedBackfillRepository,
+
+    /// 출판사별 처리 프로필 (한 달치 수집 잡에 전달한다.)
+    profiles: PublisherProfiles,
+
+    /// 수집 시작일 (시작 연도의 1월 1일)
+    start: NaiveDate,
+
+    /// 한 달치 수집을 마친 후 기다릴 간격
+    delay: Duration,
+
+    /// 수집 종료일(오늘)과 진행 기록 시각에 사용할 시계
+    clock: SharedClock,
+}
+
+impl BackfillJob {
+
+    /// 수집 종료일과 진행 기록 시각에 사용할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 백필을 실행한다. 취소된 경우 수집 중인 달의 진행 기록은 저장하지 않으므로 다시 실행하면 그 달부터 이어서 수집한다.
+    pub fn run(&self, params: &JobParameter, cancel: &CancellationToken) -> Result<(), JobRuntimeError<Book, Book>> {
+        let today = self.clock.today();
+        let publishers = self.load_publisher(params)
+            .map_err(JobRuntimeError::ReadFailed)?;
+
+        for publisher in publishers {
+            let resume_from = self.backfill_repo.find_progress(&Site::NLGO, publisher.id())
+                .and_then(|progress| progress.completed_through().succ_opt())
+                .map(|date| date.max(self.start))
+                .unwrap_or(self.start);
+            info!("{} => Backfill starts from {}", publisher.name(), resume_from);
+
+            let mut from = resume_from;
+            while from <= today {
+                let to = last_day_of_month(from).min(today);
+                self.run_month(&publisher, from, to, params, cancel)?;
+
+                if !is_dry_run(params) {
+                    let progress = BackfillProgress::new(Site::NLGO, publisher.id(), to, self.clock.now());
+                    self.backfill_repo.save_progress(&progress);
+                }
+                info!("{} => Backfill completed through {}", publisher.name(), to);
+
+                from = match to.succ_opt() {
+                    Some(next) => next,
+                    None => break,
+                };
+                if from <= today {
+                    thread::sleep(self.delay);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn load_publisher(&self, params: &JobParameter) -> Result<Vec<Publisher>, JobReadFailed> {
+        let publisher_id = retrieve_publisher_id_in_parameter(params)?;
+        let publishers = if !publisher_id.is_empty() {
+            self.pub_repo.find_by_id(&publisher_id)
+        } else {
+            self.pub_repo.get_all()
+        };
+
+        let publishers = publishers.into_iter()
+            .filter(|publisher| {
+                let has_keyword = publisher.keywords().get(&Site::NLGO).is_some_and(|k| !k.is_empty());
+                if !has_keyword {
+                    warn!("{} => No keywords for site {:?}, backfill skipped", publisher.name(), Site::NLGO);
+                }
+                has_keyword
+            })
+            .collect();
+        Ok(publishers)
+    }
+
+    fn run_month(&self, publisher: &Publisher, from: NaiveDate, to: NaiveDate, params: &JobParameter, cancel: &CancellationToken) -> Result<(), JobRuntimeError<Book, Book>> {
+        let mut month_params = params.clone();
+        month_params.remove(PARAM_NAME_INPUT);
+        month_params.remove(PARAM_NAME_OUTPUT);
+        month_params.insert(PARAM_NAME_FROM.to_owned(), from.format("%Y-%m-%d").to_string());
+        month_params.insert(PARAM_NAME_TO.to_owned(), to.format("%Y-%m-%d").to_string());
+        month_params.insert(PARAM_NAME_PUBLISHER_ID.to_owned(), publisher.id().to_string());
+
+        let job = nlgo_job::create_job(
+            self.client.clone(),
+            self.pub_repo.clone(),
+            self.book_repo.clone(),
+            self.filter_repo.clone(),
+            &self.profiles,
+            &month_params,
+        ).map_err(|e| JobRuntimeError::ReadFailed(JobReadFailed::InvalidArguments(e.to_string())))?;
+        let job = if is_dry_run(&month_params) { job.with_dry_run() } else { job };
+
+        info!("{} => Backfill {} ~ {}", publisher.name(), from, to);
+        job.run(&month_params, cancel).map(|_| ())
+    }
+}
+
+pub fn create_job(
+    client: Rc<dyn Client<Request = SearchRequest>>,
+    pub_repo: SharedPublisherRepository,
+    book_repo: SharedBookRepository,
+    filter_repo: SharedFilterRepository,
+    backfill_repo: SharedBackfillRepository,
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<BackfillJob, JobBuildError> {
+    let start_year = params.get(PARAM_NAME_START_YEAR)
+        .ok_or_else(|| JobBuildError::MissingRequireParameter(PARAM_NAME_START_YEAR.to_owned()))?
+        .trim()
+        .parse::<i32>()
+        .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_START_YEAR, e)))?;
+    let start = NaiveDate::from_ymd_opt(start_year, 1, 1)
+        .ok_or_else(|| JobBuildError::InvalidParameter(format!("{}: {} is out of range", PARAM_NAME_START_YEAR, start_year)))?;
+
+    let delay = env::var("BACKFILL_DELAY_MS").ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_BACKFILL_DELAY_MILLIS);
+
+    Ok(BackfillJob {
+        client,
+        pub_repo,
+        book_repo,
+        filter_repo,
+        backfill_repo,
+        profiles: profiles.clone(),
+        start,
+        delay: Duration::from_millis(delay),
+        clock: system_clock(),
+    })
+}
+
+/// 날짜가 속한 달의 마지막 날을 반환한다.
+fn last_day_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1)
+        .and_then(|first| first.checked_add_months(Months::new(1)))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(date)
+}
@@ -0,0 +1,268 @@
+use crate::batch::book::{create_description_processor, retrieve_exists_book_in_db, retrieve_isbn_in_parameter, retrieve_publisher_id_in_parameter, UpsertBookWriter, UpsertMode};
+use crate::batch::error::{JobBuildError, JobReadFailed, JobWriteFailed};
+use crate::batch::metrics::{Metrics, METRIC_NOT_FOUND};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Reader, Writer, DEF_CHUNK_SIZE, trace};
+use crate::item::{Book, Raw, SharedBookRepository, Site};
+use crate::provider::api::{ClientError, LookupClient};
+use crate::provider::html;
+use crate::provider::html::ParsingError;
+use crate::{JobName, PARAM_NAME_UPSERT};
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use tracing::{error, warn};
+
+/// ISBN 단건 수집 리더
+///
+/// # Description
+/// 입력 받은 ISBN으로 등록된 모든 사이트(API, HTML)에서 도서를 조회하고 하나의 도서로 병합한다.
+/// 사이트 조회는 등록된 순서대로 진행 되며 제목, 출판일은 나중에 조회된 사이트의 값으로 덮어 쓴다.
+///
+/// # Note
+/// - 특정 사이트의 조회가 실패 하더라도 로그를 남기고 다음 사이트를 조회한다.
+/// - `publisher_id` 파라미터가 있을 경우 첫번째 출판사 아이디를 도서의 출판사로 설정한다.
+/// - 사이트에서 도서를 찾을 수 없는 경우는 에러로 기록하지 않고 `not_found` 카운터에 기록한다.
+pub struct FetchReader {
+    api_clients: Vec<Rc<dyn LookupClient>>,
+    html_clients: Vec<(Site, Rc<dyn html::Client>)>,
+    metrics: Metrics,
+}
+
+impl FetchReader {
+    pub fn new(api_clients: Vec<Rc<dyn LookupClient>>, html_clients: Vec<(Site, Rc<dyn html::Client>)>) -> Self {
+        Self { api_clients, html_clients, metrics: Metrics::new() }
+    }
+
+    fn fetch(&self, isbn: &str) -> Vec<Book> {
+        let mut books = Vec::new();
+
+        for client in &self.api_clients {
+            match client.lookup(isbn) {
+                Ok(response) => {
+                    let found = response.books.into_iter()
+                        .filter_map(|builder| builder.build().ok())
+                        .filter(|book| is_same_isbn(book.isbn(), isbn));
+                    books.extend(found);
+                }
+                Err(ClientError::NotFound(_)) => {
+                    warn!("{} => Item(isbn) not found: {}", client.site(), isbn);
+                    self.metrics.increment(METRIC_NOT_FOUND);
+                }
+                Err(err) => error!("{} => Failed to lookup isbn {}: {:?}", client.site(), isbn, err),
+            }
+        }
+
+        for (site, client) in &self.html_clients {
+            match client.get(isbn).map(|builder| builder.build()) {
+                Ok(Ok(book)) => books.push(book),
+                Ok(Err(err)) => error!("{} => Failed to build book {}: {}", site, isbn, err),
+                Err(ParsingError::ItemNotFound) => {
+                    warn!("{} => Item(isbn) not found: {}", site, isbn);
+                    self.metrics.increment(METRIC_NOT_FOUND);
+                }
+                Err(err) => error!("{} => Failed to lookup isbn {}: {}", site, isbn, err),
+            }
+        }
+
+        books
+    }
+}
+
+impl Reader for FetchReader {
+    type Item = Book;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let isbn_vec = retrieve_isbn_in_parameter(params)?;
+        if isbn_vec.is_empty() {
+            return Err(JobReadFailed::InvalidArguments("isbn is required".to_owned()));
+        }
+        let publisher_id = retrieve_publisher_id_in_parameter(params)?.first().cloned();
+
+        let mut result = Vec::new();
+        for isbn in isbn_vec {
+            let merged = self.fetch(&isbn).into_iter()
+                .reduce(|merged, book| merged.merge(&book));
+
+            match merged {
+                Some(book) => {
+                    let mut builder = book.to_builder().isbn(isbn);
+                    if let Some(publisher_id) = publisher_id {
+                        builder = builder.publisher_id(publisher_id);
+                    }
+                    result.push(builder.build().unwrap());
+                }
+                None => warn!("Item(isbn) not found in any site: {}", isbn),
+            }
+        }
+        self.metrics.log("FETCH");
+        Ok(result)
+    }
+}
+
+/// 수집한 도서와 저장소의 도서를 비교하여 출력하는 라이터
+///
+/// # Description
+/// 수집한 도서와 저장소에 저장된 도서의 차이를 표준 출력으로 출력한다.
+/// `upsert`가 설정 되어 있을 경우 출력 후 [`UpsertBookWriter`]를 이용해 도서를 저장한다.
+///
+/// # Note
+/// 저장소에 없는 새 도서는 출판사 아이디가 없으면 저장하지 않는다. (`--publisher-id`로 출판사를 지정해야 한다.)
+pub struct FetchReportWriter {
+    repo: SharedBookRepository,
+    upsert: Option<UpsertBookWriter>,
+}
+
+impl FetchReportWriter {
+    pub fn new(repo: SharedBookRepository, upsert: bool) -> Self {
+        let upsert = if upsert {
+            Some(UpsertBookWriter::new(repo.clone()))
+        } else {
+            None
+        };
+        Self { repo, upsert }
+    }
+
+    /// `upsert`가 설정된 경우 도서를 저장할 방식을 변경한다.
+    pub fn with_upsert_mode(mut self, mode: UpsertMode) -> Self {
+        self.upsert = self.upsert.map(|writer| writer.with_mode(mode));
+        self
+    }
+}
+
+impl Writer for FetchReportWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let exists_in_db = retrieve_exists_book_in_db(&self.repo, &items);
+
+        for book in &items {
+            let db_book = exists_in_db.get(book.isbn());
+            let lines = match db_book {
+                Some(db_book) => diff_book(Some(db_book), &db_book.merge(book)),
+                None => diff_book(None, book),
+            };
+
+            println!("=== {} ({}) ===", book.isbn(), if db_book.is_some() { "exists" } else { "new" });
+            if lines.is_empty() {
+                println!("  (no changes)");
+            }
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+
+        match &self.upsert {
+            Some(writer) => {
+                let (savable, skipped): (Vec<Book>, Vec<Book>) = items.into_iter()
+                    .partition(|book| exists_in_db.contains_key(book.isbn()) || book.publisher_id() != 0);
+                for book in skipped {
+                    warn!("Publisher id is required to save new book: {}", book.isbn());
+                }
+                writer.do_write(savable)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// 두 도서의 차이를 사람이 읽을 수 있는 문자열 리스트로 반환한다.
+///
+/// 변경 전 도서(`before`)가 없을 경우 변경 후 도서(`after`)의 모든 속성을 추가된 것으로 표시한다.
+/// 삭제된 값은 `-`, 추가된 값은 `+`를 앞에 붙이며 차이가 없는 속성은 표시하지 않는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::book::fetch::diff_book;
+/// use book_batch_rust::item::Book;
+///
+/// let before = Book::builder().isbn("9791136202093".to_owned()).title("이전 제목".to_owned()).build().unwrap();
+/// let after = Book::builder().isbn("9791136202093".to_owned()).title("새 제목".to_owned()).build().unwrap();
+///
+/// let lines = diff_book(Some(&before), &after);
+/// assert_eq!(lines, vec!["- title: 이전 제목", "+ title: 새 제목"]);
+///
+/// assert!(diff_book(Some(&before), &before).is_empty());
+/// ```
+pub fn diff_book(before: Option<&Book>, after: &Book) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    diff_value(&mut lines, "title", before.map(|b| b.title().to_owned()), Some(after.title().to_owned()));
+    diff_value(&mut lines, "scheduled_pub_date",
+               before.and_then(|b| b.scheduled_pub_date()).map(|d| d.to_string()),
+               after.scheduled_pub_date().map(|d| d.to_string()));
+    diff_value(&mut lines, "actual_pub_date",
+               before.and_then(|b| b.actual_pub_date()).map(|d| d.to_string()),
+               after.actual_pub_date().map(|d| d.to_string()));
+
+    let mut sites = before.into_iter()
+        .flat_map(|b| b.originals().keys())
+        .chain(after.originals().keys())
+        .collect::<Vec<_>>();
+    sites.sort_by_key(|site| site.to_string());
+    sites.dedup();
+
+    for site in sites {
+        let before_raw = before.and_then(|b| b.originals().get(site));
+        let after_raw = after.originals().get(site);
+        diff_raw(&mut lines, site, before_raw, after_raw);
+    }
+
+    lines
+}
+
+fn diff_raw(lines: &mut Vec<String>, site: &Site, before: Option<&Raw>, after: Option<&Raw>) {
+    let keys = before.into_iter()
+        .flat_map(|r| r.keys())
+        .chain(after.into_iter().flat_map(|r| r.keys()))
+        .collect::<BTreeSet<_>>();
+
+    for key in keys {
+        let name = format!("{}.{}", site, key);
+        let before_value = before.and_then(|r| r.get(key)).map(|v| v.to_string());
+        let after_value = after.and_then(|r| r.get(key)).map(|v| v.to_string());
+        diff_value(lines, &name, before_value, after_value);
+    }
+}
+
+fn diff_value(lines: &mut Vec<String>, name: &str, before: Option<String>, after: Option<String>) {
+    if before == after {
+        return;
+    }
+    if let Some(before) = before {
+        lines.push(format!("- {}: {}", name, before));
+    }
+    if let Some(after) = after {
+        lines.push(format!("+ {}: {}", name, after));
+    }
+}
+
+/// 사이트마다 ISBN 표기가 다를 수 있으므로(ex: 네이버 "ISBN10 ISBN13") 공백으로 구분된 ISBN 중 하나라도 일치하는지 확인한다.
+pub(crate) fn is_same_isbn(book_isbn: &str, isbn: &str) -> bool {
+    book_isbn.split_whitespace().any(|i| i == isbn)
+}
+
+pub fn create_job(
+    api_clients: Vec<Rc<dyn LookupClient>>,
+    html_clients: Vec<(Site, Rc<dyn html::Client>)>,
+    book_repo: SharedBookRepository,
+    upsert_mode: UpsertMode,
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<Job<Book, Book>, JobBuildError> {
+    let upsert = params.get(PARAM_NAME_UPSERT)
+        .map(|v| v.parse::<bool>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_UPSERT, e))))
+        .transpose()?
+        .unwrap_or(false);
+
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+
+    let job = job_builder()
+        .reader(Box::new(FetchReader::new(api_clients, html_clients)))
+        .processor(Box::new(create_description_processor(params)?.with_profiles(profiles.clone())))
+        .writer(Box::new(FetchReportWriter::new(book_repo.clone(), upsert).with_upsert_mode(upsert_mode)))
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key)
+        .with_quarantine(JobName::FETCH))
+}
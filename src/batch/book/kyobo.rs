@@ -1,33 +1,36 @@
-use crate::batch::book::{retrieve_from_to_in_parameter, retrieve_isbn_in_parameter, UpsertBookWriter};
-use crate::batch::error::{JobProcessFailed, JobReadFailed};
-use crate::batch::{job_builder, Job, JobParameter, Processor, Reader};
-use crate::item::{Book, RawValue, SharedBookRepository, Site};
-use crate::provider::html::{kyobo, Client, ParsingError};
+use crate::batch::book::retry::{clear_retry_on_write, EnrichmentRetryQueue, RETRY_ERROR_NOT_FOUND, RETRY_ERROR_PARSE_FAILED, RETRY_ERROR_REQUEST_FAILED};
+use crate::batch::book::{create_description_processor, retrieve_from_to_in_parameter, retrieve_isbn_in_parameter, OriginOnlyWriter, UpsertMode};
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed};
+use crate::batch::file::{retrieve_input_reader_in_parameter, retrieve_output_writer_in_parameter};
+use crate::batch::metrics::{Metrics, METRIC_NOT_FOUND};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Processor, Reader, DEF_CHUNK_SIZE, trace};
+use crate::item::{Book, RawValue, SharedBookRepository, SharedRetryRepository, Site};
+use crate::provider::html::{Client, ParsingError};
+use serde::Deserialize;
 use std::rc::Rc;
-use tracing::{error, warn};
-use crate::PARAM_NAME_ISBN;
+use tracing::warn;
+use crate::{JobName, PARAM_NAME_ISBN, PARAM_NAME_NO_LOGIN};
 
-pub struct KyoboReader<LP>
-where
-    LP: kyobo::LoginProvider,
-{
-    client: Rc<kyobo::Client<LP>>,
+pub struct KyoboReader {
+    client: Rc<dyn Client>,
     book_repo: SharedBookRepository,
+    retry_queue: EnrichmentRetryQueue,
+    metrics: Metrics,
 }
 
-impl<LP> KyoboReader<LP>
-where
-    LP: kyobo::LoginProvider,
-{
-    pub fn new(client: Rc<kyobo::Client<LP>>, book_repo: SharedBookRepository) -> Self {
-        Self { client, book_repo }
+impl KyoboReader {
+    pub fn new(client: Rc<dyn Client>, book_repo: SharedBookRepository, retry_repo: SharedRetryRepository) -> Self {
+        Self {
+            client,
+            book_repo,
+            retry_queue: EnrichmentRetryQueue::new(retry_repo, Site::KyoboBook),
+            metrics: Metrics::new(),
+        }
     }
 }
 
-impl <LP> Reader for KyoboReader<LP>
-where
-    LP: kyobo::LoginProvider,
-{
+impl Reader for KyoboReader {
     type Item = Book;
 
     fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
@@ -37,38 +40,109 @@ where
             retrieve_isbn_in_parameter(params)?
         } else {
             let (from, to) = retrieve_from_to_in_parameter(params)?;
-            self.book_repo.find_by_pub_between(&from, &to).iter()
+            let isbn_vec = self.book_repo.find_by_pub_between(&from, &to).iter()
                 .map(|book| book.isbn().to_owned())
-                .collect()
+                .collect();
+            // 기간으로 조회할 때는 이전 실행에서 실패한 ISBN 중 재시도 시각이 지난 ISBN을 먼저 조회한다.
+            self.retry_queue.prepend_due(isbn_vec)
         };
 
         for isbn in isbn_vec {
-            let response = self.client.get(&isbn)
+            let response = trace::traced(&isbn, || self.client.get(&isbn))
                 .map(|builder| builder.build().unwrap());
             match response {
+                // 재시도 기록은 저장을 마친 후 삭제한다. (`clear_retry_on_write`)
                 Ok(book) => result.push(book),
                 Err(err) => {
                     match err {
                         // ItemNotFound (데이터를 찾을 수 없음) 로그를 남기고 작업을 진핸한다.
-                        ParsingError::ItemNotFound => error!("Item(isbn) not found: {}", isbn),
+                        ParsingError::ItemNotFound => {
+                            warn!("Item(isbn) not found: {}", isbn);
+                            self.metrics.increment(METRIC_NOT_FOUND);
+                            self.retry_queue.failed(&isbn, RETRY_ERROR_NOT_FOUND, &err.to_string());
+                        }
+                        // 요청, 파싱 실패는 재시도 큐에 기록하고 다음 ISBN을 조회한다.
+                        ParsingError::RequestFailed(_) => {
+                            self.retry_queue.failed(&isbn, RETRY_ERROR_REQUEST_FAILED, &err.to_string());
+                        }
+                        ParsingError::PageNotFound(_)
+                        | ParsingError::ElementNotFound(_)
+                        | ParsingError::ResponseTextExtractionFailed(_)
+                        | ParsingError::ResponseTooLarge(_) => {
+                            self.retry_queue.failed(&isbn, RETRY_ERROR_PARSE_FAILED, &err.to_string());
+                        }
+                        // 인증 실패, robots.txt에서 허용 되지 않은 경로 등은 다른 ISBN도 실패 하므로 잡을 중단한다.
                         _ => return Err(JobReadFailed::UnknownError(err.to_string()))
                     }
                 }
             }
         }
+        self.metrics.log("KYOBO");
         Ok(result)
     }
 }
 
-pub fn create_job<LP>(
-    client: Rc<kyobo::Client<LP>>,
+/// 교보문고 잡 설정
+///
+/// # Description
+/// 설정 파일의 `kyobo` 섹션([`crate::configs::Config`])에서 읽으며, 입력하지 않은 값은 기본값을 사용한다.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct KyoboConfig {
+    /// 교보문고 로그인 사용 여부 (기본값: `true`)
+    /// 교보문고 로그인 정보를 저장할 수 없는 환경에서 `false`로 설정하면 항상 공개된 페이지만 조회한다. (`--no-login`과 동일)
+    pub login: bool,
+}
+
+impl Default for KyoboConfig {
+    fn default() -> Self {
+        Self { login: true }
+    }
+}
+
+/// 교보문고에 로그인 하지 않고 공개된 페이지만 조회하는지 여부
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::book::kyobo::is_no_login;
+/// use book_batch_rust::batch::JobParameter;
+///
+/// let mut parameter = JobParameter::new();
+/// assert!(!is_no_login(&parameter));
+///
+/// parameter.insert("no_login".to_owned(), "true".to_owned());
+/// assert!(is_no_login(&parameter));
+/// ```
+pub fn is_no_login(params: &JobParameter) -> bool {
+    params.get(PARAM_NAME_NO_LOGIN)
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"))
+}
+
+pub fn create_job(
+    client: Rc<dyn Client>,
     book_repo: SharedBookRepository,
-) -> Job<Book, Book>
-where
-    LP: kyobo::LoginProvider + 'static,
-{
-    job_builder()
-        .reader(Box::new(KyoboReader::new(client.clone(), book_repo.clone())))
-        .writer(Box::new(UpsertBookWriter::new(book_repo.clone())))
-        .build()
+    retry_repo: SharedRetryRepository,
+    upsert_mode: UpsertMode,
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<Job<Book, Book>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+    let reader = match retrieve_input_reader_in_parameter(params) {
+        Some(reader) => reader,
+        None => Box::new(KyoboReader::new(client.clone(), book_repo.clone(), retry_repo.clone())),
+    };
+    let writer = match retrieve_output_writer_in_parameter(params)? {
+        Some(writer) => writer,
+        None => Box::new(OriginOnlyWriter::new(book_repo.clone()).with_mode(upsert_mode)),
+    };
+    let writer = clear_retry_on_write(writer, retry_repo.clone(), Site::KyoboBook, params);
+
+    let job = job_builder()
+        .reader(reader)
+        .processor(Box::new(create_description_processor(params)?.with_profiles(profiles.clone())))
+        .writer(writer)
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key)
+        .with_quarantine(JobName::KYOBO))
 }
\ No newline at end of file
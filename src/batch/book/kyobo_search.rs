@@ -0,0 +1,138 @@
+use crate::batch::book::{create_default_filter_chain, create_original_data_filter, drop_read_isbn, ByPublisher, OnlyNewBooksWriter};
+use crate::batch::error::{JobBuildError, JobReadFailed};
+use crate::batch::file::{retrieve_input_reader_in_parameter, retrieve_output_writer_in_parameter};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, retrieve_spill_threshold_in_parameter, Job, JobParameter, ReadPages, Reader, DEF_CHUNK_SIZE, trace};
+use crate::item::{Book, BookBuilder, RawValue, SharedBookRepository, SharedFilterRepository, SharedPublisherRepository, Site};
+use crate::provider::api::Client;
+use crate::provider::html::kyobo::search::SearchRequest;
+use crate::JobName;
+use std::rc::Rc;
+use tracing::warn;
+
+const PAGE_SIZE: usize = 50;
+
+/// 출판사 키워드 하나로 조회할 최대 검색 결과 수
+const MAX_RESULT: usize = 200;
+
+/// 교보문고 출판사별 도서 검색 리더
+///
+/// # Description
+/// 출판사의 교보문고 키워드로 교보문고를 검색하여 새로 등록된 도서의 ISBN을 찾는다.
+/// 국립중앙도서관에 아직 등록 되지 않은 예약 판매 도서를 먼저 수집하기 위해 사용한다.
+/// 검색 결과 중 출판사명이 키워드와 일치하는 도서만 해당 출판사의 도서로 수집한다.
+pub struct KyoboSearchReader {
+    client: Rc<dyn Client<Request = SearchRequest>>,
+    pub_repo: SharedPublisherRepository,
+}
+
+impl KyoboSearchReader {
+    pub fn new(client: Rc<dyn Client<Request = SearchRequest>>, pub_repo: SharedPublisherRepository) -> Self {
+        Self { client, pub_repo }
+    }
+}
+
+impl Reader for KyoboSearchReader {
+    type Item = Book;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        <Self as ByPublisher>::read_books(self, params)
+    }
+
+    fn do_read_pages<'a>(&'a self, params: &'a JobParameter) -> Result<ReadPages<'a, Self::Item>, JobReadFailed>
+    where
+        Self::Item: 'a,
+    {
+        Ok(drop_read_isbn(<Self as ByPublisher>::read_pages(self, params)?))
+    }
+}
+
+impl ByPublisher for KyoboSearchReader {
+    fn site(&self) -> &Site {
+        &Site::KyoboBook
+    }
+
+    fn repository(&self) -> &SharedPublisherRepository {
+        &self.pub_repo
+    }
+
+    fn by_publisher_keyword(&self, keyword: &str, _: &JobParameter) -> Result<Vec<BookBuilder>, JobReadFailed> {
+        let mut result = Vec::new();
+        let mut current_fetch_size = 0;
+        let mut next_request = Some(SearchRequest::builder(keyword)
+            .size(PAGE_SIZE as u32)
+            .build());
+        while current_fetch_size < MAX_RESULT {
+            let Some(request) = next_request.take() else {
+                break;
+            };
+
+            let response = match self.client.get_books(&request) {
+                Ok(response) => response,
+                Err(err) => {
+                    warn!("{} => Kyobo search failed: {:?}", keyword, err);
+                    break;
+                }
+            };
+            current_fetch_size += response.books.len();
+            next_request = response.next_page_request(&request);
+
+            for builder in response.books {
+                match builder.build() {
+                    Ok(book) if is_publisher_matched(&book, keyword) => result.push(book.to_builder()),
+                    _ => {},
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// 검색 결과 도서의 출판사명(교보문고 원본 데이터의 `publisher`)이 키워드와 일치하는지 여부
+fn is_publisher_matched(book: &Book, keyword: &str) -> bool {
+    book.originals().get(&Site::KyoboBook)
+        .and_then(|raw| raw.get("publisher"))
+        .is_some_and(|publisher| matches!(publisher, RawValue::Text(name) if name.trim() == keyword.trim()))
+}
+
+/// 교보문고 검색 잡을 생성한다.
+///
+/// # Description
+/// 검색 결과는 상세 정보가 없으므로 이미 저장된 도서는 수정하지 않고 새 도서만 저장한다. ([`OnlyNewBooksWriter`])
+/// 새로 저장된 도서의 상세 정보는 `KYOBO` 잡(`--follow-up`)으로 보강한다.
+pub fn create_job(
+    client: Rc<dyn Client<Request = SearchRequest>>,
+    pub_repo: SharedPublisherRepository,
+    book_repo: SharedBookRepository,
+    filter_repo: SharedFilterRepository,
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<Job<Book, Book>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+    let reader = match retrieve_input_reader_in_parameter(params) {
+        Some(reader) => reader,
+        None => Box::new(KyoboSearchReader::new(client.clone(), pub_repo.clone())),
+    };
+    let writer = match retrieve_output_writer_in_parameter(params)? {
+        Some(writer) => writer,
+        None => Box::new(OnlyNewBooksWriter::new(book_repo.clone()).with_publisher_repo(pub_repo.clone())),
+    };
+
+    let mut filter_chain = create_default_filter_chain();
+    if let Some(filter) = create_original_data_filter(filter_repo.clone(), Site::KyoboBook, params)? {
+        filter_chain = filter_chain.add_filter(Box::new(filter.with_profiles(profiles.clone())));
+    }
+
+    let job = job_builder()
+        .reader(reader)
+        .filter(Box::new(filter_chain))
+        .writer(writer)
+        .build();
+
+    let job = job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key)
+        .with_quarantine(JobName::KYOBO_SEARCH);
+    match retrieve_spill_threshold_in_parameter(params)? {
+        Some(threshold) => Ok(job.with_spill_threshold(threshold)),
+        None => Ok(job),
+    }
+}
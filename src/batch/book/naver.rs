@@ -1,19 +1,134 @@
-use crate::batch::book::{retrieve_from_to_in_parameter, UpsertBookWriter};
-use crate::batch::error::JobReadFailed;
-use crate::batch::{job_builder, Job, JobParameter, Reader};
-use crate::item::{Book, SharedBookRepository};
-use crate::provider;
-use crate::provider::api::{naver, Client};
+use crate::batch::book::quota::DailyQuota;
+use crate::batch::book::retry::{clear_retry_on_write, EnrichmentRetryQueue, RETRY_ERROR_NOT_FOUND, RETRY_ERROR_PARSE_FAILED, RETRY_ERROR_QUOTA_EXCEEDED, RETRY_ERROR_REQUEST_FAILED};
+use crate::batch::book::{create_description_processor, retrieve_from_to_in_parameter, OriginOnlyWriter, UpsertMode};
+use crate::batch::error::{JobBuildError, JobReadFailed};
+use crate::batch::file::{retrieve_input_reader_in_parameter, retrieve_output_writer_in_parameter};
+use crate::batch::metrics::{Metrics, METRIC_NOT_FOUND, METRIC_PARTIAL};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Reader, DEF_CHUNK_SIZE, trace};
+use crate::clock::{system_clock, SharedClock};
+use crate::item::{Book, SharedBookRepository, SharedQuotaRepository, SharedRetryRepository, Site};
+use crate::provider::api::naver::IsbnRequest;
+use crate::provider::api::{Client, ClientError, Response};
+use crate::JobName;
 use std::rc::Rc;
+use tracing::warn;
 
+/// 네이버 도서 API에서 ISBN 하나를 검색한 결과
+#[derive(Debug)]
+pub enum NaverOutcome {
+    /// 도서를 찾음, 응답의 도서 중 변환하지 못한 도서의 수를 함께 가진다.
+    Found { books: Vec<Book>, skipped: usize },
+
+    /// 도서를 찾을 수 없음
+    NotFound(String),
+
+    /// 요청 또는 응답 변환에 실패함 (재시도 큐에 기록할 에러 종류, 메시지)
+    Failed(&'static str, String),
+}
+
+impl NaverOutcome {
+    /// 검색 응답을 ISBN 하나의 검색 결과로 변환한다.
+    ///
+    /// # Description
+    /// 응답의 도서는 하나씩 변환하여 변환에 성공한 도서만 결과로 사용한다. 따라서 일부 도서의 변환에 실패 하더라도 나머지 도서는 저장된다.
+    /// 응답에 도서가 있지만 모두 변환에 실패한 경우 응답 변환 실패(`PARSE_FAILED`)로 다음 실행에서 다시 검색한다.
+    /// 검색 결과가 없는 빈 응답은 [`ClientError::NotFound`]와 같이 도서를 찾을 수 없음으로 처리한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::book::naver::NaverOutcome;
+    /// use book_batch_rust::item::{Book, Site};
+    /// use book_batch_rust::provider::api::{ClientError, Response};
+    ///
+    /// let mut response = Response::empty(Site::Naver);
+    /// response.books.push(Book::builder().isbn("9791133478410".to_owned()).title("원피스 1".to_owned()));
+    /// response.books.push(Book::builder().isbn("9791133478427".to_owned()));
+    /// assert!(matches!(NaverOutcome::from_response(Ok(response)), NaverOutcome::Found { ref books, skipped: 1 } if books.len() == 1));
+    ///
+    /// let mut response = Response::empty(Site::Naver);
+    /// response.books.push(Book::builder().isbn("9791133478427".to_owned()));
+    /// assert!(matches!(NaverOutcome::from_response(Ok(response)), NaverOutcome::Failed("PARSE_FAILED", _)));
+    ///
+    /// let outcome = NaverOutcome::from_response(Err(ClientError::NotFound("9791133478410".to_owned())));
+    /// assert!(matches!(outcome, NaverOutcome::NotFound(_)));
+    ///
+    /// let outcome = NaverOutcome::from_response(Ok(Response::empty(Site::Naver)));
+    /// assert!(matches!(outcome, NaverOutcome::NotFound(_)));
+    /// ```
+    pub fn from_response(response: Result<Response, ClientError>) -> Self {
+        let response = match response {
+            Ok(response) => response,
+            Err(ClientError::NotFound(message)) => return NaverOutcome::NotFound(message),
+            Err(err) => {
+                let error_type = match err {
+                    ClientError::ResponseParseFailed(_) => RETRY_ERROR_PARSE_FAILED,
+                    ClientError::QuotaExceeded(_) => RETRY_ERROR_QUOTA_EXCEEDED,
+                    _ => RETRY_ERROR_REQUEST_FAILED,
+                };
+                return NaverOutcome::Failed(error_type, format!("{:?}", err));
+            }
+        };
+        if response.books.is_empty() {
+            return NaverOutcome::NotFound("empty response".to_owned());
+        }
+
+        let total = response.books.len();
+        let mut books = Vec::with_capacity(total);
+        let mut errors = Vec::new();
+        for builder in response.books {
+            match builder.build() {
+                Ok(book) => books.push(book),
+                Err(err) => errors.push(format!("{:?}", err)),
+            }
+        }
+        if books.is_empty() {
+            return NaverOutcome::Failed(RETRY_ERROR_PARSE_FAILED, format!("all {} books are invalid: {}", total, errors.join(", ")));
+        }
+        if !errors.is_empty() {
+            warn!("{} of {} books are skipped: {}", errors.len(), total, errors.join(", "));
+        }
+        NaverOutcome::Found { books, skipped: errors.len() }
+    }
+}
+
+/// 네이버 도서 정보 보강 리더
+///
+/// # Description
+/// `from/to` 기간에 출판(예정)된 도서를 네이버 도서 API에서 ISBN으로 검색한다.
+/// 이전 실행에서 실패 하여 재시도 시각이 지난 ISBN을 먼저 검색하며, 검색에 실패 하거나 도서를 찾을 수 없는 ISBN은 재시도 큐에 기록한다.
+///
+/// # Note
+/// - ISBN마다 검색 결과([`NaverOutcome`])를 나누어 처리하므로 일부 ISBN의 검색, 응답 변환에 실패 하더라도 나머지 ISBN의 도서는 저장되며 실패한 ISBN만 재시도 한다.
+/// - 도서를 찾을 수 없는 경우(빈 응답)는 장애가 아니므로 건너뛰고 `not_found` 카운터에 기록한다.
+/// - 일일 요청 한도([`DailyQuota`])를 넘을 경우 남은 ISBN은 요청하지 않고 다음날 재시도 하도록 재시도 큐에 기록한다.
 pub struct NaverReader {
-    client: Rc<naver::Client>,
-    book_repo: SharedBookRepository
+    client: Rc<dyn Client<Request = IsbnRequest>>,
+    book_repo: SharedBookRepository,
+    retry_queue: EnrichmentRetryQueue,
+    quota: DailyQuota,
+    metrics: Metrics,
+    clock: SharedClock,
 }
 
 impl NaverReader {
-    pub fn new(client: Rc<naver::Client>, book_repo: SharedBookRepository) -> Self {
-        Self { client, book_repo }
+    pub fn new(client: Rc<dyn Client<Request = IsbnRequest>>, book_repo: SharedBookRepository, retry_repo: SharedRetryRepository, quota_repo: SharedQuotaRepository) -> Self {
+        Self {
+            client,
+            book_repo,
+            retry_queue: EnrichmentRetryQueue::new(retry_repo, Site::Naver),
+            quota: DailyQuota::with_env(quota_repo, Site::Naver),
+            metrics: Metrics::new(),
+            clock: system_clock(),
+        }
+    }
+
+    /// 재시도 시각과 일일 요청 한도의 날짜에 사용할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.retry_queue = self.retry_queue.with_clock(clock.clone());
+        self.quota = self.quota.with_clock(clock.clone());
+        self.clock = clock;
+        self
     }
 }
 
@@ -22,27 +137,72 @@ impl Reader for NaverReader {
 
     fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
         let (from, to) = retrieve_from_to_in_parameter(params)?;
-        let results = self.book_repo.find_by_pub_between(&from, &to).into_iter()
-            .flat_map(|book| {
-                let request = provider::api::Request::builder()
-                    .query(book.isbn().to_owned())
-                    .build().unwrap();
-
-                self.client.get_books(&request).unwrap().books
-                    .into_iter()
-                    .map(|b| b.build().unwrap())
-            })
+        let isbn_vec = self.book_repo.find_by_pub_between(&from, &to).into_iter()
+            .map(|book| book.isbn().to_owned())
             .collect();
+
+        let mut results = Vec::new();
+        let mut isbn_iter = self.retry_queue.prepend_due(isbn_vec).into_iter();
+        while let Some(isbn) = isbn_iter.next() {
+            if !self.quota.try_acquire() {
+                let remaining: Vec<String> = std::iter::once(isbn).chain(isbn_iter).collect();
+                let tomorrow = self.clock.today().succ_opt()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .unwrap_or_else(|| self.clock.now());
+                self.retry_queue.deferred(&remaining, RETRY_ERROR_QUOTA_EXCEEDED, "daily quota exceeded", tomorrow);
+                break;
+            }
+
+            let outcome = trace::traced(&isbn, || NaverOutcome::from_response(self.client.get_books(&IsbnRequest::new(isbn.clone()))));
+            match outcome {
+                NaverOutcome::Found { books, skipped } => {
+                    if skipped > 0 {
+                        self.metrics.increment(METRIC_PARTIAL);
+                    }
+                    // 재시도 기록은 저장을 마친 후 삭제한다. (`clear_retry_on_write`)
+                    results.extend(books);
+                }
+                NaverOutcome::NotFound(message) => {
+                    self.metrics.increment(METRIC_NOT_FOUND);
+                    self.retry_queue.failed(&isbn, RETRY_ERROR_NOT_FOUND, &message);
+                }
+                NaverOutcome::Failed(error_type, message) => {
+                    self.retry_queue.failed(&isbn, error_type, &message);
+                }
+            }
+        }
+        self.quota.log();
+        self.metrics.log("NAVER");
         Ok(results)
     }
 }
 
 pub fn create_job(
-    client: Rc<naver::Client>,
+    client: Rc<dyn Client<Request = IsbnRequest>>,
     book_repo: SharedBookRepository,
-) -> Job<Book, Book> {
-    job_builder()
-        .reader(Box::new(NaverReader::new(client.clone(), book_repo.clone())))
-        .writer(Box::new(UpsertBookWriter::new(book_repo.clone())))
-        .build()
+    retry_repo: SharedRetryRepository,
+    quota_repo: SharedQuotaRepository,
+    upsert_mode: UpsertMode,
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<Job<Book, Book>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+    let reader = match retrieve_input_reader_in_parameter(params) {
+        Some(reader) => reader,
+        None => Box::new(NaverReader::new(client.clone(), book_repo.clone(), retry_repo.clone(), quota_repo.clone())),
+    };
+    let writer = match retrieve_output_writer_in_parameter(params)? {
+        Some(writer) => writer,
+        None => Box::new(OriginOnlyWriter::new(book_repo.clone()).with_mode(upsert_mode)),
+    };
+    let writer = clear_retry_on_write(writer, retry_repo.clone(), Site::Naver, params);
+
+    let job = job_builder()
+        .reader(reader)
+        .processor(Box::new(create_description_processor(params)?.with_profiles(profiles.clone())))
+        .writer(writer)
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key)
+        .with_quarantine(JobName::NAVER))
 }
\ No newline at end of file
@@ -1,20 +1,28 @@
-use crate::batch::book::{create_default_filter_chain, retrieve_from_to_in_parameter, ByPublisher, OnlyNewBooksWriter, OriginalDataFilter};
-use crate::batch::error::JobReadFailed;
-use crate::batch::{job_builder, Job, JobParameter, Reader};
+use crate::batch::book::{create_default_filter_chain, create_description_processor, create_original_data_filter, date_windows, retrieve_from_to_in_parameter, drop_read_isbn, ByPublisher, OnlyNewBooksWriter};
+use crate::batch::error::{JobBuildError, JobReadFailed};
+use crate::batch::file::{retrieve_input_reader_in_parameter, retrieve_output_writer_in_parameter};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, retrieve_spill_threshold_in_parameter, Job, JobParameter, ReadPages, Reader, DEF_CHUNK_SIZE, trace};
 use crate::item::{Book, BookBuilder, SharedBookRepository, SharedFilterRepository, SharedPublisherRepository, Site};
-use crate::provider;
-use crate::provider::api::{nlgo, Client};
+use crate::provider::api::nlgo::SearchRequest;
+use crate::provider::api::Client;
+use crate::JobName;
 use std::rc::Rc;
+use tracing::warn;
 
 const PAGE_SIZE: usize = 500;
 
+/// 한 번의 요청으로 조회할 최대 기간(일)
+/// 넓은 기간을 한 번에 조회하면 응답 시간이 초과되거나 결과가 잘려서 반환되므로 기간을 나누어 조회한다.
+const WINDOW_DAYS: u64 = 31;
+
 pub struct NlgoBookReader {
-    client: Rc<nlgo::Client>,
+    client: Rc<dyn Client<Request = SearchRequest>>,
     pub_repo: SharedPublisherRepository,
 }
 
 impl NlgoBookReader {
-    pub fn new(client: Rc<nlgo::Client>, pub_repo: SharedPublisherRepository) -> Self {
+    pub fn new(client: Rc<dyn Client<Request = SearchRequest>>, pub_repo: SharedPublisherRepository) -> Self {
         Self { client, pub_repo }
     }
 }
@@ -25,6 +33,13 @@ impl Reader for NlgoBookReader {
     fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
         <Self as ByPublisher>::read_books(self, params)
     }
+
+    fn do_read_pages<'a>(&'a self, params: &'a JobParameter) -> Result<ReadPages<'a, Self::Item>, JobReadFailed>
+    where
+        Self::Item: 'a,
+    {
+        Ok(drop_read_isbn(<Self as ByPublisher>::read_pages(self, params)?))
+    }
 }
 
 impl ByPublisher for NlgoBookReader {
@@ -39,39 +54,58 @@ impl ByPublisher for NlgoBookReader {
 
     fn by_publisher_keyword(&self, keyword: &str, params: &JobParameter) -> Result<Vec<BookBuilder>, JobReadFailed> {
         let mut result = Vec::new();
-        let mut current_page = 1;
 
         let (from, to) = retrieve_from_to_in_parameter(params)?;
-        loop {
-            let request = provider::api::Request::builder()
-                .page(current_page).size(PAGE_SIZE as i32)
-                .query(keyword.to_owned())
-                .start_date(from).end_date(to)
-                .build().unwrap();
-
-            let response = self.client.get_books(&request).unwrap();
-            if !response.books.is_empty() {
+        for (window_from, window_to) in date_windows(from, to, WINDOW_DAYS) {
+            let mut next_request = Some(SearchRequest::builder(keyword, window_from, window_to)
+                .size(PAGE_SIZE as u32)
+                .build());
+            while let Some(request) = next_request.take() {
+                let response = self.client.get_books(&request)
+                    .inspect_err(|e| warn!("{} => Search failed: {:?}", keyword, e))
+                    .map_err(JobReadFailed::from)?;
+                next_request = response.next_page_request(&request);
                 response.books.into_iter().for_each(|b| result.push(b));
-                current_page += 1;
-            } else {
-                break Ok(result);
             }
         }
+        Ok(result)
     }
 }
 
 pub fn create_job(
-    client: Rc<nlgo::Client>,
+    client: Rc<dyn Client<Request = SearchRequest>>,
     pub_repo: SharedPublisherRepository,
     book_repo: SharedBookRepository,
     filter_repo: SharedFilterRepository,
-) -> Job<Book, Book> {
-    let filter_chain = create_default_filter_chain()
-        .add_filter(Box::new(OriginalDataFilter::new(filter_repo.clone(), Site::NLGO)));
-    
-    job_builder()
-        .reader(Box::new(NlgoBookReader::new(client.clone(), pub_repo.clone())))
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<Job<Book, Book>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+    let reader = match retrieve_input_reader_in_parameter(params) {
+        Some(reader) => reader,
+        None => Box::new(NlgoBookReader::new(client.clone(), pub_repo.clone())),
+    };
+    let writer = match retrieve_output_writer_in_parameter(params)? {
+        Some(writer) => writer,
+        None => Box::new(OnlyNewBooksWriter::new(book_repo.clone()).with_publisher_repo(pub_repo.clone())),
+    };
+
+    let mut filter_chain = create_default_filter_chain();
+    if let Some(filter) = create_original_data_filter(filter_repo.clone(), Site::NLGO, params)? {
+        filter_chain = filter_chain.add_filter(Box::new(filter.with_profiles(profiles.clone())));
+    }
+
+    let job = job_builder()
+        .reader(reader)
         .filter(Box::new(filter_chain))
-        .writer(Box::new(OnlyNewBooksWriter::new(book_repo.clone())))
-        .build()
+        .processor(Box::new(create_description_processor(params)?.with_profiles(profiles.clone())))
+        .writer(writer)
+        .build();
+
+    let job = job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key)
+        .with_quarantine(JobName::NLGO);
+    match retrieve_spill_threshold_in_parameter(params)? {
+        Some(threshold) => Ok(job.with_spill_threshold(threshold)),
+        None => Ok(job),
+    }
 }
@@ -0,0 +1,127 @@
+use crate::clock::{system_clock, SharedClock};
+use crate::item::{SharedQuotaRepository, Site};
+use crate::provider::credential::configured_key_count;
+use chrono::NaiveDate;
+use std::cell::Cell;
+use std::env;
+use tracing::{info, warn};
+
+/// 알라딘 TTB API의 기본 일일 요청 한도
+pub const DEFAULT_ALADIN_DAILY_QUOTA: u32 = 5_000;
+
+/// 네이버 검색 API의 기본 일일 요청 한도
+pub const DEFAULT_NAVER_DAILY_QUOTA: u32 = 25_000;
+
+/// 사이트 API 일일 요청 한도
+///
+/// # Description
+/// 사이트 API로 요청을 보내기 전 [`DailyQuota::try_acquire`]로 오늘 남은 요청 수를 확인하고 요청 수를 저장소에 기록한다.
+/// 요청 수는 사이트, 날짜별로 저장소에 누적되므로 같은 날 여러번 실행 하더라도 한도를 넘지 않는다.
+///
+/// # Note
+/// 한도는 환경 변수 `<사이트>_DAILY_QUOTA`(ex: `ALADIN_DAILY_QUOTA`, `NAVER_DAILY_QUOTA`)로 설정하며
+/// 설정하지 않을 경우 사이트별 기본값에 설정된 API 키의 수(ex: `ALADIN_KEY=key1,key2`)를 곱한 값을 사용한다.
+pub struct DailyQuota {
+    repo: SharedQuotaRepository,
+    site: Site,
+    limit: u32,
+
+    /// 마지막으로 확인한 날짜와 그 날의 누적 요청 수
+    used_today: Cell<Option<(NaiveDate, u32)>>,
+
+    /// 이번 실행에서 보낸 요청 수
+    consumed: Cell<u32>,
+
+    /// 이번 실행에서 한도 초과로 거절된 요청 수
+    refused: Cell<u32>,
+
+    /// 오늘 날짜를 확인할 시계
+    clock: SharedClock,
+}
+
+impl DailyQuota {
+    pub fn new(repo: SharedQuotaRepository, site: Site, limit: u32) -> Self {
+        Self {
+            repo,
+            site,
+            limit,
+            used_today: Cell::new(None),
+            consumed: Cell::new(0),
+            refused: Cell::new(0),
+            clock: system_clock(),
+        }
+    }
+
+    /// 오늘 날짜를 확인할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 환경 변수 `<사이트>_DAILY_QUOTA`에 설정된 한도로 생성한다.
+    pub fn with_env(repo: SharedQuotaRepository, site: Site) -> Self {
+        let env_name = format!("{}_DAILY_QUOTA", site);
+        let limit = env::var(&env_name).ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .unwrap_or_else(|| default_daily_quota(&site).saturating_mul(key_count(&site)));
+        Self::new(repo, site, limit)
+    }
+
+    /// 요청 한 건을 보낼 수 있는지 확인한다.
+    /// 한도 내일 경우 요청 수를 기록하고 `true`를, 한도를 넘을 경우 `false`를 반환한다.
+    pub fn try_acquire(&self) -> bool {
+        let today = self.clock.today();
+        let used = match self.used_today.get() {
+            Some((date, used)) if date == today => used,
+            _ => self.repo.find_used(&self.site, &today),
+        };
+
+        if used >= self.limit {
+            self.used_today.set(Some((today, used)));
+            self.refused.set(self.refused.get() + 1);
+            return false;
+        }
+
+        self.repo.add_used(&self.site, &today, 1);
+        self.used_today.set(Some((today, used + 1)));
+        self.consumed.set(self.consumed.get() + 1);
+        true
+    }
+
+    /// 이번 실행에서 보낸 요청 수
+    pub fn consumed(&self) -> u32 {
+        self.consumed.get()
+    }
+
+    /// 이번 실행에서 한도 초과로 거절된 요청 수
+    pub fn refused(&self) -> u32 {
+        self.refused.get()
+    }
+
+    /// 이번 실행의 요청 수와 오늘의 누적 요청 수를 로그로 출력한다.
+    pub fn log(&self) {
+        let used = self.used_today.get().map(|(_, used)| used).unwrap_or(0);
+        info!("{} => Quota consumed {} request(s) in this run, {}/{} used today", self.site, self.consumed(), used, self.limit);
+        if self.refused() > 0 {
+            warn!("{} => Daily quota exhausted, {} request(s) refused", self.site, self.refused());
+        }
+    }
+}
+
+/// 사이트에 설정된 API 키의 수, 키가 설정 되지 않은 경우에도 1을 반환한다.
+fn key_count(site: &Site) -> u32 {
+    let count = match site {
+        Site::Aladin => configured_key_count("ALADIN_KEY"),
+        Site::Naver => configured_key_count("NAVER_KEY"),
+        _ => 1,
+    };
+    count.max(1) as u32
+}
+
+fn default_daily_quota(site: &Site) -> u32 {
+    match site {
+        Site::Aladin => DEFAULT_ALADIN_DAILY_QUOTA,
+        Site::Naver => DEFAULT_NAVER_DAILY_QUOTA,
+        _ => u32::MAX,
+    }
+}
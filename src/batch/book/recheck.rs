@@ -0,0 +1,210 @@
+use crate::batch::book::fetch::is_same_isbn;
+use crate::batch::book::retrieve_from_to_in_parameter;
+use crate::batch::book::retry::{EnrichmentRetryQueue, RETRY_ERROR_PUB_DATE_SLIPPED};
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::{audit, job_builder, retrieve_chunk_size_in_parameter, trace, Job, JobParameter, Processor, Reader, Writer, DEF_CHUNK_SIZE};
+use crate::clock::{system_clock, SharedClock};
+use crate::item::{Book, SharedBookRepository, SharedRetryRepository, Site};
+use crate::provider::api::{ClientError, LookupClient};
+use crate::{PARAM_NAME_FROM, PARAM_NAME_TO};
+use chrono::{Days, NaiveDate};
+use std::rc::Rc;
+use tracing::info;
+
+/// 기간 파라미터가 없을 때 재확인할 출간 예정일의 기간(일), 출간 예정일이 이보다 오래 지난 도서는 더 이상 재확인하지 않는다.
+const DEFAULT_RECHECK_DAYS: u64 = 180;
+
+/// 출간 예정일이 지났지만 실제 출간일이 확인 되지 않은 도서인지 여부
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::book::recheck::is_slipped;
+/// use book_batch_rust::item::Book;
+/// use chrono::NaiveDate;
+///
+/// let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+/// let book = |scheduled: u32, actual: Option<u32>| {
+///     let mut builder = Book::builder().isbn("9791136202093".to_owned()).title("도서".to_owned())
+///         .scheduled_pub_date(NaiveDate::from_ymd_opt(2025, 2, scheduled).unwrap());
+///     if let Some(day) = actual {
+///         builder = builder.actual_pub_date(NaiveDate::from_ymd_opt(2025, 2, day).unwrap());
+///     }
+///     builder.build().unwrap()
+/// };
+///
+/// assert!(is_slipped(&book(20, None), &today));
+/// assert!(!is_slipped(&book(20, Some(21)), &today));
+/// assert!(!is_slipped(&book(20, None), &NaiveDate::from_ymd_opt(2025, 2, 20).unwrap()));
+/// ```
+pub fn is_slipped(book: &Book, today: &NaiveDate) -> bool {
+    book.actual_pub_date().is_none()
+        && book.scheduled_pub_date().is_some_and(|scheduled| scheduled < *today)
+}
+
+/// 출간 예정일이 지난 도서를 검색하는 리더
+///
+/// # Description
+/// `from/to` 기간(입력하지 않은 경우 어제까지 180일)에 출간 예정일이 있는 도서 중 실제 출간일이 확인 되지 않은 도서를 조회한다.
+/// 이전 재확인 이후 국립중앙도서관 재시도 큐의 재시도 시각이 지나지 않은 도서는 제외한다.
+pub struct SlippedBookReader {
+    book_repo: SharedBookRepository,
+    retry_queue: EnrichmentRetryQueue,
+    clock: SharedClock,
+}
+
+impl SlippedBookReader {
+    pub fn new(book_repo: SharedBookRepository, retry_repo: SharedRetryRepository) -> Self {
+        Self {
+            book_repo,
+            retry_queue: EnrichmentRetryQueue::new(retry_repo, Site::NLGO),
+            clock: system_clock(),
+        }
+    }
+
+    fn window(&self, params: &JobParameter, today: &NaiveDate) -> Result<(NaiveDate, NaiveDate), JobReadFailed> {
+        if params.contains_key(PARAM_NAME_FROM) || params.contains_key(PARAM_NAME_TO) {
+            return retrieve_from_to_in_parameter(params);
+        }
+        let to = today.pred_opt().unwrap();
+        let from = to.checked_sub_days(Days::new(DEFAULT_RECHECK_DAYS)).unwrap();
+        Ok((from, to))
+    }
+}
+
+impl Reader for SlippedBookReader {
+    type Item = Book;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let today = self.clock.today();
+        let (from, to) = self.window(params, &today)?;
+
+        let slipped: Vec<Book> = self.book_repo.find_by_pub_between(&from, &to).into_iter()
+            .filter(|book| is_slipped(book, &today))
+            .collect();
+        let isbn_vec: Vec<String> = slipped.iter().map(|book| book.isbn().to_owned()).collect();
+        let waiting = self.retry_queue.waiting_isbn(&isbn_vec);
+
+        info!("{} slipped book(s) found between {} and {}, {} book(s) are waiting for next recheck", slipped.len(), from, to, waiting.len());
+        Ok(slipped.into_iter()
+            .filter(|book| !waiting.contains(book.isbn()))
+            .collect())
+    }
+}
+
+/// 출간 예정일 재확인 결과
+#[derive(Debug)]
+pub enum RecheckResult {
+    /// 실제 출간일이 확인된 도서 (저장된 도서, 조회한 도서를 병합한 도서)
+    Landed(Book, Book),
+
+    /// 실제 출간일이 확인 되지 않은 도서와 사유
+    Pending(Book, String),
+}
+
+/// 국립중앙도서관 API에서 ISBN으로 도서를 다시 조회하여 실제 출간일을 확인하는 프로세서
+///
+/// # Description
+/// 조회한 도서에 실제 출간일이 있는 경우 저장된 도서와 병합([`Book::merge`])하며, 조회에 실패한 경우에도 잡을 중단하지 않고 확인 되지 않은 도서로 반환한다.
+pub struct NlgoRecheckProcessor {
+    client: Rc<dyn LookupClient>,
+}
+
+impl NlgoRecheckProcessor {
+    pub fn new(client: Rc<dyn LookupClient>) -> Self {
+        Self { client }
+    }
+}
+
+impl Processor for NlgoRecheckProcessor {
+    type In = Book;
+    type Out = RecheckResult;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let found = match self.client.lookup(item.isbn()) {
+            Ok(response) => response.books.into_iter()
+                .filter_map(|builder| builder.build().ok())
+                .find(|book| is_same_isbn(book.isbn(), item.isbn())),
+            Err(ClientError::NotFound(_)) => None,
+            Err(err) => return Ok(RecheckResult::Pending(item, format!("lookup failed: {:?}", err))),
+        };
+
+        match found {
+            Some(found) if found.actual_pub_date().is_some() => {
+                let merged = item.merge(&found);
+                Ok(RecheckResult::Landed(item, merged))
+            }
+            Some(_) => Ok(RecheckResult::Pending(item, "actual pub date not found".to_owned())),
+            None => Ok(RecheckResult::Pending(item, "item not found".to_owned())),
+        }
+    }
+}
+
+/// 재확인 결과 라이터
+///
+/// # Description
+/// - 실제 출간일이 확인된 도서는 저장소에 반영하고 국립중앙도서관 재시도 기록을 삭제한다.
+/// - 확인 되지 않은 도서는 국립중앙도서관 재시도 큐에 실패로 기록하여 다음 재확인까지의 대기 시간을 늘리고([`crate::item::EnrichmentRetry::backoff`]),
+///   다음 `KYOBO` 잡에서 도서 상세 페이지를 다시 수집하도록 교보문고 재시도 큐에 즉시 재시도 대상으로 기록한다.
+pub struct RecheckWriter {
+    book_repo: SharedBookRepository,
+    nlgo_queue: EnrichmentRetryQueue,
+    kyobo_queue: EnrichmentRetryQueue,
+    clock: SharedClock,
+}
+
+impl RecheckWriter {
+    pub fn new(book_repo: SharedBookRepository, retry_repo: SharedRetryRepository) -> Self {
+        Self {
+            book_repo,
+            nlgo_queue: EnrichmentRetryQueue::new(retry_repo.clone(), Site::NLGO),
+            kyobo_queue: EnrichmentRetryQueue::new(retry_repo, Site::KyoboBook),
+            clock: system_clock(),
+        }
+    }
+}
+
+impl Writer for RecheckWriter {
+    type Item = RecheckResult;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let mut pending = Vec::new();
+        for item in &items {
+            match item {
+                RecheckResult::Landed(before, after) => {
+                    info!("Actual pub date landed: {} ({:?} => {:?})", after.isbn(), before.scheduled_pub_date(), after.actual_pub_date());
+                    self.book_repo.update_book(after);
+                    audit::record_updated_book(before, after);
+                    self.nlgo_queue.succeeded(after.isbn());
+                }
+                RecheckResult::Pending(book, message) => {
+                    self.nlgo_queue.failed(book.isbn(), RETRY_ERROR_PUB_DATE_SLIPPED, message);
+                    pending.push(book.isbn().to_owned());
+                }
+            }
+        }
+        self.kyobo_queue.deferred(&pending, RETRY_ERROR_PUB_DATE_SLIPPED, "scheduled pub date passed without actual pub date", self.clock.now());
+        Ok(())
+    }
+}
+
+/// 출간 예정일 재확인 잡을 생성한다.
+///
+/// # Description
+/// 출간 예정일이 지났지만 실제 출간일이 확인 되지 않은 도서를 국립중앙도서관 API에서 ISBN으로 다시 조회하고, 교보문고 재시도 큐에 등록하여 상세 페이지를 다시 수집한다.
+/// 재확인 주기는 재시도 큐의 대기 시간을 사용하므로 확인 되지 않을 수록 점점 길어지며(최대 7일), 매일 실행하는 것을 기준으로 한다.
+pub fn create_job(
+    client: Rc<dyn LookupClient>,
+    book_repo: SharedBookRepository,
+    retry_repo: SharedRetryRepository,
+    params: &JobParameter,
+) -> Result<Job<Book, RecheckResult>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+
+    let job = job_builder()
+        .reader(Box::new(SlippedBookReader::new(book_repo.clone(), retry_repo.clone())))
+        .processor(Box::new(NlgoRecheckProcessor::new(client)))
+        .writer(Box::new(RecheckWriter::new(book_repo, retry_repo)))
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key))
+}
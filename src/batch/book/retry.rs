@@ -0,0 +1,187 @@
+use crate::batch::error::JobWriteFailed;
+use crate::batch::{is_dry_run, trace, JobParameter, Writer};
+use crate::clock::{system_clock, SharedClock};
+use crate::item::{Book, EnrichmentRetry, SharedRetryRepository, Site};
+use crate::PARAM_NAME_OUTPUT;
+use chrono::NaiveDateTime;
+use std::collections::{HashMap, HashSet};
+use tracing::{info, warn};
+
+/// 한번에 불러올 재시도 대상 ISBN의 최대 개수
+const DEFAULT_RETRY_LIMIT: usize = 200;
+
+/// 도서를 찾을 수 없음
+pub const RETRY_ERROR_NOT_FOUND: &str = "NOT_FOUND";
+
+/// 요청 실패 (네트워크 에러 등)
+pub const RETRY_ERROR_REQUEST_FAILED: &str = "REQUEST_FAILED";
+
+/// 응답 파싱 실패
+pub const RETRY_ERROR_PARSE_FAILED: &str = "PARSE_FAILED";
+
+/// 일일 요청 한도 초과로 요청하지 못함
+pub const RETRY_ERROR_QUOTA_EXCEEDED: &str = "QUOTA_EXCEEDED";
+
+/// 출간 예정일이 지났지만 실제 출간일이 확인 되지 않음 ([`crate::batch::book::recheck`])
+pub const RETRY_ERROR_PUB_DATE_SLIPPED: &str = "PUB_DATE_SLIPPED";
+
+/// 도서 정보 보강 재시도 큐
+///
+/// # Description
+/// 네이버, 교보문고 등 ISBN으로 도서 정보를 보강하는 잡에서 실패한 ISBN을 사이트별로 기록하고,
+/// 다음 잡 실행시 재시도 시각이 지난 ISBN을 먼저 처리할 수 있도록 반환한다.
+///
+/// # Note
+/// 재시도에 성공한 ISBN의 기록은 삭제되며 다시 실패한 ISBN은 시도 횟수가 늘어나 다음 재시도까지의 대기 시간이 지수적으로 늘어난다.
+pub struct EnrichmentRetryQueue {
+    repo: SharedRetryRepository,
+    site: Site,
+    clock: SharedClock,
+}
+
+impl EnrichmentRetryQueue {
+    pub fn new(repo: SharedRetryRepository, site: Site) -> Self {
+        Self { repo, site, clock: system_clock() }
+    }
+
+    /// 실패 시각과 재시도 시각 계산에 사용할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 재시도 시각이 지난 ISBN 리스트를 반환한다.
+    pub fn due_isbn(&self) -> Vec<String> {
+        let now = self.clock.now();
+        let due = self.repo.find_due(&self.site, &now, DEFAULT_RETRY_LIMIT);
+        if !due.is_empty() {
+            info!("{} => {} isbn(s) are waiting for retry", self.site, due.len());
+        }
+        due.into_iter()
+            .map(|retry| retry.isbn().to_owned())
+            .collect()
+    }
+
+    /// 재시도 기록이 있고 아직 재시도 시각이 지나지 않은 ISBN 집합을 반환한다.
+    pub fn waiting_isbn(&self, isbn_vec: &[String]) -> HashSet<String> {
+        if isbn_vec.is_empty() {
+            return HashSet::new();
+        }
+        let now = self.clock.now();
+        let isbn_refs: Vec<&str> = isbn_vec.iter().map(|isbn| isbn.as_str()).collect();
+        self.repo.find_by_isbn(&self.site, &isbn_refs).into_iter()
+            .filter(|retry| retry.next_retry_at() > now)
+            .map(|retry| retry.isbn().to_owned())
+            .collect()
+    }
+
+    /// 재시도 대상 ISBN을 앞에 두고 중복된 ISBN을 제거하여 처리할 ISBN 리스트를 만든다.
+    pub fn prepend_due(&self, isbn_vec: Vec<String>) -> Vec<String> {
+        let mut visited = HashSet::new();
+        self.due_isbn().into_iter()
+            .chain(isbn_vec)
+            .filter(|isbn| visited.insert(isbn.clone()))
+            .collect()
+    }
+
+    /// ISBN의 보강 실패를 기록한다. 기록되는 메시지에는 ISBN의 추적 아이디([`trace::item_trace_id`])가 붙는다.
+    pub fn failed(&self, isbn: &str, error_type: &str, message: &str) {
+        let now = self.clock.now();
+        let trace_id = trace::item_trace_id(isbn);
+        let message = format!("[trace:{}] {}", trace_id, message);
+        let retry = match self.repo.find_by_isbn(&self.site, &[isbn]).into_iter().next() {
+            Some(mut retry) => {
+                retry.fail_again(error_type.to_owned(), message.to_owned(), now);
+                retry
+            }
+            None => EnrichmentRetry::new(self.site.clone(), isbn.to_owned(), error_type.to_owned(), message.to_owned(), now),
+        };
+        warn!(trace_id = %trace_id, "{} => Enrichment failed({}, attempt {}): {}, next retry at {}",
+            self.site, retry.error_type(), retry.attempt(), isbn, retry.next_retry_at());
+        self.repo.save_retry(&[retry]);
+    }
+
+    /// 요청 한도 초과 등으로 요청하지 못한 ISBN들을 `retry_at` 이후에 처리하도록 기록한다.
+    /// 실패가 아니므로 이미 기록이 있는 ISBN의 시도 횟수는 늘리지 않는다.
+    pub fn deferred(&self, isbn_vec: &[String], error_type: &str, message: &str, retry_at: NaiveDateTime) {
+        if isbn_vec.is_empty() {
+            return;
+        }
+        let now = self.clock.now();
+        let isbn_refs: Vec<&str> = isbn_vec.iter().map(|isbn| isbn.as_str()).collect();
+        let attempts: HashMap<String, u32> = self.repo.find_by_isbn(&self.site, &isbn_refs).into_iter()
+            .map(|retry| (retry.isbn().to_owned(), retry.attempt()))
+            .collect();
+
+        let retries: Vec<EnrichmentRetry> = isbn_vec.iter()
+            .map(|isbn| EnrichmentRetry::restore(
+                self.site,
+                isbn.clone(),
+                error_type.to_owned(),
+                message.to_owned(),
+                attempts.get(isbn).cloned().unwrap_or(0),
+                retry_at,
+                now,
+            ))
+            .collect();
+        warn!("{} => {} isbn(s) deferred({}) until {}", self.site, retries.len(), error_type, retry_at);
+        self.repo.save_retry(&retries);
+    }
+
+    /// ISBN의 보강 성공을 기록한다. (실패 기록이 있을 경우 삭제한다.)
+    pub fn succeeded(&self, isbn: &str) {
+        self.repo.delete_retry(&self.site, &[isbn]);
+    }
+
+    /// ISBN들의 보강 성공을 한번에 기록한다. (실패 기록이 있을 경우 삭제한다.)
+    pub fn succeeded_all(&self, isbn_vec: &[&str]) {
+        if isbn_vec.is_empty() {
+            return;
+        }
+        self.repo.delete_retry(&self.site, isbn_vec);
+    }
+}
+
+/// 저장을 마친 도서의 재시도 기록을 삭제하는 라이터
+///
+/// # Description
+/// 내부 라이터로 청크를 저장한 후 저장된 도서의 ISBN을 재시도 큐에서 삭제한다.
+/// 조회에 성공 하더라도 처리, 저장에 실패한 도서는 다음 실행에서 다시 조회해야 하므로 리더가 아닌 라이터에서 삭제한다.
+/// 청크의 저장에 실패한 경우 청크의 어떤 도서도 삭제하지 않고 다음 실행에서 다시 조회한다.
+pub struct RetryClearingWriter {
+    inner: Box<dyn Writer<Item = Book>>,
+    retry_queue: EnrichmentRetryQueue,
+}
+
+impl RetryClearingWriter {
+    pub fn new(inner: Box<dyn Writer<Item = Book>>, retry_repo: SharedRetryRepository, site: Site) -> Self {
+        Self { inner, retry_queue: EnrichmentRetryQueue::new(retry_repo, site) }
+    }
+}
+
+impl Writer for RetryClearingWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let isbn_vec: Vec<String> = items.iter()
+            .map(|book| book.isbn().to_owned())
+            .collect();
+
+        self.inner.do_write(items)?;
+        let written: Vec<&str> = isbn_vec.iter()
+            .map(|isbn| isbn.as_str())
+            .collect();
+        self.retry_queue.succeeded_all(&written);
+        Ok(())
+    }
+}
+
+/// 라이터가 저장을 마친 도서의 재시도 기록을 삭제하도록 감싼다. ([`RetryClearingWriter`])
+/// `dry_run` 파라미터가 `true`이거나 `output` 파라미터로 파일에 출력하는 경우 도서가 저장소에 저장 되지 않으므로 감싸지 않는다.
+pub fn clear_retry_on_write(writer: Box<dyn Writer<Item = Book>>, retry_repo: SharedRetryRepository, site: Site, params: &JobParameter) -> Box<dyn Writer<Item = Book>> {
+    if is_dry_run(params) || params.contains_key(PARAM_NAME_OUTPUT) {
+        writer
+    } else {
+        Box::new(RetryClearingWriter::new(writer, retry_repo, site))
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(unix)]
+use tracing::warn;
+
+/// 잡 취소 토큰
+///
+/// # Description
+/// 잡 실행([`crate::batch::Job::run`])에 전달하여 실행 중인 잡을 협조적으로 취소할 때 사용한다.
+/// 잡은 청크를 처리하기 전 마다 취소 여부를 확인하며, 취소된 경우 처리 중인 청크를 마친 후 [`crate::batch::error::JobRuntimeError::Cancelled`]를 반환한다.
+/// 따라서 강제 종료(SIGKILL)와 달리 청크의 저장이 중간에 끊기지 않는다.
+///
+/// # Note
+/// 토큰은 복제하여 여러 곳에서 공유할 수 있으며 복제된 토큰은 같은 취소 상태를 가진다.
+/// 시그널 처리기 등 다른 스레드에서도 취소할 수 있도록 원자적 변수를 사용한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::cancel::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let shared = token.clone();
+/// assert!(!shared.is_cancelled());
+///
+/// token.cancel();
+/// assert!(shared.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 취소를 요청한다.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// 취소가 요청 되었는지 여부
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// 종료 시그널(SIGINT, SIGTERM)을 받으면 토큰을 취소하는 시그널 처리기를 등록한다.
+///
+/// # Description
+/// 시그널을 받은 경우 프로세스를 바로 종료하지 않고 토큰을 취소 하므로, 잡은 처리 중인 청크를 마친 후 취소된 상태로 실행 기록을 남기고 종료한다.
+/// 이미 취소된 상태에서 다시 시그널을 받은 경우 기다리지 않고 프로세스를 종료한다.
+#[cfg(unix)]
+pub fn cancel_on_signal(token: &CancellationToken) {
+    for signal in [libc::SIGINT, libc::SIGTERM] {
+        let token = token.clone();
+        // 시그널 처리기 안에서는 원자적 변수 접근과 프로세스 종료만 수행한다.
+        let registered = unsafe {
+            signal_hook_registry::register(signal, move || {
+                if token.is_cancelled() {
+                    libc::_exit(130);
+                }
+                token.cancel();
+            })
+        };
+        if let Err(e) = registered {
+            warn!("Failed to register signal handler({}): {}", signal, e);
+        }
+    }
+}
+
+/// 종료 시그널을 지원하지 않는 플랫폼에서는 시그널 처리기를 등록하지 않는다.
+#[cfg(not(unix))]
+pub fn cancel_on_signal(_: &CancellationToken) {}
@@ -0,0 +1,136 @@
+use crate::batch::JobParameter;
+use crate::item::SharedCheckpointRepository;
+use crate::{JobName, PARAM_NAME_ALLOW_DUPLICATE, PARAM_NAME_RESUME};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// 체크포인트로 중단된 실행을 이어서 실행(`--resume`)할 수 있는 잡
+///
+/// # Description
+/// 같은 파라미터로 다시 실행했을 때 리더가 같은 아이템을 같은 순서로 읽는 잡만 포함한다.
+/// `SERIES` 잡은 시리즈가 연결 되지 않은 도서만 읽으므로 체크포인트 없이 다시 실행해도 남은 도서부터 처리된다.
+pub const CHECKPOINT_JOBS: [JobName; 1] = [JobName::KYOBO];
+
+/// 잡이 체크포인트를 기록하고 이어서 실행할 수 있는지 여부
+pub fn is_checkpoint_supported(job: &JobName) -> bool {
+    CHECKPOINT_JOBS.contains(job)
+}
+
+/// 체크포인트를 구분할 키를 만든다.
+///
+/// # Description
+/// 잡 이름과 정규화된 파라미터를 SHA-256으로 해시하여 16진수 소문자로 반환한다.
+/// [`crate::batch::duplicate::parameter_hash`]와 달리 실행 날짜를 포함하지 않으므로 다음 날 다시 실행해도 같은 키를 얻으며,
+/// 실행 방식에만 영향을 주는 이어서 실행 여부(`resume`)와 중복 실행 허용 여부(`allow_duplicate`)는 제외한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::checkpoint::checkpoint_key;
+/// use book_batch_rust::batch::JobParameter;
+///
+/// let parameter = JobParameter::from([
+///     ("from".to_owned(), "2025-05-01".to_owned()),
+///     ("to".to_owned(), "2025-05-31".to_owned()),
+/// ]);
+/// let mut resume = parameter.clone();
+/// resume.insert("resume".to_owned(), "true".to_owned());
+/// let mut other = parameter.clone();
+/// other.insert("chunk_size".to_owned(), "50".to_owned());
+///
+/// assert_eq!(checkpoint_key("KYOBO", &parameter), checkpoint_key("KYOBO", &resume));
+/// assert_ne!(checkpoint_key("KYOBO", &parameter), checkpoint_key("KYOBO", &other));
+/// assert_ne!(checkpoint_key("KYOBO", &parameter), checkpoint_key("NAVER", &parameter));
+/// ```
+pub fn checkpoint_key(job_name: &str, params: &JobParameter) -> String {
+    let normalized = params.iter()
+        .filter(|(k, _)| k.as_str() != PARAM_NAME_RESUME && k.as_str() != PARAM_NAME_ALLOW_DUPLICATE)
+        .map(|(k, v)| (k.as_str(), v.trim()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut hasher = Sha256::new();
+    hasher.update(job_name.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(serde_json::to_string(&normalized).unwrap_or_default().as_bytes());
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// [`JobParameter`]에 이어서 실행 여부(`resume`)가 `true`로 설정 되어 있는지 여부
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::checkpoint::is_resume;
+/// use book_batch_rust::batch::JobParameter;
+///
+/// let mut parameter = JobParameter::new();
+/// assert!(!is_resume(&parameter));
+///
+/// parameter.insert("resume".to_owned(), "true".to_owned());
+/// assert!(is_resume(&parameter));
+/// ```
+pub fn is_resume(params: &JobParameter) -> bool {
+    params.get(PARAM_NAME_RESUME)
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"))
+}
+
+/// 잡 체크포인트
+///
+/// # Description
+/// [`crate::batch::Job::with_checkpoint`]로 설정하며, 잡은 청크의 저장을 마칠 때 마다 청크의 순번을 기록하고 정상 종료된 경우 기록을 삭제한다.
+/// 실패하거나 취소된 실행을 같은 파라미터와 `resume` 파라미터로 다시 실행하면 기록된 순번까지의 청크를 처리하지 않고 건너뛴다.
+pub struct Checkpoint {
+    repo: SharedCheckpointRepository,
+    job_name: String,
+}
+
+impl Checkpoint {
+    pub fn new(repo: SharedCheckpointRepository, job: &JobName) -> Self {
+        Self {
+            repo,
+            job_name: format!("{:?}", job),
+        }
+    }
+
+    /// 파라미터로 실행할 체크포인트를 시작한다. `resume` 파라미터가 `true`인 경우 기록된 청크의 순번을 찾는다.
+    pub fn start(&self, params: &JobParameter) -> CheckpointRun<'_> {
+        let key = checkpoint_key(&self.job_name, params);
+        let resume_after = if is_resume(params) {
+            let found = self.repo.find_checkpoint(&key);
+            match found {
+                Some(index) => info!("{} => Resume from checkpoint, chunks up to #{} are skipped", self.job_name, index),
+                None => info!("{} => No checkpoint found, start from first chunk", self.job_name),
+            }
+            found
+        } else {
+            None
+        };
+        CheckpointRun { checkpoint: self, key, resume_after }
+    }
+}
+
+/// 한 번의 잡 실행에서 사용하는 체크포인트
+pub struct CheckpointRun<'a> {
+    checkpoint: &'a Checkpoint,
+    key: String,
+
+    /// 이어서 실행하는 경우 이전 실행에서 마지막으로 저장을 마친 청크의 순번
+    resume_after: Option<u64>,
+}
+
+impl CheckpointRun<'_> {
+
+    /// 이전 실행에서 저장을 마친 청크인지 여부
+    pub fn is_written(&self, index: u64) -> bool {
+        self.resume_after.is_some_and(|last| index <= last)
+    }
+
+    /// 청크의 저장을 마쳤음을 기록한다.
+    pub fn save(&self, index: u64) {
+        self.checkpoint.repo.save_checkpoint(&self.key, &self.checkpoint.job_name, index);
+    }
+
+    /// 잡이 정상 종료되어 더 이상 필요 없는 체크포인트를 삭제한다.
+    pub fn clear(&self) {
+        self.checkpoint.repo.delete_checkpoint(&self.key);
+    }
+}
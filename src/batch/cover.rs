@@ -0,0 +1,181 @@
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Processor, Reader, Writer, DEF_CHUNK_SIZE};
+use crate::item::{raw_utils, Book, RawDataKind, Series, SharedBookRepository, SharedSeriesRepository, Site};
+use crate::PARAM_NAME_LIMIT;
+use chrono::NaiveDate;
+use tracing::info;
+
+/// 한번에 조회할 시리즈 수 기본값
+const DEFAULT_READ_LIMIT: usize = 50;
+
+/// 표지 이미지를 찾을 사이트의 우선 순위
+const THUMBNAIL_SITES: [Site; 3] = [Site::KyoboBook, Site::Aladin, Site::Naver];
+
+/// 권 번호 뒤에 붙을 수 있는 접미사
+const VOLUME_SUFFIXES: [&str; 5] = ["권", "巻", "화", ")", "]"];
+
+/// 시리즈 대표 이미지 잡에서 사용하는 원본 데이터 종류
+/// 도서 저장소의 원본 데이터 조회 프로젝션([`raw_utils::origin_projection`])에 사용한다.
+pub const COVER_ORIGIN_KINDS: [RawDataKind; 1] = [RawDataKind::Thumbnail];
+
+/// 대표 이미지가 없는 시리즈를 검색하는 리더
+///
+/// # Description
+/// 대표 이미지가 없는 시리즈를 최근 등록된 순서로 `limit` 파라미터(기본값 50) 개수 만큼 조회한다.
+pub struct CoverlessSeriesReader {
+    series_repo: SharedSeriesRepository,
+
+    /// `limit` 파라미터가 없을 때 한번에 조회할 시리즈 수
+    pub read_limit: usize,
+}
+
+impl CoverlessSeriesReader {
+    pub fn new(series_repo: SharedSeriesRepository) -> Self {
+        Self { series_repo, read_limit: DEFAULT_READ_LIMIT }
+    }
+}
+
+impl Reader for CoverlessSeriesReader {
+    type Item = Series;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let limit = params.get(PARAM_NAME_LIMIT)
+            .map(|s| {
+                s.parse::<usize>()
+                    .map_err(|e| JobReadFailed::InvalidArguments(format!("{}: {} is not a number", PARAM_NAME_LIMIT, e)))
+            })
+            .unwrap_or_else(|| Ok(self.read_limit))?;
+
+        Ok(self.series_repo.find_without_cover(limit))
+    }
+}
+
+/// 시리즈와 선택된 대표 이미지
+#[derive(Debug)]
+pub struct SeriesCover {
+    pub series: Series,
+    /// 대표 이미지 URL, 썸네일이 있는 도서가 없는 경우 `None`
+    pub cover: Option<String>,
+}
+
+/// 도서 제목 끝의 권 번호를 반환한다. 권 번호가 없는 경우 `None`을 반환한다.
+///
+/// # Description
+/// 제목 끝의 숫자를 권 번호로 사용하며, 숫자 뒤에 붙은 `권`, `巻`, 닫는 괄호 등의 접미사는 무시한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::cover::volume_number;
+///
+/// assert_eq!(volume_number("원피스 1"), Some(1));
+/// assert_eq!(volume_number("원피스 105권"), Some(105));
+/// assert_eq!(volume_number("나의 히어로 아카데미아 (03)"), Some(3));
+/// assert_eq!(volume_number("원피스 외전"), None);
+/// ```
+pub fn volume_number(title: &str) -> Option<u32> {
+    let mut rest = title.trim_end();
+    while let Some(stripped) = VOLUME_SUFFIXES.iter().find_map(|suffix| rest.strip_suffix(suffix)) {
+        rest = stripped.trim_end();
+    }
+
+    let digits = rest.chars().rev()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<Vec<_>>();
+    digits.into_iter().rev()
+        .collect::<String>()
+        .parse::<u32>()
+        .ok()
+}
+
+/// 시리즈에 속한 도서들 중 대표 이미지로 사용할 썸네일을 선택한다.
+///
+/// # Description
+/// 썸네일이 있는 도서 중 권 번호([`volume_number`])가 가장 작은 도서(1권)의 썸네일을 우선 선택한다.
+/// 권 번호가 같거나 없는 경우 출판일(실제 출판일, 없으면 출판 예정일)이 빠른 도서를, 그 마저 같은 경우 ISBN이 작은 도서를 선택한다.
+/// 도서의 썸네일은 교보문고, 알라딘, 네이버 순서로 찾는다.
+pub fn select_cover(books: &[Book]) -> Option<String> {
+    books.iter()
+        .filter_map(|book| thumbnail(book).map(|thumbnail| (book, thumbnail)))
+        .min_by_key(|(book, _)| {
+            let pub_date = book.actual_pub_date().or(book.scheduled_pub_date()).unwrap_or(NaiveDate::MAX);
+            (volume_number(book.title()).unwrap_or(u32::MAX), pub_date, book.isbn().to_owned())
+        })
+        .map(|(_, thumbnail)| thumbnail)
+}
+
+fn thumbnail(book: &Book) -> Option<String> {
+    THUMBNAIL_SITES.iter()
+        .filter_map(|site| book.originals().get(site).map(|raw| (site, raw)))
+        .find_map(|(site, raw)| raw_utils::retrieve_thumbnail_from_raw(&raw_utils::load_site_dict(site), raw))
+}
+
+/// 시리즈 대표 이미지 선택 프로세서
+///
+/// # Description
+/// 시리즈에 속한 도서들을 원본 데이터와 함께 조회하여 대표 이미지를 선택([`select_cover`])한다.
+pub struct SeriesCoverProcessor {
+    book_repo: SharedBookRepository,
+}
+
+impl SeriesCoverProcessor {
+    pub fn new(book_repo: SharedBookRepository) -> Self {
+        Self { book_repo }
+    }
+}
+
+impl Processor for SeriesCoverProcessor {
+    type In = Series;
+    type Out = SeriesCover;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let books = self.book_repo.find_by_series_id(item.id());
+        let cover = select_cover(&books);
+
+        Ok(SeriesCover { series: item, cover })
+    }
+}
+
+/// 시리즈 대표 이미지 저장 라이터
+///
+/// # Description
+/// 선택된 대표 이미지를 시리즈에 저장한다. 대표 이미지를 찾지 못한 시리즈는 저장하지 않으며 다음 실행에서 다시 조회된다.
+pub struct SeriesCoverWriter {
+    series_repo: SharedSeriesRepository,
+}
+
+impl SeriesCoverWriter {
+    pub fn new(series_repo: SharedSeriesRepository) -> Self {
+        Self { series_repo }
+    }
+}
+
+impl Writer for SeriesCoverWriter {
+    type Item = SeriesCover;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let mut updated = 0;
+        for item in items.iter() {
+            if let Some(cover) = &item.cover {
+                updated += self.series_repo.update_series_cover(item.series.id(), cover);
+            }
+        }
+        info!("{} series covers updated, {} series have no thumbnail", updated, items.iter().filter(|item| item.cover.is_none()).count());
+        Ok(())
+    }
+}
+
+pub fn create_job(
+    book_repo: SharedBookRepository,
+    series_repo: SharedSeriesRepository,
+    params: &JobParameter,
+) -> Result<Job<Series, SeriesCover>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+
+    let job = job_builder()
+        .reader(Box::new(CoverlessSeriesReader::new(series_repo.clone())))
+        .processor(Box::new(SeriesCoverProcessor::new(book_repo)))
+        .writer(Box::new(SeriesCoverWriter::new(series_repo)))
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size))
+}
@@ -0,0 +1,119 @@
+use crate::batch::error::JobWriteFailed;
+use crate::item::SharedDeadLetterRepository;
+use crate::JobName;
+use std::collections::BTreeMap;
+use std::env;
+use tracing::{debug, error};
+
+/// 같은 아이템을 같은 에러로 격리할 최대 횟수 기본값
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// 에러 유형의 최대 길이 (`books.dead_letter.error_class` 컬럼)
+const ERROR_CLASS_MAX_LENGTH: usize = 128;
+
+/// 환경 변수 `DEAD_LETTER_MAX_ATTEMPTS`에서 같은 아이템을 같은 에러로 격리할 최대 횟수를 읽는다.
+/// 설정 되지 않았거나 1 미만인 경우 기본값([`DEFAULT_MAX_ATTEMPTS`])을 사용한다.
+pub fn max_attempts_from_env() -> u32 {
+    env::var("DEAD_LETTER_MAX_ATTEMPTS").ok()
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_MAX_ATTEMPTS)
+}
+
+/// 저장 실패 사유에서 에러 유형을 만든다.
+///
+/// # Description
+/// 같은 원인의 에러를 같은 유형으로 묶을 수 있도록 대소문자를 구분하지 않고, 아이템마다 다른 숫자는 `#`으로 바꾼다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::dead_letter::error_class;
+///
+/// assert_eq!(error_class("title length 600 (1 ~ 512)"), "title length # (# ~ #)");
+/// assert_eq!(error_class("Failed to insert books"), error_class("failed to insert books"));
+/// ```
+pub fn error_class(cause: &str) -> String {
+    let mut class = String::new();
+    for c in cause.trim().to_lowercase().chars() {
+        if c.is_ascii_digit() {
+            if !class.ends_with('#') {
+                class.push('#');
+            }
+        } else {
+            class.push(c);
+        }
+    }
+    class.chars().take(ERROR_CLASS_MAX_LENGTH).collect()
+}
+
+/// 격리된 아이템의 실패 기록
+///
+/// # Description
+/// [`crate::batch::Job::with_dead_letter`]로 설정하며, 잡은 저장에 실패한 아이템을 격리하기 전 잡, ISBN, 에러 유형별 격리 횟수를 기록한다.
+/// 여러 실행에서 같은 아이템이 같은 에러로 최대 횟수를 넘게 실패한 경우 더 이상 격리 파일에 추가하지 않으며,
+/// 처음 최대 횟수를 넘었을 때 잡, ISBN, 에러 유형, 격리 횟수를 필드로 가진 에러 로그(`alert = "DEAD_LETTER_ESCALATED"`)로 알린다.
+pub struct DeadLetter {
+    repo: SharedDeadLetterRepository,
+    job_name: String,
+    max_attempts: u32,
+}
+
+impl DeadLetter {
+    pub fn new(repo: SharedDeadLetterRepository, job: &JobName) -> Self {
+        Self {
+            repo,
+            job_name: format!("{:?}", job),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    /// 같은 아이템을 같은 에러로 격리할 최대 횟수를 설정한다.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// 저장에 실패한 아이템들의 격리 횟수를 기록하고 최대 횟수를 넘지 않은 아이템만 담은 에러를 반환한다.
+    ///
+    /// # Description
+    /// 아이템의 ISBN은 `key`로 얻으며, 에러 유형은 아이템별 저장 실패 사유([`JobWriteFailed::causes`])로 만든다.
+    /// 실패 기록을 저장하지 못한 아이템은 격리 대상에 남긴다.
+    pub fn filter<O>(&self, failed: JobWriteFailed<O>, key: impl Fn(&O) -> String) -> JobWriteFailed<O> {
+        let message = failed.message().to_owned();
+        let has_causes = failed.has_causes();
+
+        let mut by_class = BTreeMap::new();
+        for (item, cause) in failed.into_causes() {
+            by_class.entry(error_class(&cause)).or_insert_with(Vec::new).push((key(&item), item, cause));
+        }
+
+        let mut kept = Vec::new();
+        for (class, items) in by_class {
+            let isbn_vec = items.iter().map(|(isbn, _, _)| isbn.clone()).collect::<Vec<_>>();
+            let attempts = self.repo.increment_attempts(&self.job_name, &class, &isbn_vec);
+            for (isbn, item, cause) in items {
+                let attempt = attempts.get(&isbn).copied().unwrap_or(1);
+                if attempt <= self.max_attempts {
+                    kept.push((item, cause));
+                } else if attempt == self.max_attempts + 1 {
+                    error!(
+                        alert = "DEAD_LETTER_ESCALATED",
+                        job = %self.job_name,
+                        isbn = %isbn,
+                        class = %class,
+                        attempt,
+                        "{} => {} failed {} times with same error, no longer quarantined: {}", self.job_name, isbn, attempt, cause
+                    );
+                } else {
+                    debug!("{} => {} failed {} times with same error, quarantine skipped", self.job_name, isbn, attempt);
+                }
+            }
+        }
+
+        if has_causes {
+            JobWriteFailed::new_with_causes(kept, &message)
+        } else {
+            JobWriteFailed::new(kept.into_iter().map(|(item, _)| item).collect(), &message)
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::batch::JobParameter;
+use crate::PARAM_NAME_ALLOW_DUPLICATE;
+use chrono::NaiveDate;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// 잡 실행의 중복 여부를 확인할 때 사용할 해시를 만든다.
+///
+/// # Description
+/// 잡 이름, 정규화된 파라미터, 실행 날짜를 SHA-256으로 해시하여 16진수 소문자로 반환한다.
+/// 파라미터는 키 순서로 정렬하고 값의 앞뒤 공백을 제거하며, 실행 결과에 영향을 주지 않는 중복 실행 허용 여부(`allow_duplicate`)는 제외한다.
+/// 따라서 같은 날 같은 잡을 같은 파라미터로 실행하면 파라미터의 입력 순서와 관계 없이 같은 해시를 얻는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::duplicate::parameter_hash;
+/// use book_batch_rust::batch::JobParameter;
+/// use chrono::NaiveDate;
+///
+/// let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+/// let parameter = JobParameter::from([
+///     ("from".to_owned(), "2025-05-01".to_owned()),
+///     ("to".to_owned(), "2025-05-31".to_owned()),
+/// ]);
+/// let mut same = parameter.clone();
+/// same.insert("to".to_owned(), " 2025-05-31 ".to_owned());
+/// same.insert("allow_duplicate".to_owned(), "true".to_owned());
+///
+/// assert_eq!(parameter_hash("NLGO", &parameter, &today), parameter_hash("NLGO", &same, &today));
+/// assert_ne!(parameter_hash("NLGO", &parameter, &today), parameter_hash("ALADIN", &parameter, &today));
+/// assert_ne!(parameter_hash("NLGO", &parameter, &today), parameter_hash("NLGO", &parameter, &today.succ_opt().unwrap()));
+/// ```
+pub fn parameter_hash(job_name: &str, params: &JobParameter, date: &NaiveDate) -> String {
+    let normalized = params.iter()
+        .filter(|(k, _)| k.as_str() != PARAM_NAME_ALLOW_DUPLICATE)
+        .map(|(k, v)| (k.as_str(), v.trim()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut hasher = Sha256::new();
+    hasher.update(job_name.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(serde_json::to_string(&normalized).unwrap_or_default().as_bytes());
+    hasher.update(b"\n");
+    hasher.update(date.format("%Y-%m-%d").to_string().as_bytes());
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// [`JobParameter`]에 중복 실행 허용 여부(`allow_duplicate`)가 `true`로 설정 되어 있는지 여부
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::duplicate::is_duplicate_allowed;
+/// use book_batch_rust::batch::JobParameter;
+///
+/// let mut parameter = JobParameter::new();
+/// assert!(!is_duplicate_allowed(&parameter));
+///
+/// parameter.insert("allow_duplicate".to_owned(), "true".to_owned());
+/// assert!(is_duplicate_allowed(&parameter));
+/// ```
+pub fn is_duplicate_allowed(params: &JobParameter) -> bool {
+    params.get(PARAM_NAME_ALLOW_DUPLICATE)
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"))
+}
@@ -1,19 +1,35 @@
+use crate::provider::api::ClientError;
+
 #[derive(Debug)]
 pub enum JobRuntimeError<I, O> {
     ReadFailed(JobReadFailed),
     ProcessFailed(JobProcessFailed<I>),
     WriteFailed(JobWriteFailed<O>),
+    Cancelled, // 취소 토큰으로 실행이 취소됨
 }
 
 #[derive(Debug)]
 pub enum JobBuildError {
     MissingRequireParameter(String),
+    InvalidParameter(String),
+}
+
+impl std::fmt::Display for JobBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobBuildError::MissingRequireParameter(message) => write!(f, "Missing require parameter, {}", message.to_owned()),
+            JobBuildError::InvalidParameter(message) => write!(f, "Invalid parameter, {}", message.to_owned()),
+        }
+    }
 }
 
+impl std::error::Error for JobBuildError {}
+
 #[derive(Debug)]
 pub enum JobReadFailed {
     EmptyData(String),
     InvalidArguments(String),
+    ResponseTooLarge(String), // 사이트 응답 본문이 허용된 최대 크기를 초과함
     UnknownError(String),
 }
 
@@ -22,11 +38,36 @@ impl std::fmt::Display for JobReadFailed {
         match self {
             JobReadFailed::EmptyData(message) => write!(f, "Empty data, {}", message.to_owned()),
             JobReadFailed::InvalidArguments(message) => write!(f, "Invalid arguments, {}", message.to_owned()),
+            JobReadFailed::ResponseTooLarge(message) => write!(f, "Response too large, {}", message.to_owned()),
             JobReadFailed::UnknownError(message) => write!(f, "Unknown, {}", message.to_owned()),
         }
     }
 }
 
+/// 사이트 클라이언트 에러를 읽기 실패로 변환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::error::JobReadFailed;
+/// use book_batch_rust::provider::api::ClientError;
+///
+/// let failed = JobReadFailed::from(ClientError::ResponseTooLarge("body exceeded 10485760 bytes".to_owned()));
+/// assert!(matches!(failed, JobReadFailed::ResponseTooLarge(_)));
+///
+/// let failed = JobReadFailed::from(ClientError::RequestFailed("timeout".to_owned()));
+/// assert!(matches!(failed, JobReadFailed::UnknownError(_)));
+/// ```
+impl From<ClientError> for JobReadFailed {
+    fn from(err: ClientError) -> Self {
+        match err {
+            ClientError::ResponseTooLarge(message) => JobReadFailed::ResponseTooLarge(message),
+            ClientError::NotFound(message) => JobReadFailed::EmptyData(message),
+            ClientError::MissingRequiredParameter(message) => JobReadFailed::InvalidArguments(message),
+            err => JobReadFailed::UnknownError(format!("{:?}", err)),
+        }
+    }
+}
+
 impl std::error::Error for JobReadFailed {}
 
 pub struct JobProcessFailed<I> {
@@ -54,6 +95,11 @@ impl <I> JobProcessFailed<I> {
         &self.item
     }
 
+    /// 처리에 실패한 아이템의 소유권을 반환한다.
+    pub fn into_item(self) -> Option<I> {
+        self.item
+    }
+
     pub fn message(&self) -> &str {
         &self.message
     }
@@ -76,6 +122,12 @@ impl <I> std::error::Error for JobProcessFailed<I> {}
 pub struct JobWriteFailed<O> {
     item: Vec<O>,
     message: String,
+
+    /// 아이템별 저장 실패 사유 (`item`과 같은 순서, 아이템별 사유가 없는 경우 비어 있음)
+    causes: Vec<String>,
+
+    /// 저장에 실패한 아이템들을 기록한 격리 파일 경로
+    quarantine: Option<String>,
 }
 
 impl<O> JobWriteFailed<O> {
@@ -83,9 +135,40 @@ impl<O> JobWriteFailed<O> {
         JobWriteFailed {
             item,
             message: message.to_owned(),
+            causes: Vec::new(),
+            quarantine: None,
+        }
+    }
+
+    /// 아이템과 아이템별 저장 실패 사유로 에러를 생성한다. `message`는 청크 전체의 실패 사유를 요약한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::error::JobWriteFailed;
+    ///
+    /// let failed = JobWriteFailed::new_with_causes(vec![(1, "too long".to_owned()), (2, "not found".to_owned())], "Invalid items");
+    /// assert_eq!(failed.causes(), vec![(&1, "too long"), (&2, "not found")]);
+    ///
+    /// // 아이템별 사유가 없는 경우 에러 메시지를 사유로 사용한다.
+    /// let failed = JobWriteFailed::new(vec![1, 2], "Failed to insert");
+    /// assert_eq!(failed.into_causes(), vec![(1, "Failed to insert".to_owned()), (2, "Failed to insert".to_owned())]);
+    /// ```
+    pub fn new_with_causes(failures: Vec<(O, String)>, message: &str) -> Self {
+        let (item, causes) = failures.into_iter().unzip();
+        JobWriteFailed {
+            item,
+            message: message.to_owned(),
+            causes,
+            quarantine: None,
         }
     }
 
+    /// 저장에 실패한 아이템들을 기록한 격리 파일 경로를 설정한다.
+    pub fn with_quarantine(mut self, path: String) -> Self {
+        self.quarantine = Some(path);
+        self
+    }
+
     pub fn item(&self) -> &Vec<O> {
         &self.item
     }
@@ -93,17 +176,54 @@ impl<O> JobWriteFailed<O> {
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// 저장에 실패한 아이템들의 소유권을 반환한다.
+    pub fn into_item(self) -> Vec<O> {
+        self.item
+    }
+
+    /// 아이템별 저장 실패 사유가 있는지 여부
+    pub fn has_causes(&self) -> bool {
+        !self.causes.is_empty()
+    }
+
+    /// 아이템과 아이템별 저장 실패 사유, 아이템별 사유가 없는 경우 에러 메시지를 사유로 반환한다.
+    pub fn causes(&self) -> Vec<(&O, &str)> {
+        self.item.iter().enumerate()
+            .map(|(i, item)| (item, self.causes.get(i).map(|c| c.as_str()).unwrap_or(&self.message)))
+            .collect()
+    }
+
+    /// 아이템과 아이템별 저장 실패 사유의 소유권을 반환한다. 아이템별 사유가 없는 경우 에러 메시지를 사유로 반환한다.
+    pub fn into_causes(self) -> Vec<(O, String)> {
+        let mut causes = self.causes.into_iter();
+        self.item.into_iter()
+            .map(|item| (item, causes.next().unwrap_or_else(|| self.message.clone())))
+            .collect()
+    }
+
+    /// 격리 파일 경로, 격리 되지 않은 경우 `None`
+    pub fn quarantine(&self) -> Option<&str> {
+        self.quarantine.as_deref()
+    }
 }
 
 impl<O> std::fmt::Display for JobWriteFailed<O> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        write!(f, "{}", self.message)?;
+        if self.has_causes() {
+            write!(f, " ({} item causes)", self.causes.len())?;
+        }
+        match &self.quarantine {
+            Some(path) => write!(f, " (quarantined: {})", path),
+            None => Ok(()),
+        }
     }
 }
 
 impl<O> std::fmt::Debug for JobWriteFailed<O> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        std::fmt::Display::fmt(self, f)
     }
 }
 
@@ -0,0 +1,274 @@
+use crate::batch::error::{JobBuildError, JobReadFailed, JobWriteFailed};
+use crate::batch::{JobParameter, Reader, Writer};
+use crate::item::{Book, Site};
+use crate::transliterate::{retrieve_transliterator_in_parameter, SharedTransliterator};
+use crate::{PARAM_NAME_INPUT, PARAM_NAME_OUTPUT};
+use std::cell::RefCell;
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+/// 표준 입/출력을 나타내는 경로
+pub const STDIO_PATH: &str = "-";
+
+/// 입력 경로를 열어 [`BufRead`]로 반환한다.
+/// 경로가 `-`일 경우 표준 입력을, 그 외에는 파일을 열어 반환한다.
+pub fn open_input(path: &str) -> io::Result<Box<dyn BufRead>> {
+    if path == STDIO_PATH {
+        Ok(Box::new(BufReader::new(io::stdin())))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+/// 출력 경로를 열어 [`Write`]로 반환한다.
+/// 경로가 `-`일 경우 표준 출력을, 그 외에는 파일을 새로 생성(기존 파일은 덮어씀)하여 반환한다.
+pub fn open_output(path: &str) -> io::Result<Box<dyn Write>> {
+    if path == STDIO_PATH {
+        Ok(Box::new(io::stdout()))
+    } else {
+        Ok(Box::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+/// [`JobParameter`]에서 `output`을 키로 사용하여 파일 라이터를 생성한다.
+/// 만약 `JobParameter`에 출력 경로가 없을 경우 [`None`]을 반환한다.
+///
+/// 출력 경로의 확장자가 `.csv`일 경우 [`CsvWriter`]를, 그 외에는 [`JsonLinesWriter`]를 생성한다.
+/// `romanize` 파라미터가 `true`일 경우 제목의 로마자 표기(`romanized_title`)를 함께 출력한다.
+///
+/// # Note
+/// 출판사 키워드로 수집하는 잡(`ALADIN`, `NLGO`, `KYOBO_SEARCH`)은 출력 경로가 있을 경우 데이터베이스 없이 실행한다. ([`crate::runtime::is_offline_run`])
+/// 저장된 도서를 보강하는 잡은 리더가 데이터베이스를 사용하므로 파일 라이터는 도서 저장소만 대신한다.
+pub fn retrieve_output_writer_in_parameter(params: &JobParameter) -> Result<Option<Box<dyn Writer<Item = Book>>>, JobBuildError> {
+    let path = match params.get(PARAM_NAME_OUTPUT) {
+        Some(path) => path,
+        None => return Ok(None),
+    };
+
+    let transliterator = retrieve_transliterator_in_parameter(params)?;
+    let output = open_output(path)
+        .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_OUTPUT, e)))?;
+
+    let writer: Box<dyn Writer<Item = Book>> = match (path.to_lowercase().ends_with(".csv"), transliterator) {
+        (true, Some(transliterator)) => Box::new(CsvWriter::new(output).with_transliterator(transliterator)),
+        (true, None) => Box::new(CsvWriter::new(output)),
+        (false, Some(transliterator)) => Box::new(JsonLinesWriter::new(output).with_transliterator(transliterator)),
+        (false, None) => Box::new(JsonLinesWriter::new(output)),
+    };
+    Ok(Some(writer))
+}
+
+/// [`JobParameter`]에서 `input`을 키로 사용하여 파일 리더를 생성한다.
+/// 만약 `JobParameter`에 입력 경로가 없을 경우 [`None`]을 반환한다.
+pub fn retrieve_input_reader_in_parameter(params: &JobParameter) -> Option<Box<dyn Reader<Item = Book>>> {
+    params.get(PARAM_NAME_INPUT)
+        .map(|path| Box::new(JsonLinesReader::new(path.to_owned())) as Box<dyn Reader<Item = Book>>)
+}
+
+/// JSON Lines 리더
+///
+/// # Description
+/// [`JsonLinesWriter`]로 출력한 파일을 읽어 도서로 역직렬화 한다.
+/// 이전에 수집한 결과를 다시 필터, 프로세서, 라이터에 입력하여 파이프라인을 재실행 할 때 사용한다.
+///
+/// # Note
+/// 빈 줄은 무시하며 역직렬화 할 수 없는 줄이 있을 경우 줄 번호와 함께 `JobReadFailed` 에러를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::file::{JsonLinesReader, JsonLinesWriter};
+/// use book_batch_rust::batch::{JobParameter, Reader, Writer};
+/// use book_batch_rust::item::Book;
+///
+/// let path = std::env::temp_dir().join("book-batch-json-lines-reader.jsonl");
+/// let path = path.to_str().unwrap();
+///
+/// let book = Book::builder().isbn("9791136202093".to_owned()).title("도서".to_owned()).build().unwrap();
+/// let writer = JsonLinesWriter::new(Box::new(std::fs::File::create(path).unwrap()));
+/// writer.do_write(vec![book.clone()]).unwrap();
+///
+/// let reader = JsonLinesReader::new(path.to_owned());
+/// assert_eq!(reader.do_read(&JobParameter::new()).unwrap(), vec![book]);
+/// ```
+pub struct JsonLinesReader {
+    path: String,
+}
+
+impl JsonLinesReader {
+    pub fn new(path: String) -> Self {
+        Self { path }
+    }
+}
+
+impl Reader for JsonLinesReader {
+    type Item = Book;
+
+    fn do_read(&self, _params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let input = open_input(&self.path)
+            .map_err(|e| JobReadFailed::InvalidArguments(format!("{}: {}", self.path, e)))?;
+
+        let mut books = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let line = line
+                .map_err(|e| JobReadFailed::UnknownError(format!("{}:{}: {}", self.path, i + 1, e)))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let book = serde_json::from_str::<Book>(&line)
+                .map_err(|e| JobReadFailed::UnknownError(format!("{}:{}: {}", self.path, i + 1, e)))?;
+            books.push(book);
+        }
+        Ok(books)
+    }
+}
+
+/// JSON Lines 라이터
+///
+/// # Description
+/// 도서를 한 줄에 하나씩 JSON으로 직렬화 하여 출력한다.
+/// 출력된 파일은 데이터베이스 없이 수집 결과를 확인하거나 다른 파이프라인에 다시 입력하는 용도로 사용한다.
+/// 로마자 변환기가 설정된 경우 제목의 로마자 표기를 `romanized_title` 속성으로 함께 출력한다.
+pub struct JsonLinesWriter {
+    output: RefCell<Box<dyn Write>>,
+    transliterator: Option<SharedTransliterator>,
+}
+
+impl JsonLinesWriter {
+    pub fn new(output: Box<dyn Write>) -> Self {
+        Self { output: RefCell::new(output), transliterator: None }
+    }
+
+    /// 제목의 로마자 표기에 사용할 변환기를 설정한다.
+    pub fn with_transliterator(mut self, transliterator: SharedTransliterator) -> Self {
+        self.transliterator = Some(transliterator);
+        self
+    }
+
+    fn to_line(&self, book: &Book) -> serde_json::Result<String> {
+        match self.transliterator.as_ref() {
+            Some(transliterator) => {
+                let mut value = serde_json::to_value(book)?;
+                if let serde_json::Value::Object(map) = &mut value {
+                    map.insert("romanized_title".to_owned(), serde_json::Value::String(transliterator.romanize(book.title())));
+                }
+                serde_json::to_string(&value)
+            }
+            None => serde_json::to_string(book),
+        }
+    }
+}
+
+impl Writer for JsonLinesWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let mut output = self.output.borrow_mut();
+        for book in &items {
+            let line = match self.to_line(book) {
+                Ok(line) => line,
+                Err(e) => return Err(JobWriteFailed::new(items.clone(), &e.to_string())),
+            };
+            if let Err(e) = writeln!(output, "{}", line) {
+                return Err(JobWriteFailed::new(items.clone(), &e.to_string()));
+            }
+        }
+        output.flush()
+            .map_err(|e| JobWriteFailed::new(items, &e.to_string()))
+    }
+}
+
+/// CSV 라이터에서 사용할 컬럼 목록
+const CSV_HEADERS: [&str; 9] = [
+    "id", "isbn", "publisher_id", "series_id", "title", "scheduled_pub_date", "actual_pub_date", "description", "sites",
+];
+
+/// CSV 라이터
+///
+/// # Description
+/// 도서의 주요 속성을 CSV로 출력한다. 헤더는 첫번째 청크를 출력할 때 한번만 출력한다.
+///
+/// # Note
+/// 사이트별 원본 데이터는 출력하지 않으며 원본 데이터가 있는 사이트 목록만 `|`로 연결하여 출력한다.
+/// 원본 데이터까지 필요한 경우 [`JsonLinesWriter`]를 사용한다.
+/// 로마자 변환기가 설정된 경우 제목의 로마자 표기를 `romanized_title` 컬럼으로 마지막에 추가한다.
+pub struct CsvWriter {
+    output: RefCell<csv::Writer<Box<dyn Write>>>,
+    header_written: RefCell<bool>,
+    transliterator: Option<SharedTransliterator>,
+}
+
+impl CsvWriter {
+    pub fn new(output: Box<dyn Write>) -> Self {
+        Self {
+            output: RefCell::new(csv::Writer::from_writer(output)),
+            header_written: RefCell::new(false),
+            transliterator: None,
+        }
+    }
+
+    /// 제목의 로마자 표기에 사용할 변환기를 설정한다.
+    pub fn with_transliterator(mut self, transliterator: SharedTransliterator) -> Self {
+        self.transliterator = Some(transliterator);
+        self
+    }
+
+    fn headers(&self) -> Vec<&'static str> {
+        let mut headers = CSV_HEADERS.to_vec();
+        if self.transliterator.is_some() {
+            headers.push("romanized_title");
+        }
+        headers
+    }
+
+    fn to_record(&self, book: &Book) -> Vec<String> {
+        let mut record = to_csv_record(book).to_vec();
+        if let Some(transliterator) = self.transliterator.as_ref() {
+            record.push(transliterator.romanize(book.title()));
+        }
+        record
+    }
+}
+
+impl Writer for CsvWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let mut output = self.output.borrow_mut();
+        let mut header_written = self.header_written.borrow_mut();
+
+        if !*header_written {
+            if let Err(e) = output.write_record(self.headers()) {
+                return Err(JobWriteFailed::new(items, &e.to_string()));
+            }
+            *header_written = true;
+        }
+
+        for book in &items {
+            if let Err(e) = output.write_record(self.to_record(book)) {
+                return Err(JobWriteFailed::new(items.clone(), &e.to_string()));
+            }
+        }
+        output.flush()
+            .map_err(|e| JobWriteFailed::new(items, &e.to_string()))
+    }
+}
+
+fn to_csv_record(book: &Book) -> [String; 9] {
+    let mut sites = book.originals().keys()
+        .map(Site::to_string)
+        .collect::<Vec<_>>();
+    sites.sort();
+
+    [
+        book.id().to_string(),
+        book.isbn().to_owned(),
+        book.publisher_id().to_string(),
+        book.series_id().map(|id| id.to_string()).unwrap_or_default(),
+        book.title().to_owned(),
+        book.scheduled_pub_date().map(|d| d.to_string()).unwrap_or_default(),
+        book.actual_pub_date().map(|d| d.to_string()).unwrap_or_default(),
+        book.description().unwrap_or_default().to_owned(),
+        sites.join("|"),
+    ]
+}
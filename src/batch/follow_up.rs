@@ -0,0 +1,75 @@
+use crate::batch::audit::JobAudit;
+use crate::batch::error::JobBuildError;
+use crate::batch::JobParameter;
+use crate::{JobName, PARAM_NAME_FOLLOW_UP, PARAM_NAME_ISBN, PARAM_NAME_ISBN_SET};
+
+/// 잡이 끝난 후 이어서 실행할 후속 잡
+///
+/// # Description
+/// 선행 잡의 변경 내역([`JobAudit`])과 파라미터로 후속 잡의 파라미터를 만든다.
+/// 기간(`from/to`)이 아닌 선행 잡에서 실제로 변경된 도서만 후속 잡에서 처리할 수 있도록 한다.
+pub struct FollowUp {
+    /// 후속 잡
+    pub job: JobName,
+
+    /// 후속 잡의 파라미터를 만드는 함수, `None`을 반환할 경우 후속 잡을 실행하지 않는다.
+    pub derive: fn(&JobAudit, &JobParameter) -> Option<JobParameter>,
+}
+
+/// 잡에 정의된 후속 잡 리스트를 반환한다.
+///
+/// # Description
+/// - `NLGO`, `ALADIN`, `KYOBO_SEARCH`: 새로 저장된 도서의 ISBN으로 `KYOBO` 잡을 실행해 도서 정보를 보강한다.
+pub fn follow_ups(job: &JobName) -> Vec<FollowUp> {
+    match job {
+        JobName::NLGO | JobName::ALADIN | JobName::KYOBO_SEARCH => vec![
+            FollowUp { job: JobName::KYOBO, derive: created_isbn_parameter },
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// [`JobParameter`]에서 후속 잡 실행 여부(`follow_up`)를 얻는다. 파라미터가 없을 경우 `false`를 반환한다.
+pub fn is_follow_up_enabled(params: &JobParameter) -> Result<bool, JobBuildError> {
+    params.get(PARAM_NAME_FOLLOW_UP)
+        .map(|v| v.trim().parse::<bool>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_FOLLOW_UP, e))))
+        .transpose()
+        .map(|enabled| enabled.unwrap_or(false))
+}
+
+/// 선행 잡에서 새로 저장된 도서의 ISBN을 `isbn` 파라미터로 만든다. 새로 저장된 도서가 없을 경우 `None`을 반환한다.
+///
+/// # Description
+/// 새로 저장된 도서의 ISBN을 ISBN 집합으로 저장한 경우 ISBN 리스트 대신 `isbn_set` 파라미터로 ISBN 집합 이름을 전달한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::audit::JobAudit;
+/// use book_batch_rust::batch::follow_up::created_isbn_parameter;
+/// use book_batch_rust::batch::JobParameter;
+///
+/// let mut audit = JobAudit::default();
+/// assert!(created_isbn_parameter(&audit, &JobParameter::new()).is_none());
+///
+/// audit.created_isbn = vec!["9791136202093".to_owned(), "9791136202109".to_owned()];
+/// let params = created_isbn_parameter(&audit, &JobParameter::new()).unwrap();
+/// assert_eq!(params.get("isbn").unwrap(), "9791136202093,9791136202109");
+///
+/// audit.created_isbn_set = Some("nlgo:2024-06-01:new".to_owned());
+/// let params = created_isbn_parameter(&audit, &JobParameter::new()).unwrap();
+/// assert_eq!(params.get("isbn_set").unwrap(), "nlgo:2024-06-01:new");
+/// assert!(params.get("isbn").is_none());
+/// ```
+pub fn created_isbn_parameter(audit: &JobAudit, _: &JobParameter) -> Option<JobParameter> {
+    if audit.created_isbn.is_empty() {
+        return None;
+    }
+
+    let mut params = JobParameter::new();
+    match audit.created_isbn_set.as_ref() {
+        Some(set_name) => params.insert(PARAM_NAME_ISBN_SET.to_owned(), set_name.to_owned()),
+        None => params.insert(PARAM_NAME_ISBN.to_owned(), audit.created_isbn.join(",")),
+    };
+    Some(params)
+}
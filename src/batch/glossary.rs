@@ -0,0 +1,294 @@
+use crate::batch::book::retrieve_publisher_id_in_parameter;
+use crate::batch::cover::volume_number;
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::file::{open_output, STDIO_PATH};
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Processor, Reader, Writer};
+use crate::item::{Book, Publisher, Series, SharedBookRepository, SharedPublisherRepository, SharedSeriesRepository};
+use crate::PARAM_NAME_OUTPUT;
+use chrono::NaiveDate;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use tracing::info;
+
+/// 출판사 용어집 잡의 기본 청크 사이즈 (출판사 단위로 처리한다.)
+const DEFAULT_CHUNK_SIZE: usize = 10;
+
+/// 엑셀에서 UTF-8 CSV를 열 때 한글이 깨지지 않도록 파일 앞에 붙이는 BOM
+const UTF8_BOM: &str = "\u{FEFF}";
+
+const GLOSSARY_HEADERS: [&str; 9] = [
+    "publisher_id", "publisher", "series_id", "canonical_title", "volume", "isbn", "title", "normalized_title", "pub_date",
+];
+
+/// 용어집을 만들 출판사를 조회하는 리더
+///
+/// # Description
+/// `publisher_id` 파라미터가 있는 경우 해당 출판사만, 없는 경우 모든 출판사를 조회한다.
+pub struct GlossaryPublisherReader {
+    pub_repo: SharedPublisherRepository,
+}
+
+impl GlossaryPublisherReader {
+    pub fn new(pub_repo: SharedPublisherRepository) -> Self {
+        Self { pub_repo }
+    }
+}
+
+impl Reader for GlossaryPublisherReader {
+    type Item = Publisher;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let publisher_id = retrieve_publisher_id_in_parameter(params)?;
+        let mut publishers = if publisher_id.is_empty() {
+            self.pub_repo.get_all()
+        } else {
+            self.pub_repo.find_by_id(&publisher_id)
+        };
+        publishers.sort_by_key(|p| p.id());
+        Ok(publishers)
+    }
+}
+
+/// 시리즈 하나의 용어집 항목
+#[derive(Debug, Clone)]
+pub struct GlossaryEntry {
+    pub series_id: u64,
+
+    /// 정규화된 시리즈 제목, 시리즈 제목이 없는 경우 소속 도서에서 가장 많이 사용된 정규화 제목
+    pub canonical_title: String,
+
+    /// 소속 도서 (권 번호, 출판일, ISBN 순서)
+    pub books: Vec<Book>,
+}
+
+/// 출판사 하나의 용어집
+#[derive(Debug, Clone)]
+pub struct PublisherGlossary {
+    pub publisher: Publisher,
+
+    /// 정규화된 시리즈 제목 순서로 정렬된 용어집 항목
+    pub entries: Vec<GlossaryEntry>,
+}
+
+/// 시리즈에 연결된 도서들을 시리즈별로 묶어 용어집 항목을 만든다.
+///
+/// # Description
+/// 항목의 대표 제목은 시리즈 제목(LLM으로 정규화된 제목)을 사용하며, 시리즈 제목이 없는 경우 소속 도서에서 가장 많이 사용된 정규화 제목을,
+/// 그 마저 없는 경우 가장 앞 도서의 제목을 사용한다. 시리즈에 연결되지 않은 도서는 제외한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::glossary::build_entries;
+/// use book_batch_rust::item::{Book, Series};
+///
+/// let book = |isbn: &str, title: &str, series_id: u64| Book::builder()
+///     .isbn(isbn.to_owned()).title(title.to_owned()).series_id(series_id).build().unwrap();
+/// let books = vec![
+///     book("9791133478427", "원피스 2", 1),
+///     book("9791133478410", "원피스 1", 1),
+///     book("9791136202093", "나루토 1", 2),
+/// ];
+/// let series = vec![Series::builder().id(1).title("원피스".to_owned()).build().unwrap()];
+///
+/// let entries = build_entries(books, &series);
+/// assert_eq!(entries.len(), 2);
+/// assert_eq!(entries[0].canonical_title, "나루토 1");
+/// assert_eq!(entries[1].canonical_title, "원피스");
+/// assert_eq!(entries[1].books[0].isbn(), "9791133478410");
+/// ```
+pub fn build_entries(books: Vec<Book>, series: &[Series]) -> Vec<GlossaryEntry> {
+    let titles: HashMap<u64, &str> = series.iter()
+        .filter_map(|s| s.title().as_deref().map(|title| (s.id(), title)))
+        .collect();
+
+    let mut grouped: BTreeMap<u64, Vec<Book>> = BTreeMap::new();
+    for book in books {
+        if let Some(series_id) = book.series_id() {
+            grouped.entry(series_id).or_default().push(book);
+        }
+    }
+
+    let mut entries = grouped.into_iter()
+        .map(|(series_id, mut books)| {
+            books.sort_by_key(|book| (
+                volume_number(book.title()).unwrap_or(u32::MAX),
+                book.actual_pub_date().or(book.scheduled_pub_date()).unwrap_or(NaiveDate::MAX),
+                book.isbn().to_owned(),
+            ));
+            let canonical_title = titles.get(&series_id)
+                .map(|title| title.to_string())
+                .or_else(|| most_used_normalized_title(&books))
+                .unwrap_or_else(|| books[0].title().to_owned());
+            GlossaryEntry { series_id, canonical_title, books }
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.canonical_title.cmp(&b.canonical_title).then(a.series_id.cmp(&b.series_id)));
+    entries
+}
+
+fn most_used_normalized_title(books: &[Book]) -> Option<String> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for title in books.iter().filter_map(|book| book.normalized_title()) {
+        *counts.entry(title).or_insert(0) += 1;
+    }
+    counts.into_iter()
+        .max_by(|(a_title, a_count), (b_title, b_count)| a_count.cmp(b_count).then(b_title.cmp(a_title)))
+        .map(|(title, _)| title.to_owned())
+}
+
+/// 출판사별 용어집 프로세서
+///
+/// # Description
+/// 출판사의 도서 중 시리즈에 연결된 도서와 그 시리즈를 조회하여 용어집 항목([`build_entries`])을 만든다.
+pub struct GlossaryProcessor {
+    book_repo: SharedBookRepository,
+    series_repo: SharedSeriesRepository,
+}
+
+impl GlossaryProcessor {
+    pub fn new(book_repo: SharedBookRepository, series_repo: SharedSeriesRepository) -> Self {
+        Self { book_repo, series_repo }
+    }
+}
+
+impl Processor for GlossaryProcessor {
+    type In = Publisher;
+    type Out = PublisherGlossary;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let books = self.book_repo.find_series_linked_by_publisher(item.id());
+        let mut series_id = books.iter()
+            .filter_map(|book| book.series_id())
+            .collect::<Vec<_>>();
+        series_id.sort();
+        series_id.dedup();
+
+        let series = self.series_repo.find_by_id(&series_id);
+        Ok(PublisherGlossary { publisher: item, entries: build_entries(books, &series) })
+    }
+}
+
+/// 출판사 용어집 CSV 라이터
+///
+/// # Description
+/// 출판사 > 시리즈(정규화된 제목) > 소속 도서 순서로 도서 한 권을 한 행으로 출력한다.
+/// 편집팀이 엑셀에서 바로 열 수 있도록 파일 앞에 UTF-8 BOM을 붙이며, 헤더와 함께 첫번째 청크를 출력할 때 한번만 출력한다.
+/// 시리즈가 없는 출판사는 출력하지 않는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::glossary::GlossaryCsvWriter;
+/// use book_batch_rust::batch::Writer;
+/// use std::cell::RefCell;
+/// use std::io::Write;
+/// use std::rc::Rc;
+///
+/// struct Buffer(Rc<RefCell<Vec<u8>>>);
+/// impl Write for Buffer {
+///     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+///         self.0.borrow_mut().write(buf)
+///     }
+///     fn flush(&mut self) -> std::io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let buffer = Rc::new(RefCell::new(Vec::new()));
+/// let writer = GlossaryCsvWriter::new(Box::new(Buffer(buffer.clone())));
+/// writer.do_write(Vec::new()).unwrap();
+/// writer.do_write(Vec::new()).unwrap();
+///
+/// let output = String::from_utf8(buffer.borrow().clone()).unwrap();
+/// assert!(output.as_bytes().starts_with(&[0xEF, 0xBB, 0xBF]));
+/// assert_eq!(output.matches('\u{FEFF}').count(), 1);
+/// assert!(output.trim_start_matches('\u{FEFF}').starts_with("publisher_id,"));
+/// ```
+pub struct GlossaryCsvWriter {
+    /// BOM과 헤더를 출력하기 전의 출력 대상, 첫번째 청크를 출력할 때 BOM을 출력한 후 CSV 라이터로 감싼다.
+    pending: RefCell<Option<Box<dyn Write>>>,
+    output: RefCell<Option<csv::Writer<Box<dyn Write>>>>,
+}
+
+impl GlossaryCsvWriter {
+    pub fn new(output: Box<dyn Write>) -> Self {
+        Self {
+            pending: RefCell::new(Some(output)),
+            output: RefCell::new(None),
+        }
+    }
+}
+
+impl Writer for GlossaryCsvWriter {
+    type Item = PublisherGlossary;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let mut output = self.output.borrow_mut();
+
+        if let Some(mut pending) = self.pending.borrow_mut().take() {
+            if let Err(e) = pending.write_all(UTF8_BOM.as_bytes()) {
+                return Err(JobWriteFailed::new(items, &e.to_string()));
+            }
+            let mut writer = csv::Writer::from_writer(pending);
+            if let Err(e) = writer.write_record(GLOSSARY_HEADERS) {
+                return Err(JobWriteFailed::new(items, &e.to_string()));
+            }
+            *output = Some(writer);
+        }
+        let Some(output) = output.as_mut() else {
+            return Err(JobWriteFailed::new(items, "glossary output is not available"));
+        };
+
+        for glossary in &items {
+            for entry in &glossary.entries {
+                for book in &entry.books {
+                    if let Err(e) = output.write_record(to_glossary_record(glossary, entry, book)) {
+                        return Err(JobWriteFailed::new(items.clone(), &e.to_string()));
+                    }
+                }
+            }
+            info!("{}({}) => {} series exported", glossary.publisher.name(), glossary.publisher.id(), glossary.entries.len());
+        }
+        output.flush()
+            .map_err(|e| JobWriteFailed::new(items, &e.to_string()))
+    }
+}
+
+fn to_glossary_record(glossary: &PublisherGlossary, entry: &GlossaryEntry, book: &Book) -> [String; 9] {
+    [
+        glossary.publisher.id().to_string(),
+        glossary.publisher.name().to_owned(),
+        entry.series_id.to_string(),
+        entry.canonical_title.clone(),
+        volume_number(book.title()).map(|v| v.to_string()).unwrap_or_default(),
+        book.isbn().to_owned(),
+        book.title().to_owned(),
+        book.normalized_title().unwrap_or_default().to_owned(),
+        book.actual_pub_date().or(book.scheduled_pub_date()).map(|d| d.to_string()).unwrap_or_default(),
+    ]
+}
+
+/// 출판사 용어집 잡을 생성한다.
+///
+/// # Description
+/// `output` 파라미터의 경로에 CSV로 출력하며 경로가 없을 경우 표준 출력으로 출력한다.
+pub fn create_job(
+    book_repo: SharedBookRepository,
+    series_repo: SharedSeriesRepository,
+    pub_repo: SharedPublisherRepository,
+    params: &JobParameter,
+) -> Result<Job<Publisher, PublisherGlossary>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEFAULT_CHUNK_SIZE);
+
+    let path = params.get(PARAM_NAME_OUTPUT).map(|p| p.as_str()).unwrap_or(STDIO_PATH);
+    let output = open_output(path)
+        .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_OUTPUT, e)))?;
+
+    let job = job_builder()
+        .reader(Box::new(GlossaryPublisherReader::new(pub_repo)))
+        .processor(Box::new(GlossaryProcessor::new(book_repo, series_repo)))
+        .writer(Box::new(GlossaryCsvWriter::new(output)))
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size))
+}
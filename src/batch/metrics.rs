@@ -0,0 +1,156 @@
+use crate::item::JobExecutionCounts;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::info;
+
+/// 조회한 도서를 찾을 수 없어 건너뛴 횟수
+pub const METRIC_NOT_FOUND: &str = "not_found";
+
+/// 조회한 응답의 일부 도서만 변환하여 나머지를 건너뛴 횟수
+pub const METRIC_PARTIAL: &str = "partial";
+
+thread_local! {
+    /// 현재 실행 중인 잡에서 실행한 쿼리 유형별 실행 통계
+    static QUERIES: RefCell<BTreeMap<String, QueryStat>> = RefCell::new(BTreeMap::new());
+
+    /// 현재 실행 중인 잡에서 읽고, 처리하고, 저장하고, 건너뛴 아이템 수
+    static ITEMS: RefCell<JobExecutionCounts> = RefCell::new(JobExecutionCounts::default());
+}
+
+/// 잡 실행 중 발생한 이벤트의 횟수를 이름별로 기록하는 카운터
+///
+/// # Description
+/// 리더, 프로세서 등에서 에러가 아닌 정상적인 예외 상황(도서를 찾을 수 없음 등)을 건너뛸 때 그 횟수를 기록하여
+/// 잡 실행 후 장애와 구분하여 확인할 수 있도록 한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::metrics::{Metrics, METRIC_NOT_FOUND};
+///
+/// let metrics = Metrics::new();
+/// metrics.increment(METRIC_NOT_FOUND);
+/// metrics.increment(METRIC_NOT_FOUND);
+///
+/// assert_eq!(metrics.get(METRIC_NOT_FOUND), 2);
+/// assert_eq!(metrics.get("unknown"), 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct Metrics {
+    counters: RefCell<BTreeMap<String, usize>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 이름에 해당하는 카운터를 1 증가 시킨다.
+    pub fn increment(&self, name: &str) {
+        *self.counters.borrow_mut().entry(name.to_owned()).or_insert(0) += 1;
+    }
+
+    /// 이름에 해당하는 카운터의 현재 값을 반환한다. 기록된 적 없는 카운터는 0을 반환한다.
+    pub fn get(&self, name: &str) -> usize {
+        self.counters.borrow().get(name).cloned().unwrap_or(0)
+    }
+
+    /// 모든 카운터의 현재 값을 반환한다.
+    pub fn snapshot(&self) -> BTreeMap<String, usize> {
+        self.counters.borrow().clone()
+    }
+
+    /// 기록된 카운터들을 로그로 출력한다.
+    pub fn log(&self, target: &str) {
+        for (name, count) in self.counters.borrow().iter() {
+            info!("{} => {}: {}", target, name, count);
+        }
+    }
+}
+
+/// 쿼리 유형별 실행 통계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueryStat {
+    /// 실행 횟수
+    pub count: usize,
+
+    /// 총 실행 시간
+    pub total: Duration,
+
+    /// 가장 오래 걸린 실행 시간
+    pub max: Duration,
+}
+
+impl QueryStat {
+    /// 평균 실행 시간
+    pub fn average(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.count as u32
+        }
+    }
+}
+
+/// 쿼리 유형(ex: `SELECT book`)의 실행 시간을 기록한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::metrics::{record_query, take_query_stats};
+/// use std::time::Duration;
+///
+/// record_query("SELECT book", Duration::from_millis(10));
+/// record_query("SELECT book", Duration::from_millis(30));
+///
+/// let stats = take_query_stats();
+/// let stat = stats.get("SELECT book").unwrap();
+/// assert_eq!(stat.count, 2);
+/// assert_eq!(stat.max, Duration::from_millis(30));
+/// assert_eq!(stat.average(), Duration::from_millis(20));
+///
+/// assert!(take_query_stats().is_empty());
+/// ```
+pub fn record_query(kind: &str, elapsed: Duration) {
+    QUERIES.with(|queries| {
+        let mut queries = queries.borrow_mut();
+        let stat = queries.entry(kind.to_owned()).or_default();
+        stat.count += 1;
+        stat.total += elapsed;
+        stat.max = stat.max.max(elapsed);
+    });
+}
+
+/// 지금까지 기록된 쿼리 실행 통계를 반환하고 초기화 한다.
+pub fn take_query_stats() -> BTreeMap<String, QueryStat> {
+    QUERIES.with(|queries| queries.take())
+}
+
+/// 쿼리 실행 통계를 로그로 출력한다.
+pub fn log_query_stats(target: &str, stats: &BTreeMap<String, QueryStat>) {
+    for (kind, stat) in stats {
+        info!("{} => query {}: count {}, total {} ms, avg {} ms, max {} ms", target, kind,
+            stat.count, stat.total.as_millis(), stat.average().as_millis(), stat.max.as_millis());
+    }
+}
+
+/// 잡에서 읽고, 처리하고, 저장하고, 건너뛴 아이템 수를 지금까지 기록된 수에 더한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::metrics::{record_item_counts, take_item_counts};
+/// use book_batch_rust::item::JobExecutionCounts;
+///
+/// record_item_counts(&JobExecutionCounts { read: 3, processed: 2, skipped: 1, ..Default::default() });
+/// record_item_counts(&JobExecutionCounts { written: 2, ..Default::default() });
+///
+/// assert_eq!(take_item_counts(), JobExecutionCounts { read: 3, processed: 2, written: 2, skipped: 1 });
+/// assert_eq!(take_item_counts(), JobExecutionCounts::default());
+/// ```
+pub fn record_item_counts(counts: &JobExecutionCounts) {
+    ITEMS.with(|items| items.borrow_mut().add(counts));
+}
+
+/// 지금까지 기록된 아이템 수를 반환하고 초기화 한다.
+pub fn take_item_counts() -> JobExecutionCounts {
+    ITEMS.with(|items| items.take())
+}
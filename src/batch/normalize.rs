@@ -0,0 +1,241 @@
+use crate::batch::book::retrieve_isbn_in_parameter;
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::retry::RetryPolicy;
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Processor, Reader, Writer, DEF_CHUNK_SIZE, trace};
+use crate::clock::{system_clock, SharedClock};
+use crate::item::{raw_utils, Book, RawDataKind, SharedBookRepository, SharedTitleNormalizationRepository, Site, TitleNormalization};
+use crate::prompt::{NormalizeRequest, NormalizeRequestSaleInfo, SharedPrompt};
+use crate::{PARAM_NAME_LIMIT, PARAM_NAME_SITE_PRIORITY};
+use tracing::info;
+
+const DEFAULT_READ_LIMIT: usize = 50;
+
+/// LLM 요청에 사용할 사이트별 원본 데이터 우선순위 기본값
+pub const DEFAULT_SITE_PRIORITY: [Site; 4] = [Site::KyoboBook, Site::Aladin, Site::Naver, Site::NLGO];
+
+/// 제목 정규화 요청([`convert_book_to_normalize_request`])에 사용하는 원본 데이터 종류
+/// 도서 저장소의 원본 데이터 조회 프로젝션([`raw_utils::origin_projection`])에 사용한다.
+pub const NORMALIZE_ORIGIN_KINDS: [RawDataKind; 4] = [RawDataKind::Title, RawDataKind::SalePrice, RawDataKind::Description, RawDataKind::SeriesList];
+
+/// 제목이 정규화 되지 않은 도서를 검색하는 리더
+///
+/// # Description
+/// 정규화된 제목이 없는 도서들을 데이터베이스에서 조회한다.
+/// `JobParameter`에서 `limit` 키로 조회할 도서의 수를 지정할 수 있으며 50개를 기본값으로 사용한다.
+///
+/// # Note
+/// `isbn` 파라미터가 있을 경우 정규화 여부와 관계 없이 해당 ISBN의 도서들을 조회한다.
+/// 이미 정규화된 제목을 다시 정규화 하고 싶을 때 사용한다.
+///
+/// 정규화 이후 제목이 바뀐 도서도 다시 정규화 할 수 있도록 함께 조회한다.
+pub struct UnnormalizedBookReader {
+    book_repo: SharedBookRepository,
+    normalization_repo: SharedTitleNormalizationRepository,
+}
+
+impl UnnormalizedBookReader {
+    pub fn new(book_repo: SharedBookRepository, normalization_repo: SharedTitleNormalizationRepository) -> Self {
+        Self { book_repo, normalization_repo }
+    }
+}
+
+impl Reader for UnnormalizedBookReader {
+    type Item = Book;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let isbn_vec = retrieve_isbn_in_parameter(params)?;
+        if !isbn_vec.is_empty() {
+            let isbn_vec = isbn_vec.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+            return Ok(self.book_repo.find_by_isbn(&isbn_vec));
+        }
+
+        let limit = params.get(PARAM_NAME_LIMIT)
+            .map(|s| {
+                s.parse::<usize>()
+                    .map_err(|e| JobReadFailed::InvalidArguments(format!("{}: {} is not a number", PARAM_NAME_LIMIT, e)))
+            })
+            .unwrap_or_else(|| Ok(DEFAULT_READ_LIMIT))?;
+
+        let title_changed = self.normalization_repo.find_title_changed_isbn(limit);
+        let title_changed = title_changed.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+
+        let mut books = self.book_repo.find_title_unnormalized(limit);
+        if !title_changed.is_empty() {
+            books.extend(self.book_repo.find_by_isbn(&title_changed));
+        }
+        Ok(books)
+    }
+}
+
+/// 제목 정규화 결과
+#[derive(Debug)]
+pub struct NormalizedBook {
+
+    /// 정규화된 제목이 설정된 도서
+    pub book: Book,
+
+    /// 새로 정규화 한 경우 저장할 정규화 이력, 이전 정규화 결과를 그대로 사용한 경우 `None`
+    pub normalization: Option<TitleNormalization>,
+}
+
+/// 도서 제목 정규화 프로세서
+///
+/// # Description
+/// LLM 프롬프트를 이용하여 도서의 제목에서 권수, 특장판 표기 등 불필요한 정보를 제거하고 정규화된 제목을 도서에 설정한다.
+/// 시리즈 분류와 관계 없이 사용할 수 있으므로 이미 시리즈에 속한 도서도 검색 색인, 중복 제거 등에 사용할 정규화된 제목을 가질 수 있다.
+///
+/// # Note
+/// 정규화 이력이 있고 이력의 제목과 프롬프트 버전이 현재와 같다면 LLM을 호출하지 않고 이력의 정규화된 제목을 사용한다.
+pub struct NormalizeTitleProcessor {
+    prompt: SharedPrompt,
+    normalization_repo: SharedTitleNormalizationRepository,
+
+    /// 정규화 요청에 판매처 정보를 전달할 사이트 순서
+    pub site_priority: Vec<Site>,
+
+    /// 정규화 시각에 사용할 시계
+    clock: SharedClock,
+}
+
+impl NormalizeTitleProcessor {
+    pub fn new(prompt: SharedPrompt, normalization_repo: SharedTitleNormalizationRepository) -> Self {
+        Self { prompt, normalization_repo, site_priority: DEFAULT_SITE_PRIORITY.to_vec(), clock: system_clock() }
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Processor for NormalizeTitleProcessor {
+    type In = Book;
+    type Out = NormalizedBook;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let prompt_version = self.prompt.prompt_version();
+        let previous = self.normalization_repo.find_by_book_id(&[item.id()]).into_iter().next()
+            .filter(|n| n.is_up_to_date(item.title(), prompt_version.as_deref()));
+        if let Some(previous) = previous {
+            info!("Title normalization is up to date: {} => {}", item.isbn(), previous.normalized_title());
+            let mut item = item;
+            item.set_normalized_title(previous.normalized_title().to_owned());
+            return Ok(NormalizedBook { book: item, normalization: None });
+        }
+
+        let request = convert_book_to_normalize_request(&item, &self.site_priority);
+
+        match self.prompt.normalize(&request) {
+            Ok(normalized) => {
+                let normalization = TitleNormalization::new(
+                    item.id(),
+                    item.title().to_owned(),
+                    normalized.title.clone(),
+                    normalized.reason,
+                    normalized.prompt_version,
+                    self.clock.now(),
+                );
+                let mut item = item;
+                item.set_normalized_title(normalized.title);
+                Ok(NormalizedBook { book: item, normalization: Some(normalization) })
+            }
+            Err(e) => Err(JobProcessFailed::new(item, trace::with_trace_id(&format!("failed title normalize {}", e))))
+        }
+    }
+}
+
+/// 정규화된 제목과 정규화 이력을 저장하는 라이터
+pub struct NormalizedTitleWriter {
+    book_repo: SharedBookRepository,
+    normalization_repo: SharedTitleNormalizationRepository,
+}
+
+impl NormalizedTitleWriter {
+    pub fn new(book_repo: SharedBookRepository, normalization_repo: SharedTitleNormalizationRepository) -> Self {
+        Self { book_repo, normalization_repo }
+    }
+}
+
+impl Writer for NormalizedTitleWriter {
+    type Item = NormalizedBook;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        for item in &items {
+            self.book_repo.update_book(&item.book);
+        }
+
+        let normalization = items.into_iter()
+            .filter_map(|item| item.normalization)
+            .collect::<Vec<_>>();
+        self.normalization_repo.save(&normalization);
+        Ok(())
+    }
+}
+
+pub fn create_job(
+    book_repo: SharedBookRepository,
+    normalization_repo: SharedTitleNormalizationRepository,
+    prompt: SharedPrompt,
+    params: &JobParameter,
+) -> Result<Job<Book, NormalizedBook>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+
+    let mut processor = NormalizeTitleProcessor::new(prompt, normalization_repo.clone());
+    if let Some(site_priority) = retrieve_site_priority_in_parameter(params)? {
+        processor.site_priority = site_priority;
+    }
+
+    let job = job_builder()
+        .reader(Box::new(UnnormalizedBookReader::new(book_repo.clone(), normalization_repo.clone())))
+        .processor(Box::new(processor))
+        .writer(Box::new(NormalizedTitleWriter::new(book_repo, normalization_repo)))
+        .build();
+
+    let job = job.set_chunk_size(chunk_size).with_trace_key(trace::book_trace_key);
+    match RetryPolicy::from_env() {
+        Some(policy) => Ok(job.with_retry_policy(policy)),
+        None => Ok(job),
+    }
+}
+
+/// [`JobParameter`]의 `site_priority`를 사이트 우선순위로 변환한다. 파라미터가 없을 경우 `None`을 반환한다.
+///
+/// 알 수 없는 사이트가 입력된 경우 `JobBuildError` 에러를 반환한다.
+pub fn retrieve_site_priority_in_parameter(params: &JobParameter) -> Result<Option<Vec<Site>>, JobBuildError> {
+    params.get(PARAM_NAME_SITE_PRIORITY)
+        .map(|sites| sites.split(',')
+            .map(|s| Site::try_from(s.trim())
+                .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_SITE_PRIORITY, e))))
+            .collect::<Result<Vec<_>, _>>())
+        .transpose()
+}
+
+/// 도서와 도서의 사이트별 원본 데이터를 제목 정규화 요청으로 변환한다.
+/// 원본 데이터의 제목, 소개글 등은 [`raw_utils::sanitize_html`]로 정리하여 LLM에 일반 텍스트만 전달 되도록 한다.
+///
+/// 판매처 정보는 `site_priority` 순서로 정렬하여 같은 도서는 항상 같은 요청으로 변환 되도록 한다.
+pub fn convert_book_to_normalize_request(book: &Book, site_priority: &[Site]) -> NormalizeRequest {
+    let mut request = NormalizeRequest::new(book.title());
+    let original = book.originals();
+
+    let mut sale_info_vec = Vec::new();
+    for (site, raw) in raw_utils::sort_by_site_priority(original, site_priority) {
+        let dict = raw_utils::load_site_dict(site);
+        if let Some(title) = raw_utils::retrieve_title_from_raw(&dict, raw) {
+            let mut sale_info = NormalizeRequestSaleInfo::new(&site.to_string(), &raw_utils::sanitize_html(&title));
+            sale_info.price = raw_utils::retrieve_sale_price_from_raw(&dict, raw);
+            sale_info.desc = raw_utils::retrieve_description_from_raw(&dict, raw)
+                .map(|desc| raw_utils::sanitize_html(&desc))
+                .filter(|desc| !desc.is_empty());
+            sale_info.series = raw_utils::retrieve_series_list_titles_from_raw(&dict, raw)
+                .map(|titles| titles.iter().map(|t| raw_utils::sanitize_html(t)).collect());
+            sale_info_vec.push(sale_info);
+        }
+    }
+
+    if !sale_info_vec.is_empty() {
+        request.sale_info = Some(sale_info_vec);
+    }
+
+    request
+}
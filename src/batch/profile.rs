@@ -0,0 +1,135 @@
+use crate::batch::book::retrieve_publisher_id_in_parameter;
+use crate::batch::JobParameter;
+use crate::item::{Book, Site};
+use crate::PARAM_NAME_FOLLOW_UP;
+use serde::Deserialize;
+
+/// 출판사별 처리 프로필
+///
+/// # Description
+/// 설정 파일의 `publisher_profile` 섹션([`crate::configs::Config`])에서 읽으며, 출판사마다 다른 필터, 기준값, 보강 사이트를 사용할 때 정의한다.
+/// 입력하지 않은 항목은 잡 파라미터와 잡 설정의 값을 그대로 사용한다.
+///
+/// # Example
+/// ```toml
+/// [[publisher_profile]]
+/// publisher_id = 20050726
+/// skip_filter = true
+/// description_site = ["KYOBO", "ALADIN"]
+/// follow_up = true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct PublisherProfile {
+    pub publisher_id: u64,
+
+    /// 사이트별 원본 데이터 필터([`crate::batch::book::OriginalDataFilter`])를 적용하지 않을지 여부
+    pub skip_filter: Option<bool>,
+
+    /// 도서 소개글을 선택할 사이트 우선순위 ([`crate::batch::book::BookDescriptionProcessor`])
+    pub description_site: Option<Vec<Site>>,
+
+    /// 시리즈를 연결할 기준 유사도 ([`crate::batch::series::SeriesConfig::similar_score`])
+    pub similar_score: Option<f64>,
+
+    /// 새로 저장된 도서를 교보문고 잡으로 보강할지 여부 (`follow_up` 파라미터)
+    pub follow_up: Option<bool>,
+}
+
+/// 출판사별 처리 프로필 목록
+///
+/// # Description
+/// 잡을 구성할 때 잡 설정과 함께 전달 되며, 필터와 프로세서는 처리하는 도서의 출판사 프로필을 확인하여 동작을 바꾼다.
+/// 프로필이 없는 출판사의 도서는 기존과 동일하게 처리한다.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct PublisherProfiles(Vec<PublisherProfile>);
+
+impl PublisherProfiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 프로필을 추가한다. 같은 출판사의 프로필이 이미 있는 경우 교체한다.
+    pub fn with_profile(mut self, profile: PublisherProfile) -> Self {
+        self.0.retain(|p| p.publisher_id != profile.publisher_id);
+        self.0.push(profile);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 출판사 아이디로 프로필을 찾는다.
+    pub fn find(&self, publisher_id: u64) -> Option<&PublisherProfile> {
+        self.0.iter().find(|p| p.publisher_id == publisher_id)
+    }
+
+    /// 도서의 출판사 프로필을 찾는다. 출판사가 지정 되지 않은 도서(출판사 아이디 0)는 `None`을 반환한다.
+    pub fn find_by_book(&self, book: &Book) -> Option<&PublisherProfile> {
+        if book.publisher_id() == 0 {
+            return None;
+        }
+        self.find(book.publisher_id())
+    }
+
+    /// 도서의 출판사 프로필에서 필터를 적용하지 않도록 설정 되어 있는지 여부
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::profile::{PublisherProfile, PublisherProfiles};
+    /// use book_batch_rust::item::Book;
+    ///
+    /// let profiles = PublisherProfiles::new()
+    ///     .with_profile(PublisherProfile { publisher_id: 1, skip_filter: Some(true), ..Default::default() });
+    ///
+    /// let book = |publisher_id: u64| Book::builder()
+    ///     .isbn("9791136202093".to_owned()).title("도서".to_owned()).publisher_id(publisher_id).build().unwrap();
+    /// assert!(profiles.is_filter_skipped(&book(1)));
+    /// assert!(!profiles.is_filter_skipped(&book(2)));
+    /// ```
+    pub fn is_filter_skipped(&self, book: &Book) -> bool {
+        self.find_by_book(book)
+            .and_then(|p| p.skip_filter)
+            .unwrap_or(false)
+    }
+
+    /// 잡 파라미터에 출판사 프로필의 잡 단위 설정을 적용한다.
+    ///
+    /// # Description
+    /// `publisher_id` 파라미터의 모든 출판사 프로필에 같은 값이 설정된 경우에만 적용하며, 파라미터가 이미 입력된 경우 파라미터를 우선한다.
+    /// - `follow_up`: 후속 잡 실행 여부
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::profile::{PublisherProfile, PublisherProfiles};
+    /// use book_batch_rust::batch::JobParameter;
+    ///
+    /// let profiles = PublisherProfiles::new()
+    ///     .with_profile(PublisherProfile { publisher_id: 1, follow_up: Some(true), ..Default::default() });
+    ///
+    /// let mut params = JobParameter::new();
+    /// params.insert("publisher_id".to_owned(), "1".to_owned());
+    /// assert_eq!(profiles.apply_to_parameter(&params).get("follow_up").unwrap(), "true");
+    ///
+    /// params.insert("publisher_id".to_owned(), "1,2".to_owned());
+    /// assert!(profiles.apply_to_parameter(&params).get("follow_up").is_none());
+    /// ```
+    pub fn apply_to_parameter(&self, params: &JobParameter) -> JobParameter {
+        let mut applied = params.clone();
+        let publisher_id = retrieve_publisher_id_in_parameter(params).unwrap_or_default();
+        if publisher_id.is_empty() || self.is_empty() {
+            return applied;
+        }
+
+        let follow_up = publisher_id.iter()
+            .map(|id| self.find(*id).and_then(|p| p.follow_up))
+            .reduce(|a, b| if a == b { a } else { None })
+            .flatten();
+        if let Some(follow_up) = follow_up {
+            applied.entry(PARAM_NAME_FOLLOW_UP.to_owned()).or_insert_with(|| follow_up.to_string());
+        }
+        applied
+    }
+}
@@ -0,0 +1,205 @@
+use crate::batch::book::{retrieve_from_to_in_parameter, retrieve_isbn_in_parameter, retrieve_publisher_id_in_parameter};
+use crate::batch::error::{JobBuildError, JobReadFailed, JobWriteFailed};
+use crate::batch::file::{open_output, STDIO_PATH};
+use crate::batch::{audit, is_dry_run, job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Reader, Writer, DEF_CHUNK_SIZE};
+use crate::item::{Book, SharedBookRepository, SharedSeriesRepository};
+use crate::{PARAM_NAME_CONFIRM, PARAM_NAME_OUTPUT};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::io::Write;
+use tracing::{info, warn};
+
+const PURGE_LIST_HEADERS: [&str; 6] = ["id", "isbn", "title", "publisher_id", "pub_date", "series_id"];
+
+/// [`JobParameter`]에 `confirm`이 `true`로 설정 되어 있는지 여부
+/// 확인 되지 않은 도서 삭제 잡은 도서를 삭제하지 않고 삭제 대상 목록만 출력한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::purge::is_confirmed;
+/// use book_batch_rust::batch::JobParameter;
+///
+/// let mut parameter = JobParameter::new();
+/// assert!(!is_confirmed(&parameter));
+///
+/// parameter.insert("confirm".to_owned(), "true".to_owned());
+/// assert!(is_confirmed(&parameter));
+/// ```
+pub fn is_confirmed(params: &JobParameter) -> bool {
+    params.get(PARAM_NAME_CONFIRM)
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"))
+}
+
+/// 삭제할 도서를 검색하는 리더
+///
+/// # Description
+/// `isbn` 파라미터가 있을 경우 해당 ISBN의 도서들을, 없을 경우 `publisher_id` 출판사의 도서 중 `from/to` 기간에 출판(예정)된 도서들을 조회한다.
+/// ISBN과 함께 출판사를 입력한 경우 해당 출판사의 도서만 삭제 대상으로 한다.
+///
+/// # Errors
+/// 실수로 많은 도서를 삭제하지 않도록 ISBN이 없을 때 출판사와 기간 중 하나라도 없는 경우 `JobReadFailed::InvalidArguments`를 반환한다.
+pub struct PurgeTargetReader {
+    book_repo: SharedBookRepository,
+}
+
+impl PurgeTargetReader {
+    pub fn new(book_repo: SharedBookRepository) -> Self {
+        Self { book_repo }
+    }
+}
+
+impl Reader for PurgeTargetReader {
+    type Item = Book;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let publisher_id = retrieve_publisher_id_in_parameter(params)?;
+        let isbn_vec = retrieve_isbn_in_parameter(params)?;
+
+        let books = if !isbn_vec.is_empty() {
+            let isbn_vec = isbn_vec.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+            let books = self.book_repo.find_by_isbn(&isbn_vec);
+            let found = books.iter().map(|book| book.isbn()).collect::<HashSet<_>>();
+            let missing = isbn_vec.iter().filter(|isbn| !found.contains(*isbn)).collect::<Vec<_>>();
+            if !missing.is_empty() {
+                warn!("{} isbn not found and skipped: {:?}", missing.len(), missing);
+            }
+            books
+        } else if publisher_id.is_empty() {
+            return Err(JobReadFailed::InvalidArguments("isbn or publisher_id with from/to is required".to_owned()));
+        } else {
+            let (from, to) = retrieve_from_to_in_parameter(params)?;
+            self.book_repo.find_by_pub_between(&from, &to)
+        };
+
+        Ok(books.into_iter()
+            .filter(|book| publisher_id.is_empty() || publisher_id.contains(&book.publisher_id()))
+            .collect())
+    }
+}
+
+/// 도서 삭제 라이터
+///
+/// # Description
+/// 도서와 도서를 참조하는 데이터(원본 데이터 포함)를 청크 단위로 삭제([`crate::item::BookRepository::delete_books`])한 후,
+/// 삭제된 도서가 속해 있던 시리즈 중 더 이상 소속 도서가 없는 시리즈를 삭제한다. 삭제된 도서와 시리즈는 변경 내역에 기록한다.
+pub struct PurgeWriter {
+    book_repo: SharedBookRepository,
+    series_repo: SharedSeriesRepository,
+}
+
+impl PurgeWriter {
+    pub fn new(book_repo: SharedBookRepository, series_repo: SharedSeriesRepository) -> Self {
+        Self { book_repo, series_repo }
+    }
+}
+
+impl Writer for PurgeWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let book_id = items.iter().map(|book| book.id()).collect::<Vec<_>>();
+        let deleted_count = self.book_repo.delete_books(&book_id);
+        if deleted_count == 0 {
+            return Err(JobWriteFailed::new(items, "Failed to delete books"));
+        }
+        if deleted_count < items.len() {
+            warn!("{} books requested but {} books deleted, the others may be already deleted", items.len(), deleted_count);
+        }
+        audit::record_deleted_books(&items);
+
+        let mut series_id = items.iter()
+            .filter_map(|book| book.series_id())
+            .collect::<Vec<_>>();
+        series_id.sort();
+        series_id.dedup();
+        let deleted_series = self.series_repo.delete_empty_series(&series_id);
+        audit::record_deleted_series(&deleted_series);
+
+        info!("{} books deleted, {} empty series deleted", deleted_count, deleted_series.len());
+        Ok(())
+    }
+}
+
+/// 삭제 대상 목록 출력 라이터
+///
+/// # Description
+/// `confirm` 파라미터 없이 실행하거나 `--dry-run`으로 실행한 경우 도서를 삭제하지 않고 삭제될 도서를 CSV로 출력한다.
+/// 헤더는 첫번째 청크를 출력할 때 한번만 출력한다.
+pub struct PurgeListWriter {
+    output: RefCell<csv::Writer<Box<dyn Write>>>,
+    header_written: RefCell<bool>,
+}
+
+impl PurgeListWriter {
+    pub fn new(output: Box<dyn Write>) -> Self {
+        Self {
+            output: RefCell::new(csv::Writer::from_writer(output)),
+            header_written: RefCell::new(false),
+        }
+    }
+}
+
+impl Writer for PurgeListWriter {
+    type Item = Book;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let mut output = self.output.borrow_mut();
+        let mut header_written = self.header_written.borrow_mut();
+
+        if !*header_written {
+            if let Err(e) = output.write_record(PURGE_LIST_HEADERS) {
+                return Err(JobWriteFailed::new(items, &e.to_string()));
+            }
+            *header_written = true;
+        }
+
+        for book in &items {
+            if let Err(e) = output.write_record(to_purge_record(book)) {
+                return Err(JobWriteFailed::new(items.clone(), &e.to_string()));
+            }
+        }
+        info!("{} books will be deleted, run again with --confirm to delete", items.len());
+        output.flush()
+            .map_err(|e| JobWriteFailed::new(items, &e.to_string()))
+    }
+}
+
+fn to_purge_record(book: &Book) -> [String; 6] {
+    [
+        book.id().to_string(),
+        book.isbn().to_owned(),
+        book.title().to_owned(),
+        book.publisher_id().to_string(),
+        book.actual_pub_date().or(book.scheduled_pub_date()).map(|d| d.to_string()).unwrap_or_default(),
+        book.series_id().map(|id| id.to_string()).unwrap_or_default(),
+    ]
+}
+
+/// 도서 삭제 잡을 생성한다.
+///
+/// # Description
+/// `confirm` 파라미터가 `true`이고 미리보기 실행이 아닌 경우에만 도서를 삭제([`PurgeWriter`])하며,
+/// 그 외에는 `output` 파라미터의 경로(없을 경우 표준 출력)에 삭제 대상 목록을 출력([`PurgeListWriter`])한다.
+pub fn create_job(
+    book_repo: SharedBookRepository,
+    series_repo: SharedSeriesRepository,
+    params: &JobParameter,
+) -> Result<Job<Book, Book>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+
+    let writer: Box<dyn Writer<Item = Book>> = if is_confirmed(params) && !is_dry_run(params) {
+        Box::new(PurgeWriter::new(book_repo.clone(), series_repo))
+    } else {
+        let path = params.get(PARAM_NAME_OUTPUT).map(|p| p.as_str()).unwrap_or(STDIO_PATH);
+        let output = open_output(path)
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_OUTPUT, e)))?;
+        Box::new(PurgeListWriter::new(output))
+    };
+
+    let job = job_builder()
+        .reader(Box::new(PurgeTargetReader::new(book_repo)))
+        .writer(writer)
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size))
+}
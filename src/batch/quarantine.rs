@@ -0,0 +1,144 @@
+use crate::batch::error::JobWriteFailed;
+use crate::batch::{audit, trace};
+use crate::JobName;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{error, warn};
+
+/// 격리 파일을 저장할 기본 디렉토리
+pub const DEFAULT_QUARANTINE_DIR: &str = "quarantine";
+
+/// 격리된 청크를 다시 저장([`crate::batch::Job::replay`])할 수 있는 잡
+pub const REPLAYABLE_JOBS: [JobName; 6] = [JobName::ALADIN, JobName::NAVER, JobName::NLGO, JobName::KYOBO, JobName::FETCH, JobName::KYOBO_SEARCH];
+
+/// 격리 파일의 첫 줄에 기록하는 헤더
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuarantineHeader {
+    /// 청크를 저장하지 못한 잡 이름 (`--job`으로 입력하는 이름)
+    pub job: String,
+
+    /// 청크를 저장하지 못한 실행의 실행 아이디 ([`trace::run_id`])
+    pub run_id: Option<String>,
+
+    /// 저장에 실패한 사유
+    pub message: String,
+
+    /// 격리된 아이템 수
+    pub count: usize,
+
+    /// 아이템별 저장 실패 사유 (아이템과 같은 순서, 아이템별 사유가 없는 경우 비어 있음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+}
+
+/// 잡이 격리된 청크를 다시 저장할 수 있는지 여부
+pub fn is_replayable(job: &JobName) -> bool {
+    REPLAYABLE_JOBS.contains(job)
+}
+
+/// 저장에 실패한 청크의 아이템들을 `dir` 디렉토리 아래 JSON Lines 격리 파일로 기록하고 파일 경로를 반환한다.
+///
+/// # Description
+/// 첫 줄에는 헤더([`QuarantineHeader`])를, 이후 한 줄에 아이템 하나씩 기록한다. 아이템별 저장 실패 사유(`causes`)는 헤더에 아이템과 같은 순서로 기록한다.
+/// 파일명은 `<실행 아이디>-<순번>.jsonl` 형식이며 실행 아이디가 없는 경우 잡 이름과 프로세스 아이디를 사용한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::quarantine::{read, write};
+/// use book_batch_rust::JobName;
+///
+/// let dir = std::env::temp_dir().join(format!("book-batch-quarantine-{}", std::process::id()));
+/// let causes = vec!["duplicated".to_owned(), "too long".to_owned(), "not found".to_owned()];
+/// let path = write(&dir, &JobName::NLGO, "Failed to insert books", &vec![1, 2, 3], &causes).unwrap();
+///
+/// let (header, items) = read::<i32>(&path).unwrap();
+/// assert_eq!(header.job, "NLGO");
+/// assert_eq!(header.message, "Failed to insert books");
+/// assert_eq!(header.count, 3);
+/// assert_eq!(header.causes, causes);
+/// assert_eq!(items, vec![1, 2, 3]);
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub fn write<T: Serialize>(dir: &Path, job: &JobName, message: &str, items: &[T], causes: &[String]) -> io::Result<PathBuf> {
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+    fs::create_dir_all(dir)?;
+    let header = QuarantineHeader {
+        job: format!("{:?}", job),
+        run_id: trace::run_id(),
+        message: message.to_owned(),
+        count: items.len(),
+        causes: causes.to_vec(),
+    };
+    let prefix = header.run_id.clone().unwrap_or_else(|| format!("{}-{}", header.job, process::id()));
+    let path = dir.join(format!("{}-{}.jsonl", prefix, SEQUENCE.fetch_add(1, Ordering::Relaxed)));
+
+    let mut writer = BufWriter::new(File::options().write(true).create_new(true).open(&path)?);
+    serde_json::to_writer(&mut writer, &header)?;
+    writer.write_all(b"\n")?;
+    for item in items {
+        serde_json::to_writer(&mut writer, item)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(path)
+}
+
+/// 격리 파일의 헤더를 읽는다.
+pub fn read_header(path: &Path) -> io::Result<QuarantineHeader> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header = lines.next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "quarantine header is missing"))??;
+    Ok(serde_json::from_str(&header)?)
+}
+
+/// 격리 파일의 헤더와 아이템들을 읽는다.
+///
+/// # Errors
+/// 파일을 읽을 수 없거나 헤더, 아이템으로 변환하지 못한 줄이 있는 경우 에러를 반환한다.
+/// 다시 저장할 때 일부 아이템이 빠지지 않도록 변환하지 못한 줄을 건너뛰지 않는다.
+pub fn read<T: DeserializeOwned>(path: &Path) -> io::Result<(QuarantineHeader, Vec<T>)> {
+    let header = read_header(path)?;
+    let items = BufReader::new(File::open(path)?).lines()
+        .skip(1)
+        .filter(|line| !line.as_ref().is_ok_and(|line| line.trim().is_empty()))
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect::<io::Result<Vec<T>>>()?;
+    if items.len() != header.count {
+        warn!("{} => {} items are recorded in header but {} items are read", path.display(), header.count, items.len());
+    }
+    Ok((header, items))
+}
+
+/// 저장에 실패한 청크의 아이템들을 환경 변수 `QUARANTINE_DIR`(기본값 `quarantine`) 디렉토리에 격리하고 파일 경로를 반환한다.
+///
+/// # Description
+/// 격리 파일의 경로는 변경 내역([`audit::record_quarantined`])에도 기록되어 잡 실행 기록에서 확인할 수 있다.
+/// 에러에 아이템별 저장 실패 사유가 있는 경우 함께 기록한다. 기록에 실패한 경우 에러 로그를 남기고 `None`을 반환한다.
+pub fn quarantine<T: Serialize>(job: &JobName, failed: &JobWriteFailed<T>) -> Option<String> {
+    let dir = env::var("QUARANTINE_DIR").unwrap_or_else(|_| DEFAULT_QUARANTINE_DIR.to_owned());
+    let items = failed.item();
+    let causes = if failed.has_causes() {
+        failed.causes().into_iter().map(|(_, cause)| cause.to_owned()).collect()
+    } else {
+        Vec::new()
+    };
+    match write(Path::new(&dir), job, failed.message(), items, &causes) {
+        Ok(path) => {
+            let path = path.to_string_lossy().into_owned();
+            warn!("{:?} => {} items of failed chunk quarantined to {}", job, items.len(), path);
+            audit::record_quarantined(&path);
+            Some(path)
+        }
+        Err(e) => {
+            error!("{:?} => Failed to quarantine {} items of failed chunk: {}", job, items.len(), e);
+            None
+        }
+    }
+}
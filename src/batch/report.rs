@@ -0,0 +1,328 @@
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::file::{open_output, STDIO_PATH};
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Processor, Reader, Writer, DEF_CHUNK_SIZE};
+use crate::clock::system_clock;
+use crate::item::{raw_utils, Book, SharedBookRepository, SharedPublisherRepository, Site};
+use crate::{PARAM_NAME_OUTPUT, PARAM_NAME_REPORT_DAYS};
+use headless_chrome::{Browser, LaunchOptions};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+/// 신간 리포트의 기본 수집 기간(일)
+const DEFAULT_REPORT_DAYS: u64 = 7;
+
+/// 표지 이미지를 찾을 사이트의 우선 순위
+const THUMBNAIL_SITES: [Site; 3] = [Site::KyoboBook, Site::Aladin, Site::Naver];
+
+/// 바코드 한 모듈(막대 한 칸)의 너비(px)
+const BARCODE_MODULE_WIDTH: usize = 2;
+/// 바코드 막대의 높이(px)
+const BARCODE_HEIGHT: usize = 60;
+
+/// 최근 수집된 도서를 검색하는 리더
+///
+/// # Description
+/// 오늘을 포함하여 `report_days` 파라미터(기본값 7일)에 지정된 기간 동안 저장소에 등록(수집)된 도서를 원본 데이터와 함께 조회한다.
+pub struct NewArrivalReader {
+    book_repo: SharedBookRepository,
+}
+
+impl NewArrivalReader {
+    pub fn new(book_repo: SharedBookRepository) -> Self {
+        Self { book_repo }
+    }
+}
+
+impl Reader for NewArrivalReader {
+    type Item = Book;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let days = params.get(PARAM_NAME_REPORT_DAYS)
+            .map(|s| {
+                s.parse::<u64>()
+                    .map_err(|e| JobReadFailed::InvalidArguments(format!("{}: {} is not a number", PARAM_NAME_REPORT_DAYS, e)))
+            })
+            .unwrap_or_else(|| Ok(DEFAULT_REPORT_DAYS))?;
+
+        let today = system_clock().today();
+        let from = today.checked_sub_days(chrono::Days::new(days.saturating_sub(1)))
+            .ok_or_else(|| JobReadFailed::InvalidArguments(format!("{}: {} is too large", PARAM_NAME_REPORT_DAYS, days)))?;
+        let to = today.succ_opt().unwrap();
+
+        Ok(self.book_repo.find_by_registered_between(&from.and_hms_opt(0, 0, 0).unwrap(), &to.and_hms_opt(0, 0, 0).unwrap()))
+    }
+}
+
+/// 신간 리포트의 도서 한 건
+#[derive(Debug, Clone)]
+pub struct ReportEntry {
+    pub book: Book,
+    pub publisher: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+/// 신간 리포트 변환 프로세서
+///
+/// # Description
+/// 도서의 출판사 이름과 사이트별 원본 데이터의 표지 이미지 URL(교보문고, 알라딘, 네이버 순)을 찾아 리포트 항목으로 변환한다.
+pub struct ReportEntryProcessor {
+    publishers: HashMap<u64, String>,
+}
+
+impl ReportEntryProcessor {
+    pub fn new(pub_repo: SharedPublisherRepository) -> Self {
+        let publishers = pub_repo.get_all().into_iter()
+            .map(|p| (p.id(), p.name().to_owned()))
+            .collect();
+        Self { publishers }
+    }
+}
+
+impl Processor for ReportEntryProcessor {
+    type In = Book;
+    type Out = ReportEntry;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let publisher = self.publishers.get(&item.publisher_id()).cloned();
+        let thumbnail = THUMBNAIL_SITES.iter()
+            .filter_map(|site| item.originals().get(site).map(|raw| (site, raw)))
+            .find_map(|(site, raw)| raw_utils::retrieve_thumbnail_from_raw(&raw_utils::load_site_dict(site), raw));
+
+        Ok(ReportEntry { book: item, publisher, thumbnail })
+    }
+}
+
+/// 신간 HTML 리포트 라이터
+///
+/// # Description
+/// 리포트 항목을 도서 한 건당 제목, 출판사, 출판일, 표지 이미지, ISBN 바코드를 가진 카드로 출력한다.
+/// 문서의 머리말과 스타일은 첫번째 청크를 출력할 때 한번만 출력하며, 인쇄시 카드가 페이지에 걸쳐 나뉘지 않도록 스타일을 지정한다.
+///
+/// # Note
+/// 청크 단위로 출력 하므로 문서의 닫는 태그(`</body>`, `</html>`)는 출력하지 않는다. (HTML 표준에서 생략 가능한 태그이다.)
+pub struct HtmlReportWriter {
+    output: RefCell<Box<dyn Write>>,
+    header_written: RefCell<bool>,
+}
+
+impl HtmlReportWriter {
+    pub fn new(output: Box<dyn Write>) -> Self {
+        Self {
+            output: RefCell::new(output),
+            header_written: RefCell::new(false),
+        }
+    }
+}
+
+impl Writer for HtmlReportWriter {
+    type Item = ReportEntry;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        let mut output = self.output.borrow_mut();
+        let mut header_written = self.header_written.borrow_mut();
+
+        let mut html = String::new();
+        if !*header_written {
+            html.push_str(&report_header());
+            *header_written = true;
+        }
+        for entry in &items {
+            html.push_str(&report_card(entry));
+        }
+
+        output.write_all(html.as_bytes())
+            .and_then(|_| output.flush())
+            .map_err(|e| JobWriteFailed::new(items, &e.to_string()))
+    }
+}
+
+fn report_header() -> String {
+    let title = format!("신간 도서 리포트 ({})", system_clock().today().format("%Y-%m-%d"));
+    format!(r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 16px; }}
+.books {{ display: grid; grid-template-columns: repeat(2, 1fr); gap: 12px; }}
+.book {{ display: flex; gap: 12px; border: 1px solid #ccc; padding: 8px; break-inside: avoid; page-break-inside: avoid; }}
+.book img.cover {{ width: 90px; height: 130px; object-fit: contain; }}
+.book .no-cover {{ width: 90px; height: 130px; background: #eee; }}
+.book h2 {{ font-size: 14px; margin: 0 0 4px 0; }}
+.book p {{ font-size: 12px; margin: 2px 0; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<div class="books">
+"#)
+}
+
+fn report_card(entry: &ReportEntry) -> String {
+    let book = &entry.book;
+    let cover = match &entry.thumbnail {
+        Some(url) => format!(r#"<img class="cover" src="{}" alt="">"#, escape_html(url)),
+        None => r#"<div class="no-cover"></div>"#.to_owned(),
+    };
+    let pub_date = book.actual_pub_date().or(book.scheduled_pub_date())
+        .map(|d| d.to_string())
+        .unwrap_or_default();
+    let barcode = ean13_svg(book.isbn()).unwrap_or_default();
+
+    format!(
+        "<div class=\"book\">{}<div><h2>{}</h2><p>{}</p><p>{}</p><p>ISBN {}</p>{}</div></div>\n",
+        cover,
+        escape_html(book.title()),
+        escape_html(entry.publisher.as_deref().unwrap_or_default()),
+        pub_date,
+        escape_html(book.isbn()),
+        barcode,
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// EAN-13 왼쪽 6자리의 L 코드 (홀수 패리티)
+const EAN_L_CODES: [&str; 10] = [
+    "0001101", "0011001", "0010011", "0111101", "0100011", "0110001", "0101111", "0111011", "0110111", "0001011",
+];
+/// EAN-13 왼쪽 6자리의 G 코드 (짝수 패리티)
+const EAN_G_CODES: [&str; 10] = [
+    "0100111", "0110011", "0011011", "0100001", "0011101", "0111001", "0000101", "0010001", "0001001", "0010111",
+];
+/// EAN-13 오른쪽 6자리의 R 코드
+const EAN_R_CODES: [&str; 10] = [
+    "1110010", "1100110", "1101100", "1000010", "1011100", "1001110", "1010000", "1000100", "1001000", "1110100",
+];
+/// 첫번째 자리 숫자에 따른 왼쪽 6자리의 패리티 (L/G)
+const EAN_PARITY: [&str; 10] = [
+    "LLLLLL", "LLGLGG", "LLGGLG", "LLGGGL", "LGLLGG", "LGGLLG", "LGGGLL", "LGLGLG", "LGLGGL", "LGGLGL",
+];
+
+/// ISBN-13을 EAN-13 바코드 모듈 배열로 변환한다.
+///
+/// # Description
+/// 바코드의 막대를 `1`, 공백을 `0`으로 표현한 95자리 문자열을 반환한다.
+/// ISBN이 13자리 숫자가 아니거나 체크 디지트가 올바르지 않을 경우 [`None`]을 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::report::ean13_modules;
+///
+/// let modules = ean13_modules("9791136202093").unwrap();
+/// assert_eq!(modules.len(), 95);
+/// assert!(modules.starts_with("101"));
+/// assert!(modules.ends_with("101"));
+/// assert_eq!(&modules[45..50], "01010");
+///
+/// assert_eq!(ean13_modules("9791136202090"), None); // 체크 디지트 오류
+/// assert_eq!(ean13_modules("979113620209"), None);
+/// ```
+pub fn ean13_modules(isbn: &str) -> Option<String> {
+    let digits = isbn.chars()
+        .map(|c| c.to_digit(10).map(|d| d as usize))
+        .collect::<Option<Vec<_>>>()?;
+    if digits.len() != 13 {
+        return None;
+    }
+
+    let sum = digits[..12].iter().enumerate()
+        .map(|(i, d)| if i % 2 == 0 { *d } else { d * 3 })
+        .sum::<usize>();
+    if (10 - sum % 10) % 10 != digits[12] {
+        return None;
+    }
+
+    let mut modules = String::from("101");
+    for (i, parity) in EAN_PARITY[digits[0]].chars().enumerate() {
+        let codes = if parity == 'L' { &EAN_L_CODES } else { &EAN_G_CODES };
+        modules.push_str(codes[digits[i + 1]]);
+    }
+    modules.push_str("01010");
+    for d in &digits[7..] {
+        modules.push_str(EAN_R_CODES[*d]);
+    }
+    modules.push_str("101");
+
+    Some(modules)
+}
+
+/// ISBN-13을 EAN-13 바코드 SVG 이미지로 변환한다. 바코드로 변환할 수 없는 ISBN은 [`None`]을 반환한다.
+pub fn ean13_svg(isbn: &str) -> Option<String> {
+    let modules = ean13_modules(isbn)?;
+    let width = modules.len() * BARCODE_MODULE_WIDTH;
+
+    let bars = modules.chars().enumerate()
+        .filter(|(_, m)| *m == '1')
+        .map(|(i, _)| format!(r#"<rect x="{}" y="0" width="{}" height="{}"/>"#, i * BARCODE_MODULE_WIDTH, BARCODE_MODULE_WIDTH, BARCODE_HEIGHT))
+        .collect::<String>();
+
+    Some(format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">{bars}</svg>"#,
+        w = width, h = BARCODE_HEIGHT, bars = bars,
+    ))
+}
+
+/// 신간 리포트의 출력 경로가 PDF인지 여부
+pub fn is_pdf_output(params: &JobParameter) -> bool {
+    params.get(PARAM_NAME_OUTPUT)
+        .is_some_and(|path| path.to_lowercase().ends_with(".pdf"))
+}
+
+/// PDF 출력을 위해 HTML 리포트를 임시로 출력할 경로
+pub fn html_path_for_pdf(pdf_path: &str) -> String {
+    format!("{}.html", pdf_path)
+}
+
+/// HTML 리포트를 크롬 브라우저로 열어 PDF로 변환한다.
+pub fn export_pdf(html_path: &str, pdf_path: &str) -> Result<(), String> {
+    let html_path = std::fs::canonicalize(Path::new(html_path))
+        .map_err(|e| format!("{}: {}", html_path, e))?;
+    let url = format!("file://{}", html_path.display());
+
+    let options = LaunchOptions { headless: true, ..Default::default() };
+    let browser = Browser::new(options).map_err(|e| e.to_string())?;
+    let tab = browser.new_tab().map_err(|e| e.to_string())?;
+    tab.navigate_to(&url).map_err(|e| e.to_string())?;
+    tab.wait_until_navigated().map_err(|e| e.to_string())?;
+
+    let pdf = tab.print_to_pdf(None).map_err(|e| e.to_string())?;
+    std::fs::write(pdf_path, pdf).map_err(|e| format!("{}: {}", pdf_path, e))
+}
+
+/// 신간 리포트 잡을 생성한다.
+///
+/// # Description
+/// `output` 파라미터의 경로에 HTML 리포트를 출력하며 경로가 없을 경우 표준 출력으로 출력한다.
+/// 경로의 확장자가 `.pdf`일 경우 `{경로}.html`에 HTML 리포트를 출력하며, 잡 실행 후 [`export_pdf`]로 PDF로 변환해야 한다.
+pub fn create_job(
+    book_repo: SharedBookRepository,
+    pub_repo: SharedPublisherRepository,
+    params: &JobParameter,
+) -> Result<Job<Book, ReportEntry>, JobBuildError> {
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(DEF_CHUNK_SIZE);
+
+    let path = match params.get(PARAM_NAME_OUTPUT) {
+        Some(path) if is_pdf_output(params) => html_path_for_pdf(path),
+        Some(path) => path.to_owned(),
+        None => STDIO_PATH.to_owned(),
+    };
+    let output = open_output(&path)
+        .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_OUTPUT, e)))?;
+
+    let job = job_builder()
+        .reader(Box::new(NewArrivalReader::new(book_repo)))
+        .processor(Box::new(ReportEntryProcessor::new(pub_repo)))
+        .writer(Box::new(HtmlReportWriter::new(output)))
+        .build();
+
+    Ok(job.set_chunk_size(chunk_size))
+}
@@ -0,0 +1,191 @@
+use std::env;
+use std::fmt::{Debug, Display, Formatter};
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+use tracing::warn;
+
+/// 재시도 대기 시간 기본값 (단위: 밀리초)
+const DEFAULT_BACKOFF_MILLIS: u64 = 500;
+
+/// 지수 백오프의 최대 대기 시간 기본값 (단위: 밀리초)
+const DEFAULT_MAX_BACKOFF_MILLIS: u64 = 30_000;
+
+/// 재시도 대기 시간 계산 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// 항상 같은 시간을 대기한다.
+    Fixed(Duration),
+
+    /// `initial`부터 재시도 할 때마다 대기 시간을 두배로 늘리며 `max`를 넘지 않는다.
+    Exponential { initial: Duration, max: Duration },
+}
+
+impl Backoff {
+
+    /// `attempt`번째 시도가 실패한 후 대기할 시간을 반환한다. (`attempt`는 1부터 시작한다.)
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::batch::retry::Backoff;
+    /// use std::time::Duration;
+    ///
+    /// let backoff = Backoff::Exponential { initial: Duration::from_millis(100), max: Duration::from_millis(350) };
+    /// assert_eq!(backoff.delay(1), Duration::from_millis(100));
+    /// assert_eq!(backoff.delay(2), Duration::from_millis(200));
+    /// assert_eq!(backoff.delay(3), Duration::from_millis(350));
+    ///
+    /// assert_eq!(Backoff::Fixed(Duration::from_secs(1)).delay(5), Duration::from_secs(1));
+    /// ```
+    pub fn delay(&self, attempt: u32) -> Duration {
+        match self {
+            Backoff::Fixed(delay) => *delay,
+            Backoff::Exponential { initial, max } => {
+                let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+                initial.checked_mul(factor).unwrap_or(*max).min(*max)
+            }
+        }
+    }
+}
+
+/// 잡의 처리, 저장 실패 재시도 정책
+///
+/// # Description
+/// 프로세서나 라이터가 실패한 경우 에러 메시지가 재시도 가능한 에러(`retryable`)일 때 최대 시도 횟수까지 대기 후 다시 시도한다.
+/// 사이트나 브릿지 서버의 일시적인 장애로 잡 전체가 중단 되지 않도록 [`crate::batch::Job::with_retry_policy`]로 설정한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::retry::{Backoff, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(3)
+///     .with_backoff(Backoff::Fixed(Duration::ZERO))
+///     .with_retryable_messages(vec!["timeout".to_owned()]);
+///
+/// let mut attempts = 0;
+/// let result: Result<u32, String> = policy.retry("example", || {
+///     attempts += 1;
+///     if attempts < 3 { Err("request timeout".to_owned()) } else { Ok(attempts) }
+/// });
+/// assert_eq!(result, Ok(3));
+///
+/// let mut attempts = 0;
+/// let result: Result<u32, String> = policy.retry("example", || {
+///     attempts += 1;
+///     Err("invalid response".to_owned())
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(attempts, 1);
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// 최초 시도를 포함한 최대 시도 횟수 (1인 경우 재시도 하지 않는다.)
+    pub max_attempts: u32,
+
+    /// 재시도 대기 시간 계산 방식
+    pub backoff: Backoff,
+
+    /// 에러 메시지로 재시도 가능 여부를 판단하는 함수
+    retryable: Rc<dyn Fn(&str) -> bool>,
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+
+    /// 모든 에러를 재시도 하고 0.5초 부터 두배씩 대기 시간을 늘리는(최대 30초) 정책을 생성한다.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Backoff::Exponential {
+                initial: Duration::from_millis(DEFAULT_BACKOFF_MILLIS),
+                max: Duration::from_millis(DEFAULT_MAX_BACKOFF_MILLIS),
+            },
+            retryable: Rc::new(|_| true),
+        }
+    }
+
+    /// 환경 변수로 재시도 정책을 생성한다. `JOB_RETRY_MAX_ATTEMPTS`가 설정 되지 않았거나 2 미만인 경우 `None`을 반환한다.
+    ///
+    /// - `JOB_RETRY_MAX_ATTEMPTS`: 최초 시도를 포함한 최대 시도 횟수
+    /// - `JOB_RETRY_BACKOFF_MILLIS`: 첫번째 재시도 대기 시간 (단위는 밀리초, 기본값 500)
+    /// - `JOB_RETRY_MAX_BACKOFF_MILLIS`: 최대 재시도 대기 시간 (단위는 밀리초, 기본값 30000)
+    /// - `JOB_RETRY_ON`: 재시도할 에러 메시지에 포함된 문자열 (콤마(,)로 구분, 설정 되지 않은 경우 모든 에러를 재시도)
+    pub fn from_env() -> Option<Self> {
+        let max_attempts = read_env_number("JOB_RETRY_MAX_ATTEMPTS")
+            .filter(|attempts| *attempts > 1)?;
+        let initial = read_env_number("JOB_RETRY_BACKOFF_MILLIS").unwrap_or(DEFAULT_BACKOFF_MILLIS);
+        let max = read_env_number("JOB_RETRY_MAX_BACKOFF_MILLIS").unwrap_or(DEFAULT_MAX_BACKOFF_MILLIS);
+
+        let policy = Self::new(max_attempts as u32)
+            .with_backoff(Backoff::Exponential { initial: Duration::from_millis(initial), max: Duration::from_millis(max) });
+        let messages = env::var("JOB_RETRY_ON").unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim().to_owned())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        if messages.is_empty() {
+            Some(policy)
+        } else {
+            Some(policy.with_retryable_messages(messages))
+        }
+    }
+
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// 에러 메시지로 재시도 가능 여부를 판단하는 함수를 설정한다.
+    pub fn with_retryable<F: Fn(&str) -> bool + 'static>(mut self, retryable: F) -> Self {
+        self.retryable = Rc::new(retryable);
+        self
+    }
+
+    /// 에러 메시지에 문자열 중 하나라도 포함된 경우(대소문자 구분 없음)에만 재시도 하도록 설정한다.
+    pub fn with_retryable_messages(self, messages: Vec<String>) -> Self {
+        let messages = messages.into_iter().map(|m| m.to_lowercase()).collect::<Vec<_>>();
+        self.with_retryable(move |message| {
+            let message = message.to_lowercase();
+            messages.iter().any(|m| message.contains(m.as_str()))
+        })
+    }
+
+    /// `attempt`번째 시도가 에러 메시지로 실패한 경우 재시도 할 수 있는지 여부를 반환한다.
+    pub fn should_retry(&self, attempt: u32, message: &str) -> bool {
+        attempt < self.max_attempts && (self.retryable)(message)
+    }
+
+    /// `attempt`번째 시도가 실패한 후 경고 로그를 남기고 대기한다.
+    pub fn wait(&self, name: &str, attempt: u32, message: &str) {
+        let delay = self.backoff.delay(attempt);
+        warn!("{} failed({}), retry {}/{} after {:?}", name, message, attempt, self.max_attempts - 1, delay);
+        thread::sleep(delay);
+    }
+
+    /// 함수를 실행하고 실패한 경우 정책에 따라 재시도 한다. 재시도 하지 않는 에러거나 최대 시도 횟수를 모두 사용한 경우 마지막 에러를 반환한다.
+    pub fn retry<T, E: Display>(&self, name: &str, mut f: impl FnMut() -> Result<T, E>) -> Result<T, E> {
+        let mut attempt = 1;
+        loop {
+            match f() {
+                Err(e) if self.should_retry(attempt, &e.to_string()) => {
+                    self.wait(name, attempt, &e.to_string());
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+fn read_env_number(name: &str) -> Option<u64> {
+    env::var(name).ok().and_then(|v| v.trim().parse::<u64>().ok())
+}
@@ -1,19 +1,105 @@
-use crate::batch::error::{JobProcessFailed, JobReadFailed, JobWriteFailed};
-use crate::batch::{job_builder, Job, JobParameter, Processor, ProcessorChain, Reader, Writer};
-use crate::item::{raw_utils, Book, RawDataKind, Series, SharedBookRepository, SharedSeriesRepository, Site};
-use crate::prompt::{NormalizeRequest, NormalizeRequestSaleInfo, SeriesSimilarRequest, SeriesSimilarRequestBookInfo, SharedPrompt};
+use crate::batch::audit;
+use crate::batch::error::{JobBuildError, JobProcessFailed, JobReadFailed, JobWriteFailed};
+use crate::batch::normalize::{convert_book_to_normalize_request, retrieve_site_priority_in_parameter, DEFAULT_SITE_PRIORITY};
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::retry::RetryPolicy;
+use crate::batch::{job_builder, retrieve_chunk_size_in_parameter, Job, JobParameter, Processor, ProcessorChain, Reader, Writer};
+use crate::item::{raw_utils, Book, RawDataKind, Series, SeriesLink, SeriesLinkConfidence, SeriesOverrideTarget, SharedBookRepository, SharedSeriesOverrideRepository, SharedSeriesRepository, Site};
+use crate::prompt;
+use crate::prompt::{Normalized, SeriesSelectCandidate, SeriesSelectRequest, SeriesSimilarRequest, SeriesSimilarRequestBookInfo, SharedPrompt};
 use crate::provider::api::nlgo;
-use crate::PARAM_NAME_LIMIT;
+use crate::toggle::{FeatureToggles, TOGGLE_SERIES_SELECT_FIRST};
+use crate::{PARAM_NAME_LIMIT, PARAM_NAME_NORMALIZE_BATCH, PARAM_NAME_SERIES_SAME_PUBLISHER};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use tracing::{debug, info, warn};
 
+/// 한번에 조회할 도서 수 기본값
 const DEFAULT_READ_LIMIT: usize = 50;
 
+/// 시리즈 잡에서 사용하는 원본 데이터 종류 (제목 정규화 요청, 국립중앙도서관 세트 ISBN, 저자)
+/// 도서 저장소의 원본 데이터 조회 프로젝션([`raw_utils::origin_projection`])에 사용한다.
+pub const SERIES_ORIGIN_KINDS: [RawDataKind; 6] = [RawDataKind::Title, RawDataKind::SalePrice, RawDataKind::Description, RawDataKind::SeriesList, RawDataKind::SeriesID, RawDataKind::Author];
+
 /// 기준 유사도 기본값
 const DEFAULT_SIMILARITY_SCORE: f64 = 0.90;
 
 /// 시리즈 소속 여부 재검토 기준 유사도 기본값
 const DEFAULT_SERIES_SIMILARITY_SCORE: f64 = 0.45;
 
+/// 시리즈 소속 여부를 검토할 후보 수 기본값
+const DEFAULT_TOP_K: usize = 1;
+
+/// 시리즈 잡 기본 청크 사이즈
+/// 같은 청크 안에서 새로 생성된 시리즈는 서로 검색되지 않으므로 한 건씩 처리 한다.
+const SERIES_CHUNK_SIZE: usize = 1;
+
+/// 일괄 정규화 모드의 시리즈 잡 기본 청크 사이즈
+/// 한 청크의 도서 제목을 한번에 정규화 하며, 같은 청크에서 새로 생성된 시리즈는 [`SeriesWriter`]에서 합친다.
+const SERIES_BATCH_CHUNK_SIZE: usize = 20;
+
+/// 시리즈 잡 설정
+///
+/// # Description
+/// 설정 파일의 `series` 섹션([`crate::configs::Config`])에서 읽으며, 입력하지 않은 값은 기본값을 사용한다.
+/// 잡 파라미터(`limit`, `chunk_size`)가 입력된 경우 파라미터를 우선 사용한다.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SeriesConfig {
+    /// 한번에 조회할 도서 수 (기본값: 50)
+    pub read_limit: usize,
+
+    /// 시리즈를 연결하거나 같은 청크에서 새로 생성된 시리즈를 합칠 기준 유사도 (기본값: 0.90)
+    pub similar_score: f64,
+
+    /// 시리즈 소속 여부를 재검토할 기준 유사도 (기본값: 0.45)
+    pub series_similar_score: f64,
+
+    /// 청크 사이즈 (기본값: 1)
+    pub chunk_size: usize,
+
+    /// 일괄 정규화 모드의 청크 사이즈 (기본값: 20)
+    pub batch_chunk_size: usize,
+
+    /// 유사 시리즈 검색 전 먼저 조회할 연결 후보 수 (기본값: 0)
+    ///
+    /// # Description
+    /// 0보다 큰 경우 같은 출판사의 시리즈나 제목이 비슷한 시리즈를 최근 등록된 순서로 먼저 조회하고 후보 안에서 유사도 순으로 정렬한다.
+    /// 0인 경우 후보를 조회하지 않고 모든 시리즈에서 백터 검색한다.
+    pub candidate_limit: usize,
+
+    /// 유사 시리즈 검색 결과 중 시리즈 소속 여부를 검토할 후보 수 (기본값: 1)
+    ///
+    /// # Description
+    /// 2 이상인 경우 재검토 기준 유사도를 넘은 후보들을 함께 LLM에 전달하여 신간이 속할 시리즈를 선택([`crate::prompt::Prompt::series_select`])하게 한다.
+    pub top_k: usize,
+
+    /// 제목 정규화 전 시리즈 선택 여부 (기본값: false)
+    ///
+    /// # Description
+    /// `true`인 경우 도서 제목과 출판사로 조회한 후보 시리즈(최대 `top_k`개)를 LLM에 전달하여 한번의 요청으로 도서가 속할 시리즈를 선택한다.
+    /// LLM이 어느 후보도 선택하지 않은 경우에만 제목 정규화, 유사도 검색, 소속 여부 확인 순서로 시리즈를 찾는다.
+    /// 일부 도서나 출판사에만 적용하려면 `false`로 두고 [`crate::toggle::TOGGLE_SERIES_SELECT_FIRST`] 토글을 설정한다.
+    pub select_first: bool,
+}
+
+impl Default for SeriesConfig {
+    fn default() -> Self {
+        Self {
+            read_limit: DEFAULT_READ_LIMIT,
+            similar_score: DEFAULT_SIMILARITY_SCORE,
+            series_similar_score: DEFAULT_SERIES_SIMILARITY_SCORE,
+            chunk_size: SERIES_CHUNK_SIZE,
+            batch_chunk_size: SERIES_BATCH_CHUNK_SIZE,
+            candidate_limit: 0,
+            top_k: DEFAULT_TOP_K,
+            select_first: false,
+        }
+    }
+}
+
 /// 시리즈 처리 도중 발생하는 에러 열거
 #[derive(Debug)]
 pub enum SeriesProcessError {
@@ -38,13 +124,21 @@ impl Display for SeriesProcessError {
 /// # Description
 /// 시리즈 정보가 할당 되지 않은 도서들을 데이터베이스에서 조회한다.
 /// `JobParameter`에서 `limit` 키로 조회할 도서의 수를 지정할 수 있으며 50개를 기본값으로 사용한다.
+///
+/// # Note
+/// 운영자가 시리즈 분류를 지정 하였지만 아직 도서에 반영 되지 않은 도서들을 먼저 조회하며,
+/// 시리즈 분류가 지정된 도서는 시리즈가 없더라도(단독 도서) 자동 분류 대상으로 조회하지 않는다.
 pub struct UnorganizedBookReader {
-    book_repo: SharedBookRepository
+    book_repo: SharedBookRepository,
+    override_repo: SharedSeriesOverrideRepository,
+
+    /// `limit` 파라미터가 없을 때 한번에 조회할 도서 수
+    pub read_limit: usize,
 }
 
 impl UnorganizedBookReader {
-    pub fn new(book_repo: SharedBookRepository) -> Self {
-        Self { book_repo }
+    pub fn new(book_repo: SharedBookRepository, override_repo: SharedSeriesOverrideRepository) -> Self {
+        Self { book_repo, override_repo, read_limit: DEFAULT_READ_LIMIT }
     }
 }
 
@@ -57,18 +151,22 @@ impl Reader for UnorganizedBookReader {
                 s.parse::<usize>()
                     .map_err(|e| JobReadFailed::InvalidArguments(format!("{}: {} is not a number", PARAM_NAME_LIMIT, e)))
             })
-            .unwrap_or_else(|| Ok(DEFAULT_READ_LIMIT))?;
+            .unwrap_or_else(|| Ok(self.read_limit))?;
 
-        let books = self.book_repo.find_series_unorganized(limit);
+        let unapplied = self.override_repo.find_unapplied_isbn(limit);
+        let unapplied = unapplied.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+
+        let mut books = self.book_repo.find_by_isbn(&unapplied);
+        books.extend(self.book_repo.find_series_unorganized(limit));
         Ok(books)
     }
 }
 
-/// 가장 유사한 시리즈와 유사도를 저장하는 구조체
+/// 유사한 시리즈와 유사도를 저장하는 구조체
 #[derive(Debug)]
 pub struct MostSimilarSeries {
 
-    /// 가장 유사했던 시리즈
+    /// 유사했던 시리즈
     pub series: Series,
 
     /// 유사도 점수
@@ -79,20 +177,61 @@ pub struct MostSimilarSeries {
 #[derive(Debug)]
 pub enum SeriesMappingResult {
 
+    /// 아직 시리즈 분류가 되지 않은 도서를 의미한다.
+    Unmapped(Book),
+
+    /// 운영자가 지정한 시리즈 분류를 도서에 적용 해야함을 의미한다.
+    ///
+    /// # Tuple
+    /// - `0`: 시리즈 분류를 적용할 도서
+    /// - `1`: 운영자가 지정한 시리즈 분류
+    Manual(Book, SeriesOverrideTarget),
+
     /// 새로운 시리즈를 생성하고 도서와 연결 해야함을 의미한다.
     ///
     /// # Tuple
     /// - `0`: 시리즈에 연결 되어야 할 도서
     /// - `1`: 새로 생성될 시리즈 정보
-    /// - `2`: 가장 유사했던 시리즈와 그 유사도
-    New(Book, Series, Option<MostSimilarSeries>),
+    /// - `2`: 유사했던 시리즈와 그 유사도 (유사도가 높은 순서, 유사한 시리즈가 없는 경우 빈 리스트)
+    New(Book, Series, Vec<MostSimilarSeries>),
 
     /// 기존 시리즈에 도서를 연결 해야함을 의미한다.
     ///
     /// # Tuple
     /// - `0`: 시리즈에 연결 되어야 할 도서
     /// - `1`: 연결 대상이 되는 기존 시리즈
-    Exists(Book, Series),
+    /// - `2`: 기존 시리즈에 연결한 방법과 유사도
+    /// - `3`: 도서의 정규화된 제목의 임베딩 백터 (제목을 정규화 하지 않고 연결한 경우 `None`)
+    Exists(Book, Series, SeriesLinkConfidence, Option<Vec<f32>>),
+}
+
+/// 운영자 지정 시리즈 분류 프로세서
+///
+/// # Description
+/// 시리즈 처리 체인의 첫번째 단계로 운영자가 시리즈 분류를 지정한 도서는 [`SeriesMappingResult::Manual`]로,
+/// 그 외의 도서는 자동 분류를 위해 [`SeriesMappingResult::Unmapped`]로 변환한다.
+/// 운영자가 지정한 분류는 이후의 자동 분류 단계를 거치지 않으므로 수동으로 수정한 분류가 덮어 쓰이지 않는다.
+pub struct SeriesOverrideProcessor {
+    override_repo: SharedSeriesOverrideRepository,
+}
+
+impl SeriesOverrideProcessor {
+    pub fn new(override_repo: SharedSeriesOverrideRepository) -> Self {
+        Self { override_repo }
+    }
+}
+
+impl Processor for SeriesOverrideProcessor {
+    type In = Book;
+    type Out = SeriesMappingResult;
+
+    fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
+        let overridden = self.override_repo.find_by_isbn(&[item.isbn()]).into_iter().next();
+        match overridden {
+            Some(overridden) => Ok(SeriesMappingResult::Manual(item, overridden.target())),
+            None => Ok(SeriesMappingResult::Unmapped(item)),
+        }
+    }
 }
 
 /// 시리즈 검색 객체
@@ -114,17 +253,14 @@ impl SeriesFinder {
         series_vec.into_iter().next()
     }
 
-    /// 입력 받은 시리즈와 제목이 가장 유사한 시리즈를 데이터베이스에서 하나 찾는다.
+    /// 입력 받은 시리즈와 제목이 유사한 시리즈를 데이터베이스에서 유사도가 높은 순서로 top_k 개수 만큼 찾는다.
     ///
     /// # Flow
-    /// 1. 코사인 유사도를 기준으로 가장 유사한 시리즈 2개를 검색한다.
-    /// 2. 아래의 조건으로 반환값을 결정 한다:
-    ///     - 입력 시리즈에 ISBN이 있는 경우:
-    ///       * 검색된 시리즈 중 입력 시리즈의 ISBN과 다른 ISBN을 가지는 시리즈 반환
-    ///     - 입력 시리즈에 ISBN이 없는 경우:
-    ///       * 항상 첫 번째(0번) 시리즈 반환
-    ///
-    /// ## 특수한 반환값 결정 조건이 필요한 이유
+    /// 1. 코사인 유사도를 기준으로 가장 유사한 시리즈를 top_k + 1개 검색한다.
+    /// 2. 입력 시리즈에 ISBN이 있는 경우 검색된 시리즈 중 입력 시리즈와 같은 ISBN을 가지는 시리즈를 제외한다.
+    /// 3. 남은 시리즈 중 앞에서 부터 top_k개를 반환한다.
+    ///
+    /// ## 같은 ISBN의 시리즈를 제외하는 이유
     /// 하나의 도서가 여러 컨텐츠(예: 소설, 만화 등)로 출간될 때 각 컨텐츠별로 서로 다른 ISBN이 부여 될 수 있으며,
     /// 제목은 동일하거나 매우 유사할 수 있다. 따라서 단순히 제목의 유사도만으로 비교하면 실제로는 다른 형태의 시리즈를 동일한
     /// 시리즈로 잘못 판단 할 수 있어 이러한 오류를 방지하기 위해 ISBN 존재 여부를 추가로 확인하는 조건이 필요하다.
@@ -135,19 +271,57 @@ impl SeriesFinder {
     ///
     /// # Parameters
     /// - series: 데이터베이스에 찾고 싶은 시리즈 정보
-    fn similarity(&self, series: &Series) -> Option<(Series, Option<f64>)> {
-        let series_vec = self.series_repo.similarity(series, 2);
-        if series_vec.is_empty() {
-            return None;
-        }
-
-        let mut series_vec = series_vec.into_iter();
-        if let Some(input_series_isbn) = series.isbn().clone() {
-            series_vec
-                .find(|(s, _)| s.isbn().is_none() || s.isbn().clone().unwrap() != input_series_isbn)
+    /// - publisher_id: 시리즈에 연결할 도서의 출판사 아이디
+    /// - candidate_limit: 먼저 조회할 연결 후보 수, 0인 경우 모든 시리즈에서 백터 검색한다. ([`SeriesFinder::candidates`])
+    /// - top_k: 반환할 시리즈 수 (최소 1개)
+    fn similarity(&self, series: &Series, publisher_id: u64, candidate_limit: usize, top_k: usize) -> Vec<(Series, Option<f64>)> {
+        let top_k = top_k.max(1);
+        let series_vec = if candidate_limit > 0 {
+            self.candidates(series, publisher_id, candidate_limit, top_k + 1)
         } else {
-            series_vec.next()
+            self.series_repo.similarity(series, (top_k + 1) as i32)
+        };
+
+        let input_series_isbn = series.isbn();
+        series_vec.into_iter()
+            .filter(|(s, _)| input_series_isbn.is_none() || s.isbn() != input_series_isbn)
+            .take(top_k)
+            .collect()
+    }
+
+    /// 도서와 같은 출판사의 시리즈나 도서 제목과 시리즈명이 비슷한 시리즈를 최근 등록된 순서로 limit 개수 만큼 찾는다.
+    /// 제목 정규화 전 시리즈 선택([`SeriesMappingProcessor::select_first`])의 후보로 사용한다.
+    fn by_book(&self, book: &Book, limit: usize) -> Vec<Series> {
+        self.series_repo.find_candidates(book.title(), book.publisher_id(), limit)
+    }
+
+    /// 같은 출판사의 시리즈나 제목이 비슷한 시리즈를 연결 후보로 조회하고 코사인 거리가 가까운 시리즈를 size 개수 만큼 반환한다.
+    ///
+    /// # Description
+    /// 모든 시리즈를 대상으로 하는 백터 검색보다 데이터베이스 부하가 적고, 관련 없는 시리즈가 LLM 검증 대상으로 선택되는 것을 줄인다.
+    /// 후보가 없는 경우 기존 시리즈를 놓치지 않도록 모든 시리즈에서 백터 검색([`SeriesRepository::similarity`])한다.
+    ///
+    /// [`SeriesRepository::similarity`]: crate::item::SeriesRepository::similarity
+    fn candidates(&self, series: &Series, publisher_id: u64, limit: usize, size: usize) -> Vec<(Series, Option<f64>)> {
+        let (Some(title), Some(vec)) = (series.title().as_deref(), series.vec().as_deref()) else {
+            return self.series_repo.similarity(series, size as i32);
+        };
+
+        let candidates = self.series_repo.find_candidates(title, publisher_id, limit);
+        if candidates.is_empty() {
+            debug!("No series candidate for {}, fall back to vector search", title);
+            return self.series_repo.similarity(series, size as i32);
         }
+
+        let mut ranked = candidates.into_iter()
+            .filter_map(|candidate| {
+                let distance = candidate.vec().as_deref().map(|v| 1.0 - cosine_similarity(vec, v));
+                distance.map(|distance| (candidate, Some(distance)))
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        ranked.truncate(size);
+        ranked
     }
 }
 
@@ -158,8 +332,46 @@ impl SeriesFinder {
 /// 만약 유사한 시리즈가 없을 경우 정규화된 제목을 시리즈명으로 사용하여 신규 시리즈를 생성한다.
 pub struct SeriesMappingProcessor {
     series_finder: SeriesFinder,
+    book_repo: SharedBookRepository,
     prompt: SharedPrompt,
 
+    /// 같은 출판사 시리즈 제한 여부
+    ///
+    /// # Description
+    /// `true`일 경우 도서와 다른 출판사의 도서가 속한 시리즈는 유사도와 관계 없이 연결 후보에서 제외한다.
+    /// 다른 출판사에서 출간된 같은(혹은 매우 유사한) 제목의 도서가 같은 시리즈로 잘못 분류 되는 것을 막기 위해 사용한다.
+    ///
+    /// # Note
+    /// 시리즈 ISBN으로 찾은 시리즈는 출판사와 관계 없이 연결한다.
+    pub same_publisher_only: bool,
+
+    /// 정규화 요청에 판매처 정보를 전달할 사이트 순서
+    pub site_priority: Vec<Site>,
+
+    /// 일괄 정규화 여부
+    ///
+    /// # Description
+    /// `true`일 경우 한 청크에서 정규화가 필요한 도서들의 제목을 [`crate::prompt::Prompt::normalize_batch`]로 한번에 정규화 한다.
+    pub batch_normalize: bool,
+
+    /// 유사 시리즈 검색 전 먼저 조회할 연결 후보 수 ([`SeriesConfig::candidate_limit`])
+    pub candidate_limit: usize,
+
+    /// 유사 시리즈 검색 결과 중 새 시리즈로 분류된 도서와 함께 전달할 후보 수 ([`SeriesConfig::top_k`])
+    pub top_k: usize,
+
+    /// 제목 정규화 전 시리즈 선택 여부 ([`SeriesConfig::select_first`])
+    pub select_first: bool,
+
+    /// 실험 기능 토글
+    ///
+    /// # Description
+    /// `select_first`가 설정 되지 않은 경우 [`TOGGLE_SERIES_SELECT_FIRST`] 토글에 해당하는 도서에만 제목 정규화 전 시리즈 선택을 적용한다.
+    pub toggles: FeatureToggles,
+
+    /// 출판사별 처리 프로필, 프로필에 기준 유사도가 설정된 출판사의 도서는 `similar_score` 대신 프로필의 기준 유사도를 사용한다.
+    pub profiles: PublisherProfiles,
+
     /// 기준 유사도
     ///
     /// # Description
@@ -169,10 +381,19 @@ pub struct SeriesMappingProcessor {
 }
 
 impl SeriesMappingProcessor {
-    pub fn new(series_repo: SharedSeriesRepository, prompt: SharedPrompt) -> Self {
+    pub fn new(series_repo: SharedSeriesRepository, book_repo: SharedBookRepository, prompt: SharedPrompt) -> Self {
         Self {
             series_finder: SeriesFinder { series_repo },
+            book_repo,
             prompt,
+            same_publisher_only: false,
+            site_priority: DEFAULT_SITE_PRIORITY.to_vec(),
+            batch_normalize: false,
+            candidate_limit: 0,
+            top_k: DEFAULT_TOP_K,
+            select_first: false,
+            toggles: FeatureToggles::new(),
+            profiles: PublisherProfiles::new(),
             similar_score: DEFAULT_SIMILARITY_SCORE
         }
     }
@@ -180,22 +401,159 @@ impl SeriesMappingProcessor {
 
 impl SeriesMappingProcessor {
 
-    /// 도서의 제목을 정규화 하고 새 시리즈를 생성한다.
+    /// 도서가 속할 시리즈를 찾고 맵핑 결과로 변환한다. 자세한 흐름은 [`SeriesMappingProcessor::do_process`]를 참고한다.
+    fn map_series(&self, item: Book) -> Result<SeriesMappingResult, JobProcessFailed<Book>> {
+        if let Some((series, confidence)) = self.find_without_normalize(&item) {
+            return Ok(SeriesMappingResult::Exists(item, series, confidence, None));
+        }
+
+        let request = convert_book_to_normalize_request(&item, &self.site_priority);
+        let normalized = self.prompt.normalize(&request);
+        self.map_normalized(item, normalized)
+    }
+
+    /// 제목 정규화 없이 도서가 속할 시리즈를 찾는다.
     ///
     /// # Description
-    /// 입력 받은 도서의 제목을 정규화 하여 표준화된 제목을 추출하고 임베딩 하여 그 제목을 시리즈명으로 가지는 새 시리즈를 하나 생성한다.
+    /// 도서의 시리즈 ISBN으로 시리즈를 먼저 찾고, 찾지 못한 경우 제목 정규화 전 시리즈 선택이 설정 되어 있으면 LLM에 후보 시리즈 중 하나를 선택하게 한다.
+    fn find_without_normalize(&self, book: &Book) -> Option<(Series, SeriesLinkConfidence)> {
+        if let Some(series) = self.find_by_set_isbn(book) {
+            return Some((series, SeriesLinkConfidence::IsbnExact));
+        }
+        if !self.is_select_first(book) {
+            return None;
+        }
+        self.select_series(book)
+            .map(|series| (series, SeriesLinkConfidence::LlmSelected))
+    }
+
+    /// 도서에 제목 정규화 전 시리즈 선택을 적용할지 여부를 반환한다.
+    ///
+    /// # Description
+    /// [`TOGGLE_SERIES_SELECT_FIRST`] 토글이 정의된 경우 기존 처리 흐름과 비교할 수 있도록 도서가 속한 그룹(`experiment`, `control`)을 로그로 남긴다.
+    fn is_select_first(&self, book: &Book) -> bool {
+        if self.select_first {
+            return true;
+        }
+        if self.toggles.get(TOGGLE_SERIES_SELECT_FIRST).is_none() {
+            return false;
+        }
+        let enabled = self.toggles.is_enabled_for(TOGGLE_SERIES_SELECT_FIRST, book.isbn(), book.publisher_id());
+        let variant = if enabled { "experiment" } else { "control" };
+        info!(toggle = TOGGLE_SERIES_SELECT_FIRST, variant = variant, "{} => Series select first: {}", book.isbn(), variant);
+        enabled
+    }
+
+    /// 도서와 관련된 후보 시리즈를 [`SeriesMappingProcessor::top_k`]개 조회하고 LLM에 도서가 속하는 시리즈를 선택하게 한다.
+    ///
+    /// # Description
+    /// 후보가 없거나 LLM이 어느 후보도 선택하지 않은 경우 `None`을 반환하여 제목 정규화와 유사도 검색으로 시리즈를 찾게 한다.
+    /// LLM 요청이 실패한 경우에도 도서 처리를 실패시키지 않고 경고 로그를 남긴 후 `None`을 반환한다.
+    fn select_series(&self, book: &Book) -> Option<Series> {
+        let candidates = self.series_finder.by_book(book, self.top_k.max(1))
+            .into_iter()
+            .filter(|series| self.is_acceptable_publisher(book, series))
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let request = SeriesSelectRequest {
+            new: convert_series_similar_request_book_info(book, &self.site_priority),
+            candidates: candidates.iter()
+                .map(|series| convert_series_select_candidate(series, &self.book_repo, &self.site_priority))
+                .collect(),
+        };
+        let selected = match self.prompt.series_select(&request) {
+            Ok(selected) => selected?,
+            Err(err) => {
+                warn!("{} => Failed to select series, fall back to normalize: {}", book.isbn(), err);
+                return None;
+            }
+        };
+
+        let series = candidates.into_iter().find(|series| series.id() == selected);
+        if series.is_none() {
+            warn!("{} => selected series {} is not in candidates", book.isbn(), selected);
+        }
+        series
+    }
+
+    /// 도서의 시리즈 ISBN으로 데이터베이스에 저장된 시리즈를 찾는다.
+    fn find_by_set_isbn(&self, book: &Book) -> Option<Series> {
+        retrieve_nlgo_set_isbn(book)
+            .and_then(|set_isbn| self.series_finder.by_isbn(&set_isbn))
+    }
+
+    /// 정규화된 제목으로 새 시리즈를 생성하고 가장 유사한 시리즈와 비교하여 맵핑 결과로 변환한다.
+    fn map_normalized(&self, item: Book, normalized: Result<Normalized, prompt::Error>) -> Result<SeriesMappingResult, JobProcessFailed<Book>> {
+        let new_series = normalized
+            .map_err(|e| SeriesProcessError::FailedTitleNormalize(e.to_string()))
+            .and_then(|normalized| self.create_series(&item, normalized));
+        let new_series = match new_series {
+            Ok(new_series) => new_series,
+            Err(err) => return Err(JobProcessFailed::new(item, err.to_string())),
+        };
+
+        // 정규화된 제목은 시리즈 분류 결과와 함께 도서에도 저장한다.
+        let mut item = item;
+        if let Some(title) = new_series.title() {
+            item.set_normalized_title(title.clone());
+        }
+
+        let mut similar_series = self.series_finder
+            .similarity(&new_series, item.publisher_id(), self.candidate_limit, self.top_k)
+            .into_iter()
+            .filter_map(|(series, similar)| similar.map(|similar| MostSimilarSeries { series, score: 1.0 - similar }))
+            .filter(|similar| self.is_acceptable_publisher(&item, &similar.series))
+            .collect::<Vec<_>>();
+
+        let similar_score = self.profiles.find_by_book(&item)
+            .and_then(|profile| profile.similar_score)
+            .unwrap_or(self.similar_score);
+        if similar_series.first().is_some_and(|most_similar| most_similar.score >= similar_score) {
+            let most_similar = similar_series.remove(0);
+            Ok(SeriesMappingResult::Exists(item, most_similar.series, SeriesLinkConfidence::Similarity(most_similar.score), new_series.vec().clone()))
+        } else {
+            Ok(SeriesMappingResult::New(item, new_series, similar_series))
+        }
+    }
+
+    /// 시리즈가 도서의 연결 후보가 될 수 있는지 출판사를 확인한다.
+    ///
+    /// # Description
+    /// [`SeriesMappingProcessor::same_publisher_only`]가 설정 되어 있을 경우 시리즈에 속한 도서 중
+    /// 다른 출판사의 도서가 하나라도 있으면 연결 후보에서 제외한다.
+    fn is_acceptable_publisher(&self, book: &Book, series: &Series) -> bool {
+        if !self.same_publisher_only {
+            return true;
+        }
+
+        let other_publisher = self.book_repo.find_by_series_id(series.id())
+            .into_iter()
+            .find(|member| member.publisher_id() != book.publisher_id());
+        match other_publisher {
+            Some(member) => {
+                info!("Series candidate rejected by publisher: {}({}) => series {} has {}({})",
+                    book.isbn(), book.publisher_id(), series.id(), member.isbn(), member.publisher_id());
+                false
+            }
+            None => true
+        }
+    }
+
+    /// 도서의 정규화된 제목으로 새 시리즈를 생성한다.
+    ///
+    /// # Description
+    /// 정규화된 제목을 임베딩 하여 그 제목을 시리즈명으로 가지는 새 시리즈를 하나 생성한다.
     ///
     /// # Parmaeter
-    /// - `book`: 제목을 정규화 하고 시리즈화 할 도서 정보
+    /// - `book`: 시리즈화 할 도서 정보
+    /// - `normalized`: 도서 제목의 정규화 결과
     ///
     /// # Returns
     /// 정규화된 제목을 시리즈명으로 가지는 새 시리즈
-    fn normalize(&self, book: &Book) -> Result<Series, SeriesProcessError> {
-        let request = convert_book_to_normalize_request(book);
-
-        let normalized = self.prompt.normalize(&request)
-            .map_err(|e| SeriesProcessError::FailedTitleNormalize(e.to_string()))?;
-
+    fn create_series(&self, book: &Book, normalized: Normalized) -> Result<Series, SeriesProcessError> {
         let embedding = self.prompt.embedding(&[normalized.title.clone()])
             .map_err(|e| SeriesProcessError::FailedTitleEmbedding(e.to_string()))?;
         let embedding = embedding.into_iter().next().unwrap();
@@ -213,7 +571,7 @@ impl SeriesMappingProcessor {
 }
 
 impl Processor for SeriesMappingProcessor {
-    type In = Book;
+    type In = SeriesMappingResult;
     type Out = SeriesMappingResult;
 
     /// 도서가 속할 시리즈를 찾고 맵핑 결과로 변환한다.
@@ -224,9 +582,12 @@ impl Processor for SeriesMappingProcessor {
     /// # Flow
     /// 1. 도서에 시리즈의 ISBN이 있을 경우 데이터베이스에서 검색한다.
     /// 데이터베이스에 시리즈가 있을 경우 그 시리즈에 맵핑하라는 결과를 반환한다.
-    /// 2. 도서명을 정규화하고 임베딩 하여 데이터베이스에서 가장 유사한 시리즈를 하나 검색 한다.
-    /// 3. 검색된 시리즈의 유사도가 설정된 기준 유사도를 넘을 경우 해당 시리즈로 맵핑하라는 결과를 반환하며,
-    /// 넘지 못할 경우 새 시리즈를 생성하라는 결과를 반환한다.
+    /// 제목 정규화 전 시리즈 선택이 설정 되어 있을 경우 LLM이 선택한 후보 시리즈에 맵핑하라는 결과를 반환한다.
+    /// 2. 도서명을 정규화하고 임베딩 하여 데이터베이스에서 유사한 시리즈를 유사도가 높은 순서로 [`SeriesMappingProcessor::top_k`]개 검색 한다.
+    /// 연결 후보 수가 설정 되어 있을 경우 같은 출판사의 시리즈나 제목이 비슷한 시리즈 중에서 검색한다.
+    /// 같은 출판사 시리즈 제한이 설정 되어 있을 경우 다른 출판사의 도서가 속한 시리즈는 검색되지 않은 것으로 처리한다.
+    /// 3. 가장 유사한 시리즈의 유사도가 설정된 기준 유사도를 넘을 경우 해당 시리즈로 맵핑하라는 결과를 반환하며,
+    /// 넘지 못할 경우 검색된 시리즈들과 함께 새 시리즈를 생성하라는 결과를 반환한다.
     ///
     /// # Note
     /// - 시리즈 ISBN은 도서의 원본 데이터에서 가져오며, `국립중앙도서관(NLGO)`의 `set_isbn`을 사용한다.
@@ -237,35 +598,73 @@ impl Processor for SeriesMappingProcessor {
     /// - [`SeriesMappingResult::New`]: 설정된 유사도 이상의 유사한 시리즈를 찾지 못하였을 경우
     /// - [`SeriesMappingResult::Exists`]: 시리즈 ISBN을 데이터베이스에서 찾았거나
     /// 설정된 유사도 이상의 시리즈를 찾았을 경우
+    ///
+    /// # Note
+    /// 아직 분류 되지 않은 도서([`SeriesMappingResult::Unmapped`])만 처리하며 그 외의 결과(운영자 지정 분류 등)는 그대로 반환한다.
     fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
-        if let Some(set_isbn) = retrieve_nlgo_set_isbn(&item) {
-            let series = self.series_finder.by_isbn(&set_isbn);
-            if let Some(series) = series {
-                return Ok(SeriesMappingResult::Exists(item, series));
-            }
+        match item {
+            SeriesMappingResult::Unmapped(book) => self.map_series(book)
+                .map_err(unmapped_failure),
+            _ => Ok(item)
         }
+    }
 
-        let normalized = self.normalize(&item);
-        if normalized.is_err() {
-            return Err(JobProcessFailed::new(item, normalized.unwrap_err().to_string()));
+    /// 일괄 정규화 모드일 경우 청크에서 정규화가 필요한 도서들의 제목을 한번에 정규화 한 후 맵핑 결과로 변환한다.
+    /// 일괄 정규화 모드가 아닐 경우 도서마다 [`SeriesMappingProcessor::do_process`]를 호출한다.
+    fn do_process_chunk(&self, items: Vec<Self::In>) -> Result<Vec<Self::Out>, JobProcessFailed<Self::In>> {
+        if !self.batch_normalize {
+            return items.into_iter()
+                .map(|item| self.do_process(item))
+                .collect();
         }
-        let new_series = normalized.unwrap();
-
-        let most_similar_series = self.series_finder
-            .similarity(&new_series)
-            .filter(|(_, similar)| similar.is_some())
-            .map(|(series, similar)| (series, 1.0 - similar.unwrap()));
-
-        match most_similar_series {
-            Some((exists_series, score)) => {
-                if score >= self.similar_score {
-                    Ok(SeriesMappingResult::Exists(item, exists_series))
-                } else {
-                    Ok(SeriesMappingResult::New(item, new_series, Some(MostSimilarSeries { series: exists_series, score })))
-                }
+
+        let mut pending = Vec::with_capacity(items.len());
+        let mut requests = Vec::new();
+        for item in items {
+            match item {
+                SeriesMappingResult::Unmapped(book) => match self.find_without_normalize(&book) {
+                    Some((series, confidence)) => pending.push(PendingMapping::Mapped(SeriesMappingResult::Exists(book, series, confidence, None))),
+                    None => {
+                        requests.push(convert_book_to_normalize_request(&book, &self.site_priority));
+                        pending.push(PendingMapping::Normalize(book));
+                    }
+                },
+                _ => pending.push(PendingMapping::Mapped(item)),
             }
-            None => Ok(SeriesMappingResult::New(item, new_series, None))
         }
+
+        if !requests.is_empty() {
+            info!("Normalize {} title(s) in batch", requests.len());
+        }
+        let mut normalized = self.prompt.normalize_batch(&requests).into_iter();
+        pending.into_iter()
+            .map(|pending| match pending {
+                PendingMapping::Mapped(result) => Ok(result),
+                PendingMapping::Normalize(book) => {
+                    let result = normalized.next()
+                        .unwrap_or_else(|| Err(prompt::Error::ResponseParsingFailed("normalize batch result is missing".to_owned())));
+                    self.map_normalized(book, result).map_err(unmapped_failure)
+                }
+            })
+            .collect()
+    }
+}
+
+/// 일괄 정규화 모드에서 정규화를 기다리는 도서
+enum PendingMapping {
+    /// 정규화 없이 맵핑 결과가 결정된 도서
+    Mapped(SeriesMappingResult),
+
+    /// 제목 정규화가 필요한 도서
+    Normalize(Book),
+}
+
+/// 도서 단위의 처리 실패를 분류 되지 않은 도서([`SeriesMappingResult::Unmapped`])의 처리 실패로 변환한다.
+fn unmapped_failure(e: JobProcessFailed<Book>) -> JobProcessFailed<SeriesMappingResult> {
+    let message = e.message().to_owned();
+    match e.item().clone() {
+        Some(book) => JobProcessFailed::new(SeriesMappingResult::Unmapped(book), message),
+        None => JobProcessFailed::new_empty(message),
     }
 }
 
@@ -277,8 +676,9 @@ impl Processor for SeriesMappingProcessor {
 ///
 /// # How to work
 /// 1. 이전 단계에서 새 시리즈로 분류된 도서([`SeriesMappingResult::New`])를 대상으로 한다.
-/// 2. 해당 도서와 가장 유사했던 기존 시리즈의 도서 목록을 함께 LLM에 전달한다.
-/// 3. LLM이 신간 도서의 시리즈 소속 여부를 최종 판단한다.
+/// 2. 해당 도서와 유사했던 기존 시리즈 중 기준 유사도를 넘은 시리즈들의 도서 목록을 함께 LLM에 전달한다.
+/// 3. 후보가 하나인 경우 LLM이 신간 도서의 시리즈 소속 여부를 최종 판단하며([`crate::prompt::Prompt::series_similar`]),
+/// 여러개인 경우 후보 중 신간 도서가 속할 시리즈를 선택한다. ([`crate::prompt::Prompt::series_select`])
 ///
 /// # Why
 /// 동일한 도서라도 판매처마다 제목을 다르게 등록할 수 있어 정규화 후에도 데이터베이스에 기록된 시리즈명과 차이가 있을 수 있어
@@ -290,17 +690,71 @@ pub struct BelongToSeriesProcessor {
     /// 기준 유사도
     ///
     /// # Description
-    /// 시리즈 소속 여부를 재검토할지 여부를 확인하는 기준 유사도로 이전 단계에서 새 시리즈 생성이 필요한 도서로 분류 되었지만 가장 높은 유사도를
-    /// 가진 시리즈의 유사도가 이 값을 넘었을 때 해당 시리즈의 도서 목록과 함께 LLM에 입력으로 사용하여 시리즈에 속하는지 재검토 한다.
+    /// 시리즈 소속 여부를 재검토할지 여부를 확인하는 기준 유사도로 이전 단계에서 새 시리즈 생성이 필요한 도서로 분류 되었지만 유사했던 시리즈의
+    /// 유사도가 이 값을 넘었을 때 해당 시리즈들의 도서 목록과 함께 LLM에 입력으로 사용하여 시리즈에 속하는지 재검토 한다.
     ///
     /// # Note
     /// 0 ~ 1 사이의 값을 사용한다.
     pub similar_score: f64,
+
+    /// 도서의 저자 등 대표값을 선택할 사이트 순서
+    pub site_priority: Vec<Site>,
 }
 
 impl BelongToSeriesProcessor {
     pub fn new(book_repo: SharedBookRepository, prompt: SharedPrompt) -> Self {
-        Self { book_repo, prompt, similar_score: DEFAULT_SERIES_SIMILARITY_SCORE }
+        Self {
+            book_repo,
+            prompt,
+            similar_score: DEFAULT_SERIES_SIMILARITY_SCORE,
+            site_priority: DEFAULT_SITE_PRIORITY.to_vec(),
+        }
+    }
+}
+
+impl BelongToSeriesProcessor {
+
+    /// 시리즈에 속한 도서들을 시리즈 소속 여부 요청에 사용할 도서 정보로 변환한다.
+    fn series_books(&self, series_id: u64) -> Vec<SeriesSimilarRequestBookInfo> {
+        self.book_repo.find_by_series_id(series_id).iter()
+            .map(|b| convert_series_similar_request_book_info(b, &self.site_priority))
+            .collect()
+    }
+
+    /// 기준 유사도를 넘은 후보 시리즈 중 도서가 속하는 시리즈를 LLM에 확인하고 그 시리즈의 위치를 반환한다.
+    /// 재검토할 후보가 없거나 어느 후보에도 속하지 않는 경우 `None`을 반환한다.
+    fn verify(&self, book: &Book, candidates: &[MostSimilarSeries]) -> Result<Option<usize>, prompt::Error> {
+        let targets = candidates.iter()
+            .enumerate()
+            .filter(|(_, candidate)| candidate.score >= self.similar_score)
+            .collect::<Vec<_>>();
+        if targets.is_empty() {
+            return Ok(None);
+        }
+
+        let new_book = convert_series_similar_request_book_info(book, &self.site_priority);
+        if let [(index, candidate)] = targets.as_slice() {
+            let request = SeriesSimilarRequest { new: new_book, series: self.series_books(candidate.series.id()) };
+            return self.prompt.series_similar(&request)
+                .map(|belong| belong.then_some(*index));
+        }
+
+        let request = SeriesSelectRequest {
+            new: new_book,
+            candidates: targets.iter()
+                .map(|(_, candidate)| convert_series_select_candidate(&candidate.series, &self.book_repo, &self.site_priority))
+                .collect(),
+        };
+        let chosen = self.prompt.series_select(&request)?;
+        Ok(chosen.and_then(|series_id| {
+            let index = targets.iter()
+                .find(|(_, candidate)| candidate.series.id() == series_id)
+                .map(|(index, _)| *index);
+            if index.is_none() {
+                warn!("{} => chosen series {} is not in candidates", book.isbn(), series_id);
+            }
+            index
+        }))
     }
 }
 
@@ -310,35 +764,14 @@ impl Processor for BelongToSeriesProcessor {
 
     fn do_process(&self, item: Self::In) -> Result<Self::Out, JobProcessFailed<Self::In>> {
         match item {
-            SeriesMappingResult::New(book, new, most_similar) => {
-                if most_similar.is_none() {
-                    return Ok(SeriesMappingResult::New(book, new, None));
-                }
-                let most_similar = most_similar.unwrap();
-                if most_similar.score < self.similar_score {
-                    return Ok(SeriesMappingResult::New(book, new, Some(most_similar)));
-                }
-
-                let most_similar_series_books = self.book_repo.find_by_series_id(most_similar.series.id());
-                let series_books = most_similar_series_books.iter()
-                    .map(convert_series_similar_request_book_info)
-                    .collect();
-                let new_book = convert_series_similar_request_book_info(&book);
-
-                let request = SeriesSimilarRequest { new: new_book, series: series_books, };
-                let response = self.prompt.series_similar(&request);
-
-                if response.is_err() {
-                    let err = response.unwrap_err();
-                    return Err(JobProcessFailed::new(SeriesMappingResult::New(book, new, Some(most_similar)), err.to_string()));
+            SeriesMappingResult::New(book, new, candidates) => match self.verify(&book, &candidates) {
+                Ok(Some(index)) => {
+                    let chosen = candidates.into_iter().nth(index).unwrap();
+                    Ok(SeriesMappingResult::Exists(book, chosen.series, SeriesLinkConfidence::LlmConfirmed(chosen.score), new.vec().clone()))
                 }
-
-                if response.unwrap() {
-                    Ok(SeriesMappingResult::Exists(book, most_similar.series))
-                } else {
-                    Ok(SeriesMappingResult::New(book, new, Some(most_similar)))
-                }
-            }
+                Ok(None) => Ok(SeriesMappingResult::New(book, new, candidates)),
+                Err(err) => Err(JobProcessFailed::new(SeriesMappingResult::New(book, new, candidates), err.to_string())),
+            },
             _ => Ok(item)
         }
     }
@@ -348,14 +781,69 @@ impl Processor for BelongToSeriesProcessor {
 ///
 /// # Description
 /// 시리즈 맵핑 결과를 받아 신규 시리즈를 저장하거나, 도서의 시리즈 아이디를 연결된 시리즈의 아이디로 업데이트 한다.
+///
+/// # Note
+/// 도서를 시리즈에 연결할 때 연결한 방법과 유사도([`SeriesLinkConfidence`])를 함께 저장하여
+/// 이후 시리즈 분류를 얼마나 신뢰할 수 있는지 판단하거나 신뢰도가 낮은 연결을 재검토 할 수 있도록 한다.
+/// 정규화된 제목의 임베딩 백터가 있을 경우 도서의 백터로 함께 저장하여 도서 단위의 유사도 검색([`crate::item::BookRepository::similar_books`])에 사용한다.
+///
+/// # Duplicate
+/// 새로운 시리즈의 여러 권이 같은 청크에서 처리 될 경우 각 도서는 서로를 찾지 못해 모두 새 시리즈로 분류된다.
+/// 이를 막기 위해 잡 실행 중 새로 생성한 시리즈를 기억하고, 새 시리즈로 분류된 도서의 시리즈가 이미 생성한 시리즈와
+/// 제목이 같거나 제목 백터의 유사도가 기준 유사도 이상일 경우 새 시리즈를 생성하지 않고 이미 생성한 시리즈에 연결한다.
 pub struct SeriesWriter {
     series_repo: SharedSeriesRepository,
     book_repo: SharedBookRepository,
+
+    /// 잡 실행 중 새로 생성한 시리즈 목록
+    created: RefCell<Vec<Series>>,
+
+    /// 이미 생성한 시리즈와 같은 시리즈로 판단할 기준 유사도 (0 ~ 1)
+    pub similar_score: f64,
 }
 
 impl SeriesWriter {
     pub fn new(series_repo: SharedSeriesRepository, book_repo: SharedBookRepository) -> Self {
-        Self { series_repo, book_repo }
+        Self {
+            series_repo,
+            book_repo,
+            created: RefCell::new(Vec::new()),
+            similar_score: DEFAULT_SIMILARITY_SCORE,
+        }
+    }
+
+    /// 잡 실행 중 이미 생성한 시리즈 중 새 시리즈와 같은 시리즈를 찾는다.
+    ///
+    /// # Return
+    /// 같은 시리즈로 판단된 시리즈의 아이디와 그 유사도, 제목이 같을 경우 유사도는 1로 한다.
+    fn find_created(&self, new_series: &Series) -> Option<(u64, f64)> {
+        // 시리즈 ISBN이 서로 다른 시리즈는 제목이 같더라도 다른 형태(소설, 만화 등)의 시리즈로 판단한다.
+        let created = self.created.borrow();
+        let created = created.iter()
+            .filter(|s| s.isbn().is_none() || new_series.isbn().is_none() || s.isbn() == new_series.isbn())
+            .collect::<Vec<_>>();
+
+        let title_key = new_series.title().as_deref().map(series_title_key);
+        let same_title = created.iter()
+            .find(|s| title_key.is_some() && s.title().as_deref().map(series_title_key) == title_key);
+        if let Some(series) = same_title {
+            return Some((series.id(), 1.0));
+        }
+
+        let vec = new_series.vec().as_ref()?;
+        created.iter()
+            .filter_map(|s| s.vec().as_ref().map(|v| (s, cosine_similarity(vec, v))))
+            .filter(|(_, score)| *score >= self.similar_score)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(s, score)| (s.id(), score))
+    }
+
+    /// 도서-시리즈 연결을 저장하고 변경 내역에 기록한다.
+    fn save_link(&self, link: SeriesLink) {
+        let links = [link];
+        if self.series_repo.save_links(&links) > 0 {
+            audit::record_created_links(&links);
+        }
     }
 }
 
@@ -363,28 +851,81 @@ impl Writer for SeriesWriter {
     type Item = SeriesMappingResult;
 
     fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        // 새 시리즈는 제목 순서(보통 권수 순서)로 생성하여 같은 청크의 다른 권들이 먼저 생성된 시리즈에 연결 되도록 한다.
+        let mut items = items;
+        items.sort_by_key(|item| match item {
+            SeriesMappingResult::New(book, _, _) => Some(book.title().to_owned()),
+            _ => None,
+        });
+
+        let mut failed = Vec::new();
         for item in items.into_iter() {
             match item {
-                SeriesMappingResult::Exists(mut book, exists_series) => {
+                SeriesMappingResult::Exists(mut book, exists_series, confidence, vec) => {
                     book.set_series_id(exists_series.id());
                     self.book_repo.update_book(&book);
+                    if let Some(vec) = vec {
+                        self.book_repo.save_vector(book.id(), &vec);
+                    }
+                    self.save_link(SeriesLink::new(book.id(), exists_series.id(), confidence));
                 }
                 SeriesMappingResult::New(mut book, new_series, _) => {
+                    // 새 시리즈의 백터는 도서의 정규화된 제목을 임베딩 한 것이므로 도서의 백터로도 저장한다.
+                    if let Some(vec) = new_series.vec() {
+                        self.book_repo.save_vector(book.id(), vec);
+                    }
+
+                    if let Some((series_id, score)) = self.find_created(&new_series) {
+                        info!("Series already created in this run: {} => {}", book.isbn(), series_id);
+                        book.set_series_id(series_id);
+                        self.book_repo.update_book(&book);
+                        self.save_link(SeriesLink::new(book.id(), series_id, SeriesLinkConfidence::Similarity(score)));
+                        continue;
+                    }
+
                     let insert_series = vec![new_series];
                     let inserted_series = self.series_repo
                         .new_series(&insert_series).into_iter().next();
 
                     if inserted_series.is_none() {
                         let series = insert_series.into_iter().next().unwrap();
-                        let err_val = vec![SeriesMappingResult::New(book, series, None)];
-                        return Err(JobWriteFailed::new(err_val, "시리즈가 저장 되지 않았습니다."))
+                        failed.push(SeriesMappingResult::New(book, series, Vec::new()));
+                        continue;
                     }
 
-                    book.set_series_id(inserted_series.unwrap().id());
+                    let inserted_series = inserted_series.unwrap();
+                    let series_id = inserted_series.id();
+                    audit::record_created_series(&inserted_series);
+                    self.created.borrow_mut().push(inserted_series);
+
+                    book.set_series_id(series_id);
                     self.book_repo.update_book(&book);
+                    self.save_link(SeriesLink::new(book.id(), series_id, SeriesLinkConfidence::Created));
+                }
+                SeriesMappingResult::Manual(mut book, target) => {
+                    info!("Series override applied: {} => {:?}", book.isbn(), target);
+                    match target {
+                        SeriesOverrideTarget::Series(series_id) => {
+                            book.set_series_id(series_id);
+                            self.book_repo.update_book(&book);
+                            self.save_link(SeriesLink::new(book.id(), series_id, SeriesLinkConfidence::Manual));
+                        }
+                        SeriesOverrideTarget::Standalone => {
+                            self.series_repo.delete_links(&[book.id()]);
+                            self.book_repo.unlink_series(book.id());
+                        }
+                    }
+                }
+                SeriesMappingResult::Unmapped(book) => {
+                    warn!("Book is not mapped to any series: {}", book.isbn());
                 }
             }
         }
+
+        // 시리즈를 저장하지 못한 도서가 있더라도 청크의 나머지 도서는 저장하고, 저장하지 못한 도서를 모두 에러로 반환한다.
+        if !failed.is_empty() {
+            return Err(JobWriteFailed::new(failed, "시리즈가 저장 되지 않았습니다."));
+        }
         Ok(())
     }
 }
@@ -392,57 +933,103 @@ impl Writer for SeriesWriter {
 pub fn create_job(
     book_repo: SharedBookRepository,
     series_repo: SharedSeriesRepository,
+    override_repo: SharedSeriesOverrideRepository,
     prompt: SharedPrompt,
-) -> Job<Book, SeriesMappingResult> {
-    let reader = UnorganizedBookReader::new(book_repo.clone());
-
-    let series_mapping_processor = SeriesMappingProcessor::new(series_repo.clone(), prompt.clone());
-    let series_similar_processor = BelongToSeriesProcessor::new(book_repo.clone(), prompt.clone());
+    config: &SeriesConfig,
+    toggles: &FeatureToggles,
+    profiles: &PublisherProfiles,
+    params: &JobParameter,
+) -> Result<Job<Book, SeriesMappingResult>, JobBuildError> {
+    let batch_normalize = params.get(PARAM_NAME_NORMALIZE_BATCH)
+        .map(|v| v.trim().parse::<bool>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_NORMALIZE_BATCH, e))))
+        .transpose()?
+        .unwrap_or(false);
+    let default_chunk_size = if batch_normalize { config.batch_chunk_size } else { config.chunk_size };
+    let chunk_size = retrieve_chunk_size_in_parameter(params)?.unwrap_or(default_chunk_size);
+
+    let mut reader = UnorganizedBookReader::new(book_repo.clone(), override_repo.clone());
+    reader.read_limit = config.read_limit;
+
+    let series_override_processor = SeriesOverrideProcessor::new(override_repo.clone());
+    let same_publisher_only = params.get(PARAM_NAME_SERIES_SAME_PUBLISHER)
+        .map(|v| v.trim().parse::<bool>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_SERIES_SAME_PUBLISHER, e))))
+        .transpose()?
+        .unwrap_or(false);
+
+    let mut series_mapping_processor = SeriesMappingProcessor::new(series_repo.clone(), book_repo.clone(), prompt.clone());
+    series_mapping_processor.same_publisher_only = same_publisher_only;
+    series_mapping_processor.batch_normalize = batch_normalize;
+    series_mapping_processor.similar_score = config.similar_score;
+    series_mapping_processor.candidate_limit = config.candidate_limit;
+    series_mapping_processor.top_k = config.top_k;
+    series_mapping_processor.select_first = config.select_first;
+    series_mapping_processor.toggles = toggles.clone();
+    series_mapping_processor.profiles = profiles.clone();
+    let mut series_similar_processor = BelongToSeriesProcessor::new(book_repo.clone(), prompt.clone());
+    series_similar_processor.similar_score = config.series_similar_score;
+    if let Some(site_priority) = retrieve_site_priority_in_parameter(params)? {
+        series_mapping_processor.site_priority = site_priority.clone();
+        series_similar_processor.site_priority = site_priority;
+    }
 
-    let processor = ProcessorChain::new(Box::new(series_mapping_processor), Box::new(series_similar_processor));
+    let processor = ProcessorChain::new(
+        Box::new(series_override_processor),
+        Box::new(ProcessorChain::new(Box::new(series_mapping_processor), Box::new(series_similar_processor))),
+    );
 
-    let writer = SeriesWriter::new(series_repo.clone(), book_repo.clone());
+    let mut writer = SeriesWriter::new(series_repo.clone(), book_repo.clone());
+    writer.similar_score = config.similar_score;
 
-    let mut job = job_builder()
+    let job = job_builder()
         .reader(Box::new(reader))
         .processor(Box::new(processor))
         .writer(Box::new(writer))
         .build();
-    job.chunk_size = 1;
 
-    job
+    let job = job.set_chunk_size(chunk_size);
+    match RetryPolicy::from_env() {
+        Some(policy) => Ok(job.with_retry_policy(policy)),
+        None => Ok(job),
+    }
 }
 
-fn retrieve_nlgo_set_isbn(book: &Book) -> Option<String> {
-    let dict = nlgo::load_raw_key_dict();
-    raw_utils::retrieve_series_id_from_raw(&dict, book.originals().get(&Site::NLGO)?)
+/// 시리즈 제목 비교에 사용할 키, 공백을 제거하고 소문자로 변환한다.
+fn series_title_key(title: &str) -> String {
+    title.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
 }
 
-fn convert_book_to_normalize_request(book: &Book) -> NormalizeRequest {
-    let mut request = NormalizeRequest::new(book.title());
-    let original = book.originals();
-
-    let mut sale_info_vec = Vec::new();
-    for (site, raw) in original {
-        let dict = raw_utils::load_site_dict(site);
-        if let Some(title) = raw_utils::retrieve_title_from_raw(&dict, raw) {
-            let mut sale_info = NormalizeRequestSaleInfo::new(&site.to_string(), &title);
-            sale_info.price = raw_utils::retrieve_sale_price_from_raw(&dict, raw);
-            sale_info.desc = raw_utils::retrieve_description_from_raw(&dict, raw);
-            sale_info.series = raw_utils::retrieve_series_list_titles_from_raw(&dict, raw);
-            sale_info_vec.push(sale_info);
-        }
+/// 두 백터의 코사인 유사도 (-1 ~ 1, 1에 가까울수록 유사함)
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    let dot = a.iter().zip(b).map(|(x, y)| (*x as f64) * (*y as f64)).sum::<f64>();
+    let norm_a = a.iter().map(|x| (*x as f64).powi(2)).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| (*x as f64).powi(2)).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
     }
+    dot / (norm_a * norm_b)
+}
 
-    if !sale_info_vec.is_empty() {
-        request.sale_info = Some(sale_info_vec);
-    }
+/// 후보 시리즈와 소속 도서들을 시리즈 선택 요청([`SeriesSelectRequest`])의 후보로 변환한다.
+fn convert_series_select_candidate(series: &Series, book_repo: &SharedBookRepository, site_priority: &[Site]) -> SeriesSelectCandidate {
+    let books = book_repo.find_by_series_id(series.id()).iter()
+        .map(|b| convert_series_similar_request_book_info(b, site_priority))
+        .collect();
+    SeriesSelectCandidate { series_id: series.id(), title: series.title().clone(), books }
+}
 
-    request
+fn retrieve_nlgo_set_isbn(book: &Book) -> Option<String> {
+    let dict = nlgo::load_raw_key_dict();
+    raw_utils::retrieve_series_id_from_raw(&dict, book.originals().get(&Site::NLGO)?)
 }
 
-fn convert_series_similar_request_book_info(book: &Book) -> SeriesSimilarRequestBookInfo {
-    let author = book.originals().iter()
+fn convert_series_similar_request_book_info(book: &Book, site_priority: &[Site]) -> SeriesSimilarRequestBookInfo {
+    let author = raw_utils::sort_by_site_priority(book.originals(), site_priority)
+        .into_iter()
         .find_map(|(site, raw)| {
             let dict = raw_utils::load_site_dict(site);
             dict.get(&RawDataKind::Author)
@@ -0,0 +1,274 @@
+use crate::batch::book::fetch::{diff_book, is_same_isbn};
+use crate::batch::book::retrieve_isbn_in_parameter;
+use crate::batch::error::{JobBuildError, JobReadFailed, JobWriteFailed};
+use crate::batch::{job_builder, Job, JobParameter, Reader, Writer};
+use crate::item::{Book, Site};
+use crate::provider::api::{ClientError, LookupClient};
+use crate::provider::html;
+use crate::provider::html::ParsingError;
+use crate::{PARAM_NAME_SNAPSHOT_DIR, PARAM_NAME_UPDATE_SNAPSHOT};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use tracing::{info, warn};
+
+/// 스냅샷 디렉토리의 기본 경로
+pub const DEFAULT_SNAPSHOT_DIR: &str = "snapshots";
+
+/// 기준 ISBN 목록 파일 이름 (스냅샷 디렉토리 아래에 위치한다.)
+const ISBN_LIST_FILE: &str = "isbn.txt";
+
+/// 조회 시점에 따라 값이 바뀌는 원본 데이터 키, 스냅샷 비교에서 제외한다.
+const VOLATILE_KEYS: [&str; 4] = ["sale_status", "sale_price", "discount", "update_date"];
+
+/// 사이트 하나에서 조회한 기준 도서
+#[derive(Debug, Clone)]
+pub struct SnapshotItem {
+    pub site: Site,
+    pub isbn: String,
+
+    /// 사이트에서 조회하여 변환한 도서, 조회 혹은 변환에 실패한 경우 에러 메시지
+    pub book: Result<Book, String>,
+}
+
+/// 기준 ISBN 조회 리더
+///
+/// # Description
+/// `isbn` 파라미터의 ISBN을, 파라미터가 없을 경우 스냅샷 디렉토리의 `isbn.txt`에 기록된 ISBN을 등록된 모든 사이트(API, HTML)에서 조회한다.
+/// [`crate::batch::book::fetch::FetchReader`]와 달리 사이트별 도서를 병합하지 않고 사이트마다 하나의 아이템으로 반환한다.
+///
+/// # Note
+/// `isbn.txt`는 한 줄에 ISBN 하나를 기록하며 빈 줄과 `#`으로 시작하는 줄은 무시한다.
+pub struct SnapshotReader {
+    api_clients: Vec<Rc<dyn LookupClient>>,
+    html_clients: Vec<(Site, Rc<dyn html::Client>)>,
+    dir: PathBuf,
+}
+
+impl SnapshotReader {
+    pub fn new(api_clients: Vec<Rc<dyn LookupClient>>, html_clients: Vec<(Site, Rc<dyn html::Client>)>, dir: PathBuf) -> Self {
+        Self { api_clients, html_clients, dir }
+    }
+
+    fn read_isbn_list(&self) -> Result<Vec<String>, JobReadFailed> {
+        let path = self.dir.join(ISBN_LIST_FILE);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| JobReadFailed::InvalidArguments(format!("Failed to read {}: {}", path.display(), e)))?;
+        Ok(text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_owned)
+            .collect())
+    }
+
+    fn fetch(&self, isbn: &str) -> Vec<SnapshotItem> {
+        let mut items = Vec::new();
+
+        for client in &self.api_clients {
+            let book = match client.lookup(isbn) {
+                Ok(response) => response.books.into_iter()
+                    .filter_map(|builder| builder.build().ok())
+                    .find(|book| is_same_isbn(book.isbn(), isbn))
+                    .ok_or_else(|| "item not found".to_owned()),
+                Err(ClientError::NotFound(_)) => Err("item not found".to_owned()),
+                Err(err) => Err(format!("lookup failed: {:?}", err)),
+            };
+            items.push(SnapshotItem { site: client.site(), isbn: isbn.to_owned(), book });
+        }
+
+        for (site, client) in &self.html_clients {
+            let book = match client.get(isbn) {
+                Ok(builder) => builder.build().map_err(|err| format!("build failed: {}", err)),
+                Err(ParsingError::ItemNotFound) => Err("item not found".to_owned()),
+                Err(err) => Err(format!("lookup failed: {}", err)),
+            };
+            items.push(SnapshotItem { site: *site, isbn: isbn.to_owned(), book });
+        }
+
+        items
+    }
+}
+
+impl Reader for SnapshotReader {
+    type Item = SnapshotItem;
+
+    fn do_read(&self, params: &JobParameter) -> Result<Vec<Self::Item>, JobReadFailed> {
+        let mut isbn_vec = retrieve_isbn_in_parameter(params)?;
+        if isbn_vec.is_empty() {
+            isbn_vec = self.read_isbn_list()?;
+        }
+        if isbn_vec.is_empty() {
+            return Err(JobReadFailed::InvalidArguments("isbn is required".to_owned()));
+        }
+
+        Ok(isbn_vec.iter().flat_map(|isbn| self.fetch(isbn)).collect())
+    }
+}
+
+/// 스냅샷 비교 라이터
+///
+/// # Description
+/// 조회한 도서를 스냅샷 디렉토리의 `{사이트}/{ISBN}.json` 파일과 비교하여 차이를 속성 단위로 표준 출력에 출력한다.
+/// 차이가 있거나 스냅샷이 없는 경우, 사이트 조회에 실패한 경우 모두 불일치로 처리하며 불일치가 하나라도 있으면 저장 실패 에러를 반환하여 잡을 실패시킨다.
+/// `update` 가 설정된 경우 비교하지 않고 조회한 도서로 스냅샷을 갱신한다.
+///
+/// # Note
+/// 판매 상태, 가격 등 조회 시점에 따라 바뀌는 원본 데이터 키는 비교하지 않는다. ([`compare_snapshot`])
+pub struct SnapshotWriter {
+    dir: PathBuf,
+    update: bool,
+}
+
+impl SnapshotWriter {
+    pub fn new(dir: PathBuf, update: bool) -> Self {
+        Self { dir, update }
+    }
+
+    fn snapshot_path(&self, item: &SnapshotItem) -> PathBuf {
+        self.dir.join(item.site.to_string()).join(format!("{}.json", item.isbn))
+    }
+
+    fn save(&self, path: &Path, book: &Book) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        // 파일의 키 순서가 실행마다 바뀌지 않도록 JSON 값으로 변환(키 정렬) 후 저장한다.
+        let value = serde_json::to_value(book).map_err(|e| e.to_string())?;
+        let text = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+        fs::write(path, text + "\n").map_err(|e| e.to_string())
+    }
+
+    /// 스냅샷과 비교한 결과를 출력하고 일치 여부를 반환한다.
+    fn verify(&self, item: &SnapshotItem) -> bool {
+        let path = self.snapshot_path(item);
+        println!("=== {} {} ({}) ===", item.site, item.isbn, path.display());
+
+        let book = match &item.book {
+            Ok(book) => book,
+            Err(message) => {
+                println!("  {}", message);
+                return false;
+            }
+        };
+        let snapshot = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(_) => {
+                println!("  snapshot not found (run with --update-snapshot)");
+                return false;
+            }
+        };
+        let snapshot = match serde_json::from_str::<Book>(&snapshot) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                println!("  invalid snapshot: {}", e);
+                return false;
+            }
+        };
+
+        let lines = compare_snapshot(&snapshot, book);
+        if lines.is_empty() {
+            println!("  (no changes)");
+        }
+        for line in &lines {
+            println!("{}", line);
+        }
+        lines.is_empty()
+    }
+}
+
+impl Writer for SnapshotWriter {
+    type Item = SnapshotItem;
+
+    fn do_write(&self, items: Vec<Self::Item>) -> Result<(), JobWriteFailed<Self::Item>> {
+        if self.update {
+            for item in &items {
+                let path = self.snapshot_path(item);
+                match &item.book {
+                    Ok(book) => match self.save(&path, book) {
+                        Ok(_) => info!("{} => Snapshot updated: {}", item.site, path.display()),
+                        Err(e) => return Err(JobWriteFailed::new(items.clone(), &format!("{}: {}", path.display(), e))),
+                    },
+                    Err(message) => warn!("{} => Snapshot skipped {}: {}", item.site, item.isbn, message),
+                }
+            }
+            return Ok(());
+        }
+
+        let mismatched = items.iter()
+            .map(|item| self.verify(item))
+            .filter(|matched| !matched)
+            .count();
+        if mismatched > 0 {
+            return Err(JobWriteFailed::new(items, &format!("{} snapshot(s) differ", mismatched)));
+        }
+        Ok(())
+    }
+}
+
+/// 스냅샷 도서와 조회한 도서의 차이를 속성 단위의 문자열 리스트로 반환한다.
+///
+/// [`diff_book`]의 결과에서 판매 상태, 가격 등 조회 시점에 따라 바뀌는 원본 데이터 키의 차이는 제외한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::snapshot::compare_snapshot;
+/// use book_batch_rust::item::{Book, Raw, Site};
+///
+/// let book = |title: &str, sale_status: &str| {
+///     let mut raw = Raw::new();
+///     raw.insert("title".to_owned(), title.into());
+///     raw.insert("sale_status".to_owned(), sale_status.into());
+///     Book::builder().isbn("9791133478427".to_owned()).title(title.to_owned())
+///         .add_original(Site::KyoboBook, raw).build().unwrap()
+/// };
+///
+/// let snapshot = book("원피스 1", "판매중");
+/// assert!(compare_snapshot(&snapshot, &book("원피스 1", "품절")).is_empty());
+///
+/// let lines = compare_snapshot(&snapshot, &book("원피스 01", "판매중"));
+/// assert_eq!(lines, vec!["- title: 원피스 1", "+ title: 원피스 01", "- KYOBO.title: 원피스 1", "+ KYOBO.title: 원피스 01"]);
+/// ```
+pub fn compare_snapshot(snapshot: &Book, book: &Book) -> Vec<String> {
+    diff_book(Some(snapshot), book).into_iter()
+        .filter(|line| !is_volatile(line))
+        .collect()
+}
+
+fn is_volatile(line: &str) -> bool {
+    let name = line.get(2..)
+        .and_then(|line| line.split_once(": "))
+        .map(|(name, _)| name)
+        .unwrap_or_default();
+    VOLATILE_KEYS.iter().any(|key| name.ends_with(&format!(".{}", key)))
+}
+
+/// 스냅샷 디렉토리 경로를 반환한다. `snapshot_dir` 파라미터가 없을 경우 [`DEFAULT_SNAPSHOT_DIR`]을 사용한다.
+pub fn retrieve_snapshot_dir_in_parameter(params: &JobParameter) -> PathBuf {
+    PathBuf::from(params.get(PARAM_NAME_SNAPSHOT_DIR).map(|dir| dir.as_str()).unwrap_or(DEFAULT_SNAPSHOT_DIR))
+}
+
+/// 사이트 변환 스냅샷 비교 잡을 생성한다.
+///
+/// # Description
+/// 기준 ISBN을 모든 사이트에서 조회하여 체크인된 스냅샷과 비교한다. 사이트의 응답 형식 변경이나 도서 변환 로직의 회귀를 조기에 발견하기 위한 개발용 잡이다.
+/// `update_snapshot` 파라미터가 `true`인 경우 비교하지 않고 스냅샷을 갱신한다.
+pub fn create_job(
+    api_clients: Vec<Rc<dyn LookupClient>>,
+    html_clients: Vec<(Site, Rc<dyn html::Client>)>,
+    params: &JobParameter,
+) -> Result<Job<SnapshotItem, SnapshotItem>, JobBuildError> {
+    let update = params.get(PARAM_NAME_UPDATE_SNAPSHOT)
+        .map(|v| v.parse::<bool>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_UPDATE_SNAPSHOT, e))))
+        .transpose()?
+        .unwrap_or(false);
+    let dir = retrieve_snapshot_dir_in_parameter(params);
+
+    let job = job_builder()
+        .reader(Box::new(SnapshotReader::new(api_clients, html_clients, dir.clone())))
+        .writer(Box::new(SnapshotWriter::new(dir, update)))
+        .build();
+
+    // 불일치를 한번에 확인할 수 있도록 모든 아이템을 하나의 청크로 비교한다.
+    Ok(job.set_chunk_size(usize::MAX))
+}
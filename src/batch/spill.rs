@@ -0,0 +1,123 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Lines, Write};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{error, warn};
+
+/// 디스크에 임시로 저장된 아이템 큐
+///
+/// # Description
+/// 읽은 아이템이 많아 처리가 끝날 때 까지 메모리에 모두 유지하기 부담스러운 경우 아이템을 한 줄에 하나씩 JSON으로 임시 파일에 기록하고,
+/// 이터레이터로 변환하여 한 줄씩 다시 읽어 사용한다. 따라서 처리 중 메모리에는 읽어온 아이템만 유지된다.
+/// 임시 파일은 큐(또는 큐에서 만든 이터레이터)가 해제될 때 삭제된다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::spill::SpillQueue;
+///
+/// let queue = SpillQueue::write(&vec![1, 2, 3]).unwrap();
+/// assert_eq!(queue.len(), 3);
+///
+/// let items: Vec<i32> = queue.into_iter().collect();
+/// assert_eq!(items, vec![1, 2, 3]);
+/// ```
+pub struct SpillQueue<T> {
+    file: SpillFile,
+    len: usize,
+    _item: PhantomData<T>,
+}
+
+impl<T: Serialize> SpillQueue<T> {
+    /// 아이템들을 임시 파일에 기록한다.
+    pub fn write(items: &[T]) -> io::Result<Self> {
+        let file = SpillFile::create()?;
+        let mut writer = BufWriter::new(File::create(&file.path)?);
+        for item in items {
+            serde_json::to_writer(&mut writer, item)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(Self { file, len: items.len(), _item: PhantomData })
+    }
+}
+
+impl<T> SpillQueue<T> {
+    /// 기록된 아이템의 개수
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: DeserializeOwned> IntoIterator for SpillQueue<T> {
+    type Item = T;
+    type IntoIter = SpillIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let lines = File::open(&self.file.path)
+            .map(|file| BufReader::new(file).lines())
+            .map_err(|e| error!("Failed to open spill file({}): {}", self.file.path.display(), e))
+            .ok();
+        SpillIter { _file: self.file, lines, _item: PhantomData }
+    }
+}
+
+/// [`SpillQueue`]에 기록된 아이템을 한 줄씩 읽어 반환하는 이터레이터
+///
+/// # Note
+/// 파일을 읽거나 아이템으로 변환하지 못한 줄은 에러 로그를 남기고 건너뛴다.
+pub struct SpillIter<T> {
+    _file: SpillFile,
+    lines: Option<Lines<BufReader<File>>>,
+    _item: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> Iterator for SpillIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let lines = self.lines.as_mut()?;
+        for line in lines.by_ref() {
+            let parsed = line
+                .map_err(|e| e.to_string())
+                .and_then(|line| serde_json::from_str(&line).map_err(|e| e.to_string()));
+            match parsed {
+                Ok(item) => return Some(item),
+                Err(e) => error!("Failed to read spilled item: {}", e),
+            }
+        }
+        None
+    }
+}
+
+/// 해제될 때 삭제되는 임시 파일
+struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    fn create() -> io::Result<Self> {
+        static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+        let name = format!("book-batch-spill-{}-{}.jsonl", process::id(), SEQUENCE.fetch_add(1, Ordering::Relaxed));
+        let path = env::temp_dir().join(name);
+        File::options().write(true).create_new(true).open(&path)?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            warn!("Failed to remove spill file({}): {}", self.path.display(), e);
+        }
+    }
+}
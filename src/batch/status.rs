@@ -0,0 +1,170 @@
+use crate::batch::book::retrieve_publisher_id_in_parameter;
+use crate::batch::error::JobBuildError;
+use crate::batch::JobParameter;
+use crate::clock::system_clock;
+use crate::item::{CollectionStatus, Publisher, SharedCollectionStatusRepository, SharedPublisherRepository, Site};
+use crate::PARAM_NAME_STALE_DAYS;
+use chrono::{NaiveDateTime, TimeDelta};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// 수집 기록이 오래 되었다고 판단할 기본 기간(일)
+pub const DEFAULT_STALE_DAYS: i64 = 7;
+
+/// 수집 현황표에 표시할 사이트 순서
+const STATUS_SITES: [Site; 4] = [Site::NLGO, Site::Aladin, Site::Naver, Site::KyoboBook];
+
+/// 오래 된 수집 기록 표시
+const STALE_MARK: &str = "!";
+
+thread_local! {
+    /// 현재 실행 중인 잡에서 수집한 사이트, 출판사별 도서 수
+    static COLLECTED: RefCell<HashMap<(Site, u64), usize>> = RefCell::new(HashMap::new());
+}
+
+/// 사이트, 출판사의 수집 결과를 기록한다. 같은 잡에서 여러번 기록할 경우 도서 수를 합산한다.
+///
+/// # Note
+/// 기록은 잡이 정상적으로 끝난 후 [`take`]로 가져와 저장소에 저장한다.
+/// 잡이 실패한 경우 저장하지 않으므로 마지막으로 성공한 수집 기록이 유지된다.
+pub fn record_collected(site: Site, publisher_id: u64, count: usize) {
+    COLLECTED.with(|collected| {
+        *collected.borrow_mut().entry((site, publisher_id)).or_insert(0) += count;
+    });
+}
+
+/// 지금까지 기록된 수집 결과를 `collected_at` 시각의 수집 기록으로 반환하고 초기화 한다.
+pub fn take(collected_at: NaiveDateTime) -> Vec<CollectionStatus> {
+    COLLECTED.with(|collected| collected.take())
+        .into_iter()
+        .map(|((site, publisher_id), count)| CollectionStatus::new(site, publisher_id, collected_at, count))
+        .collect()
+}
+
+/// 사이트, 출판사별 수집 현황표를 만든다.
+///
+/// # Description
+/// 출판사를 행으로, 출판사 키워드가 있거나 수집 기록이 있는 사이트를 열로 하여 마지막 수집 시각과 조회된 도서 수를 표시한다.
+/// 마지막 수집 시각이 `now`로 부터 `stale_after` 이상 지났거나 키워드가 있지만 수집 기록이 없는 조합은 `!`로 표시한다.
+/// 키워드도 수집 기록도 없는 조합은 `-`로 표시한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::status::render_status;
+/// use book_batch_rust::item::{CollectionStatus, Publisher, Site};
+/// use chrono::{NaiveDate, TimeDelta};
+/// use std::collections::HashMap;
+///
+/// let now = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let publishers = vec![
+///     Publisher::new(1, "fresh".to_owned(), HashMap::from([(Site::NLGO, vec!["fresh".to_owned()])])),
+///     Publisher::new(2, "stale".to_owned(), HashMap::from([(Site::NLGO, vec!["stale".to_owned()])])),
+/// ];
+/// let statuses = vec![
+///     CollectionStatus::new(Site::NLGO, 1, now - TimeDelta::days(1), 12),
+///     CollectionStatus::new(Site::NLGO, 2, now - TimeDelta::days(30), 3),
+/// ];
+///
+/// let (table, stale) = render_status(&publishers, &statuses, now, TimeDelta::days(7));
+/// assert_eq!(stale, 1);
+/// assert!(table.contains("2025-06-09 00:00 (12)"));
+/// assert!(table.contains("! 2025-05-11 00:00 (3)"));
+/// ```
+pub fn render_status(
+    publishers: &[Publisher],
+    statuses: &[CollectionStatus],
+    now: NaiveDateTime,
+    stale_after: TimeDelta,
+) -> (String, usize) {
+    let status_map = statuses.iter()
+        .map(|status| ((*status.site(), status.publisher_id()), status))
+        .collect::<HashMap<_, _>>();
+    let sites = STATUS_SITES.into_iter()
+        .filter(|site| publishers.iter().any(|publisher| {
+            publisher.keywords().get(site).is_some_and(|k| !k.is_empty())
+                || status_map.contains_key(&(*site, publisher.id()))
+        }))
+        .collect::<Vec<_>>();
+
+    let mut stale = 0;
+    let mut rows = vec![
+        std::iter::once("PUBLISHER".to_owned())
+            .chain(sites.iter().map(|site| site.to_string()))
+            .collect::<Vec<_>>()
+    ];
+    for publisher in publishers {
+        let mut row = vec![format!("{}({})", publisher.name(), publisher.id())];
+        for site in sites.iter() {
+            let has_keyword = publisher.keywords().get(site).is_some_and(|k| !k.is_empty());
+            let cell = match status_map.get(&(*site, publisher.id())) {
+                Some(status) => {
+                    let text = format!("{} ({})", status.collected_at().format("%Y-%m-%d %H:%M"), status.result_count());
+                    if now - status.collected_at() >= stale_after {
+                        stale += 1;
+                        format!("{} {}", STALE_MARK, text)
+                    } else {
+                        text
+                    }
+                }
+                None if has_keyword => {
+                    stale += 1;
+                    format!("{} never", STALE_MARK)
+                }
+                None => "-".to_owned(),
+            };
+            row.push(cell);
+        }
+        rows.push(row);
+    }
+
+    let widths = (0..=sites.len())
+        .map(|col| rows.iter().map(|row| row[col].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let table = rows.iter()
+        .map(|row| row.iter().zip(widths.iter())
+            .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - cell.chars().count())))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_owned())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (table, stale)
+}
+
+/// [`JobParameter`]에서 수집 기록이 오래 되었다고 판단할 기간(`stale_days`)을 얻는다. 파라미터가 없을 경우 기본값(7일)을 반환한다.
+pub fn retrieve_stale_days_in_parameter(params: &JobParameter) -> Result<i64, JobBuildError> {
+    params.get(PARAM_NAME_STALE_DAYS)
+        .map(|v| v.trim().parse::<i64>()
+            .map_err(|e| JobBuildError::InvalidParameter(format!("{}: {}", PARAM_NAME_STALE_DAYS, e))))
+        .transpose()
+        .map(|days| days.unwrap_or(DEFAULT_STALE_DAYS))
+}
+
+/// 사이트, 출판사별 수집 현황표를 표준 출력으로 출력한다.
+///
+/// # Description
+/// `publisher_id` 파라미터가 있을 경우 입력된 출판사만, 없을 경우 모든 출판사의 수집 현황을 출력한다.
+pub fn print_status(
+    pub_repo: SharedPublisherRepository,
+    status_repo: SharedCollectionStatusRepository,
+    params: &JobParameter,
+) -> Result<(), JobBuildError> {
+    let stale_days = retrieve_stale_days_in_parameter(params)?;
+    let publisher_id = retrieve_publisher_id_in_parameter(params)
+        .map_err(|e| JobBuildError::InvalidParameter(e.to_string()))?;
+    let publishers = if !publisher_id.is_empty() {
+        pub_repo.find_by_id(&publisher_id)
+    } else {
+        pub_repo.get_all()
+    };
+
+    let statuses = status_repo.find_all();
+    let now = system_clock().now();
+    let (table, stale) = render_status(&publishers, &statuses, now, TimeDelta::days(stale_days));
+
+    println!("{}", table);
+    println!();
+    println!("{} stale combination(s), not collected for {} day(s) ({} = stale)", stale, stale_days, STALE_MARK);
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::item::Book;
+use chrono::NaiveDateTime;
+use std::cell::RefCell;
+use std::process;
+use tracing::{info, info_span};
+
+/// 외부 요청(프롬프트 등)에 추적 아이디를 전달할 때 사용하는 헤더
+pub const TRACE_ID_HEADER: &str = "X-Trace-Id";
+
+thread_local! {
+    /// 현재 실행 중인 잡의 실행 아이디
+    static RUN_ID: RefCell<Option<String>> = RefCell::new(None);
+
+    /// 현재 처리 중인 아이템의 추적 아이디
+    static CURRENT: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// 잡 실행을 시작하고 실행 아이디를 반환한다. 이후 만들어지는 추적 아이디는 이 실행 아이디로 만든다.
+///
+/// # Description
+/// 실행 아이디는 `{잡}-{시작 시각}-{프로세스 아이디}` 형식이며, 같은 ISBN이라도 실행마다 다른 추적 아이디를 가지도록 한다.
+pub fn start_run(job_name: &str, started_at: &NaiveDateTime) -> String {
+    let run_id = format!("{}-{}-{}", job_name, started_at.format("%Y%m%d%H%M%S"), process::id());
+    RUN_ID.with(|current| current.replace(Some(run_id.clone())));
+    info!("{} => Run started: {}", job_name, run_id);
+    run_id
+}
+
+/// 현재 실행 중인 잡의 실행 아이디, 실행을 시작하지 않은 경우 `None`을 반환한다.
+pub fn run_id() -> Option<String> {
+    RUN_ID.with(|current| current.borrow().clone())
+}
+
+/// 실행 아이디와 ISBN으로 추적 아이디를 만든다.
+///
+/// # Description
+/// 추적 아이디는 실행 아이디와 ISBN의 FNV-1a 해시를 16자리 16진수로 표기한 값으로, 같은 입력에 대해 항상 같은 값을 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::trace::trace_id;
+///
+/// let id = trace_id("NLGO-20250601000000-42", "9791133478410");
+/// assert_eq!(id.len(), 16);
+/// assert_eq!(id, trace_id("NLGO-20250601000000-42", "9791133478410"));
+/// assert_ne!(id, trace_id("NLGO-20250601000000-42", "9791133478427"));
+/// assert_ne!(id, trace_id("NLGO-20250602000000-42", "9791133478410"));
+/// ```
+pub fn trace_id(run_id: &str, isbn: &str) -> String {
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let hash = run_id.bytes()
+        .chain(std::iter::once(b'/'))
+        .chain(isbn.bytes())
+        .fold(FNV_OFFSET, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME));
+    format!("{:016x}", hash)
+}
+
+/// 현재 실행 아이디([`run_id`])와 ISBN으로 추적 아이디를 만든다.
+pub fn item_trace_id(isbn: &str) -> String {
+    trace_id(&run_id().unwrap_or_default(), isbn)
+}
+
+/// 도서 아이템의 추적 아이디에 사용할 ISBN을 반환한다. 도서 잡의 [`crate::batch::Job::with_trace_key`]에 사용한다.
+pub fn book_trace_key(book: &Book) -> String {
+    book.isbn().to_owned()
+}
+
+/// 현재 처리 중인 아이템의 추적 아이디, 아이템을 처리 중이 아닌 경우 `None`을 반환한다.
+pub fn current_trace_id() -> Option<String> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// ISBN의 추적 아이디를 현재 아이템으로 설정하고 `f`를 실행한다.
+///
+/// # Description
+/// `f`가 실행되는 동안 남기는 로그는 `trace_id`, `isbn` 필드를 가진 `item` 스팬 안에서 출력되며,
+/// 프롬프트 요청 등 외부 요청과 에러 기록은 [`current_trace_id`]로 추적 아이디를 얻어 함께 남긴다.
+/// 따라서 추적 아이디로 로그를 검색하면 도서 한 권이 잡을 거쳐간 과정을 처음부터 끝까지 확인할 수 있다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::trace::{current_trace_id, item_trace_id, traced};
+///
+/// assert_eq!(current_trace_id(), None);
+/// let inside = traced("9791133478410", || current_trace_id());
+/// assert_eq!(inside, Some(item_trace_id("9791133478410")));
+/// assert_eq!(current_trace_id(), None);
+/// ```
+pub fn traced<T, F: FnOnce() -> T>(isbn: &str, f: F) -> T {
+    let id = item_trace_id(isbn);
+    let span = info_span!("item", trace_id = %id, isbn = %isbn);
+    let _entered = span.enter();
+
+    let previous = CURRENT.with(|current| current.replace(Some(id)));
+    let result = f();
+    CURRENT.with(|current| current.replace(previous));
+    result
+}
+
+/// 에러 기록에 남길 메시지에 현재 추적 아이디를 붙인다. 아이템을 처리 중이 아닌 경우 메시지를 그대로 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::trace::{item_trace_id, traced, with_trace_id};
+///
+/// assert_eq!(with_trace_id("timeout"), "timeout");
+/// let message = traced("9791133478410", || with_trace_id("timeout"));
+/// assert_eq!(message, format!("[trace:{}] timeout", item_trace_id("9791133478410")));
+/// ```
+pub fn with_trace_id(message: &str) -> String {
+    match current_trace_id() {
+        Some(id) => format!("[trace:{}] {}", id, message),
+        None => message.to_owned(),
+    }
+}
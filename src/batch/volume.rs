@@ -0,0 +1,213 @@
+use crate::batch::audit::JobAudit;
+use crate::item::{CollectionStatus, CollectionVolume, SharedVolumeRepository};
+use chrono::NaiveDateTime;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use tracing::{info, warn};
+
+/// 수집량이 평균에서 벗어났다고 판단할 기본 배수
+pub const DEFAULT_ALERT_FACTOR: f64 = 5.0;
+
+/// 평균을 계산할 최근 실행 기본 개수
+pub const DEFAULT_ALERT_WINDOW: usize = 7;
+
+/// 평균을 계산하기 위해 필요한 최소 실행 기록 개수
+pub const DEFAULT_ALERT_MIN_HISTORY: usize = 3;
+
+/// 이상 여부를 확인할 최소 평균 도서 수, 평균이 이보다 작은 경우 수집량 변화가 커도 확인하지 않는다.
+pub const DEFAULT_ALERT_MIN_BASELINE: f64 = 10.0;
+
+/// 비교한 수집량의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeMetric {
+    /// 조회된 도서 수
+    Read,
+    /// 새로 저장된 도서 수
+    Write,
+}
+
+impl fmt::Display for VolumeMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VolumeMetric::Read => write!(f, "READ"),
+            VolumeMetric::Write => write!(f, "WRITE"),
+        }
+    }
+}
+
+/// 최근 실행들의 평균에서 벗어난 수집량
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeAnomaly {
+    pub job_name: String,
+    pub publisher_id: u64,
+    pub metric: VolumeMetric,
+    /// 이번 실행의 도서 수
+    pub count: usize,
+    /// 최근 실행들의 평균 도서 수
+    pub baseline: f64,
+}
+
+/// 수집량 이상 판단 기준
+///
+/// # Description
+/// 환경 변수로 기준을 변경할 수 있으며 설정 하지 않을 경우 기본값을 사용한다.
+/// - `VOLUME_ALERT_FACTOR`: 수집량이 평균의 `1/배수` 보다 작거나 `배수` 보다 크면 이상으로 판단 (기본값 5)
+/// - `VOLUME_ALERT_WINDOW`: 평균을 계산할 최근 실행 개수 (기본값 7)
+/// - `VOLUME_ALERT_MIN_BASELINE`: 확인할 최소 평균 도서 수 (기본값 10)
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumePolicy {
+    pub factor: f64,
+    pub window: usize,
+    pub min_history: usize,
+    pub min_baseline: f64,
+}
+
+impl Default for VolumePolicy {
+    fn default() -> Self {
+        Self {
+            factor: DEFAULT_ALERT_FACTOR,
+            window: DEFAULT_ALERT_WINDOW,
+            min_history: DEFAULT_ALERT_MIN_HISTORY,
+            min_baseline: DEFAULT_ALERT_MIN_BASELINE,
+        }
+    }
+}
+
+impl VolumePolicy {
+    /// 환경 변수에서 판단 기준을 읽는다. 1 보다 작은 배수와 0 이하의 실행 개수는 무시하고 기본값을 사용한다.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            factor: env::var("VOLUME_ALERT_FACTOR").ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| *v > 1.0)
+                .unwrap_or(default.factor),
+            window: env::var("VOLUME_ALERT_WINDOW").ok()
+                .and_then(|v| v.trim().parse::<usize>().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(default.window),
+            min_baseline: env::var("VOLUME_ALERT_MIN_BASELINE").ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .unwrap_or(default.min_baseline),
+            ..default
+        }
+    }
+}
+
+/// 이번 실행의 사이트, 출판사별 수집 기록과 변경 내역으로 출판사별 수집량을 만든다.
+/// 여러 사이트에서 조회한 경우 조회된 도서 수를 합산한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::audit::JobAudit;
+/// use book_batch_rust::batch::volume::collect_volumes;
+/// use book_batch_rust::item::{CollectionStatus, Site};
+/// use chrono::NaiveDate;
+///
+/// let now = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let collected = vec![CollectionStatus::new(Site::NLGO, 1, now, 120), CollectionStatus::new(Site::NLGO, 2, now, 0)];
+/// let mut audit = JobAudit::default();
+/// audit.created_by_publisher.insert(1, 4);
+///
+/// let volumes = collect_volumes("NLGO", &collected, &audit, now);
+/// assert_eq!(volumes.len(), 2);
+/// assert_eq!((volumes[0].read_count(), volumes[0].write_count()), (120, 4));
+/// assert_eq!((volumes[1].read_count(), volumes[1].write_count()), (0, 0));
+/// ```
+pub fn collect_volumes(job_name: &str, collected: &[CollectionStatus], audit: &JobAudit, executed_at: NaiveDateTime) -> Vec<CollectionVolume> {
+    let mut read_counts = BTreeMap::new();
+    for status in collected {
+        *read_counts.entry(status.publisher_id()).or_insert(0) += status.result_count();
+    }
+    read_counts.into_iter()
+        .map(|(publisher_id, read_count)| {
+            let write_count = audit.created_by_publisher.get(&publisher_id).copied().unwrap_or(0);
+            CollectionVolume::new(job_name, publisher_id, executed_at, read_count, write_count)
+        })
+        .collect()
+}
+
+/// 이번 실행의 수집량을 최근 실행들의 평균과 비교하여 벗어난 수집량을 반환한다.
+///
+/// # Description
+/// 조회된 도서 수와 새로 저장된 도서 수를 각각 비교하며, 평균을 계산할 실행 기록이 부족하거나 평균이 너무 작은 경우 확인하지 않는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::volume::{check_volume, VolumeMetric, VolumePolicy};
+/// use book_batch_rust::item::CollectionVolume;
+/// use chrono::NaiveDate;
+///
+/// let now = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let history = vec![
+///     CollectionVolume::new("NLGO", 1, now, 100, 2),
+///     CollectionVolume::new("NLGO", 1, now, 120, 3),
+///     CollectionVolume::new("NLGO", 1, now, 80, 1),
+/// ];
+///
+/// let normal = CollectionVolume::new("NLGO", 1, now, 90, 0);
+/// assert!(check_volume(&normal, &history, &VolumePolicy::default()).is_empty());
+///
+/// let broken = CollectionVolume::new("NLGO", 1, now, 0, 0);
+/// let anomalies = check_volume(&broken, &history, &VolumePolicy::default());
+/// assert_eq!(anomalies.len(), 1);
+/// assert_eq!(anomalies[0].metric, VolumeMetric::Read);
+/// assert_eq!(anomalies[0].baseline, 100.0);
+/// ```
+pub fn check_volume(current: &CollectionVolume, history: &[CollectionVolume], policy: &VolumePolicy) -> Vec<VolumeAnomaly> {
+    if history.len() < policy.min_history {
+        return Vec::new();
+    }
+
+    [VolumeMetric::Read, VolumeMetric::Write].into_iter()
+        .filter_map(|metric| {
+            let count_of = |volume: &CollectionVolume| match metric {
+                VolumeMetric::Read => volume.read_count(),
+                VolumeMetric::Write => volume.write_count(),
+            };
+            let baseline = history.iter().map(count_of).sum::<usize>() as f64 / history.len() as f64;
+            let count = count_of(current);
+            if baseline < policy.min_baseline {
+                return None;
+            }
+            let deviated = (count as f64) * policy.factor < baseline || (count as f64) > baseline * policy.factor;
+            deviated.then(|| VolumeAnomaly {
+                job_name: current.job_name().to_owned(),
+                publisher_id: current.publisher_id(),
+                metric,
+                count,
+                baseline,
+            })
+        })
+        .collect()
+}
+
+/// 이번 실행의 수집량을 최근 실행들과 비교하여 이상이 있는 경우 알린 후 수집량을 기록한다.
+///
+/// # Description
+/// 평균에서 벗어난 수집량은 잡, 출판사 아이디, 종류, 도서 수, 평균을 필드로 가진 경고 로그(`alert = "VOLUME_ANOMALY"`)로 남겨
+/// 사이트 응답 형식이 바뀌어 조회 결과가 0건이 되는 등 오류 없이 수집이 멈춘 경우를 알 수 있도록 한다.
+pub fn check_and_record(volume_repo: &SharedVolumeRepository, volumes: &[CollectionVolume], policy: &VolumePolicy) -> Vec<VolumeAnomaly> {
+    let mut anomalies = Vec::new();
+    for volume in volumes {
+        let history = volume_repo.find_recent(volume.job_name(), volume.publisher_id(), policy.window);
+        anomalies.extend(check_volume(volume, &history, policy));
+    }
+
+    for anomaly in anomalies.iter() {
+        warn!(
+            alert = "VOLUME_ANOMALY",
+            job = %anomaly.job_name,
+            publisher_id = anomaly.publisher_id,
+            metric = %anomaly.metric,
+            count = anomaly.count,
+            baseline = anomaly.baseline,
+            "{} => Anomalous {} volume for publisher {}: {} (baseline {:.1})", anomaly.job_name, anomaly.metric, anomaly.publisher_id, anomaly.count, anomaly.baseline
+        );
+    }
+
+    let saved = volume_repo.save_volumes(volumes);
+    info!("{} collection volumes recorded, {} anomalies detected", saved, anomalies.len());
+    anomalies
+}
@@ -0,0 +1,327 @@
+//! 장애 주입(chaos) 모드
+//!
+//! `chaos` 기능(feature)을 활성화 했을 때만 컴파일 되며, 사이트 API 클라이언트, 저장소, 프롬프트를 감싸
+//! 설정된 확률로 실패와 지연을 주입한다. 운영에 적용하기 전 재시도, 건너뛰기, 이어서 수집하기 등의 장애 처리가
+//! 의도대로 동작하는지 확인하는 용도로만 사용한다.
+//!
+//! ```text
+//! $ CHAOS_FAILURE_RATE=0.3 CHAOS_MAX_LATENCY_MS=500 cargo run --features chaos -- --job NAVER
+//! ```
+use crate::item::{Book, BookBuilder, BookRepository, Originals, SharedBookRepository, Site};
+use crate::prompt::{Error, NormalizeRequest, Normalized, Prompt, SeriesSelectRequest, SeriesSimilarRequest, SharedPrompt};
+use crate::provider::api::{Client, ClientError, ItemListClient, LookupClient, Response};
+use crate::provider::html;
+use crate::provider::html::ParsingError;
+use std::cell::Cell;
+use std::env;
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+use tracing::warn;
+
+/// 기본 실패 주입 확률
+const DEFAULT_FAILURE_RATE: f64 = 0.1;
+
+/// 주입된 실패의 메시지
+const INJECTED_FAILURE: &str = "chaos: injected failure";
+
+/// 장애 주입 설정
+///
+/// # Description
+/// 환경 변수에서 설정을 읽는다.
+/// - `CHAOS_FAILURE_RATE`: 호출이 실패할 확률 (0.0 ~ 1.0, 기본값 0.1)
+/// - `CHAOS_MAX_LATENCY_MS`: 호출마다 주입할 최대 지연 시간(밀리초), 0 ~ 설정값 사이에서 무작위로 지연된다. (기본값 0)
+/// - `CHAOS_SEED`: 난수 시드, 같은 시드를 사용하면 같은 순서로 실패가 주입된다. (기본값 현재 시각)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChaosConfig {
+    pub failure_rate: f64,
+    pub max_latency: Duration,
+    pub seed: u64,
+}
+
+impl ChaosConfig {
+    pub fn from_env() -> Self {
+        let failure_rate = env::var("CHAOS_FAILURE_RATE").ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .map(|rate| rate.clamp(0.0, 1.0))
+            .unwrap_or(DEFAULT_FAILURE_RATE);
+        let max_latency = env::var("CHAOS_MAX_LATENCY_MS").ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+        let seed = env::var("CHAOS_SEED").ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .unwrap_or_else(|| chrono::Local::now().timestamp_nanos_opt().unwrap_or(0) as u64);
+
+        Self { failure_rate, max_latency: Duration::from_millis(max_latency), seed }
+    }
+}
+
+pub type SharedChaos = Rc<Chaos>;
+
+/// 실패와 지연을 주입할지 결정하는 난수 생성기
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use book_batch_rust::chaos::{Chaos, ChaosConfig};
+///
+/// let always = Chaos::new(ChaosConfig { failure_rate: 1.0, max_latency: Duration::ZERO, seed: 42 });
+/// assert!(always.strike("TEST"));
+///
+/// let never = Chaos::new(ChaosConfig { failure_rate: 0.0, max_latency: Duration::ZERO, seed: 42 });
+/// assert!(!never.strike("TEST"));
+/// ```
+#[derive(Debug)]
+pub struct Chaos {
+    config: ChaosConfig,
+    state: Cell<u64>,
+}
+
+impl Chaos {
+    pub fn new(config: ChaosConfig) -> Self {
+        // xorshift는 상태가 0이면 계속 0을 반환하므로 0은 사용하지 않는다.
+        let state = if config.seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { config.seed };
+        Self { config, state: Cell::new(state) }
+    }
+
+    pub fn with_env() -> Self {
+        Self::new(ChaosConfig::from_env())
+    }
+
+    /// 대상 호출에 지연을 주입하고 실패를 주입할지 여부를 반환한다.
+    pub fn strike(&self, target: &str) -> bool {
+        if !self.config.max_latency.is_zero() {
+            let latency = self.config.max_latency.mul_f64(self.next_f64());
+            thread::sleep(latency);
+        }
+
+        let failed = self.next_f64() < self.config.failure_rate;
+        if failed {
+            warn!("{} => {}", target, INJECTED_FAILURE);
+        }
+        failed
+    }
+
+    /// 0.0 이상 1.0 미만의 난수를 반환한다. (xorshift64)
+    fn next_f64(&self) -> f64 {
+        let mut x = self.state.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state.set(x);
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// 장애를 주입하는 사이트 클라이언트
+///
+/// # Description
+/// API 클라이언트([`Client`], [`LookupClient`], [`ItemListClient`])와 HTML 클라이언트([`html::Client`])를 감싸 호출 전 실패와 지연을 주입한다.
+/// 주입된 실패는 요청 실패([`ClientError::RequestFailed`], [`ParsingError::RequestFailed`])로 반환된다.
+pub struct ChaosClient<C> {
+    inner: C,
+    target: String,
+    chaos: SharedChaos,
+}
+
+impl<C> ChaosClient<C> {
+    pub fn new(inner: C, target: &str, chaos: SharedChaos) -> Self {
+        Self { inner, target: target.to_owned(), chaos }
+    }
+}
+
+impl<C: Client> Client for ChaosClient<C> {
+    type Request = C::Request;
+
+    fn get_books(&self, request: &C::Request) -> Result<Response, ClientError> {
+        if self.chaos.strike(&self.target) {
+            return Err(ClientError::RequestFailed(INJECTED_FAILURE.to_owned()));
+        }
+        self.inner.get_books(request)
+    }
+}
+
+impl<C: LookupClient> LookupClient for ChaosClient<C> {
+    fn site(&self) -> Site {
+        self.inner.site()
+    }
+
+    fn lookup(&self, isbn: &str) -> Result<Response, ClientError> {
+        if self.chaos.strike(&self.target) {
+            return Err(ClientError::RequestFailed(INJECTED_FAILURE.to_owned()));
+        }
+        self.inner.lookup(isbn)
+    }
+}
+
+impl<C: ItemListClient> ItemListClient for ChaosClient<C> {
+    type Request = C::Request;
+
+    fn item_list(&self, request: &C::Request) -> Result<Response, ClientError> {
+        if self.chaos.strike(&self.target) {
+            return Err(ClientError::RequestFailed(INJECTED_FAILURE.to_owned()));
+        }
+        self.inner.item_list(request)
+    }
+}
+
+impl<C: html::Client> html::Client for ChaosClient<C> {
+    fn get(&self, isbn: &str) -> Result<BookBuilder, ParsingError> {
+        if self.chaos.strike(&self.target) {
+            return Err(ParsingError::RequestFailed(INJECTED_FAILURE.to_owned()));
+        }
+        self.inner.get(isbn)
+    }
+}
+
+/// 장애를 주입하는 도서 저장소
+///
+/// # Description
+/// 저장소 호출 전 실패와 지연을 주입한다. 저장소는 에러를 반환하지 않고 로그를 남긴 후 기본값을 반환하므로
+/// 주입된 실패도 빈 결과(조회) 혹은 0건(저장)으로 반환된다.
+pub struct ChaosBookRepository {
+    inner: SharedBookRepository,
+    chaos: SharedChaos,
+}
+
+impl ChaosBookRepository {
+    pub fn new(inner: SharedBookRepository, chaos: SharedChaos) -> Self {
+        Self { inner, chaos }
+    }
+
+    fn strike(&self) -> bool {
+        self.chaos.strike("BOOK_REPOSITORY")
+    }
+}
+
+impl BookRepository for ChaosBookRepository {
+    fn find_by_pub_between(&self, from: &chrono::NaiveDate, to: &chrono::NaiveDate) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.find_by_pub_between(from, to)
+    }
+
+    fn find_by_isbn(&self, isbn: &[&str]) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.find_by_isbn(isbn)
+    }
+
+    fn save_books(&self, books: &[Book]) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.save_books(books)
+    }
+
+    fn update_book(&self, book: &Book) -> usize {
+        if self.strike() { return 0; }
+        self.inner.update_book(book)
+    }
+
+    fn update_origins(&self, book_id: u64, originals: &Originals) -> usize {
+        if self.strike() { return 0; }
+        self.inner.update_origins(book_id, originals)
+    }
+
+    fn is_origin_update_enabled(&self) -> bool {
+        self.inner.is_origin_update_enabled()
+    }
+
+    fn find_series_unorganized(&self, limit: usize) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.find_series_unorganized(limit)
+    }
+
+    fn find_by_series_id(&self, series_id: u64) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.find_by_series_id(series_id)
+    }
+
+    fn find_series_linked_by_publisher(&self, publisher_id: u64) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.find_series_linked_by_publisher(publisher_id)
+    }
+
+    fn find_title_unnormalized(&self, limit: usize) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.find_title_unnormalized(limit)
+    }
+
+    fn find_by_registered_between(&self, from: &chrono::NaiveDateTime, to: &chrono::NaiveDateTime) -> Vec<Book> {
+        if self.strike() { return Vec::new(); }
+        self.inner.find_by_registered_between(from, to)
+    }
+
+    fn unlink_series(&self, book_id: u64) -> usize {
+        if self.strike() { return 0; }
+        self.inner.unlink_series(book_id)
+    }
+
+    fn save_vector(&self, book_id: u64, vec: &[f32]) -> usize {
+        if self.strike() { return 0; }
+        self.inner.save_vector(book_id, vec)
+    }
+
+    fn similar_books(&self, vec: &[f32], limit: i32) -> Vec<(Book, f64)> {
+        if self.strike() { return Vec::new(); }
+        self.inner.similar_books(vec, limit)
+    }
+
+    fn delete_books(&self, book_id: &[u64]) -> usize {
+        if self.strike() { return 0; }
+        self.inner.delete_books(book_id)
+    }
+}
+
+/// 장애를 주입하는 프롬프트
+///
+/// # Description
+/// 프롬프트 호출 전 실패와 지연을 주입한다. 주입된 실패는 연동 실패([`Error::ConnectFailed`])로 반환된다.
+pub struct ChaosPrompt {
+    inner: SharedPrompt,
+    chaos: SharedChaos,
+}
+
+impl ChaosPrompt {
+    pub fn new(inner: SharedPrompt, chaos: SharedChaos) -> Self {
+        Self { inner, chaos }
+    }
+
+    fn strike(&self) -> Result<(), Error> {
+        if self.chaos.strike("PROMPT") {
+            Err(Error::ConnectFailed(INJECTED_FAILURE.to_owned()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Prompt for ChaosPrompt {
+    fn normalize(&self, request: &NormalizeRequest) -> Result<Normalized, Error> {
+        self.strike()?;
+        self.inner.normalize(request)
+    }
+
+    fn normalize_batch(&self, request: &[NormalizeRequest]) -> Vec<Result<Normalized, Error>> {
+        if let Err(err) = self.strike() {
+            return request.iter().map(|_| Err(err.clone())).collect();
+        }
+        self.inner.normalize_batch(request)
+    }
+
+    fn embedding(&self, request: &[String]) -> Result<Vec<Vec<f32>>, Error> {
+        self.strike()?;
+        self.inner.embedding(request)
+    }
+
+    fn series_similar(&self, request: &SeriesSimilarRequest) -> Result<bool, Error> {
+        self.strike()?;
+        self.inner.series_similar(request)
+    }
+
+    fn series_select(&self, request: &SeriesSelectRequest) -> Result<Option<u64>, Error> {
+        self.strike()?;
+        self.inner.series_select(request)
+    }
+
+    fn prompt_version(&self) -> Option<String> {
+        self.inner.prompt_version()
+    }
+}
@@ -0,0 +1,162 @@
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+use std::cell::Cell;
+use std::env;
+use std::rc::Rc;
+use tracing::warn;
+
+/// 기본 시간대, 도서 정보 제공 사이트들의 날짜는 한국 표준시(KST) 기준이다.
+pub const DEFAULT_TIMEZONE: &str = "Asia/Seoul";
+
+/// 시간대 이름 또는 UTC 오프셋을 고정 오프셋으로 변환한다. 변환할 수 없는 경우 `None`을 반환한다.
+///
+/// # Description
+/// 서머타임이 없는 시간대 이름(`Asia/Seoul`, `KST`, `Asia/Tokyo`, `UTC`)과 `+09:00`, `+0900`, `-05` 형식의 오프셋을 지원한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::clock::parse_timezone;
+/// use chrono::FixedOffset;
+///
+/// assert_eq!(parse_timezone("Asia/Seoul"), FixedOffset::east_opt(9 * 3600));
+/// assert_eq!(parse_timezone("utc"), FixedOffset::east_opt(0));
+/// assert_eq!(parse_timezone("+09:00"), FixedOffset::east_opt(9 * 3600));
+/// assert_eq!(parse_timezone("-0530"), FixedOffset::west_opt(5 * 3600 + 30 * 60));
+/// assert_eq!(parse_timezone("Mars/Olympus"), None);
+/// ```
+pub fn parse_timezone(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    match value.to_ascii_lowercase().as_str() {
+        "asia/seoul" | "kst" | "asia/tokyo" | "jst" => return FixedOffset::east_opt(9 * 3600),
+        "utc" | "etc/utc" | "gmt" | "z" => return FixedOffset::east_opt(0),
+        _ => {}
+    }
+
+    let (sign, offset) = match value.chars().next()? {
+        '+' => (1, &value[1..]),
+        '-' => (-1, &value[1..]),
+        _ => return None,
+    };
+    let digits = offset.replace(':', "");
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (digits[..2].parse::<i32>().ok()?, digits[2..].parse::<i32>().ok()?),
+        _ => return None,
+    };
+    if minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// 환경 변수 `BATCH_TIMEZONE`에 설정된 시간대를 반환한다.
+/// 설정 되지 않았거나 변환할 수 없는 경우 기본 시간대([`DEFAULT_TIMEZONE`])를 사용한다.
+pub fn configured_timezone() -> FixedOffset {
+    let default = parse_timezone(DEFAULT_TIMEZONE).unwrap();
+    match env::var("BATCH_TIMEZONE") {
+        Ok(value) if !value.trim().is_empty() => parse_timezone(&value).unwrap_or_else(|| {
+            warn!("Invalid BATCH_TIMEZONE({}), {} is used", value, DEFAULT_TIMEZONE);
+            default
+        }),
+        _ => default,
+    }
+}
+
+/// 현재 시각을 제공하는 시계
+///
+/// # Description
+/// 기본 수집 기간, 등록/수정 시각 등 현재 시각이 필요한 곳은 `chrono::Local::now()`를 직접 호출하지 않고 시계를 주입 받아 사용한다.
+/// 시계가 반환하는 시각은 배치 서버의 로컬 시간대와 관계 없이 설정된 시간대([`configured_timezone`])의 시각이다.
+/// 실제 실행에는 [`SystemClock`]을, 결과가 항상 같아야 하는 테스트에는 [`FixedClock`]을 사용한다.
+pub trait Clock {
+
+    /// 현재 시각 (설정된 시간대)
+    fn now(&self) -> NaiveDateTime;
+
+    /// 오늘 날짜 (설정된 시간대)
+    fn today(&self) -> NaiveDate {
+        self.now().date()
+    }
+}
+
+pub type SharedClock = Rc<Box<dyn Clock>>;
+
+/// 시스템 시각을 지정된 시간대의 시각으로 반환하는 시계
+///
+/// # Description
+/// UTC 컨테이너 등 배치 서버의 로컬 시간대가 사이트의 시간대와 다르더라도 수집 기간과 저장 시각이 밀리지 않도록
+/// 로컬 시간대를 사용하지 않고 항상 지정된 시간대의 시각을 반환한다.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    offset: FixedOffset,
+}
+
+impl SystemClock {
+    pub fn new(offset: FixedOffset) -> Self {
+        Self { offset }
+    }
+}
+
+impl Default for SystemClock {
+    /// 환경 변수에 설정된 시간대([`configured_timezone`])를 사용한다.
+    fn default() -> Self {
+        Self::new(configured_timezone())
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().with_timezone(&self.offset).naive_local()
+    }
+}
+
+/// 설정된 시간대의 시스템 시각을 반환하는 공유 시계를 생성한다.
+pub fn system_clock() -> SharedClock {
+    SharedClock::new(Box::new(SystemClock::default()))
+}
+
+/// 지정된 시각을 반환하는 테스트용 시계
+///
+/// # Description
+/// 직접 옮기기 전까지 항상 같은 시각을 반환한다. 시간이 흐르는 상황은 [`FixedClock::advance`]로 표현한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::clock::{Clock, FixedClock};
+/// use chrono::{NaiveDate, TimeDelta};
+///
+/// let now = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap().and_hms_opt(23, 0, 0).unwrap();
+/// let clock = FixedClock::new(now);
+/// assert_eq!(clock.now(), now);
+///
+/// clock.advance(TimeDelta::hours(2));
+/// assert_eq!(clock.today(), NaiveDate::from_ymd_opt(2025, 2, 1).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedClock {
+    now: Cell<NaiveDateTime>,
+}
+
+impl FixedClock {
+    pub fn new(now: NaiveDateTime) -> Self {
+        Self { now: Cell::new(now) }
+    }
+
+    /// 시계를 `now`로 맞춘다.
+    pub fn set(&self, now: NaiveDateTime) {
+        self.now.set(now);
+    }
+
+    /// 시계를 `delta`만큼 옮긴다.
+    pub fn advance(&self, delta: TimeDelta) {
+        self.now.set(self.now.get() + delta);
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.now.get()
+    }
+}
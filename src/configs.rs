@@ -1,11 +1,301 @@
+use crate::batch::book::kyobo::KyoboConfig;
+use crate::batch::book::UpsertMode;
+use crate::batch::profile::PublisherProfiles;
+use crate::batch::series::SeriesConfig;
+use crate::item::repo::{IdStrategy, VectorSearch};
+use crate::prompt::bridge::BridgeServer;
+use crate::toggle::FeatureToggles;
+use crate::JobName;
 use diesel::r2d2::ConnectionManager;
 use diesel::PgConnection;
 use r2d2::Pool;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::env;
 use std::env::VarError;
 use mongodb::sync::Client;
 
 mod logging;
+pub mod migrate;
+pub mod proxy;
+pub mod query;
+
+/// 설정 파일 기본 경로 (확장자는 생략하며 `config.toml`, `config.json` 등을 찾는다.)
+const DEFAULT_CONFIG_FILE: &str = "config";
+
+/// 설정 파일의 값을 덮어쓸 환경 변수와 설정 키
+const ENV_OVERRIDES: [(&str, &str); 23] = [
+    ("ORIGIN_STORE", "origin_store"),
+    ("ID_STRATEGY", "id_strategy"),
+    ("UPSERT_MODE", "upsert_mode"),
+    ("BRIDGE_HOST", "prompt.host"),
+    ("BRIDGE_TIMEOUT", "prompt.timeout"),
+    ("BRIDGE_NORMALIZE_ENDPOINT", "prompt.normalize_endpoint"),
+    ("BRIDGE_NORMALIZE_BATCH_ENDPOINT", "prompt.normalize_batch_endpoint"),
+    ("BRIDGE_EMBEDDING_ENDPOINT", "prompt.embedding_endpoint"),
+    ("BRIDGE_SERIES_SIMILAR_ENDPOINT", "prompt.series_similar_endpoint"),
+    ("BRIDGE_SERIES_SELECT_ENDPOINT", "prompt.series_select_endpoint"),
+    ("BRIDGE_PROMPT_VERSION", "prompt.prompt_version"),
+    ("SERIES_READ_LIMIT", "series.read_limit"),
+    ("SERIES_SIMILAR_SCORE", "series.similar_score"),
+    ("SERIES_BELONG_SIMILAR_SCORE", "series.series_similar_score"),
+    ("SERIES_CHUNK_SIZE", "series.chunk_size"),
+    ("SERIES_BATCH_CHUNK_SIZE", "series.batch_chunk_size"),
+    ("SERIES_CANDIDATE_LIMIT", "series.candidate_limit"),
+    ("SERIES_TOP_K", "series.top_k"),
+    ("SERIES_SELECT_FIRST", "series.select_first"),
+    ("KYOBO_LOGIN", "kyobo.login"),
+    ("SERIES_VECTOR_SEARCH", "vector_search.mode"),
+    ("SERIES_VECTOR_EF_SEARCH", "vector_search.ef_search"),
+    ("SERIES_VECTOR_PROBES", "vector_search.probes"),
+];
+
+/// 배치 설정
+///
+/// # Description
+/// 환경 변수 `CONFIG_FILE`(기본값 `config`)에 설정된 파일에서 시리즈 잡의 기준값(`series`)과 브릿지 서버 연결 정보(`prompt`)를 읽는다.
+/// 카탈로그(만화, 일반 도서 등) 별로 다른 데이터베이스를 사용하는 경우 `profiles` 섹션에 프로필을 정의하고 `--profile`로 선택한다.
+/// 설정 파일이 없거나 입력하지 않은 값은 기본값을 사용하며, 환경 변수가 설정된 경우 설정 파일의 값보다 우선한다.
+///
+/// | 설정 키 | 환경 변수 |
+/// |---|---|
+/// | `origin_store` | `ORIGIN_STORE` |
+/// | `id_strategy` | `ID_STRATEGY` |
+/// | `upsert_mode` | `UPSERT_MODE` |
+/// | `prompt.host` | `BRIDGE_HOST` |
+/// | `prompt.timeout` | `BRIDGE_TIMEOUT` |
+/// | `prompt.normalize_endpoint` | `BRIDGE_NORMALIZE_ENDPOINT` |
+/// | `prompt.normalize_batch_endpoint` | `BRIDGE_NORMALIZE_BATCH_ENDPOINT` |
+/// | `prompt.embedding_endpoint` | `BRIDGE_EMBEDDING_ENDPOINT` |
+/// | `prompt.series_similar_endpoint` | `BRIDGE_SERIES_SIMILAR_ENDPOINT` |
+/// | `prompt.series_select_endpoint` | `BRIDGE_SERIES_SELECT_ENDPOINT` |
+/// | `prompt.prompt_version` | `BRIDGE_PROMPT_VERSION` |
+/// | `series.read_limit` | `SERIES_READ_LIMIT` |
+/// | `series.similar_score` | `SERIES_SIMILAR_SCORE` |
+/// | `series.series_similar_score` | `SERIES_BELONG_SIMILAR_SCORE` |
+/// | `series.chunk_size` | `SERIES_CHUNK_SIZE` |
+/// | `series.batch_chunk_size` | `SERIES_BATCH_CHUNK_SIZE` |
+/// | `series.candidate_limit` | `SERIES_CANDIDATE_LIMIT` |
+/// | `series.top_k` | `SERIES_TOP_K` |
+/// | `series.select_first` | `SERIES_SELECT_FIRST` |
+/// | `kyobo.login` | `KYOBO_LOGIN` |
+/// | `vector_search.mode` | `SERIES_VECTOR_SEARCH` |
+/// | `vector_search.ef_search` | `SERIES_VECTOR_EF_SEARCH` |
+/// | `vector_search.probes` | `SERIES_VECTOR_PROBES` |
+///
+/// # Example
+/// ```toml
+/// origin_store = "postgres"
+/// upsert_mode = "two_phase"
+///
+/// [series]
+/// similar_score = 0.92
+/// batch_chunk_size = 30
+///
+/// [vector_search]
+/// mode = "ann"
+/// ef_search = 100
+///
+/// [toggles.series_select_first]
+/// percentage = 10
+/// publishers = [20050726]
+///
+/// [[publisher_profile]]
+/// publisher_id = 20050726
+/// skip_filter = true
+/// description_site = ["KYOBO", "ALADIN"]
+///
+/// [prompt]
+/// host = "http://bridge:5000"
+/// timeout = 60000
+///
+/// [profiles.comics]
+/// database_url = "postgres://batch@localhost/comics"
+/// replica_url = "postgres://batch@replica/comics"
+///
+/// [profiles.comics.prompt]
+/// host = "http://comics-bridge:5000"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// 시리즈 잡 설정
+    pub series: SeriesConfig,
+
+    /// 교보문고 잡 설정
+    pub kyobo: KyoboConfig,
+
+    /// 브릿지 서버 연결 정보
+    pub prompt: BridgeServer,
+
+    /// 이름별 프로필
+    pub profiles: HashMap<String, Profile>,
+
+    /// 도서 원본 데이터 저장소
+    pub origin_store: OriginStoreKind,
+
+    /// 새 도서, 시리즈의 아이디 발급 방식 (`database`, `ulid`)
+    pub id_strategy: IdStrategy,
+
+    /// 도서 수집 잡의 도서 저장 방식 (`combined`, `two_phase`)
+    pub upsert_mode: UpsertMode,
+
+    /// 시리즈 유사도 검색 방식 (`exact`, `ann`)과 근사 검색 파라미터
+    pub vector_search: VectorSearch,
+
+    /// 이름별 실험 기능 토글 ([`crate::toggle`])
+    pub toggles: FeatureToggles,
+
+    /// 출판사별 처리 프로필 ([`crate::batch::profile::PublisherProfile`])
+    pub publisher_profile: PublisherProfiles,
+}
+
+/// 도서 원본 데이터를 저장할 저장소 종류
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OriginStoreKind {
+    /// PostgreSQL `books.book_origin_data` 테이블 (JSONB)
+    #[default]
+    Postgres,
+
+    /// MongoDB 원본 데이터 컬렉션 (`MONGO_URL` 필요)
+    Mongo,
+}
+
+/// 데이터베이스, 브릿지 서버 연결 정보를 묶은 프로필
+///
+/// # Description
+/// 입력하지 않은 값은 프로필을 사용하지 않을 때와 동일하게 환경 변수(`DATABASE_URL`, `DATABASE_REPLICA_URL`, `MONGO_URL`)와 기본 설정(`prompt`)을 사용한다.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    /// PostgreSQL 연결 주소
+    pub database_url: Option<String>,
+
+    /// PostgreSQL 읽기 전용 복제본 연결 주소
+    pub replica_url: Option<String>,
+
+    /// MongoDB 연결 주소
+    pub mongo_url: Option<String>,
+
+    /// 브릿지 서버 연결 정보 (입력하지 않은 항목은 브릿지 서버의 기본값을 사용한다.)
+    pub prompt: Option<BridgeServer>,
+}
+
+impl Config {
+    /// 설정 파일과 환경 변수에서 설정을 읽는다.
+    pub fn load() -> Result<Self, config::ConfigError> {
+        let file = env::var("CONFIG_FILE").unwrap_or_else(|_| DEFAULT_CONFIG_FILE.to_owned());
+
+        let mut builder = config::Config::builder()
+            .add_source(config::File::with_name(&file).required(false));
+        for (name, key) in ENV_OVERRIDES {
+            let value = env::var(name).ok().filter(|v| !v.trim().is_empty());
+            builder = builder.set_override_option(key, value)?;
+        }
+
+        builder.build()?.try_deserialize()
+    }
+
+    /// 이름으로 프로필을 선택하고 프로필의 브릿지 서버 연결 정보를 설정에 적용한다.
+    /// 설정 파일에 정의 되지 않은 프로필일 경우 에러를 반환한다.
+    pub fn select_profile(&mut self, name: &str) -> Result<Profile, config::ConfigError> {
+        let profile = self.profiles.get(name)
+            .cloned()
+            .ok_or_else(|| config::ConfigError::NotFound(format!("profiles.{}", name)))?;
+        if let Some(prompt) = profile.prompt.as_ref() {
+            self.prompt = prompt.clone();
+        }
+        Ok(profile)
+    }
+}
+
+/// 잡 실행에 사용하는 환경 변수 명세
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvSpec {
+    /// 환경 변수 이름
+    pub name: &'static str,
+
+    /// 필수 여부 (필수 환경 변수가 설정 되지 않은 경우 잡을 실행하지 않는다.)
+    pub required: bool,
+
+    /// 환경 변수 설명
+    pub description: &'static str,
+}
+
+impl EnvSpec {
+    const fn required(name: &'static str, description: &'static str) -> Self {
+        Self { name, required: true, description }
+    }
+
+    const fn optional(name: &'static str, description: &'static str) -> Self {
+        Self { name, required: false, description }
+    }
+
+    /// 환경 변수가 설정 되어 있는지 여부 (공백 문자열은 설정 되지 않은 것으로 판단한다.)
+    pub fn is_set(&self) -> bool {
+        env::var(self.name).map(|v| !v.trim().is_empty()).unwrap_or(false)
+    }
+}
+
+const ENV_NLGO_KEY: &str = "NLGO_KEY";
+const ENV_ALADIN_KEY: &str = "ALADIN_KEY";
+const ENV_NAVER_KEY: &str = "NAVER_KEY";
+const ENV_NAVER_SECRET: &str = "NAVER_SECRET";
+const ENV_KYOBO_ID: &str = "KYOBO_ID";
+const ENV_KYOBO_SECRET: &str = "KYOBO_SECRET";
+const ENV_CHROMEDRIVER_URL: &str = "CHROMEDRIVER_URL";
+const ENV_MONGO_URL: &str = "MONGO_URL";
+
+/// 교보문고 로그인에 사용하는 환경 변수, 로그인 하지 않고 실행(`--no-login`)한 경우 필요하지 않다.
+pub const KYOBO_LOGIN_ENV: [&str; 2] = [ENV_KYOBO_ID, ENV_KYOBO_SECRET];
+
+/// 잡 실행에 사용하는 인증 정보 환경 변수 명세를 반환한다.
+///
+/// # Description
+/// 잡에서 사용하는 사이트의 인증 정보만 반환하며, 설정 되지 않아도 잡을 실행할 수 있는 경우 `required`가 `false`이다.
+/// 실행 전 인증 정보 확인([`crate::spec::check_credentials`])과 `--describe-job` 출력에 사용한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::configs::required_env;
+/// use book_batch_rust::JobName;
+///
+/// let names = required_env(JobName::NAVER).iter().map(|env| env.name).collect::<Vec<_>>();
+/// assert_eq!(names, vec!["NAVER_KEY", "NAVER_SECRET"]);
+///
+/// assert!(required_env(JobName::FETCH).iter().any(|env| env.name == "KYOBO_ID" && !env.required));
+/// assert!(required_env(JobName::SERIES).is_empty());
+/// ```
+pub fn required_env(job: JobName) -> Vec<EnvSpec> {
+    let nlgo = EnvSpec::required(ENV_NLGO_KEY, "국립중앙도서관 API 인증키");
+    let aladin = EnvSpec::required(ENV_ALADIN_KEY, "알라딘 API TTB 키");
+    let naver = [
+        EnvSpec::required(ENV_NAVER_KEY, "네이버 API 클라이언트 아이디"),
+        EnvSpec::required(ENV_NAVER_SECRET, "네이버 API 클라이언트 시크릿"),
+    ];
+    let kyobo = [
+        EnvSpec::required(ENV_KYOBO_ID, "교보문고 로그인 아이디"),
+        EnvSpec::required(ENV_KYOBO_SECRET, "교보문고 로그인 비밀번호"),
+        EnvSpec::optional(ENV_CHROMEDRIVER_URL, "교보문고 로그인에 사용할 ChromeDriver 서버 주소"),
+    ];
+
+    match job {
+        JobName::NLGO | JobName::BACKFILL | JobName::PUB_DATE_RECHECK => vec![nlgo],
+        JobName::ALADIN => vec![aladin],
+        JobName::NAVER => naver.to_vec(),
+        JobName::KYOBO => kyobo.to_vec(),
+        JobName::FETCH | JobName::SNAPSHOT => {
+            // 교보문고는 로그인 정보가 설정 되어 있는 경우에만 조회한다.
+            let mut envs = vec![nlgo, aladin];
+            envs.extend(naver);
+            envs.extend(kyobo.iter().map(|env| EnvSpec::optional(env.name, env.description)));
+            envs
+        }
+        JobName::MIGRATE => vec![EnvSpec::optional(ENV_MONGO_URL, "MongoDB 연결 주소 (설정된 경우 원본 데이터 인덱스를 생성)")],
+        JobName::SERIES | JobName::NORMALIZE | JobName::STOCK | JobName::REPORT | JobName::KYOBO_SEARCH | JobName::STATUS | JobName::SERIES_COVER | JobName::GLOSSARY | JobName::PURGE => Vec::new(),
+    }
+}
 
 /// 실행 환경에 따라 .env 파일을 로드한다.
 pub fn load_dotenv() {
@@ -17,20 +307,59 @@ pub fn load_dotenv() {
 }
 
 /// 데이터베이스 연결 풀을 생성한다.
-pub fn connect_to_postgres() -> Pool<ConnectionManager<PgConnection>> {
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+/// 프로필에 데이터베이스 연결 주소가 있을 경우 환경 변수 `DATABASE_URL` 대신 사용한다.
+///
+/// # Errors
+/// 연결 주소가 설정 되지 않았거나 연결 풀을 생성할 수 없는 경우(데이터베이스에 연결할 수 없는 경우 포함) 에러 메시지를 반환한다.
+pub fn connect_to_postgres(profile: Option<&Profile>) -> Result<Pool<ConnectionManager<PgConnection>>, String> {
+    let database_url = match profile.and_then(|p| p.database_url.clone()) {
+        Some(url) => url,
+        None => env::var("DATABASE_URL").map_err(|e| format!("DATABASE_URL must be set: {}", e))?,
+    };
+    build_pg_pool(database_url)
+}
+
+/// 조회 부하가 큰 쿼리를 실행할 읽기 전용 복제본의 연결 풀을 생성한다.
+///
+/// # Description
+/// 프로필의 `replica_url` 또는 환경 변수 `DATABASE_REPLICA_URL`을 사용하며, 둘 다 설정 되지 않은 경우 `None`을 반환한다.
+/// 복제본이 없으면 모든 쿼리는 [`connect_to_postgres`]로 생성한 연결 풀에서 실행한다.
+/// 복제본이 설정 되어 있지만 연결 풀을 생성할 수 없는 경우 에러 메시지를 반환한다.
+pub fn connect_to_replica(profile: Option<&Profile>) -> Result<Option<Pool<ConnectionManager<PgConnection>>>, String> {
+    profile.and_then(|p| p.replica_url.clone())
+        .or_else(|| env::var("DATABASE_REPLICA_URL").ok().filter(|v| !v.trim().is_empty()))
+        .map(|url| build_pg_pool(url).map_err(|e| format!("replica: {}", e)))
+        .transpose()
+}
+
+fn build_pg_pool(database_url: String) -> Result<Pool<ConnectionManager<PgConnection>>, String> {
+    query::install();
     let manager = ConnectionManager::<PgConnection>::new(database_url);
 
     Pool::builder()
         .test_on_check_out(true)
         .build(manager)
-        .expect("Could not build connection pool")
+        .map_err(|e| format!("Could not build connection pool: {}", e))
+}
+
+/// 프로필 또는 환경 변수 `MONGO_URL`에 MongoDB 연결 주소가 설정 되어 있는지 여부
+pub fn is_mongo_configured(profile: Option<&Profile>) -> bool {
+    profile.is_some_and(|p| p.mongo_url.is_some())
+        || env::var(ENV_MONGO_URL).is_ok_and(|v| !v.trim().is_empty())
 }
 
-pub fn connect_to_mongo() -> Client {
-    let url = env::var("MONGO_URL").expect("MONGO_URL must be set");
-    
-    Client::with_uri_str(&url).expect("Could not connect to MongoDB")
+/// MongoDB 클라이언트를 생성한다.
+/// 프로필에 MongoDB 연결 주소가 있을 경우 환경 변수 `MONGO_URL` 대신 사용한다.
+///
+/// # Errors
+/// 연결 주소가 설정 되지 않았거나 클라이언트를 생성할 수 없는 경우 에러 메시지를 반환한다.
+pub fn connect_to_mongo(profile: Option<&Profile>) -> Result<Client, String> {
+    let url = match profile.and_then(|p| p.mongo_url.clone()) {
+        Some(url) => url,
+        None => env::var(ENV_MONGO_URL).map_err(|e| format!("{} must be set: {}", ENV_MONGO_URL, e))?,
+    };
+
+    Client::with_uri_str(&url).map_err(|e| format!("Could not connect to MongoDB: {}", e))
 }
 
 /// 프로그램에서 사용할 로깅 옵션을 설정한다.
@@ -0,0 +1,34 @@
+use diesel::r2d2::ConnectionManager;
+use diesel::PgConnection;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use r2d2::Pool;
+use std::env;
+use tracing::info;
+
+/// 바이너리에 포함된 데이터베이스 마이그레이션 (`migrations` 디렉토리)
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+/// 원본 데이터를 저장하는 MongoDB 기본 데이터베이스 이름
+pub const DEFAULT_MONGO_DATABASE: &str = "books";
+
+/// 원본 데이터를 저장하는 MongoDB 데이터베이스 이름 (환경 변수 `MONGO_DATABASE`, 기본값 `books`)
+pub fn mongo_database() -> String {
+    env::var("MONGO_DATABASE").unwrap_or_else(|_| DEFAULT_MONGO_DATABASE.to_owned())
+}
+
+/// 적용 되지 않은 데이터베이스 마이그레이션을 모두 적용하고 적용한 마이그레이션의 버전을 반환한다.
+pub fn run_pending_migrations(pool: &Pool<ConnectionManager<PgConnection>>) -> Result<Vec<String>, String> {
+    let mut connection = pool.get()
+        .map_err(|e| e.to_string())?;
+
+    let versions = connection.run_pending_migrations(MIGRATIONS)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|version| version.to_string())
+        .collect::<Vec<_>>();
+
+    for version in versions.iter() {
+        info!("Migration applied: {}", version);
+    }
+    Ok(versions)
+}
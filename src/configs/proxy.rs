@@ -0,0 +1,99 @@
+use reqwest::blocking::ClientBuilder;
+use reqwest::{NoProxy, Proxy, Url};
+use std::env;
+use tracing::warn;
+
+/// 프록시를 사용하지 않도록 설정하는 값
+const PROXY_DISABLED: &str = "none";
+
+/// 외부 요청에 사용할 프록시 설정
+///
+/// # Description
+/// 모든 외부 요청에 공통으로 적용할 프록시와 대상(알라딘, 네이버 등)별로 덮어쓸 프록시를 환경 변수에서 읽는다.
+/// - `PROXY_URL`: 모든 요청에 사용할 프록시 URL (`http://`, `https://`, `socks5://` 등)
+/// - `PROXY_NO_PROXY`: 프록시를 사용하지 않을 호스트 목록 (콤마로 구분)
+/// - `{대상}_PROXY_URL`: 대상별로 사용할 프록시 URL. `none`으로 설정 하면 해당 대상은 프록시를 사용하지 않는다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProxyConfig {
+    /// 프록시 URL
+    pub url: String,
+    /// 프록시를 사용하지 않을 호스트 목록 (콤마로 구분)
+    pub no_proxy: Option<String>,
+}
+
+impl ProxyConfig {
+    /// 환경 변수에서 대상의 프록시 설정을 읽는다. 프록시가 설정 되어 있지 않을 경우 `None`을 반환한다.
+    pub fn from_env(target: &str) -> Option<Self> {
+        let global = env::var("PROXY_URL").ok();
+        let overridden = env::var(format!("{}_PROXY_URL", target)).ok();
+
+        resolve_proxy_url(global, overridden).map(|url| Self {
+            url,
+            no_proxy: env::var("PROXY_NO_PROXY").ok().filter(|v| !v.trim().is_empty()),
+        })
+    }
+
+    /// 프록시 설정을 reqwest 클라이언트 빌더에 적용한다.
+    pub fn apply(&self, builder: ClientBuilder) -> Result<ClientBuilder, reqwest::Error> {
+        let proxy = Proxy::all(self.url.as_str())?
+            .no_proxy(self.no_proxy.as_ref().and_then(|v| NoProxy::from_string(v)));
+        Ok(builder.proxy(proxy))
+    }
+
+    /// 프록시 설정을 크롬 브라우저 실행 인자로 변환한다.
+    ///
+    /// # Note
+    /// 크롬은 실행 인자로 프록시 인증 정보를 받지 않으므로 URL에 포함된 사용자 정보는 제거한다.
+    pub fn chrome_args(&self) -> Vec<String> {
+        let mut args = vec![format!("--proxy-server={}", strip_credentials(&self.url))];
+        if let Some(no_proxy) = &self.no_proxy {
+            let bypass = no_proxy.split(',')
+                .map(|host| host.trim())
+                .filter(|host| !host.is_empty())
+                .collect::<Vec<_>>()
+                .join(";");
+            args.push(format!("--proxy-bypass-list={}", bypass));
+        }
+        args
+    }
+}
+
+/// 전체 프록시 URL과 대상별 프록시 URL 중 실제로 사용할 프록시 URL을 결정한다.
+///
+/// # Description
+/// 대상별 프록시 URL이 있을 경우 우선 사용하며, 대상별 프록시 URL이 `none`일 경우 프록시를 사용하지 않는다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::configs::proxy::resolve_proxy_url;
+///
+/// let global = Some("http://proxy:3128".to_owned());
+///
+/// assert_eq!(resolve_proxy_url(global.clone(), None), Some("http://proxy:3128".to_owned()));
+/// assert_eq!(resolve_proxy_url(global.clone(), Some("socks5://socks:1080".to_owned())), Some("socks5://socks:1080".to_owned()));
+/// assert_eq!(resolve_proxy_url(global.clone(), Some("none".to_owned())), None);
+/// assert_eq!(resolve_proxy_url(None, None), None);
+/// ```
+pub fn resolve_proxy_url(global: Option<String>, overridden: Option<String>) -> Option<String> {
+    let url = overridden.or(global)
+        .map(|url| url.trim().to_owned())
+        .filter(|url| !url.is_empty())?;
+
+    if url.eq_ignore_ascii_case(PROXY_DISABLED) {
+        None
+    } else {
+        Some(url)
+    }
+}
+
+fn strip_credentials(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(mut parsed) if !parsed.username().is_empty() || parsed.password().is_some() => {
+            warn!("Chrome does not support proxy credentials in url, credentials are ignored");
+            _ = parsed.set_username("");
+            _ = parsed.set_password(None);
+            parsed.to_string().trim_end_matches('/').to_owned()
+        }
+        _ => url.to_owned(),
+    }
+}
@@ -0,0 +1,100 @@
+use crate::batch::metrics;
+use diesel::connection::{set_default_instrumentation, Instrumentation, InstrumentationEvent};
+use std::env;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// 느린 쿼리로 판단할 기본 실행 시간(ms)
+pub const DEFAULT_SLOW_QUERY_MS: u64 = 1000;
+
+/// 데이터베이스 쿼리 계측
+///
+/// # Description
+/// 쿼리의 실행 시간을 유형(ex: `SELECT book`)별로 [`metrics`]에 기록하고,
+/// 환경 변수 `SLOW_QUERY_MS`(기본값 1000)에 설정한 시간 이상 걸린 쿼리는 SQL과 함께 경고 로그를 남긴다.
+/// 모든 쿼리의 SQL은 `debug` 레벨로 출력한다.
+pub struct QueryInstrumentation {
+    slow_threshold: Duration,
+    started_at: Option<Instant>,
+}
+
+impl QueryInstrumentation {
+    pub fn new(slow_threshold: Duration) -> Self {
+        Self { slow_threshold, started_at: None }
+    }
+
+    /// 환경 변수 `SLOW_QUERY_MS`에서 느린 쿼리 기준 시간을 읽어 생성한다.
+    pub fn from_env() -> Self {
+        let slow_query_ms = env::var("SLOW_QUERY_MS").ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_SLOW_QUERY_MS);
+        Self::new(Duration::from_millis(slow_query_ms))
+    }
+}
+
+impl Instrumentation for QueryInstrumentation {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        match event {
+            InstrumentationEvent::StartQuery { .. } => {
+                self.started_at = Some(Instant::now());
+            }
+            InstrumentationEvent::FinishQuery { query, error, .. } => {
+                let elapsed = match self.started_at.take() {
+                    Some(started_at) => started_at.elapsed(),
+                    None => return,
+                };
+
+                let sql = query.to_string();
+                metrics::record_query(&query_kind(&sql), elapsed);
+                debug!("Query executed ({} ms): {}", elapsed.as_millis(), sql);
+                if elapsed >= self.slow_threshold {
+                    warn!("Slow query ({} ms): {}", elapsed.as_millis(), sql);
+                }
+                if let Some(error) = error {
+                    debug!("Query failed: {}", error);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 이후 생성되는 모든 데이터베이스 연결에 쿼리 계측([`QueryInstrumentation`])을 적용한다.
+pub fn install() {
+    if let Err(err) = set_default_instrumentation(|| Some(Box::new(QueryInstrumentation::from_env()))) {
+        warn!("Failed to install query instrumentation: {}", err);
+    }
+}
+
+/// SQL의 명령어와 대상 테이블로 쿼리 유형을 만든다. 대상 테이블을 찾을 수 없는 경우 명령어만 사용한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::configs::query::query_kind;
+///
+/// assert_eq!(query_kind(r#"SELECT "book"."id" FROM "books"."book" WHERE "book"."isbn" = $1"#), "SELECT books.book");
+/// assert_eq!(query_kind(r#"INSERT INTO "books"."series" ("name") VALUES ($1)"#), "INSERT books.series");
+/// assert_eq!(query_kind(r#"UPDATE "books"."book" SET "series_id" = $1"#), "UPDATE books.book");
+/// assert_eq!(query_kind("BEGIN"), "BEGIN");
+/// ```
+pub fn query_kind(sql: &str) -> String {
+    let sql = sql.split(" -- binds:").next().unwrap_or(sql);
+    let command = match sql.split_whitespace().next() {
+        Some(command) => command.to_uppercase(),
+        None => return String::new(),
+    };
+
+    let table_keyword = match command.as_str() {
+        "INSERT" => "INTO",
+        "UPDATE" => "UPDATE",
+        _ => "FROM",
+    };
+    let table = sql.split_whitespace()
+        .skip_while(|token| !token.eq_ignore_ascii_case(table_keyword))
+        .nth(1);
+
+    match table {
+        Some(table) => format!("{} {}", command, table.replace('"', "").trim_end_matches(|c| c == ',' || c == ';')),
+        None => command,
+    }
+}
@@ -1,8 +1,11 @@
 pub mod repo;
 pub mod raw_impl;
 pub mod raw_utils;
+pub mod readonly;
+pub mod offline;
 
 use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
@@ -60,6 +63,20 @@ impl Display for Site {
     }
 }
 
+/// 사이트는 [`Display`]로 표현되는 코드(ex: `NLGO`, `NAVER`)로 직렬화 한다.
+impl Serialize for Site {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Site {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Site::try_from(code.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
 /// 출판사
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Publisher {
@@ -114,6 +131,7 @@ pub struct Series {
     title: Option<String>,
     isbn: Option<String>,
     vec: Option<Vec<f32>>,
+    cover: Option<String>,
     registered_at: Option<chrono::NaiveDateTime>,
     modified_at: Option<chrono::NaiveDateTime>
 }
@@ -143,6 +161,11 @@ impl Series {
         self.vec = Some(vec);
     }
 
+    /// 시리즈 대표 이미지 URL, 시리즈에 속한 도서의 썸네일 중에서 선택된다.
+    pub fn cover(&self) -> &Option<String> {
+        &self.cover
+    }
+
     pub fn registered_at(&self) -> Option<chrono::NaiveDateTime> {
         self.registered_at
     }
@@ -163,6 +186,7 @@ pub struct SeriesBuilder {
     title: Option<String>,
     isbn: Option<String>,
     vec: Option<Vec<f32>>,
+    cover: Option<String>,
     registered_at: Option<chrono::NaiveDateTime>,
     modified_at: Option<chrono::NaiveDateTime>,
 }
@@ -174,6 +198,7 @@ impl SeriesBuilder {
             title: None,
             isbn: None,
             vec: None,
+            cover: None,
             registered_at: None,
             modified_at: None,
         }
@@ -199,6 +224,11 @@ impl SeriesBuilder {
         self
     }
 
+    pub fn cover(mut self, cover: String) -> Self {
+        self.cover = Some(cover);
+        self
+    }
+
     pub fn registered_at(mut self, registered_at: chrono::NaiveDateTime) -> Self {
         self.registered_at = Some(registered_at);
         self
@@ -215,6 +245,7 @@ impl SeriesBuilder {
             title: self.title,
             isbn: self.isbn,
             vec: self.vec,
+            cover: self.cover,
             registered_at: self.registered_at,
             modified_at: self.modified_at,
         })
@@ -229,16 +260,161 @@ pub trait SeriesRepository {
     /// ISBN 리스트를 받아 해당 ISBN을 가지는 시리즈를 찾는다.
     fn find_by_isbn(&self, isbn: &[&str]) -> Vec<Series>;
 
+    /// 아이디 리스트를 받아 해당 아이디의 시리즈를 찾는다.
+    fn find_by_id(&self, id: &[u64]) -> Vec<Series>;
+
     /// 전달 받은 시리즈의 백터([`Series::vec`])와 가장 유사한 시리즈를 limit 개수 만큼 찾는다.
     ///
     /// 결과는 튜플로 (유사 시리즈 - 유사도)로 묶여 반환된다.
     fn similarity(&self, series: &Series, limit: i32) -> Vec<(Series, Option<f64>)>;
 
+    /// 시리즈 연결 후보를 최근 등록된 순서로 limit 개수 만큼 찾는다.
+    ///
+    /// 후보는 백터가 있는 시리즈 중 전달 받은 출판사의 도서가 속한 시리즈이거나 시리즈명이 title과 유사(trigram)한 시리즈이다.
+    fn find_candidates(&self, title: &str, publisher_id: u64, limit: usize) -> Vec<Series>;
+
     /// 전달 받은 시리즈들을 저장소에 저장한다.
     fn new_series(&self, series: &[Series]) -> Vec<Series>;
 
     /// 전달 받은 시리즈의 `ISBN`을 업데이트 한다.
     fn update_series_isbn(&self, series_id: u64, isbn: &str) -> usize;
+
+    /// 대표 이미지가 없는 시리즈를 최근 등록된 순서로 limit 개수 만큼 찾는다.
+    fn find_without_cover(&self, limit: usize) -> Vec<Series>;
+
+    /// 전달 받은 시리즈의 대표 이미지를 업데이트 한다.
+    fn update_series_cover(&self, series_id: u64, cover: &str) -> usize;
+
+    /// 도서와 시리즈의 연결 정보를 저장한다. 이미 연결 정보가 있는 도서는 새 연결 정보로 덮어쓴다.
+    fn save_links(&self, links: &[SeriesLink]) -> usize;
+
+    /// 도서의 시리즈 연결 정보를 삭제한다.
+    fn delete_links(&self, book_id: &[u64]) -> usize;
+
+    /// 유사도로 연결된 시리즈 연결 정보 중 유사도가 score 미만인 연결 정보를 유사도가 낮은 순으로 limit 개수 만큼 찾는다.
+    fn find_links_below(&self, score: f64, limit: usize) -> Vec<SeriesLink>;
+
+    /// 전달 받은 시리즈 중 소속 도서, 도서 연결 정보, 운영자 지정 분류가 하나도 없는 시리즈를 삭제하고 삭제된 시리즈 아이디를 반환한다.
+    fn delete_empty_series(&self, series_id: &[u64]) -> Vec<u64>;
+}
+
+/// 도서와 시리즈 연결의 신뢰도
+///
+/// # Description
+/// 시리즈 잡에서 도서를 시리즈에 연결한 방법과 그 때의 유사도로 이후 시리즈 분류를 얼마나 신뢰할 수 있을지 판단하는데 사용한다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeriesLinkConfidence {
+
+    /// 시리즈 ISBN이 일치하여 연결함
+    IsbnExact,
+
+    /// 제목 유사도가 기준 유사도 이상이여서 연결함
+    Similarity(f64),
+
+    /// 기준 유사도 미만이지만 LLM이 시리즈 소속을 확인하여 연결함 (가장 유사했던 시리즈의 유사도)
+    LlmConfirmed(f64),
+
+    /// 제목 정규화 전에 LLM이 후보 시리즈 중 하나를 선택하여 연결함
+    LlmSelected,
+
+    /// 운영자가 지정한 시리즈로 연결함
+    Manual,
+
+    /// 도서의 제목으로 새 시리즈를 생성하여 연결함
+    Created,
+}
+
+impl SeriesLinkConfidence {
+
+    /// 연결 방법과 유사도로 신뢰도를 생성한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::item::SeriesLinkConfidence;
+    ///
+    /// assert_eq!(SeriesLinkConfidence::restore("isbn-exact", None), Ok(SeriesLinkConfidence::IsbnExact));
+    /// assert_eq!(SeriesLinkConfidence::restore("similarity", Some(0.93)), Ok(SeriesLinkConfidence::Similarity(0.93)));
+    /// assert_eq!(SeriesLinkConfidence::restore("llm-confirmed", Some(0.5)), Ok(SeriesLinkConfidence::LlmConfirmed(0.5)));
+    /// assert_eq!(SeriesLinkConfidence::restore("llm-selected", None), Ok(SeriesLinkConfidence::LlmSelected));
+    /// assert!(SeriesLinkConfidence::restore("similarity", None).is_err());
+    /// assert!(SeriesLinkConfidence::restore("unknown", None).is_err());
+    /// ```
+    pub fn restore(method: &str, score: Option<f64>) -> Result<Self, ItemError> {
+        match (method, score) {
+            ("isbn-exact", _) => Ok(SeriesLinkConfidence::IsbnExact),
+            ("similarity", Some(score)) => Ok(SeriesLinkConfidence::Similarity(score)),
+            ("llm-confirmed", Some(score)) => Ok(SeriesLinkConfidence::LlmConfirmed(score)),
+            ("llm-selected", _) => Ok(SeriesLinkConfidence::LlmSelected),
+            ("manual", _) => Ok(SeriesLinkConfidence::Manual),
+            ("created", _) => Ok(SeriesLinkConfidence::Created),
+            _ => Err(ItemError::UnknownCode(format!("{}({:?})", method, score)))
+        }
+    }
+
+    /// 연결 방법
+    pub fn method(&self) -> &'static str {
+        match self {
+            SeriesLinkConfidence::IsbnExact => "isbn-exact",
+            SeriesLinkConfidence::Similarity(_) => "similarity",
+            SeriesLinkConfidence::LlmConfirmed(_) => "llm-confirmed",
+            SeriesLinkConfidence::LlmSelected => "llm-selected",
+            SeriesLinkConfidence::Manual => "manual",
+            SeriesLinkConfidence::Created => "created",
+        }
+    }
+
+    /// 연결 당시의 제목 유사도 (유사도를 사용하지 않은 연결은 `None`)
+    pub fn score(&self) -> Option<f64> {
+        match self {
+            SeriesLinkConfidence::Similarity(score) | SeriesLinkConfidence::LlmConfirmed(score) => Some(*score),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SeriesLinkConfidence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.score() {
+            Some(score) => write!(f, "{}({:.4})", self.method(), score),
+            None => write!(f, "{}", self.method()),
+        }
+    }
+}
+
+/// 도서와 시리즈의 연결 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesLink {
+    book_id: u64,
+    series_id: u64,
+    confidence: SeriesLinkConfidence,
+    linked_at: Option<chrono::NaiveDateTime>,
+}
+
+impl SeriesLink {
+    pub fn new(book_id: u64, series_id: u64, confidence: SeriesLinkConfidence) -> Self {
+        Self { book_id, series_id, confidence, linked_at: None }
+    }
+
+    /// 저장소에 저장된 연결 정보를 복원한다.
+    pub fn restore(book_id: u64, series_id: u64, confidence: SeriesLinkConfidence, linked_at: chrono::NaiveDateTime) -> Self {
+        Self { book_id, series_id, confidence, linked_at: Some(linked_at) }
+    }
+
+    pub fn book_id(&self) -> u64 {
+        self.book_id
+    }
+
+    pub fn series_id(&self) -> u64 {
+        self.series_id
+    }
+
+    pub fn confidence(&self) -> SeriesLinkConfidence {
+        self.confidence
+    }
+
+    pub fn linked_at(&self) -> Option<chrono::NaiveDateTime> {
+        self.linked_at
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -274,13 +450,15 @@ pub type Raw = HashMap<String, RawValue>;
 pub type Originals = HashMap<Site, Raw>;
 
 /// 도서
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Book {
     id: u64,
     isbn: String,
     publisher_id: u64,
     series_id: Option<u64>,
     title: String,
+    normalized_title: Option<String>,
+    description: Option<String>,
     scheduled_pub_date: Option<chrono::NaiveDate>,
     actual_pub_date: Option<chrono::NaiveDate>,
     originals: Originals,
@@ -317,6 +495,24 @@ impl Book {
         &self.title
     }
 
+    /// 불필요한 정보(권수, 특장판 표기 등)가 제거되어 표준화된 도서 제목
+    pub fn normalized_title(&self) -> Option<&str> {
+        self.normalized_title.as_deref()
+    }
+
+    pub fn set_normalized_title(&mut self, normalized_title: String) {
+        self.normalized_title = Some(normalized_title);
+    }
+
+    /// 사이트별 원본 데이터 중에서 선택되어 HTML 태그가 제거된 도서 소개글
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description(&mut self, description: String) {
+        self.description = Some(description);
+    }
+
     pub fn scheduled_pub_date(&self) -> Option<chrono::NaiveDate> {
         self.scheduled_pub_date
     }
@@ -352,16 +548,20 @@ impl Book {
             new_builder = new_builder.title(other.title.clone());
         }
 
-        if let Some(spd) = other.scheduled_pub_date {
-            if Some(spd) != self.scheduled_pub_date {
-                new_builder = new_builder.scheduled_pub_date(spd);
-            }
+        if let Some(normalized_title) = other.normalized_title.as_ref().or(self.normalized_title.as_ref()) {
+            new_builder = new_builder.normalized_title(normalized_title.clone());
+        }
+
+        if let Some(description) = other.description.as_ref().or(self.description.as_ref()) {
+            new_builder = new_builder.description(description.clone());
         }
 
-        if let Some(apd) = other.actual_pub_date {
-            if Some(apd) != self.actual_pub_date {
-                new_builder = new_builder.actual_pub_date(apd);
-            }
+        if let Some(spd) = other.scheduled_pub_date.or(self.scheduled_pub_date) {
+            new_builder = new_builder.scheduled_pub_date(spd);
+        }
+
+        if let Some(apd) = other.actual_pub_date.or(self.actual_pub_date) {
+            new_builder = new_builder.actual_pub_date(apd);
         }
 
         for (site, raw) in &other.originals {
@@ -383,6 +583,16 @@ impl Book {
             builder = builder.series_id(series_id);
         }
 
+        // normalized_title이 있는 경우 추가
+        if let Some(normalized_title) = self.normalized_title.as_ref() {
+            builder = builder.normalized_title(normalized_title.clone());
+        }
+
+        // description이 있는 경우 추가
+        if let Some(description) = self.description.as_ref() {
+            builder = builder.description(description.clone());
+        }
+
         // scheduled_pub_date가 있는 경우 추가
         if let Some(scheduled_date) = self.scheduled_pub_date {
             builder = builder.scheduled_pub_date(scheduled_date);
@@ -445,6 +655,12 @@ pub enum RawDataKind {
 
     /// 도서의 저자
     Author,
+
+    /// 판매처에서 제공하는 판매(재고) 상태
+    SaleStatus,
+
+    /// 판매처에서 제공하는 도서 표지 이미지 URL
+    Thumbnail,
 }
 
 /// 원본 데이터 종류키 사전
@@ -456,6 +672,12 @@ pub type RawKeyDict = HashMap<RawDataKind, String>;
 /// 각 사이트별 원본 데이터 종류키 사전
 pub type SiteRawKeyDict = HashMap<Site, RawKeyDict>;
 
+/// 원본 데이터 조회시 사이트별로 가져올 키 목록
+///
+/// # Description
+/// 원본 데이터 저장소는 프로젝션에 포함된 사이트의 원본 데이터만, 사이트에 지정된 키만 남겨 반환한다.
+pub type OriginProjection = HashMap<Site, Vec<String>>;
+
 /// Book 빌더
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct BookBuilder {
@@ -464,6 +686,8 @@ pub struct BookBuilder {
     publisher_id: Option<u64>,
     series_id: Option<u64>,
     title: Option<String>,
+    normalized_title: Option<String>,
+    description: Option<String>,
     scheduled_pub_date: Option<chrono::NaiveDate>,
     actual_pub_date: Option<chrono::NaiveDate>,
     originals: Originals,
@@ -479,6 +703,8 @@ impl BookBuilder {
             publisher_id: None,
             series_id: None,
             title: None,
+            normalized_title: None,
+            description: None,
             scheduled_pub_date: None,
             actual_pub_date: None,
             originals: HashMap::new(),
@@ -507,6 +733,16 @@ impl BookBuilder {
         self
     }
 
+    pub fn normalized_title(mut self, normalized_title: String) -> Self {
+        self.normalized_title = Some(normalized_title);
+        self
+    }
+
+    pub fn description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
     pub fn scheduled_pub_date(mut self, date: chrono::NaiveDate) -> Self {
         self.scheduled_pub_date = Some(date);
         self
@@ -554,6 +790,8 @@ impl BookBuilder {
             publisher_id: self.publisher_id.unwrap_or(0),
             series_id: self.series_id,
             title,
+            normalized_title: self.normalized_title,
+            description: self.description,
             scheduled_pub_date: self.scheduled_pub_date,
             actual_pub_date: self.actual_pub_date,
             originals: self.originals,
@@ -580,11 +818,782 @@ pub trait BookRepository {
     /// 전달 받은 도서 정보로 저장소의 도서를 업데이트 한다.
     fn update_book(&self, book: &Book) -> usize;
 
+    /// 전달 받은 도서들로 저장소의 도서를 업데이트 하고 업데이트된 도서 수를 반환한다.
+    ///
+    /// # Description
+    /// 기본 구현은 [`BookRepository::update_book`]을 도서마다 호출한다.
+    /// 트랜잭션을 지원하는 저장소는 모든 도서를 하나의 트랜잭션으로 업데이트 하도록 재정의 하며, 실패한 경우 0을 반환한다.
+    fn update_books(&self, books: &[Book]) -> usize {
+        books.iter()
+            .map(|book| self.update_book(book))
+            .sum()
+    }
+
+    /// 도서 행은 수정하지 않고 전달 받은 사이트들의 원본 데이터만 교체한 후 저장된 원본 데이터의 수를 반환한다.
+    fn update_origins(&self, book_id: u64, originals: &Originals) -> usize;
+
+    /// 원본 데이터를 교체할 수 있는지 여부, 원본 데이터 수정을 사용하지 않는 저장소는 [`BookRepository::update_origins`]에서 항상 0을 반환한다. (기본값: `true`)
+    fn is_origin_update_enabled(&self) -> bool {
+        true
+    }
+
     /// 시리즈화 되지 않은(시리즈 설정이 되지 않은) 도서를 limit 개수만큼 찾는다.
     fn find_series_unorganized(&self, limit: usize) -> Vec<Book>;
 
     /// 전달 받은 시리즈로 설정된 도서를 찾는다.
     fn find_by_series_id(&self, series_id: u64) -> Vec<Book>;
+
+    /// 출판사의 도서 중 시리즈에 연결된 도서를 시리즈 아이디, 도서 아이디 순서로 찾는다.
+    fn find_series_linked_by_publisher(&self, publisher_id: u64) -> Vec<Book>;
+
+    /// 제목이 정규화 되지 않은(정규화된 제목이 없는) 도서를 limit 개수만큼 찾는다.
+    fn find_title_unnormalized(&self, limit: usize) -> Vec<Book>;
+
+    /// 시작 - 종료 시각을 받아 해당 기간에 저장소에 등록(수집)된 도서를 검색한다. 종료 시각은 포함하지 않는다.
+    fn find_by_registered_between(&self, from: &chrono::NaiveDateTime, to: &chrono::NaiveDateTime) -> Vec<Book>;
+
+    /// 도서와 시리즈의 연결을 해제한다. (도서의 시리즈 아이디를 비운다.)
+    fn unlink_series(&self, book_id: u64) -> usize;
+
+    /// 도서 제목의 임베딩 백터를 저장한다. 이미 백터가 있는 도서는 새 백터로 덮어쓴다.
+    fn save_vector(&self, book_id: u64, vec: &[f32]) -> usize;
+
+    /// 전달 받은 백터와 제목 백터가 가장 유사한 도서를 limit 개수 만큼 찾는다.
+    ///
+    /// 결과는 튜플로 (유사 도서 - 코사인 거리)로 묶여 반환되며 거리가 0에 가까울수록 유사함을 나타낸다.
+    fn similar_books(&self, vec: &[f32], limit: i32) -> Vec<(Book, f64)>;
+
+    /// 도서와 도서를 참조하는 데이터(원본 데이터, 판매 상태, 제목 백터, 시리즈 연결 정보, 제목 정규화 기록, 보강 재시도)를 삭제하고 삭제된 도서 수를 반환한다.
+    ///
+    /// # Description
+    /// 트랜잭션을 지원하는 저장소는 전달 받은 도서를 하나의 트랜잭션으로 삭제하며, 실패한 경우 0을 반환한다.
+    fn delete_books(&self, book_id: &[u64]) -> usize;
+}
+
+/// 판매처의 도서 판매 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SaleStatus {
+    /// 판매중
+    Available,
+
+    /// 예약 판매중
+    PreOrder,
+
+    /// 품절 (일시 품절 포함)
+    OutOfStock,
+
+    /// 절판
+    OutOfPrint,
+
+    /// 판매 상태를 알 수 없음
+    Unknown,
+}
+
+impl SaleStatus {
+
+    /// 판매처에서 제공하는 판매 상태 문구를 판매 상태로 변환한다.
+    ///
+    /// # Note
+    /// 알라딘은 정상 판매중인 도서의 재고 상태(`stockStatus`)를 빈 문자열로 반환하므로 빈 문자열은 판매중으로 판단한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::item::SaleStatus;
+    ///
+    /// assert_eq!(SaleStatus::from_text(""), SaleStatus::Available);
+    /// assert_eq!(SaleStatus::from_text("일시품절"), SaleStatus::OutOfStock);
+    /// assert_eq!(SaleStatus::from_text("구판절판"), SaleStatus::OutOfPrint);
+    /// assert_eq!(SaleStatus::from_text("예약판매"), SaleStatus::PreOrder);
+    /// assert_eq!(SaleStatus::from_text("알 수 없음"), SaleStatus::Unknown);
+    /// ```
+    pub fn from_text(text: &str) -> Self {
+        let text = text.trim();
+        if text.is_empty() || text.contains("판매중") {
+            SaleStatus::Available
+        } else if text.contains("절판") {
+            SaleStatus::OutOfPrint
+        } else if text.contains("품절") {
+            SaleStatus::OutOfStock
+        } else if text.contains("예약") {
+            SaleStatus::PreOrder
+        } else {
+            SaleStatus::Unknown
+        }
+    }
+
+    /// 도서를 구매할 수 있는 상태인지 여부
+    pub fn is_available(&self) -> bool {
+        matches!(self, SaleStatus::Available | SaleStatus::PreOrder)
+    }
+}
+
+impl TryFrom<&str> for SaleStatus {
+    type Error = ItemError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "AVAILABLE" => Ok(SaleStatus::Available),
+            "PRE_ORDER" => Ok(SaleStatus::PreOrder),
+            "OUT_OF_STOCK" => Ok(SaleStatus::OutOfStock),
+            "OUT_OF_PRINT" => Ok(SaleStatus::OutOfPrint),
+            "UNKNOWN" => Ok(SaleStatus::Unknown),
+            _ => Err(ItemError::UnknownCode(value.to_owned()))
+        }
+    }
+}
+
+impl Display for SaleStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SaleStatus::Available => write!(f, "AVAILABLE"),
+            SaleStatus::PreOrder => write!(f, "PRE_ORDER"),
+            SaleStatus::OutOfStock => write!(f, "OUT_OF_STOCK"),
+            SaleStatus::OutOfPrint => write!(f, "OUT_OF_PRINT"),
+            SaleStatus::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// 사이트별 도서 판매 상태 기록
+///
+/// # Description
+/// 도서의 판매 상태를 사이트별로 기록한다. 판매 상태를 확인한 시각(`checked_at`)과
+/// 판매 상태가 마지막으로 변경된 시각(`changed_at`)을 함께 기록한다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Availability {
+    book_id: u64,
+    site: Site,
+    status: SaleStatus,
+    raw_status: String,
+    checked_at: chrono::NaiveDateTime,
+    changed_at: chrono::NaiveDateTime,
+}
+
+impl Availability {
+
+    /// 새 판매 상태 기록을 생성한다. 판매 상태 변경 시각은 확인 시각으로 초기화 된다.
+    pub fn new(book_id: u64, site: Site, raw_status: String, checked_at: chrono::NaiveDateTime) -> Self {
+        Self {
+            book_id,
+            site,
+            status: SaleStatus::from_text(&raw_status),
+            raw_status,
+            checked_at,
+            changed_at: checked_at,
+        }
+    }
+
+    pub fn book_id(&self) -> u64 {
+        self.book_id
+    }
+
+    pub fn site(&self) -> &Site {
+        &self.site
+    }
+
+    pub fn status(&self) -> SaleStatus {
+        self.status
+    }
+
+    /// 판매처에서 제공한 판매 상태 문구
+    pub fn raw_status(&self) -> &str {
+        &self.raw_status
+    }
+
+    pub fn checked_at(&self) -> chrono::NaiveDateTime {
+        self.checked_at
+    }
+
+    pub fn changed_at(&self) -> chrono::NaiveDateTime {
+        self.changed_at
+    }
+
+    pub fn set_changed_at(&mut self, changed_at: chrono::NaiveDateTime) {
+        self.changed_at = changed_at;
+    }
+
+    /// 저장소에서 불러온 판매 상태 기록을 생성한다.
+    pub fn restore(
+        book_id: u64,
+        site: Site,
+        status: SaleStatus,
+        raw_status: String,
+        checked_at: chrono::NaiveDateTime,
+        changed_at: chrono::NaiveDateTime
+    ) -> Self {
+        Self { book_id, site, status, raw_status, checked_at, changed_at }
+    }
+}
+
+pub type SharedAvailabilityRepository = Rc<Box<dyn AvailabilityRepository>>;
+
+/// 도서 판매 상태 저장소
+pub trait AvailabilityRepository {
+
+    /// 전달 받은 도서 아이디들의 사이트별 판매 상태를 찾는다.
+    fn find_by_book_id(&self, book_id: &[u64]) -> Vec<Availability>;
+
+    /// 판매 상태를 저장한다. 이미 같은 도서, 사이트의 판매 상태가 있을 경우 덮어쓴다.
+    fn save_availability(&self, availability: &[Availability]) -> usize;
+}
+
+/// 재시도 대기 시간의 기본 단위(분)
+const RETRY_BACKOFF_BASE_MINUTES: i64 = 60;
+
+/// 재시도 대기 시간의 최대값(분), 7일
+const RETRY_BACKOFF_MAX_MINUTES: i64 = 60 * 24 * 7;
+
+/// 도서 정보 보강(네이버, 교보문고 등) 실패 기록
+///
+/// # Description
+/// 특정 사이트에서 ISBN으로 도서 정보를 보강하지 못했을 때 실패 원인과 시도 횟수를 기록한다.
+/// 다음 재시도 시각(`next_retry_at`)은 시도 횟수에 따라 지수적으로 늘어나며 [`EnrichmentRetry::backoff`]로 계산한다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnrichmentRetry {
+    site: Site,
+    isbn: String,
+    error_type: String,
+    message: String,
+    attempt: u32,
+    next_retry_at: chrono::NaiveDateTime,
+    last_failed_at: chrono::NaiveDateTime,
+}
+
+impl EnrichmentRetry {
+
+    /// 첫번째 실패 기록을 생성한다.
+    pub fn new(site: Site, isbn: String, error_type: String, message: String, failed_at: chrono::NaiveDateTime) -> Self {
+        Self {
+            site,
+            isbn,
+            error_type,
+            message,
+            attempt: 1,
+            next_retry_at: failed_at + Self::backoff(1),
+            last_failed_at: failed_at,
+        }
+    }
+
+    /// 저장소에서 불러온 실패 기록을 생성한다.
+    pub fn restore(
+        site: Site,
+        isbn: String,
+        error_type: String,
+        message: String,
+        attempt: u32,
+        next_retry_at: chrono::NaiveDateTime,
+        last_failed_at: chrono::NaiveDateTime,
+    ) -> Self {
+        Self { site, isbn, error_type, message, attempt, next_retry_at, last_failed_at }
+    }
+
+    /// 재시도에 다시 실패 했을 때 시도 횟수를 늘리고 실패 원인과 다음 재시도 시각을 갱신한다.
+    pub fn fail_again(&mut self, error_type: String, message: String, failed_at: chrono::NaiveDateTime) {
+        self.attempt += 1;
+        self.error_type = error_type;
+        self.message = message;
+        self.next_retry_at = failed_at + Self::backoff(self.attempt);
+        self.last_failed_at = failed_at;
+    }
+
+    /// 시도 횟수에 따른 재시도 대기 시간을 계산한다.
+    /// 첫번째 실패 후 1시간을 대기하며 이후 실패 할 때마다 대기 시간이 두배로 늘어난다. (최대 7일)
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::item::EnrichmentRetry;
+    ///
+    /// assert_eq!(EnrichmentRetry::backoff(1), chrono::Duration::hours(1));
+    /// assert_eq!(EnrichmentRetry::backoff(2), chrono::Duration::hours(2));
+    /// assert_eq!(EnrichmentRetry::backoff(4), chrono::Duration::hours(8));
+    /// assert_eq!(EnrichmentRetry::backoff(30), chrono::Duration::days(7));
+    /// ```
+    pub fn backoff(attempt: u32) -> chrono::Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let minutes = RETRY_BACKOFF_BASE_MINUTES.saturating_mul(1 << exponent);
+        chrono::Duration::minutes(minutes.min(RETRY_BACKOFF_MAX_MINUTES))
+    }
+
+    pub fn site(&self) -> &Site {
+        &self.site
+    }
+
+    pub fn isbn(&self) -> &str {
+        &self.isbn
+    }
+
+    /// 실패 원인 구분 (ex: `NOT_FOUND`, `REQUEST_FAILED`)
+    pub fn error_type(&self) -> &str {
+        &self.error_type
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    pub fn next_retry_at(&self) -> chrono::NaiveDateTime {
+        self.next_retry_at
+    }
+
+    pub fn last_failed_at(&self) -> chrono::NaiveDateTime {
+        self.last_failed_at
+    }
+}
+
+pub type SharedRetryRepository = Rc<Box<dyn RetryRepository>>;
+
+/// 도서 정보 보강 실패 기록 저장소
+pub trait RetryRepository {
+
+    /// 재시도 시각이 지난 사이트의 실패 기록을 재시도 시각 순서로 limit 개수만큼 찾는다.
+    fn find_due(&self, site: &Site, now: &chrono::NaiveDateTime, limit: usize) -> Vec<EnrichmentRetry>;
+
+    /// 사이트와 ISBN으로 실패 기록을 찾는다.
+    fn find_by_isbn(&self, site: &Site, isbn: &[&str]) -> Vec<EnrichmentRetry>;
+
+    /// 실패 기록을 저장한다. 이미 같은 사이트, ISBN의 기록이 있을 경우 덮어쓴다.
+    fn save_retry(&self, retry: &[EnrichmentRetry]) -> usize;
+
+    /// 사이트와 ISBN으로 실패 기록을 삭제한다.
+    fn delete_retry(&self, site: &Site, isbn: &[&str]) -> usize;
+}
+
+/// 과거 도서 수집(백필) 진행 기록
+///
+/// # Description
+/// 사이트, 출판사별로 어느 날짜까지 수집을 마쳤는지 기록하여 중단된 백필을 이어서 진행할 수 있도록 한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackfillProgress {
+    site: Site,
+    publisher_id: u64,
+    completed_through: chrono::NaiveDate,
+    updated_at: chrono::NaiveDateTime,
+}
+
+impl BackfillProgress {
+    pub fn new(site: Site, publisher_id: u64, completed_through: chrono::NaiveDate, updated_at: chrono::NaiveDateTime) -> Self {
+        Self { site, publisher_id, completed_through, updated_at }
+    }
+
+    pub fn site(&self) -> &Site {
+        &self.site
+    }
+
+    pub fn publisher_id(&self) -> u64 {
+        self.publisher_id
+    }
+
+    /// 수집을 마친 마지막 날짜
+    pub fn completed_through(&self) -> chrono::NaiveDate {
+        self.completed_through
+    }
+
+    pub fn updated_at(&self) -> chrono::NaiveDateTime {
+        self.updated_at
+    }
+}
+
+pub type SharedBackfillRepository = Rc<Box<dyn BackfillRepository>>;
+
+/// 과거 도서 수집(백필) 진행 기록 저장소
+pub trait BackfillRepository {
+
+    /// 사이트, 출판사의 백필 진행 기록을 찾는다.
+    fn find_progress(&self, site: &Site, publisher_id: u64) -> Option<BackfillProgress>;
+
+    /// 백필 진행 기록을 저장한다. 이미 같은 사이트, 출판사의 기록이 있을 경우 덮어쓴다.
+    fn save_progress(&self, progress: &BackfillProgress) -> usize;
+}
+
+/// 사이트, 출판사별 최근 수집 기록
+///
+/// # Description
+/// 사이트, 출판사별로 마지막으로 수집에 성공한 시각과 조회된 도서 수를 기록하여 오랫동안 수집 되지 않은 조합을 확인할 수 있도록 한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionStatus {
+    site: Site,
+    publisher_id: u64,
+    collected_at: chrono::NaiveDateTime,
+    result_count: usize,
+}
+
+impl CollectionStatus {
+    pub fn new(site: Site, publisher_id: u64, collected_at: chrono::NaiveDateTime, result_count: usize) -> Self {
+        Self { site, publisher_id, collected_at, result_count }
+    }
+
+    pub fn site(&self) -> &Site {
+        &self.site
+    }
+
+    pub fn publisher_id(&self) -> u64 {
+        self.publisher_id
+    }
+
+    /// 마지막으로 수집에 성공한 시각
+    pub fn collected_at(&self) -> chrono::NaiveDateTime {
+        self.collected_at
+    }
+
+    /// 마지막 수집에서 조회된 도서 수
+    pub fn result_count(&self) -> usize {
+        self.result_count
+    }
+}
+
+pub type SharedCollectionStatusRepository = Rc<Box<dyn CollectionStatusRepository>>;
+
+/// 사이트, 출판사별 최근 수집 기록 저장소
+pub trait CollectionStatusRepository {
+
+    /// 모든 수집 기록을 찾는다.
+    fn find_all(&self) -> Vec<CollectionStatus>;
+
+    /// 수집 기록을 저장한다. 이미 같은 사이트, 출판사의 기록이 있을 경우 덮어쓴다.
+    fn save_status(&self, status: &[CollectionStatus]) -> usize;
+}
+
+/// 잡, 출판사별 한 번의 실행에서 조회하고 새로 저장한 도서 수
+///
+/// # Description
+/// 실행마다 기록하여 최근 실행들의 평균과 비교해 수집량이 갑자기 줄거나 늘어난 경우를 찾는데 사용한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionVolume {
+    job_name: String,
+    publisher_id: u64,
+    executed_at: chrono::NaiveDateTime,
+    read_count: usize,
+    write_count: usize,
+}
+
+impl CollectionVolume {
+    pub fn new(job_name: &str, publisher_id: u64, executed_at: chrono::NaiveDateTime, read_count: usize, write_count: usize) -> Self {
+        Self { job_name: job_name.to_owned(), publisher_id, executed_at, read_count, write_count }
+    }
+
+    pub fn job_name(&self) -> &str {
+        &self.job_name
+    }
+
+    pub fn publisher_id(&self) -> u64 {
+        self.publisher_id
+    }
+
+    pub fn executed_at(&self) -> chrono::NaiveDateTime {
+        self.executed_at
+    }
+
+    /// 실행에서 조회된 도서 수
+    pub fn read_count(&self) -> usize {
+        self.read_count
+    }
+
+    /// 실행에서 새로 저장된 도서 수
+    pub fn write_count(&self) -> usize {
+        self.write_count
+    }
+}
+
+pub type SharedVolumeRepository = Rc<Box<dyn VolumeRepository>>;
+
+/// 잡, 출판사별 수집량 기록 저장소
+pub trait VolumeRepository {
+
+    /// 잡, 출판사의 수집량 기록을 최근 실행 순으로 최대 `limit`개 찾는다.
+    fn find_recent(&self, job_name: &str, publisher_id: u64, limit: usize) -> Vec<CollectionVolume>;
+
+    /// 수집량 기록을 저장한다.
+    fn save_volumes(&self, volumes: &[CollectionVolume]) -> usize;
+}
+
+pub type SharedQuotaRepository = Rc<Box<dyn QuotaRepository>>;
+
+/// 사이트 API 일일 요청 수 저장소
+///
+/// # Description
+/// 알라딘(TTB), 네이버 등 하루 요청 수가 제한된 API의 사용량을 사이트, 날짜별로 기록하여 여러번의 실행에 걸쳐 합산한다.
+pub trait QuotaRepository {
+
+    /// 사이트의 해당 날짜 요청 수를 반환한다. 기록이 없을 경우 0을 반환한다.
+    fn find_used(&self, site: &Site, date: &chrono::NaiveDate) -> u32;
+
+    /// 사이트의 해당 날짜 요청 수를 `count` 만큼 늘린다.
+    fn add_used(&self, site: &Site, date: &chrono::NaiveDate, count: u32) -> usize;
+}
+
+/// 잡 실행 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    /// 실행 중 (종료 되지 않고 남아 있는 경우 비정상 종료된 실행)
+    Started,
+
+    /// 정상 종료
+    Completed,
+
+    /// 취소 요청으로 종료 (처리 중인 청크까지만 저장됨)
+    Cancelled,
+
+    /// 복구할 수 없는 에러로 종료 (저장에 실패한 청크는 격리 파일로 남음)
+    Failed,
+}
+
+impl Display for JobStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JobStatus::Started => write!(f, "STARTED"),
+            JobStatus::Completed => write!(f, "COMPLETED"),
+            JobStatus::Cancelled => write!(f, "CANCELLED"),
+            JobStatus::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// 잡 실행 중 처리한 아이템 수
+///
+/// # Description
+/// 한 번의 실행에서 잡을 여러 번 실행하는 경우(ex: 기간을 나누어 실행하는 백필) 모든 실행의 합계를 기록한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::JobExecutionCounts;
+///
+/// let mut counts = JobExecutionCounts { read: 10, processed: 8, written: 8, skipped: 2 };
+/// counts.add(&JobExecutionCounts { read: 5, written: 5, ..Default::default() });
+///
+/// assert_eq!(counts, JobExecutionCounts { read: 15, processed: 8, written: 13, skipped: 2 });
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JobExecutionCounts {
+    /// 리더가 읽은 아이템 수 (필터로 제외된 아이템 포함)
+    pub read: usize,
+
+    /// 프로세서가 처리에 성공한 아이템 수
+    pub processed: usize,
+
+    /// 라이터가 저장에 성공한 아이템 수
+    pub written: usize,
+
+    /// 처리, 저장에 실패하여 건너뛴 아이템 수
+    pub skipped: usize,
+}
+
+impl JobExecutionCounts {
+
+    /// 다른 실행의 아이템 수를 더한다.
+    pub fn add(&mut self, other: &JobExecutionCounts) {
+        self.read += other.read;
+        self.processed += other.processed;
+        self.written += other.written;
+        self.skipped += other.skipped;
+    }
+}
+
+pub type SharedJobExecutionRepository = Rc<Box<dyn JobExecutionRepository>>;
+
+/// 잡 실행 기록 저장소
+pub trait JobExecutionRepository {
+
+    /// 잡 실행 시작을 기록하고 실행 기록의 아이디를 반환한다. 기록에 실패한 경우 `None`을 반환한다.
+    /// `param_hash`는 같은 실행을 찾을 때 사용할 해시로 [`crate::batch::duplicate::parameter_hash`]로 만든다.
+    fn start(&self, job_name: &str, parameters: &str, param_hash: &str, started_at: &chrono::NaiveDateTime) -> Option<u64>;
+
+    /// 해시가 같은 실행 중 정상 종료된 실행의 아이디를 찾는다. 없을 경우 `None`을 반환한다.
+    fn find_completed(&self, param_hash: &str) -> Option<u64>;
+
+    /// 잡 실행 종료와 실행 중 처리한 아이템 수를 기록한다. 변경 내역 파일이 있을 경우 그 경로를 함께 기록한다.
+    fn finish(&self, id: u64, status: JobStatus, finished_at: &chrono::NaiveDateTime, audit_path: Option<&str>, counts: &JobExecutionCounts) -> usize;
+}
+
+pub type SharedCheckpointRepository = Rc<Box<dyn CheckpointRepository>>;
+
+/// 잡 체크포인트 저장소
+///
+/// # Description
+/// 오래 걸리는 잡이 중단된 경우 다음 실행에서 이미 저장한 청크를 건너뛸 수 있도록 마지막으로 저장을 마친 청크의 순번을 기록한다.
+/// 체크포인트는 잡 이름과 파라미터로 만든 키([`crate::batch::checkpoint::checkpoint_key`])로 구분한다.
+pub trait CheckpointRepository {
+
+    /// 키에 해당하는 체크포인트(마지막으로 저장을 마친 청크의 순번)를 찾는다. 없을 경우 `None`을 반환한다.
+    fn find_checkpoint(&self, key: &str) -> Option<u64>;
+
+    /// 체크포인트를 저장한다. 이미 같은 키의 체크포인트가 있을 경우 덮어쓴다.
+    fn save_checkpoint(&self, key: &str, job_name: &str, chunk_index: u64) -> usize;
+
+    /// 키에 해당하는 체크포인트를 삭제한다.
+    fn delete_checkpoint(&self, key: &str) -> usize;
+}
+
+pub type SharedDeadLetterRepository = Rc<Box<dyn DeadLetterRepository>>;
+
+/// 저장에 실패하여 격리된 아이템의 실패 기록 저장소
+///
+/// # Description
+/// 여러 실행에서 같은 아이템이 같은 에러로 반복해서 격리 되는 것을 확인할 수 있도록 잡, ISBN, 에러 유형별로 격리된 횟수를 기록한다.
+pub trait DeadLetterRepository {
+
+    /// 잡, ISBN, 에러 유형별 격리 횟수를 1 늘리고 ISBN별로 늘어난 격리 횟수를 반환한다. 기록이 없는 ISBN은 1로 기록한다.
+    fn increment_attempts(&self, job_name: &str, error_class: &str, isbn: &[String]) -> HashMap<String, u32>;
+}
+
+pub type SharedIsbnSetRepository = Rc<Box<dyn IsbnSetRepository>>;
+
+/// 이름 있는 ISBN 집합 저장소
+///
+/// # Description
+/// 잡이 처리한 ISBN 집합을 이름(ex: `nlgo:2024-06-01:new`)으로 저장하여 다른 잡에서 이름으로 불러와 처리할 수 있도록 한다.
+pub trait IsbnSetRepository {
+
+    /// 이름에 해당하는 ISBN 집합을 찾는다. 집합이 없을 경우 빈 `Vec`를 반환한다.
+    fn find_isbn(&self, name: &str) -> Vec<String>;
+
+    /// ISBN들을 이름에 해당하는 집합에 추가한다. 이미 집합에 있는 ISBN은 무시한다.
+    fn save_isbn(&self, name: &str, isbn: &[String]) -> usize;
+}
+
+/// 운영자가 지정한 도서의 시리즈 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesOverrideTarget {
+    /// 지정한 시리즈에 속함
+    Series(u64),
+
+    /// 어떤 시리즈에도 속하지 않는 단독 도서
+    Standalone,
+}
+
+/// 운영자가 직접 지정한 도서의 시리즈 분류
+///
+/// # Description
+/// 자동 시리즈 분류가 잘못된 도서를 운영자가 수정하기 위해 ISBN별로 속할 시리즈(혹은 단독 도서)를 지정한다.
+/// 지정된 분류는 시리즈 잡에서 자동 분류 보다 우선하여 적용되며 이후의 자동 분류로 덮어쓰지 않는다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesOverride {
+    isbn: String,
+    target: SeriesOverrideTarget,
+    note: Option<String>,
+}
+
+impl SeriesOverride {
+    pub fn new(isbn: String, target: SeriesOverrideTarget, note: Option<String>) -> Self {
+        Self { isbn, target, note }
+    }
+
+    pub fn isbn(&self) -> &str {
+        &self.isbn
+    }
+
+    pub fn target(&self) -> SeriesOverrideTarget {
+        self.target
+    }
+
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+pub type SharedSeriesOverrideRepository = Rc<Box<dyn SeriesOverrideRepository>>;
+
+/// 운영자 지정 시리즈 분류 저장소
+pub trait SeriesOverrideRepository {
+
+    /// ISBN으로 운영자가 지정한 시리즈 분류를 찾는다.
+    fn find_by_isbn(&self, isbn: &[&str]) -> Vec<SeriesOverride>;
+
+    /// 지정한 시리즈 분류가 아직 도서에 반영 되지 않은(도서의 시리즈가 지정한 시리즈와 다른) ISBN을 limit 개수만큼 찾는다.
+    fn find_unapplied_isbn(&self, limit: usize) -> Vec<String>;
+}
+
+/// 도서 제목 정규화 이력
+///
+/// # Description
+/// LLM으로 도서 제목을 정규화 한 결과와 그 이유, 사용한 프롬프트의 버전을 기록하여 정규화 품질을 검토하는데 사용한다.
+/// 정규화 당시의 도서 제목(`source_title`)을 함께 기록하여 도서 제목이 바뀌지 않았다면 다시 정규화 하지 않도록 한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleNormalization {
+    book_id: u64,
+    source_title: String,
+    normalized_title: String,
+    reason: String,
+    prompt_version: Option<String>,
+    normalized_at: chrono::NaiveDateTime,
+}
+
+impl TitleNormalization {
+    pub fn new(
+        book_id: u64,
+        source_title: String,
+        normalized_title: String,
+        reason: String,
+        prompt_version: Option<String>,
+        normalized_at: chrono::NaiveDateTime,
+    ) -> Self {
+        Self { book_id, source_title, normalized_title, reason, prompt_version, normalized_at }
+    }
+
+    pub fn book_id(&self) -> u64 {
+        self.book_id
+    }
+
+    /// 정규화 당시의 도서 제목
+    pub fn source_title(&self) -> &str {
+        &self.source_title
+    }
+
+    pub fn normalized_title(&self) -> &str {
+        &self.normalized_title
+    }
+
+    /// LLM이 설명한 제목에서 제거된 요소
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn prompt_version(&self) -> Option<&str> {
+        self.prompt_version.as_deref()
+    }
+
+    pub fn normalized_at(&self) -> chrono::NaiveDateTime {
+        self.normalized_at
+    }
+
+    /// 같은 제목을 같은 버전의 프롬프트로 정규화 하였는지 여부
+    ///
+    /// # Note
+    /// 프롬프트 버전을 알 수 없는 경우 같은 프롬프트로 정규화 하였다고 판단하지 않는다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::item::TitleNormalization;
+    ///
+    /// let now = chrono::Local::now().naive_local();
+    /// let normalization = TitleNormalization::new(1, "원피스 1권".to_owned(), "원피스".to_owned(), "권수 제거".to_owned(), Some("v2".to_owned()), now);
+    ///
+    /// assert!(normalization.is_up_to_date("원피스 1권", Some("v2")));
+    /// assert!(!normalization.is_up_to_date("원피스 1권 (특장판)", Some("v2")));
+    /// assert!(!normalization.is_up_to_date("원피스 1권", Some("v3")));
+    /// assert!(!normalization.is_up_to_date("원피스 1권", None));
+    /// ```
+    pub fn is_up_to_date(&self, title: &str, prompt_version: Option<&str>) -> bool {
+        self.source_title == title && prompt_version.is_some() && self.prompt_version() == prompt_version
+    }
+}
+
+pub type SharedTitleNormalizationRepository = Rc<Box<dyn TitleNormalizationRepository>>;
+
+/// 도서 제목 정규화 이력 저장소
+pub trait TitleNormalizationRepository {
+
+    /// 도서 아이디로 제목 정규화 이력을 찾는다.
+    fn find_by_book_id(&self, book_id: &[u64]) -> Vec<TitleNormalization>;
+
+    /// 제목 정규화 이력을 저장한다. 이미 이력이 있는 도서는 새 이력으로 덮어쓴다.
+    fn save(&self, normalization: &[TitleNormalization]) -> usize;
+
+    /// 정규화 이후 제목이 바뀐 도서의 ISBN을 limit 개수만큼 찾는다.
+    fn find_title_changed_isbn(&self, limit: usize) -> Vec<String>;
 }
 
 /// 유효성 체크에 사용할 연산자 열거
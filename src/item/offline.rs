@@ -0,0 +1,174 @@
+use crate::batch::JobParameter;
+use crate::item::{Book, BookRepository, FilterRepository, FilterRule, Originals, Publisher, PublisherRepository, QuotaRepository, Site};
+use crate::{PARAM_NAME_KEYWORD, PARAM_NAME_PUBLISHER_ID};
+use std::collections::HashMap;
+
+/// 파라미터로 입력한 키워드를 출판사로 사용하는 출판사 저장소
+///
+/// # Description
+/// 데이터베이스 없이 파일로 출력하는 실행(`--output`)에서 출판사 저장소를 대신한다.
+/// `keyword` 파라미터의 키워드들을 모든 사이트의 검색 키워드로 가진 출판사 하나를 반환하며, 출판사 아이디는 `publisher_id` 파라미터의 첫 번째 아이디(입력하지 않은 경우 0)를 사용한다.
+/// 키워드를 입력하지 않은 경우 출판사가 없는 저장소가 된다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::JobParameter;
+/// use book_batch_rust::item::offline::ParameterPublisherRepository;
+/// use book_batch_rust::item::{PublisherRepository, Site};
+///
+/// let parameter = JobParameter::from([
+///     ("keyword".to_owned(), "민음사, 문학동네".to_owned()),
+///     ("publisher_id".to_owned(), "7".to_owned()),
+/// ]);
+/// let repo = ParameterPublisherRepository::from_parameter(&parameter);
+///
+/// let publishers = repo.get_all();
+/// assert_eq!(publishers.len(), 1);
+/// assert_eq!(publishers[0].id(), 7);
+/// assert_eq!(publishers[0].keywords().get(&Site::NLGO).unwrap(), &vec!["민음사".to_owned(), "문학동네".to_owned()]);
+/// assert_eq!(repo.find_by_id(&[7]).len(), 1);
+/// assert!(repo.find_by_id(&[8]).is_empty());
+///
+/// let repo = ParameterPublisherRepository::from_parameter(&JobParameter::new());
+/// assert!(repo.get_all().is_empty());
+/// ```
+pub struct ParameterPublisherRepository {
+    publisher: Option<Publisher>,
+}
+
+impl ParameterPublisherRepository {
+    pub fn from_parameter(params: &JobParameter) -> Self {
+        let keywords = params.get(PARAM_NAME_KEYWORD)
+            .map(|keywords| keywords.split(',')
+                .map(|keyword| keyword.trim().to_owned())
+                .filter(|keyword| !keyword.is_empty())
+                .collect::<Vec<_>>())
+            .unwrap_or_default();
+        if keywords.is_empty() {
+            return Self { publisher: None };
+        }
+
+        let id = params.get(PARAM_NAME_PUBLISHER_ID)
+            .and_then(|ids| ids.split(',').next())
+            .and_then(|id| id.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+        let sites = [Site::NLGO, Site::Naver, Site::Aladin, Site::KyoboBook];
+        let site_keywords = sites.into_iter()
+            .map(|site| (site, keywords.clone()))
+            .collect::<HashMap<_, _>>();
+        Self { publisher: Some(Publisher::new(id, keywords.join(", "), site_keywords)) }
+    }
+}
+
+impl PublisherRepository for ParameterPublisherRepository {
+    fn get_all(&self) -> Vec<Publisher> {
+        self.publisher.iter().cloned().collect()
+    }
+
+    fn find_by_id(&self, id: &[u64]) -> Vec<Publisher> {
+        self.publisher.iter()
+            .filter(|publisher| id.contains(&publisher.id()))
+            .cloned()
+            .collect()
+    }
+}
+
+/// 필터 규칙이 없는 필터 저장소
+///
+/// # Description
+/// 데이터베이스 없이 실행하는 경우 필터 저장소를 대신하며 원본 데이터 필터는 모든 도서를 통과시킨다.
+pub struct EmptyFilterRepository;
+
+impl FilterRepository for EmptyFilterRepository {
+    fn find_by_site(&self, _: &Site) -> Vec<FilterRule> {
+        Vec::new()
+    }
+}
+
+/// 요청 수를 기록하지 않는 API 일일 요청 수 저장소
+///
+/// # Description
+/// 데이터베이스 없이 실행하는 경우 요청 수 저장소를 대신한다. 사용량은 항상 0이며 실행 중 늘어난 요청 수는 저장하지 않는다.
+pub struct EmptyQuotaRepository;
+
+impl QuotaRepository for EmptyQuotaRepository {
+    fn find_used(&self, _: &Site, _: &chrono::NaiveDate) -> u32 {
+        0
+    }
+
+    fn add_used(&self, _: &Site, _: &chrono::NaiveDate, _: u32) -> usize {
+        0
+    }
+}
+
+/// 저장된 도서가 없는 도서 저장소
+///
+/// # Description
+/// 데이터베이스 없이 파일로 출력하는 경우 도서 저장소를 대신한다. 조회는 항상 빈 결과를 반환한다.
+///
+/// # Panics
+/// 파일로 출력하는 잡은 도서 저장소에 저장하지 않으므로, 저장, 수정 요청을 받은 경우 요청한 함수 이름과 함께 패닉이 발생한다.
+pub struct EmptyBookRepository;
+
+impl BookRepository for EmptyBookRepository {
+    fn find_by_pub_between(&self, _: &chrono::NaiveDate, _: &chrono::NaiveDate) -> Vec<Book> {
+        Vec::new()
+    }
+
+    fn find_by_isbn(&self, _: &[&str]) -> Vec<Book> {
+        Vec::new()
+    }
+
+    fn save_books(&self, books: &[Book]) -> Vec<Book> {
+        reject_write("BookRepository::save_books", books.len())
+    }
+
+    fn update_book(&self, _: &Book) -> usize {
+        reject_write("BookRepository::update_book", 1)
+    }
+
+    fn update_origins(&self, _: u64, originals: &Originals) -> usize {
+        reject_write("BookRepository::update_origins", originals.len())
+    }
+
+    fn find_series_unorganized(&self, _: usize) -> Vec<Book> {
+        Vec::new()
+    }
+
+    fn find_by_series_id(&self, _: u64) -> Vec<Book> {
+        Vec::new()
+    }
+
+    fn find_series_linked_by_publisher(&self, _: u64) -> Vec<Book> {
+        Vec::new()
+    }
+
+    fn find_title_unnormalized(&self, _: usize) -> Vec<Book> {
+        Vec::new()
+    }
+
+    fn find_by_registered_between(&self, _: &chrono::NaiveDateTime, _: &chrono::NaiveDateTime) -> Vec<Book> {
+        Vec::new()
+    }
+
+    fn unlink_series(&self, _: u64) -> usize {
+        reject_write("BookRepository::unlink_series", 1)
+    }
+
+    fn save_vector(&self, _: u64, _: &[f32]) -> usize {
+        reject_write("BookRepository::save_vector", 1)
+    }
+
+    fn similar_books(&self, _: &[f32], _: i32) -> Vec<(Book, f64)> {
+        Vec::new()
+    }
+
+    fn delete_books(&self, book_id: &[u64]) -> usize {
+        reject_write("BookRepository::delete_books", book_id.len())
+    }
+}
+
+/// 데이터베이스 없이 실행 중인 잡이 보낸 저장, 수정 요청을 거부하고 잡 실행을 실패시킨다.
+fn reject_write(operation: &str, count: usize) -> ! {
+    panic!("Write rejected, no database is connected: {} ({} items), the job must write to the output file", operation, count)
+}
@@ -1,4 +1,5 @@
 use crate::item::{RawNumber, RawValue};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -521,4 +522,36 @@ impl From<RawValue> for serde_json::Value {
             },
         }
     }
-}
\ No newline at end of file
+}
+
+/// 숫자로 표현할 수 없는 값(`Undefined`)은 `null`로 직렬화 한다.
+impl Serialize for RawNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            RawNumber::Undefined => serializer.serialize_unit(),
+            RawNumber::UnsignedInt(n) => serializer.serialize_u64(*n),
+            RawNumber::SignedInt(n) => serializer.serialize_i64(*n),
+            RawNumber::Float(n) => serializer.serialize_f64(*n),
+        }
+    }
+}
+
+impl Serialize for RawValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            RawValue::Null => serializer.serialize_unit(),
+            RawValue::Text(s) => serializer.serialize_str(s),
+            RawValue::Number(n) => n.serialize(serializer),
+            RawValue::Bool(b) => serializer.serialize_bool(*b),
+            RawValue::Object(o) => serializer.collect_map(o),
+            RawValue::Array(arr) => serializer.collect_seq(arr),
+        }
+    }
+}
+
+/// [`serde_json::Value`]로 역직렬화 후 [`RawValue`]로 변환한다.
+impl<'de> Deserialize<'de> for RawValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(RawValue::from)
+    }
+}
@@ -1,6 +1,11 @@
-use crate::item::{Raw, RawDataKind, RawKeyDict, RawValue, Site};
+use crate::item::{OriginProjection, Originals, Raw, RawDataKind, RawKeyDict, RawValue, Site};
 use crate::provider::api::{aladin, naver, nlgo};
 use crate::provider::html::kyobo;
+use regex::Regex;
+use scraper::Html;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
 use tracing::warn;
 
 pub fn load_site_dict(site: &Site) -> RawKeyDict {
@@ -12,6 +17,71 @@ pub fn load_site_dict(site: &Site) -> RawKeyDict {
     }
 }
 
+/// 원본 데이터 종류들을 사이트별 원본 데이터 키로 변환하여 원본 데이터 조회 프로젝션을 만든다.
+/// 사이트의 종류키 사전에 없는 종류는 무시하며, 가져올 키가 하나도 없는 사이트는 프로젝션에서 제외한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::raw_utils::origin_projection;
+/// use book_batch_rust::item::{RawDataKind, Site};
+///
+/// let projection = origin_projection(&[RawDataKind::Title, RawDataKind::SeriesID]);
+/// let mut nlgo = projection.get(&Site::NLGO).unwrap().clone();
+/// nlgo.sort();
+/// assert_eq!(nlgo, vec!["set_isbn".to_owned(), "title".to_owned()]);
+/// ```
+pub fn origin_projection(kinds: &[RawDataKind]) -> OriginProjection {
+    [Site::NLGO, Site::Naver, Site::Aladin, Site::KyoboBook].into_iter()
+        .filter_map(|site| {
+            let dict = load_site_dict(&site);
+            let keys = kinds.iter()
+                .filter_map(|kind| dict.get(kind).cloned())
+                .collect::<Vec<_>>();
+            (!keys.is_empty()).then_some((site, keys))
+        })
+        .collect()
+}
+
+/// 원본 데이터에서 프로젝션에 지정된 키만 남긴다.
+pub fn project_raw(raw: Raw, keys: &[String]) -> Raw {
+    raw.into_iter()
+        .filter(|(k, _)| keys.contains(k))
+        .collect()
+}
+
+/// 원본 데이터의 내용 해시(SHA-256, 16진수 소문자)를 계산한다.
+///
+/// # Description
+/// 키 순서로 정렬한 JSON으로 해시를 계산하므로 키의 저장 순서와 관계 없이 내용이 같으면 같은 해시를 반환한다.
+/// 원본 데이터 저장소는 `(도서, 사이트, 내용 해시)`가 같은 원본 데이터를 한번만 저장하여,
+/// 일부 저장 후 실패한 청크를 다시 실행하더라도 같은 원본 데이터가 중복으로 저장되지 않도록 한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::raw_utils::content_hash;
+/// use book_batch_rust::item::{Raw, RawValue};
+///
+/// let mut raw = Raw::new();
+/// raw.insert("title".to_owned(), RawValue::Text("title".to_owned()));
+/// raw.insert("isbn".to_owned(), RawValue::Text("9788966261000".to_owned()));
+///
+/// let mut same = Raw::new();
+/// same.insert("isbn".to_owned(), RawValue::Text("9788966261000".to_owned()));
+/// same.insert("title".to_owned(), RawValue::Text("title".to_owned()));
+/// assert_eq!(content_hash(&raw), content_hash(&same));
+/// assert_eq!(content_hash(&raw).len(), 64);
+///
+/// raw.insert("title".to_owned(), RawValue::Text("changed".to_owned()));
+/// assert_ne!(content_hash(&raw), content_hash(&same));
+/// ```
+pub fn content_hash(raw: &Raw) -> String {
+    let sorted = raw.iter()
+        .map(|(k, v)| (k.as_str(), serde_json::Value::from(v.clone())))
+        .collect::<BTreeMap<_, _>>();
+    let json = serde_json::to_string(&sorted).unwrap_or_default();
+    Sha256::digest(json.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 pub fn retrieve_title_from_raw(dict: &RawKeyDict, raw: &Raw) -> Option<String> {
     let key = dict.get(&RawDataKind::Title)?;
     let opt = raw.get(key).map(|v| String::from(v));
@@ -42,6 +112,24 @@ pub fn retrieve_description_from_raw(dict: &RawKeyDict, raw: &Raw) -> Option<Str
     }
 }
 
+/// 원본 데이터에서 판매처의 판매(재고) 상태 문구를 가져온다.
+///
+/// # Note
+/// 정상 판매중인 도서는 판매 상태 문구가 빈 문자열일 수 있으므로 다른 속성과 달리 빈 문자열도 반환한다.
+/// 원본 데이터에 판매 상태가 없거나 판매 상태를 제공하지 않는 사이트일 경우 [`None`]을 반환한다.
+pub fn retrieve_sale_status_from_raw(dict: &RawKeyDict, raw: &Raw) -> Option<String> {
+    let key = dict.get(&RawDataKind::SaleStatus)?;
+    raw.get(key).map(|v| String::from(v))
+}
+
+/// 원본 데이터에서 도서 표지 이미지 URL을 가져온다.
+pub fn retrieve_thumbnail_from_raw(dict: &RawKeyDict, raw: &Raw) -> Option<String> {
+    let key = dict.get(&RawDataKind::Thumbnail)?;
+    raw.get(key)
+        .map(|v| String::from(v))
+        .filter(|v| !v.is_empty())
+}
+
 pub fn retrieve_sale_price_from_raw(dict: &RawKeyDict, raw: &Raw) -> Option<usize> {
     let key = dict.get(&RawDataKind::SalePrice)?;
 
@@ -79,4 +167,71 @@ fn retrieve_series_list_titles_from_raw_value(dict: &RawKeyDict, raw: &RawValue)
             }
         }
     }
-}
\ No newline at end of file
+}
+/// 사이트별 원본 데이터를 사이트 우선순위 순서로 정렬하여 반환한다.
+///
+/// # Description
+/// [`Originals`]는 해시맵이므로 순회 순서가 실행할 때마다 달라질 수 있다. 원본 데이터의 순서가 결과에 영향을 주는 경우
+/// (LLM 요청, 대표값 선택 등) 이 함수로 정렬하여 항상 같은 순서로 사용한다.
+/// 우선순위에 없는 사이트는 우선순위에 있는 사이트 뒤에 사이트 이름 순서로 정렬한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::raw_utils::sort_by_site_priority;
+/// use book_batch_rust::item::{Originals, Raw, Site};
+///
+/// let mut originals = Originals::new();
+/// originals.insert(Site::NLGO, Raw::new());
+/// originals.insert(Site::Naver, Raw::new());
+/// originals.insert(Site::Aladin, Raw::new());
+/// originals.insert(Site::KyoboBook, Raw::new());
+///
+/// let sorted = sort_by_site_priority(&originals, &[Site::Naver, Site::KyoboBook])
+///     .into_iter()
+///     .map(|(site, _)| *site)
+///     .collect::<Vec<_>>();
+/// assert_eq!(sorted, vec![Site::Naver, Site::KyoboBook, Site::Aladin, Site::NLGO]);
+/// ```
+pub fn sort_by_site_priority<'a>(originals: &'a Originals, priority: &[Site]) -> Vec<(&'a Site, &'a Raw)> {
+    let mut sorted = originals.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|(site, _)| {
+        let rank = priority.iter().position(|p| p == *site).unwrap_or(priority.len());
+        (rank, site.to_string())
+    });
+    sorted
+}
+
+/// HTML 태그 (`<` 다음에 태그 이름, 닫는 태그(`/`), 주석이나 선언(`!`)이 오는 경우만 태그로 보아 `1 < 2` 같은 비교 기호는 남긴다.)
+static HTML_TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[a-zA-Z/!][^>]*>").unwrap());
+
+/// 연속된 공백
+static WHITESPACE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s+").unwrap());
+
+/// 원본 데이터의 HTML 텍스트를 일반 텍스트로 정리한다.
+///
+/// # Description
+/// 사이트에 따라 소개글 등의 원본 데이터에 HTML 태그나 이스케이프된 엔티티(`&amp;`, `&lt;` 등)가 포함 되어 있어
+/// LLM 입력이나 파일 출력에 사용하기 전에 아래의 순서로 정리한다.
+/// 1. HTML 태그를 공백으로 치환한다. (`<br>` 등으로 구분된 문장이 붙지 않도록 공백으로 치환한다.)
+/// 2. HTML 엔티티를 디코딩 한다.
+/// 3. 디코딩 후 나타난 태그(이스케이프 되어 있던 태그)를 다시 공백으로 치환한다.
+/// 4. 연속된 공백(줄바꿈, `&nbsp;` 포함)을 하나로 줄이고 앞뒤 공백을 제거한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::raw_utils::sanitize_html;
+///
+/// assert_eq!(sanitize_html("<p>첫째 줄<br/>둘째 줄</p>"), "첫째 줄 둘째 줄");
+/// assert_eq!(sanitize_html("Tom &amp; Jerry&nbsp;&nbsp;&quot;1권&quot;"), "Tom & Jerry \"1권\"");
+/// assert_eq!(sanitize_html("&lt;b&gt;굵은&lt;/b&gt; 글씨"), "굵은 글씨");
+/// assert_eq!(sanitize_html("1 < 2"), "1 < 2");
+/// ```
+pub fn sanitize_html(text: &str) -> String {
+    let text = HTML_TAG_REGEX.replace_all(text, " ");
+    let text = Html::parse_fragment(&text).root_element()
+        .text()
+        .collect::<String>();
+    let text = HTML_TAG_REGEX.replace_all(&text, " ");
+
+    WHITESPACE_REGEX.replace_all(&text, " ").trim().to_owned()
+}
@@ -0,0 +1,274 @@
+use crate::item::{Book, BookRepository, EnrichmentRetry, Originals, QuotaRepository, RetryRepository, Series, SeriesLink, SeriesRepository, SharedBookRepository, SharedQuotaRepository, SharedRetryRepository, SharedSeriesRepository, Site};
+use tracing::debug;
+
+/// 쓰기 요청을 거부하는 읽기 전용 도서 저장소
+///
+/// # Description
+/// 조회는 내부 저장소에 위임하고 저장, 수정 요청은 저장소에 전달하지 않는다.
+/// `--dry-run`으로 실행한 잡에 사용하여 라이터가 미리보기 옵션을 확인하지 않더라도 데이터가 변경 되지 않도록 보장한다.
+///
+/// # Panics
+/// 저장, 수정 요청을 받은 경우 요청한 함수 이름과 함께 패닉이 발생한다.
+/// 라이터가 미리보기 옵션을 확인하지 않는 것은 잡의 버그이므로, 저장된 것처럼 빈 결과를 반환하지 않고 잡 실행을 실패시킨다. (실행 기록에는 실패 상태로 남는다.)
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::cancel::CancellationToken;
+/// use book_batch_rust::batch::error::{JobReadFailed, JobWriteFailed};
+/// use book_batch_rust::batch::{job_builder, JobParameter, Reader, Writer};
+/// use book_batch_rust::item::readonly::ReadOnlyBookRepository;
+/// use book_batch_rust::item::{Book, BookRepository, Originals, SharedBookRepository};
+/// use std::panic::{self, AssertUnwindSafe};
+///
+/// struct EmptyRepository;
+/// impl BookRepository for EmptyRepository {
+/// #    fn find_by_pub_between(&self, _: &chrono::NaiveDate, _: &chrono::NaiveDate) -> Vec<Book> { Vec::new() }
+/// #    fn find_by_isbn(&self, _: &[&str]) -> Vec<Book> { Vec::new() }
+///     fn save_books(&self, books: &[Book]) -> Vec<Book> { books.to_vec() }
+///     // ...
+/// #    fn update_book(&self, _: &Book) -> usize { 1 }
+/// #    fn update_origins(&self, _: u64, _: &Originals) -> usize { 0 }
+/// #    fn find_series_unorganized(&self, _: usize) -> Vec<Book> { Vec::new() }
+/// #    fn find_by_series_id(&self, _: u64) -> Vec<Book> { Vec::new() }
+/// #    fn find_series_linked_by_publisher(&self, _: u64) -> Vec<Book> { Vec::new() }
+/// #    fn find_title_unnormalized(&self, _: usize) -> Vec<Book> { Vec::new() }
+/// #    fn find_by_registered_between(&self, _: &chrono::NaiveDateTime, _: &chrono::NaiveDateTime) -> Vec<Book> { Vec::new() }
+/// #    fn unlink_series(&self, _: u64) -> usize { 0 }
+/// #    fn save_vector(&self, _: u64, _: &[f32]) -> usize { 0 }
+/// #    fn similar_books(&self, _: &[f32], _: i32) -> Vec<(Book, f64)> { Vec::new() }
+/// #    fn delete_books(&self, _: &[u64]) -> usize { 0 }
+/// }
+///
+/// struct BookReader;
+/// impl Reader for BookReader {
+///     type Item = Book;
+///
+///     fn do_read(&self, _: &JobParameter) -> Result<Vec<Book>, JobReadFailed> {
+///         Ok(vec![Book::builder().isbn("9788900000000".to_owned()).title("title".to_owned()).build().unwrap()])
+///     }
+/// }
+///
+/// // 미리보기 옵션을 확인하지 않고 저장하는 라이터
+/// struct SaveWriter(SharedBookRepository);
+/// impl Writer for SaveWriter {
+///     type Item = Book;
+///
+///     fn do_write(&self, items: Vec<Book>) -> Result<(), JobWriteFailed<Book>> {
+///         self.0.save_books(&items);
+///         Ok(())
+///     }
+/// }
+///
+/// let repo = SharedBookRepository::new(Box::new(ReadOnlyBookRepository::new(SharedBookRepository::new(Box::new(EmptyRepository)))));
+/// let job = job_builder()
+///     .reader(Box::new(BookReader))
+///     .writer(Box::new(SaveWriter(repo)))
+///     .build();
+///
+/// let parameter = JobParameter::from([("dry_run".to_owned(), "true".to_owned())]);
+/// let result = panic::catch_unwind(AssertUnwindSafe(|| job.run(&parameter, &CancellationToken::new())));
+/// assert!(result.is_err());
+/// ```
+pub struct ReadOnlyBookRepository {
+    inner: SharedBookRepository,
+}
+
+impl ReadOnlyBookRepository {
+    pub fn new(inner: SharedBookRepository) -> Self {
+        Self { inner }
+    }
+}
+
+impl BookRepository for ReadOnlyBookRepository {
+    fn find_by_pub_between(&self, from: &chrono::NaiveDate, to: &chrono::NaiveDate) -> Vec<Book> {
+        self.inner.find_by_pub_between(from, to)
+    }
+
+    fn find_by_isbn(&self, isbn: &[&str]) -> Vec<Book> {
+        self.inner.find_by_isbn(isbn)
+    }
+
+    fn save_books(&self, books: &[Book]) -> Vec<Book> {
+        reject_write("BookRepository::save_books", books.len())
+    }
+
+    fn update_book(&self, _: &Book) -> usize {
+        reject_write("BookRepository::update_book", 1)
+    }
+
+    fn update_origins(&self, _: u64, originals: &Originals) -> usize {
+        reject_write("BookRepository::update_origins", originals.len())
+    }
+
+    fn is_origin_update_enabled(&self) -> bool {
+        self.inner.is_origin_update_enabled()
+    }
+
+    fn find_series_unorganized(&self, limit: usize) -> Vec<Book> {
+        self.inner.find_series_unorganized(limit)
+    }
+
+    fn find_by_series_id(&self, series_id: u64) -> Vec<Book> {
+        self.inner.find_by_series_id(series_id)
+    }
+
+    fn find_series_linked_by_publisher(&self, publisher_id: u64) -> Vec<Book> {
+        self.inner.find_series_linked_by_publisher(publisher_id)
+    }
+
+    fn find_title_unnormalized(&self, limit: usize) -> Vec<Book> {
+        self.inner.find_title_unnormalized(limit)
+    }
+
+    fn find_by_registered_between(&self, from: &chrono::NaiveDateTime, to: &chrono::NaiveDateTime) -> Vec<Book> {
+        self.inner.find_by_registered_between(from, to)
+    }
+
+    fn unlink_series(&self, _: u64) -> usize {
+        reject_write("BookRepository::unlink_series", 1)
+    }
+
+    fn save_vector(&self, _: u64, _: &[f32]) -> usize {
+        reject_write("BookRepository::save_vector", 1)
+    }
+
+    fn similar_books(&self, vec: &[f32], limit: i32) -> Vec<(Book, f64)> {
+        self.inner.similar_books(vec, limit)
+    }
+
+    fn delete_books(&self, book_id: &[u64]) -> usize {
+        reject_write("BookRepository::delete_books", book_id.len())
+    }
+}
+
+/// 쓰기 요청을 거부하는 읽기 전용 시리즈 저장소
+///
+/// # Description
+/// [`ReadOnlyBookRepository`]와 동일하게 조회만 내부 저장소에 위임하며, 저장, 수정 요청을 받은 경우 패닉이 발생한다.
+pub struct ReadOnlySeriesRepository {
+    inner: SharedSeriesRepository,
+}
+
+impl ReadOnlySeriesRepository {
+    pub fn new(inner: SharedSeriesRepository) -> Self {
+        Self { inner }
+    }
+}
+
+impl SeriesRepository for ReadOnlySeriesRepository {
+    fn find_by_isbn(&self, isbn: &[&str]) -> Vec<Series> {
+        self.inner.find_by_isbn(isbn)
+    }
+
+    fn find_by_id(&self, id: &[u64]) -> Vec<Series> {
+        self.inner.find_by_id(id)
+    }
+
+    fn similarity(&self, series: &Series, limit: i32) -> Vec<(Series, Option<f64>)> {
+        self.inner.similarity(series, limit)
+    }
+
+    fn find_candidates(&self, title: &str, publisher_id: u64, limit: usize) -> Vec<Series> {
+        self.inner.find_candidates(title, publisher_id, limit)
+    }
+
+    fn new_series(&self, series: &[Series]) -> Vec<Series> {
+        reject_write("SeriesRepository::new_series", series.len())
+    }
+
+    fn update_series_isbn(&self, _: u64, _: &str) -> usize {
+        reject_write("SeriesRepository::update_series_isbn", 1)
+    }
+
+    fn find_without_cover(&self, limit: usize) -> Vec<Series> {
+        self.inner.find_without_cover(limit)
+    }
+
+    fn update_series_cover(&self, _: u64, _: &str) -> usize {
+        reject_write("SeriesRepository::update_series_cover", 1)
+    }
+
+    fn save_links(&self, links: &[SeriesLink]) -> usize {
+        reject_write("SeriesRepository::save_links", links.len())
+    }
+
+    fn delete_links(&self, book_id: &[u64]) -> usize {
+        reject_write("SeriesRepository::delete_links", book_id.len())
+    }
+
+    fn find_links_below(&self, score: f64, limit: usize) -> Vec<SeriesLink> {
+        self.inner.find_links_below(score, limit)
+    }
+
+    fn delete_empty_series(&self, series_id: &[u64]) -> Vec<u64> {
+        reject_write("SeriesRepository::delete_empty_series", series_id.len())
+    }
+}
+
+/// 기록 요청을 저장하지 않는 읽기 전용 보강 실패 기록 저장소
+///
+/// # Description
+/// 조회는 내부 저장소에 위임하며, 저장, 삭제 요청은 디버그 로그만 남기고 무시한다.
+/// 실패 기록은 라이터가 아닌 리더가 남기는 기록이므로 [`ReadOnlyBookRepository`]와 달리 잡 실행을 실패시키지 않는다.
+pub struct ReadOnlyRetryRepository {
+    inner: SharedRetryRepository,
+}
+
+impl ReadOnlyRetryRepository {
+    pub fn new(inner: SharedRetryRepository) -> Self {
+        Self { inner }
+    }
+}
+
+impl RetryRepository for ReadOnlyRetryRepository {
+    fn find_due(&self, site: &Site, now: &chrono::NaiveDateTime, limit: usize) -> Vec<EnrichmentRetry> {
+        self.inner.find_due(site, now, limit)
+    }
+
+    fn find_by_isbn(&self, site: &Site, isbn: &[&str]) -> Vec<EnrichmentRetry> {
+        self.inner.find_by_isbn(site, isbn)
+    }
+
+    fn save_retry(&self, retry: &[EnrichmentRetry]) -> usize {
+        ignore_write("RetryRepository::save_retry", retry.len())
+    }
+
+    fn delete_retry(&self, _: &Site, isbn: &[&str]) -> usize {
+        ignore_write("RetryRepository::delete_retry", isbn.len())
+    }
+}
+
+/// 요청 수를 기록하지 않는 읽기 전용 API 일일 요청 수 저장소
+///
+/// # Description
+/// [`ReadOnlyRetryRepository`]와 동일하게 조회만 내부 저장소에 위임하며, 요청 수 기록은 디버그 로그만 남기고 무시한다.
+pub struct ReadOnlyQuotaRepository {
+    inner: SharedQuotaRepository,
+}
+
+impl ReadOnlyQuotaRepository {
+    pub fn new(inner: SharedQuotaRepository) -> Self {
+        Self { inner }
+    }
+}
+
+impl QuotaRepository for ReadOnlyQuotaRepository {
+    fn find_used(&self, site: &Site, date: &chrono::NaiveDate) -> u32 {
+        self.inner.find_used(site, date)
+    }
+
+    fn add_used(&self, _: &Site, _: &chrono::NaiveDate, count: u32) -> usize {
+        ignore_write("QuotaRepository::add_used", count as usize)
+    }
+}
+
+/// 읽기 전용 저장소가 받은 저장, 수정 요청을 거부하고 잡 실행을 실패시킨다.
+fn reject_write(operation: &str, count: usize) -> ! {
+    panic!("Write rejected by read-only repository (dry run): {} ({} items), the writer must check the dry_run parameter", operation, count)
+}
+
+/// 읽기 전용 저장소가 받은 기록 요청을 저장하지 않고 무시한다.
+fn ignore_write(operation: &str, count: usize) -> usize {
+    debug!("Write ignored by read-only repository (dry run): {} ({} items)", operation, count);
+    0
+}
@@ -1,27 +1,120 @@
-use crate::item::repo::diesel::{BookEntity, BookOriginDataPgStore, BookOriginFilterPgStore, BookPgStore, PublisherEntity, PublisherKeywordEntity, PublisherPgStore, SeriesPgStore};
-use crate::item::{Book, BookBuilder, BookRepository, FilterRepository, FilterRule, Publisher, PublisherRepository, Raw, Series, SeriesRepository, Site};
-use chrono::NaiveDate;
+use crate::clock::SharedClock;
+use crate::item::repo::diesel::{BackfillProgressPgStore, BookAvailabilityPgStore, BookEntity, BookOriginDataPgStore, BookOriginFilterPgStore, BookPgStore, BookSeriesLinkPgStore, CollectionStatusPgStore, CollectionVolumePgStore, DeadLetterPgStore, EnrichmentRetryPgStore, IsbnSetPgStore, JobCheckpointPgStore, JobExecutionPgStore, ProviderQuotaPgStore, PublisherEntity, PublisherKeywordEntity, PublisherPgStore, SeriesOverridePgStore, SeriesPgStore, TitleNormalizationPgStore};
+use crate::item::{raw_utils, Availability, AvailabilityRepository, BackfillProgress, BackfillRepository, Book, BookBuilder, BookRepository, CheckpointRepository, CollectionStatus, CollectionStatusRepository, CollectionVolume, DeadLetterRepository, EnrichmentRetry, FilterRepository, FilterRule, IsbnSetRepository, JobExecutionCounts, JobExecutionRepository, JobStatus, OriginProjection, Originals, Publisher, PublisherRepository, QuotaRepository, Raw, RetryRepository, Series, SeriesLink, SeriesOverride, SeriesOverrideRepository, SeriesRepository, Site, TitleNormalization, TitleNormalizationRepository, VolumeRepository};
+use chrono::{NaiveDate, NaiveDateTime};
 use ::diesel::r2d2::ConnectionManager;
 use ::diesel::PgConnection;
 use r2d2::Pool;
+use serde::Deserialize;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::fmt::Debug;
 use std::rc::Rc;
-use tracing::error;
+use tracing::{error, warn};
 
 mod diesel;
+mod id;
+mod mongo;
+
+pub use id::{database_id_generator, DatabaseIdGenerator, IdGenerator, IdStrategy, SharedIdGenerator, UlidIdGenerator};
+pub use mongo::MongoOriginStore;
+
+/// 시리즈 백터 유사도 검색 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorSearchMode {
+    /// 모든 시리즈와 거리를 계산하는 정확한 검색 (인덱스를 사용하지 않는다.)
+    #[default]
+    Exact,
+
+    /// 백터 인덱스(HNSW, IVFFlat)를 사용하는 근사 검색
+    Ann,
+}
+
+/// 시리즈 백터 유사도 검색 설정
+///
+/// # Description
+/// 시리즈 수가 늘어나도 유사도 검색 시간을 일정하게 유지하려면 근사 검색([`VectorSearchMode::Ann`])을 사용한다.
+/// 근사 검색은 정확도 대신 속도를 선택하므로 `ef_search`(HNSW), `probes`(IVFFlat)를 늘려 정확도를 조절한다.
+/// 입력하지 않은 값은 PostgreSQL(pgvector)의 기본값을 사용한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::repo::{VectorSearch, VectorSearchMode};
+///
+/// let search = VectorSearch::default();
+/// assert_eq!(search.mode, VectorSearchMode::Exact);
+/// assert_eq!(search.settings(), vec!["SET LOCAL enable_indexscan = off".to_owned()]);
+///
+/// let search = VectorSearch { mode: VectorSearchMode::Ann, ef_search: Some(100), probes: None };
+/// assert_eq!(search.settings(), vec!["SET LOCAL hnsw.ef_search = 100".to_owned()]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct VectorSearch {
+    pub mode: VectorSearchMode,
+
+    /// HNSW 인덱스 검색시 유지할 후보 수 (`hnsw.ef_search`, pgvector 기본값 40)
+    pub ef_search: Option<u32>,
+
+    /// IVFFlat 인덱스 검색시 조회할 리스트 수 (`ivfflat.probes`, pgvector 기본값 1)
+    pub probes: Option<u32>,
+}
+
+impl VectorSearch {
+    /// 유사도 검색 전 트랜잭션에 적용할 설정 쿼리
+    ///
+    /// # Description
+    /// 정확한 검색은 인덱스 스캔을 사용하지 않도록 하며, 근사 검색은 입력된 `ef_search`, `probes`를 적용한다.
+    pub fn settings(&self) -> Vec<String> {
+        match self.mode {
+            VectorSearchMode::Exact => vec!["SET LOCAL enable_indexscan = off".to_owned()],
+            VectorSearchMode::Ann => [
+                self.ef_search.map(|v| format!("SET LOCAL hnsw.ef_search = {}", v)),
+                self.probes.map(|v| format!("SET LOCAL ivfflat.probes = {}", v)),
+            ].into_iter().flatten().collect(),
+        }
+    }
+}
 
 pub struct DieselSeriesRepository {
-    series_store: SeriesPgStore
+    series_store: SeriesPgStore,
+    link_store: BookSeriesLinkPgStore,
 }
 
 impl DieselSeriesRepository {
     pub fn new(db_pool: Pool<ConnectionManager<PgConnection>>) -> Self {
         Self {
-            series_store: SeriesPgStore::new(db_pool),
+            series_store: SeriesPgStore::new(db_pool.clone()),
+            link_store: BookSeriesLinkPgStore::new(db_pool),
         }
     }
+
+    /// 시리즈 유사도 검색을 읽기 전용 복제본에서 실행한다.
+    pub fn with_replica(mut self, replica: Pool<ConnectionManager<PgConnection>>) -> Self {
+        self.series_store = self.series_store.with_replica(replica);
+        self
+    }
+
+    /// 시리즈 유사도 검색 방식을 변경한다. (기본값: [`VectorSearchMode::Exact`])
+    pub fn with_vector_search(mut self, search: VectorSearch) -> Self {
+        self.series_store = self.series_store.with_vector_search(search);
+        self
+    }
+
+    /// 시리즈 등록 시각과 도서 연결 시각에 사용할 시계를 변경한다. (기본값: [`SystemClock`](crate::clock::SystemClock))
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.series_store = self.series_store.with_clock(clock.clone());
+        self.link_store = self.link_store.with_clock(clock);
+        self
+    }
+
+    /// 새 시리즈의 아이디 발급기를 변경한다. (기본값: 데이터베이스 시퀀스)
+    pub fn with_id_generator(mut self, ids: SharedIdGenerator) -> Self {
+        self.series_store = self.series_store.with_id_generator(ids);
+        self
+    }
 }
 
 impl SeriesRepository for DieselSeriesRepository {
@@ -35,6 +128,15 @@ impl SeriesRepository for DieselSeriesRepository {
             .collect()
     }
 
+    fn find_by_id(&self, id: &[u64]) -> Vec<Series> {
+        let entities = self.series_store.find_by_id(id)
+            .unwrap_or_else(logging_with_default_vec);
+
+        entities.into_iter()
+            .map(|series| series.into())
+            .collect()
+    }
+
     fn similarity(&self, series: &Series, limit: i32) -> Vec<(Series, Option<f64>)> {
         let results = self.series_store.cosine_distance(series, limit)
             .unwrap_or_else(logging_with_default_vec);
@@ -44,6 +146,14 @@ impl SeriesRepository for DieselSeriesRepository {
             .collect()
     }
 
+    fn find_candidates(&self, title: &str, publisher_id: u64, limit: usize) -> Vec<Series> {
+        self.series_store.find_candidates(title, publisher_id as i64, limit)
+            .unwrap_or_else(logging_with_default_vec)
+            .into_iter()
+            .map(|series| series.into())
+            .collect()
+    }
+
     fn new_series(&self, series: &[Series]) -> Vec<Series> {
         self.series_store.new_series(series)
             .unwrap_or_else(logging_with_default_vec)
@@ -56,67 +166,255 @@ impl SeriesRepository for DieselSeriesRepository {
         self.series_store.update_series_isbn(series_id, isbn)
             .unwrap_or_else(logging_with_default_usize)
     }
+
+    fn find_without_cover(&self, limit: usize) -> Vec<Series> {
+        self.series_store.find_without_cover(limit)
+            .unwrap_or_else(logging_with_default_vec)
+            .into_iter()
+            .map(|series| series.into())
+            .collect()
+    }
+
+    fn update_series_cover(&self, series_id: u64, cover: &str) -> usize {
+        self.series_store.update_series_cover(series_id, cover)
+            .unwrap_or_else(logging_with_default_usize)
+    }
+
+    fn save_links(&self, links: &[SeriesLink]) -> usize {
+        if links.is_empty() {
+            return 0;
+        }
+        self.link_store.upsert(links)
+            .unwrap_or_else(logging_with_default_usize)
+    }
+
+    fn delete_links(&self, book_id: &[u64]) -> usize {
+        let book_id = book_id.iter().map(|id| *id as i64).collect::<Vec<_>>();
+        self.link_store.delete_by_book_id(&book_id)
+            .unwrap_or_else(logging_with_default_usize)
+    }
+
+    fn find_links_below(&self, score: f64, limit: usize) -> Vec<SeriesLink> {
+        self.link_store.find_score_below(score, limit)
+            .unwrap_or_else(logging_with_default_vec)
+            .into_iter()
+            .filter_map(|link| link.to_domain())
+            .collect()
+    }
+
+    fn delete_empty_series(&self, series_id: &[u64]) -> Vec<u64> {
+        if series_id.is_empty() {
+            return Vec::new();
+        }
+        self.series_store.delete_empty(series_id)
+            .unwrap_or_else(logging_with_default_vec)
+            .into_iter()
+            .map(|id| id as u64)
+            .collect()
+    }
+}
+
+/// 원본 데이터 저장소 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OriginStoreError(pub String);
+
+impl fmt::Display for OriginStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// 도서의 사이트별 원본 데이터 저장소
+///
+/// # Description
+/// [`ComposeBookRepository`]는 도서 정보와 원본 데이터를 이 트레이트를 통해 분리하여 저장한다.
+/// 기본 구현은 PostgreSQL의 `books.book_origin_data` 테이블(JSONB)을 사용하며,
+/// 설정에 따라 MongoDB 컬렉션([`MongoOriginStore`])을 사용할 수 있다.
+pub trait OriginStore {
+    /// 도서 아이디들의 원본 데이터를 `(도서 아이디, 사이트, 원본 데이터)`로 반환한다.
+    /// 프로젝션이 있는 경우 프로젝션에 포함된 사이트의 원본 데이터에서 지정된 키만 반환한다.
+    fn find_by_book_id(&self, book_id: &[i64], projection: Option<&OriginProjection>) -> Result<Vec<(i64, Site, Raw)>, OriginStoreError>;
+
+    /// 도서의 사이트별 원본 데이터를 저장하고 저장된 원본 데이터의 수를 반환한다.
+    fn new_original_data(&self, book_id: i64, originals: &Originals) -> Result<usize, OriginStoreError>;
+
+    /// 도서의 사이트 원본 데이터를 삭제하고 삭제된 원본 데이터의 수를 반환한다.
+    fn delete_by_site(&self, book_id: i64, site: &Site) -> Result<usize, OriginStoreError>;
+
+    /// 도서 아이디들의 모든 사이트 원본 데이터를 삭제하고 삭제된 원본 데이터의 수를 반환한다.
+    fn delete_by_book_id(&self, book_id: &[i64]) -> Result<usize, OriginStoreError>;
+}
+
+impl OriginStore for BookOriginDataPgStore {
+    fn find_by_book_id(&self, book_id: &[i64], projection: Option<&OriginProjection>) -> Result<Vec<(i64, Site, Raw)>, OriginStoreError> {
+        let sites = projection.map(|p| p.keys().map(|site| site.to_string()).collect::<Vec<_>>());
+        let entities = BookOriginDataPgStore::find_by_book_id(self, book_id, sites.as_deref())
+            .map_err(|e| OriginStoreError(format!("{:?}", e)))?;
+
+        // JSONB 컬럼은 사이트별로 키가 달라 조회 후 필요한 키만 남긴다.
+        Ok(entities.into_iter()
+            .filter_map(|entity| {
+                let book_id = entity.book_id;
+                let (site, raw) = entity.to_domain();
+                match projection {
+                    Some(projection) => projection.get(&site)
+                        .map(|keys| (book_id, site, raw_utils::project_raw(raw, keys))),
+                    None => Some((book_id, site, raw)),
+                }
+            })
+            .collect())
+    }
+
+    fn new_original_data(&self, book_id: i64, originals: &Originals) -> Result<usize, OriginStoreError> {
+        BookOriginDataPgStore::new_original_data(self, book_id, originals)
+            .map(|v| v.len())
+            .map_err(|e| OriginStoreError(format!("{:?}", e)))
+    }
+
+    fn delete_by_site(&self, book_id: i64, site: &Site) -> Result<usize, OriginStoreError> {
+        self.delete_boko_origin_data_by_site(book_id, site)
+            .map_err(|e| OriginStoreError(format!("{:?}", e)))
+    }
+
+    fn delete_by_book_id(&self, book_id: &[i64]) -> Result<usize, OriginStoreError> {
+        BookOriginDataPgStore::delete_by_book_id(self, book_id)
+            .map_err(|e| OriginStoreError(format!("{:?}", e)))
+    }
+}
+
+/// 도서 저장소의 원본 데이터 사용 방식
+///
+/// # Description
+/// 도서를 조회, 저장, 수정할 때 각각 원본 데이터를 함께 조회, 저장, 수정할지 여부를 나타낸다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::repo::OriginMode;
+///
+/// assert_eq!(OriginMode::READ_ONLY, OriginMode { read: true, insert: false, update: false });
+/// assert_eq!(OriginMode::default(), OriginMode::NONE);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OriginMode {
+    /// 도서 조회시 원본 데이터를 함께 조회
+    pub read: bool,
+
+    /// 도서 저장시 원본 데이터를 함께 저장
+    pub insert: bool,
+
+    /// 도서 수정시 원본 데이터를 함께 수정
+    pub update: bool,
+}
+
+impl OriginMode {
+    /// 원본 데이터를 사용하지 않는다.
+    pub const NONE: OriginMode = OriginMode { read: false, insert: false, update: false };
+
+    /// 원본 데이터를 조회만 하며 저장, 수정하지 않는다.
+    pub const READ_ONLY: OriginMode = OriginMode { read: true, insert: false, update: false };
+
+    /// 원본 데이터를 조회, 저장, 수정한다.
+    pub const ALL: OriginMode = OriginMode { read: true, insert: true, update: true };
 }
 
 pub struct ComposeBookRepository {
     book_store: BookPgStore,
-    origin_store: BookOriginDataPgStore,
+    origin_store: Box<dyn OriginStore>,
 
-    read_with_origin: bool,
-    insert_with_origin: bool,
-    update_with_origin: bool,
+    /// 원본 데이터 조회시 사용할 프로젝션 (`None`일 경우 모든 원본 데이터를 조회한다.)
+    origin_projection: Option<OriginProjection>,
+
+    /// 원본 데이터 사용 방식
+    origin_mode: OriginMode,
 }
 
 impl ComposeBookRepository {
 
-
-    pub fn new(db_pool: Pool<ConnectionManager<PgConnection>>, read_with_origin: bool, insert_with_origin: bool, update_with_origin: bool) -> Self {
-        Self { 
+    pub fn new(db_pool: Pool<ConnectionManager<PgConnection>>, origin_mode: OriginMode) -> Self {
+        Self {
             book_store: BookPgStore::new(db_pool.clone()),
-            origin_store: BookOriginDataPgStore::new(db_pool.clone()),
-            read_with_origin,
-            insert_with_origin,
-            update_with_origin
+            origin_store: Box::new(BookOriginDataPgStore::new(db_pool.clone())),
+            origin_projection: None,
+            origin_mode,
         }
     }
 
+    /// 원본 데이터를 사용하지 않는 도서 저장소 ([`OriginMode::NONE`])
     pub fn without_origin(db_pool: Pool<ConnectionManager<PgConnection>>) -> Self {
-        Self {
-            book_store: BookPgStore::new(db_pool.clone()),
-            origin_store: BookOriginDataPgStore::new(db_pool.clone()),
-            read_with_origin: false,
-            insert_with_origin: false,
-            update_with_origin: false,
-        }
+        Self::new(db_pool, OriginMode::NONE)
     }
 
+    /// 원본 데이터를 조회만 하는 도서 저장소 ([`OriginMode::READ_ONLY`])
+    pub fn read_only_origin(db_pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self::new(db_pool, OriginMode::READ_ONLY)
+    }
+
+    /// 원본 데이터를 조회, 저장, 수정하는 도서 저장소 ([`OriginMode::ALL`])
     pub fn with_origin(db_pool: Pool<ConnectionManager<PgConnection>>) -> Self {
-        Self {
-            book_store: BookPgStore::new(db_pool.clone()),
-            origin_store: BookOriginDataPgStore::new(db_pool.clone()),
-            read_with_origin: true,
-            insert_with_origin: true,
-            update_with_origin: true,
-        }
+        Self::new(db_pool, OriginMode::ALL)
+    }
+
+    /// 출판일 기간 조회, 시리즈 미분류 도서 조회, 유사 도서 검색을 읽기 전용 복제본에서 실행한다.
+    /// 그 외 조회와 저장은 기존 연결(primary)을 사용한다.
+    pub fn with_replica(mut self, replica: Pool<ConnectionManager<PgConnection>>) -> Self {
+        self.book_store = self.book_store.with_replica(replica);
+        self
+    }
+
+    /// 원본 데이터를 조회할 때 프로젝션에 지정된 사이트, 키만 조회한다.
+    ///
+    /// # Note
+    /// 일부 키만 조회한 원본 데이터를 다시 저장하면 나머지 키가 사라지므로 원본 데이터를 수정하지 않는 저장소([`OriginMode::update`]가 `false`)에만 사용해야 한다.
+    pub fn with_origin_projection(mut self, projection: OriginProjection) -> Self {
+        self.origin_projection = Some(projection);
+        self
+    }
+
+    /// 원본 데이터 저장소를 변경한다. (기본값: PostgreSQL `books.book_origin_data` 테이블)
+    pub fn with_origin_store(mut self, origin_store: Box<dyn OriginStore>) -> Self {
+        self.origin_store = origin_store;
+        self
+    }
+
+    /// 도서 등록, 수정 시각에 사용할 시계를 변경한다. (기본값: [`SystemClock`](crate::clock::SystemClock))
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.book_store = self.book_store.with_clock(clock);
+        self
+    }
+
+    /// 새 도서의 아이디 발급기를 변경한다. (기본값: 데이터베이스 시퀀스)
+    pub fn with_id_generator(mut self, ids: SharedIdGenerator) -> Self {
+        self.book_store = self.book_store.with_id_generator(ids);
+        self
     }
 }
 
 impl ComposeBookRepository {
-    fn load_original_data(&self, entities: &[BookEntity]) -> HashMap<i64, (Site, Raw)> {
+    fn load_original_data(&self, entities: &[BookEntity]) -> HashMap<i64, Vec<(Site, Raw)>> {
         let book_ids = entities.iter()
             .map(|e| e.id)
             .collect::<Vec<_>>();
 
-        let originals = self.origin_store.find_by_book_id(&book_ids)
+        let originals = self.origin_store.find_by_book_id(&book_ids, self.origin_projection.as_ref())
             .unwrap_or_else(|e| logging_with_default_vec(e));
 
-        originals.into_iter()
-            .map(|origin| {
-                let book_id = origin.book_id;
-                let (site, original) = origin.to_domain();
-                (book_id, (site, original))
-            })
-            .collect()
+        // 하나의 도서는 여러 사이트의 원본 데이터를 가질 수 있으므로 도서 아이디 별로 모든 사이트의 원본 데이터를 모은다.
+        let mut result: HashMap<i64, Vec<(Site, Raw)>> = HashMap::new();
+        for (book_id, site, raw) in originals {
+            result.entry(book_id).or_default().push((site, raw));
+        }
+        result
+    }
+
+    /// 도서의 사이트별 원본 데이터를 삭제한 후 새 원본 데이터를 저장하고 저장된 원본 데이터의 수를 반환한다.
+    fn replace_originals(&self, book_id: u64, originals: &Originals) -> usize {
+        let book_id = book_id as i64;
+        for (site, _) in originals.iter() {
+            _ = self.origin_store.delete_by_site(book_id, site)
+                .unwrap_or_else(|e| logging_with_default_usize(e));
+        }
+        self.origin_store.new_original_data(book_id, originals)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
     }
 }
 
@@ -126,7 +424,7 @@ impl BookRepository for ComposeBookRepository {
             .find_by_pub_between(from, to)
             .unwrap_or_else(|e| logging_with_default_vec(e));
 
-        let mut originals = match self.read_with_origin {
+        let mut originals = match self.origin_mode.read {
             true => self.load_original_data(&book_entities),
             false => HashMap::new(),
         };
@@ -141,7 +439,7 @@ impl BookRepository for ComposeBookRepository {
             .find_by_isbn(isbn)
             .unwrap_or_else(|e| logging_with_default_vec(e));
 
-        let mut originals = match self.read_with_origin {
+        let mut originals = match self.origin_mode.read {
             true => self.load_original_data(&book_entities),
             false => HashMap::new(),
         };
@@ -166,14 +464,14 @@ impl BookRepository for ComposeBookRepository {
             return vec![];
         }
 
-        if self.insert_with_origin {
+        if self.origin_mode.insert {
             saved_book_entities.iter()
                 .filter_map(|e| {
                     isbn_with_origin.get(&e.isbn).map(|o| (e.id, o))
                 })
                 .for_each(|(id, original)| {
                     _ = self.origin_store.new_original_data(id, original)
-                        .unwrap_or_else(|e| logging_with_default_vec(e));
+                        .unwrap_or_else(|e| logging_with_default_usize(e));
                 });
         }
 
@@ -195,26 +493,48 @@ impl BookRepository for ComposeBookRepository {
         let mut updated_count = self.book_store.update_book(book)
             .unwrap_or_else(|e| logging_with_default_usize(e));
 
-        if self.update_with_origin {
-            let book_id = book.id as i64;
-            for (site, _) in book.originals.iter() {
-                _ = self.origin_store.delete_boko_origin_data_by_site(book_id, site)
-                    .unwrap_or_else(|e| logging_with_default_usize(e));
+        if self.origin_mode.update {
+            updated_count += self.replace_originals(book.id, book.originals());
+        }
+
+        updated_count
+    }
+
+    fn update_books(&self, books: &[Book]) -> usize {
+        if books.is_empty() {
+            return 0;
+        }
+        let updated_count = self.book_store.update_books(books)
+            .unwrap_or_else(|e| logging_with_default_usize(e));
+
+        // 도서 업데이트가 롤백된 경우 원본 데이터도 수정하지 않는다.
+        if self.origin_mode.update && updated_count > 0 {
+            for book in books {
+                self.replace_originals(book.id, book.originals());
             }
-            updated_count += self.origin_store.new_original_data(book_id, book.originals())
-                .map(|v| v.len())
-                .unwrap_or_else(|e| logging_with_default_usize(e));
         }
 
         updated_count
     }
 
+    fn update_origins(&self, book_id: u64, originals: &Originals) -> usize {
+        if !self.origin_mode.update {
+            warn!("Origin update is disabled, {} origins of book {} are not updated", originals.len(), book_id);
+            return 0;
+        }
+        self.replace_originals(book_id, originals)
+    }
+
+    fn is_origin_update_enabled(&self) -> bool {
+        self.origin_mode.update
+    }
+
     fn find_series_unorganized(&self, limit: usize) -> Vec<Book> {
         let book_entities = self.book_store
             .find_series_unorganized(limit)
             .unwrap_or_else(|e| logging_with_default_vec(e));
 
-        let mut originals = match self.read_with_origin {
+        let mut originals = match self.origin_mode.read {
             true => self.load_original_data(&book_entities),
             false => HashMap::new(),
         };
@@ -229,7 +549,95 @@ impl BookRepository for ComposeBookRepository {
             .find_by_series_id(series_id)
             .unwrap_or_else(|e| logging_with_default_vec(e));
 
-        let mut originals = match self.read_with_origin {
+        let mut originals = match self.origin_mode.read {
+            true => self.load_original_data(&book_entities),
+            false => HashMap::new(),
+        };
+
+        book_entities.into_iter()
+            .map(|entity| compose_entity_with_original(entity, &mut originals))
+            .collect()
+    }
+
+    fn find_series_linked_by_publisher(&self, publisher_id: u64) -> Vec<Book> {
+        let book_entities = self.book_store
+            .find_series_linked_by_publisher(publisher_id)
+            .unwrap_or_else(|e| logging_with_default_vec(e));
+
+        let mut originals = match self.origin_mode.read {
+            true => self.load_original_data(&book_entities),
+            false => HashMap::new(),
+        };
+
+        book_entities.into_iter()
+            .map(|entity| compose_entity_with_original(entity, &mut originals))
+            .collect()
+    }
+
+    fn find_title_unnormalized(&self, limit: usize) -> Vec<Book> {
+        let book_entities = self.book_store
+            .find_title_unnormalized(limit)
+            .unwrap_or_else(|e| logging_with_default_vec(e));
+
+        let mut originals = match self.origin_mode.read {
+            true => self.load_original_data(&book_entities),
+            false => HashMap::new(),
+        };
+
+        book_entities.into_iter()
+            .map(|entity| compose_entity_with_original(entity, &mut originals))
+            .collect()
+    }
+
+    fn unlink_series(&self, book_id: u64) -> usize {
+        self.book_store.unlink_series(book_id)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+
+    fn save_vector(&self, book_id: u64, vec: &[f32]) -> usize {
+        self.book_store.upsert_vector(book_id, vec)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+
+    fn similar_books(&self, vec: &[f32], limit: i32) -> Vec<(Book, f64)> {
+        let results = self.book_store
+            .cosine_distance(vec, limit)
+            .unwrap_or_else(|e| logging_with_default_vec(e));
+
+        let (book_entities, distances): (Vec<BookEntity>, Vec<f64>) = results.into_iter().unzip();
+        let mut originals = match self.origin_mode.read {
+            true => self.load_original_data(&book_entities),
+            false => HashMap::new(),
+        };
+
+        book_entities.into_iter()
+            .map(|entity| compose_entity_with_original(entity, &mut originals))
+            .zip(distances)
+            .collect()
+    }
+
+    fn delete_books(&self, book_id: &[u64]) -> usize {
+        if book_id.is_empty() {
+            return 0;
+        }
+        let book_id = book_id.iter().map(|id| *id as i64).collect::<Vec<_>>();
+        let deleted_count = self.book_store.delete_books(&book_id)
+            .unwrap_or_else(|e| logging_with_default_usize(e));
+
+        // PostgreSQL 원본 데이터는 도서와 함께 삭제되며, 별도 저장소(MongoDB)의 원본 데이터는 도서 삭제가 커밋된 후 삭제한다.
+        if deleted_count > 0 {
+            _ = self.origin_store.delete_by_book_id(&book_id)
+                .unwrap_or_else(|e| logging_with_default_usize(e));
+        }
+        deleted_count
+    }
+
+    fn find_by_registered_between(&self, from: &NaiveDateTime, to: &NaiveDateTime) -> Vec<Book> {
+        let book_entities = self.book_store
+            .find_by_registered_between(from, to)
+            .unwrap_or_else(|e| logging_with_default_vec(e));
+
+        let mut originals = match self.origin_mode.read {
             true => self.load_original_data(&book_entities),
             false => HashMap::new(),
         };
@@ -295,16 +703,17 @@ impl FilterRepository for DieselFilterRepository {
         }
 
         // 필터, 부모 필터 아이디, 루트 필터 여부
+        // 실행 마다 피연산자의 순서가 바뀌지 않도록 아이디 순서로 정렬된 맵을 사용하며
+        // 자식 필터는 아이디 오름차순으로 부모 필터에 추가 된다.
         struct Node(Rc<RefCell<FilterRule>>, Option<i64>, bool);
-        let filter_map: HashMap<i64, Node> = filter_entities.iter()
+        let filter_map: BTreeMap<i64, Node> = filter_entities.iter()
             .map(|e| {
                 let rule = Rc::new(RefCell::new(e.to_domain()));
                 (e.id, Node(rule, e.parent_id, e.is_root))
             })
             .collect();
 
-        for filter in filter_entities.iter() {
-            let current_node = filter_map.get(&filter.id).unwrap();
+        for current_node in filter_map.values() {
             if let Some(parent) = current_node.1 {
                 let parent_node = filter_map.get(&parent).unwrap();
                 parent_node.0.borrow_mut().add_operand(current_node.0.clone());
@@ -321,10 +730,428 @@ impl FilterRepository for DieselFilterRepository {
     }
 }
 
-fn compose_entity_with_original(book_entity: BookEntity, originals: &mut HashMap<i64, (Site, Raw)>) -> Book {
+pub struct DieselAvailabilityRepository {
+    store: BookAvailabilityPgStore
+}
+
+impl DieselAvailabilityRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: BookAvailabilityPgStore::new(pool),
+        }
+    }
+}
+
+impl AvailabilityRepository for DieselAvailabilityRepository {
+
+    fn find_by_book_id(&self, book_id: &[u64]) -> Vec<Availability> {
+        let book_id = book_id.iter()
+            .map(|id| *id as i64)
+            .collect::<Vec<_>>();
+
+        self.store.find_by_book_id(&book_id)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+            .into_iter()
+            .map(|entity| entity.to_domain())
+            .collect()
+    }
+
+    fn save_availability(&self, availability: &[Availability]) -> usize {
+        if availability.is_empty() {
+            return 0;
+        }
+        self.store.upsert(availability)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselRetryRepository {
+    store: EnrichmentRetryPgStore
+}
+
+impl DieselRetryRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: EnrichmentRetryPgStore::new(pool),
+        }
+    }
+}
+
+impl RetryRepository for DieselRetryRepository {
+
+    fn find_due(&self, site: &Site, now: &chrono::NaiveDateTime, limit: usize) -> Vec<EnrichmentRetry> {
+        self.store.find_due(site, now, limit)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+            .into_iter()
+            .map(|entity| entity.to_domain())
+            .collect()
+    }
+
+    fn find_by_isbn(&self, site: &Site, isbn: &[&str]) -> Vec<EnrichmentRetry> {
+        self.store.find_by_isbn(site, isbn)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+            .into_iter()
+            .map(|entity| entity.to_domain())
+            .collect()
+    }
+
+    fn save_retry(&self, retry: &[EnrichmentRetry]) -> usize {
+        if retry.is_empty() {
+            return 0;
+        }
+        self.store.upsert(retry)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+
+    fn delete_retry(&self, site: &Site, isbn: &[&str]) -> usize {
+        if isbn.is_empty() {
+            return 0;
+        }
+        self.store.delete_by_isbn(site, isbn)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselSeriesOverrideRepository {
+    store: SeriesOverridePgStore
+}
+
+impl DieselSeriesOverrideRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: SeriesOverridePgStore::new(pool),
+        }
+    }
+}
+
+impl SeriesOverrideRepository for DieselSeriesOverrideRepository {
+
+    fn find_by_isbn(&self, isbn: &[&str]) -> Vec<SeriesOverride> {
+        if isbn.is_empty() {
+            return vec![];
+        }
+        self.store.find_by_isbn(isbn)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+            .into_iter()
+            .map(|entity| entity.to_domain())
+            .collect()
+    }
+
+    fn find_unapplied_isbn(&self, limit: usize) -> Vec<String> {
+        self.store.find_unapplied_isbn(limit)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+    }
+}
+
+pub struct DieselTitleNormalizationRepository {
+    store: TitleNormalizationPgStore
+}
+
+impl DieselTitleNormalizationRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: TitleNormalizationPgStore::new(pool),
+        }
+    }
+}
+
+impl TitleNormalizationRepository for DieselTitleNormalizationRepository {
+
+    fn find_by_book_id(&self, book_id: &[u64]) -> Vec<TitleNormalization> {
+        if book_id.is_empty() {
+            return vec![];
+        }
+        let book_id = book_id.iter().map(|id| *id as i64).collect::<Vec<_>>();
+        self.store.find_by_book_id(&book_id)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+            .into_iter()
+            .map(|entity| entity.to_domain())
+            .collect()
+    }
+
+    fn save(&self, normalization: &[TitleNormalization]) -> usize {
+        if normalization.is_empty() {
+            return 0;
+        }
+        self.store.upsert(normalization)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+
+    fn find_title_changed_isbn(&self, limit: usize) -> Vec<String> {
+        self.store.find_title_changed_isbn(limit)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+    }
+}
+
+pub struct DieselBackfillRepository {
+    store: BackfillProgressPgStore
+}
+
+impl DieselBackfillRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: BackfillProgressPgStore::new(pool),
+        }
+    }
+}
+
+impl BackfillRepository for DieselBackfillRepository {
+
+    fn find_progress(&self, site: &Site, publisher_id: u64) -> Option<BackfillProgress> {
+        self.store.find(site, publisher_id as i64)
+            .unwrap_or_else(|e| {
+                error!("{:?}", e);
+                None
+            })
+            .and_then(|entity| entity.to_domain())
+    }
+
+    fn save_progress(&self, progress: &BackfillProgress) -> usize {
+        self.store.upsert(progress)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselCollectionStatusRepository {
+    store: CollectionStatusPgStore
+}
+
+impl DieselCollectionStatusRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: CollectionStatusPgStore::new(pool),
+        }
+    }
+}
+
+impl CollectionStatusRepository for DieselCollectionStatusRepository {
+
+    fn find_all(&self) -> Vec<CollectionStatus> {
+        self.store.find_all()
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+            .into_iter()
+            .filter_map(|entity| entity.to_domain())
+            .collect()
+    }
+
+    fn save_status(&self, status: &[CollectionStatus]) -> usize {
+        if status.is_empty() {
+            return 0;
+        }
+        self.store.upsert(status)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselVolumeRepository {
+    store: CollectionVolumePgStore
+}
+
+impl DieselVolumeRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: CollectionVolumePgStore::new(pool),
+        }
+    }
+}
+
+impl VolumeRepository for DieselVolumeRepository {
+
+    fn find_recent(&self, job_name: &str, publisher_id: u64, limit: usize) -> Vec<CollectionVolume> {
+        self.store.find_recent(job_name, publisher_id, limit)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+            .into_iter()
+            .map(|entity| entity.to_domain())
+            .collect()
+    }
+
+    fn save_volumes(&self, volumes: &[CollectionVolume]) -> usize {
+        if volumes.is_empty() {
+            return 0;
+        }
+        self.store.insert(volumes)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselQuotaRepository {
+    store: ProviderQuotaPgStore
+}
+
+impl DieselQuotaRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: ProviderQuotaPgStore::new(pool),
+        }
+    }
+
+    /// 요청 수 수정 시각에 사용할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.store = self.store.with_clock(clock);
+        self
+    }
+}
+
+impl QuotaRepository for DieselQuotaRepository {
+
+    fn find_used(&self, site: &Site, date: &NaiveDate) -> u32 {
+        self.store.find_used(site, date)
+            .unwrap_or_else(|e| {
+                error!("{:?}", e);
+                None
+            })
+            .map(|used| used.max(0) as u32)
+            .unwrap_or(0)
+    }
+
+    fn add_used(&self, site: &Site, date: &NaiveDate, count: u32) -> usize {
+        self.store.add_used(site, date, count as i32)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselJobExecutionRepository {
+    store: JobExecutionPgStore
+}
+
+impl DieselJobExecutionRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: JobExecutionPgStore::new(pool),
+        }
+    }
+}
+
+impl JobExecutionRepository for DieselJobExecutionRepository {
+
+    fn start(&self, job_name: &str, parameters: &str, param_hash: &str, started_at: &NaiveDateTime) -> Option<u64> {
+        self.store.insert(job_name, parameters, param_hash, started_at)
+            .map(|id| id as u64)
+            .map_err(|e| error!("{:?}", e))
+            .ok()
+    }
+
+    fn find_completed(&self, param_hash: &str) -> Option<u64> {
+        self.store.find_id_by_hash_and_status(param_hash, JobStatus::Completed)
+            .map_err(|e| error!("{:?}", e))
+            .ok()
+            .flatten()
+            .map(|id| id as u64)
+    }
+
+    fn finish(&self, id: u64, status: JobStatus, finished_at: &NaiveDateTime, audit_path: Option<&str>, counts: &JobExecutionCounts) -> usize {
+        self.store.update_finished(id as i64, status, finished_at, audit_path, counts)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselDeadLetterRepository {
+    store: DeadLetterPgStore
+}
+
+impl DieselDeadLetterRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: DeadLetterPgStore::new(pool),
+        }
+    }
+
+    /// 격리 시각에 사용할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.store = self.store.with_clock(clock);
+        self
+    }
+}
+
+impl DeadLetterRepository for DieselDeadLetterRepository {
+
+    fn increment_attempts(&self, job_name: &str, error_class: &str, isbn: &[String]) -> HashMap<String, u32> {
+        if isbn.is_empty() {
+            return HashMap::new();
+        }
+        self.store.increment(job_name, error_class, isbn)
+            .map(|attempts| attempts.into_iter().map(|(isbn, attempts)| (isbn, attempts as u32)).collect())
+            .unwrap_or_else(|e| {
+                error!("{:?}", e);
+                HashMap::new()
+            })
+    }
+}
+
+pub struct DieselCheckpointRepository {
+    store: JobCheckpointPgStore
+}
+
+impl DieselCheckpointRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: JobCheckpointPgStore::new(pool),
+        }
+    }
+
+    /// 체크포인트 저장 시각에 사용할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.store = self.store.with_clock(clock);
+        self
+    }
+}
+
+impl CheckpointRepository for DieselCheckpointRepository {
+
+    fn find_checkpoint(&self, key: &str) -> Option<u64> {
+        self.store.find_chunk_index(key)
+            .map_err(|e| error!("{:?}", e))
+            .ok()
+            .flatten()
+            .map(|index| index as u64)
+    }
+
+    fn save_checkpoint(&self, key: &str, job_name: &str, chunk_index: u64) -> usize {
+        self.store.upsert(key, job_name, chunk_index as i64)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+
+    fn delete_checkpoint(&self, key: &str) -> usize {
+        self.store.delete(key)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+pub struct DieselIsbnSetRepository {
+    store: IsbnSetPgStore
+}
+
+impl DieselIsbnSetRepository {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self {
+            store: IsbnSetPgStore::new(pool),
+        }
+    }
+
+    /// ISBN 등록 시각에 사용할 시계를 변경한다.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.store = self.store.with_clock(clock);
+        self
+    }
+}
+
+impl IsbnSetRepository for DieselIsbnSetRepository {
+
+    fn find_isbn(&self, name: &str) -> Vec<String> {
+        self.store.find_by_name(name)
+            .unwrap_or_else(|e| logging_with_default_vec(e))
+    }
+
+    fn save_isbn(&self, name: &str, isbn: &[String]) -> usize {
+        self.store.insert(name, isbn)
+            .unwrap_or_else(|e| logging_with_default_usize(e))
+    }
+}
+
+fn compose_entity_with_original(book_entity: BookEntity, originals: &mut HashMap<i64, Vec<(Site, Raw)>>) -> Book {
     let entity_id = book_entity.id;
     let mut builder: BookBuilder = book_entity.into();
-    if let Some((site, original)) = originals.remove(&entity_id) {
+    for (site, original) in originals.remove(&entity_id).unwrap_or_default() {
         builder = builder.add_original(site, original);
     }
     builder.build().unwrap()
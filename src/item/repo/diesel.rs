@@ -1,4 +1,6 @@
-use crate::item::{Book, BookBuilder, FilterRule, Operator, Originals, Raw, RawValue, Series, Site};
+use crate::clock::{system_clock, SharedClock};
+use crate::item::repo::{database_id_generator, SharedIdGenerator, VectorSearch};
+use crate::item::{raw_utils, Availability, BackfillProgress, Book, BookBuilder, CollectionStatus, CollectionVolume, EnrichmentRetry, FilterRule, JobExecutionCounts, JobStatus, Operator, Originals, Raw, RawValue, SaleStatus, Series, SeriesLink, SeriesLinkConfidence, SeriesOverride, SeriesOverrideTarget, Site, TitleNormalization};
 use diesel::prelude::*;
 use diesel::r2d2::ConnectionManager;
 use r2d2::Pool;
@@ -32,6 +34,7 @@ pub struct SeriesEntity {
     pub vec: Option<pgvector::Vector>,
     pub registered_at : chrono::NaiveDateTime,
     pub modified_at: Option<chrono::NaiveDateTime>,
+    pub cover: Option<String>,
 }
 
 impl From<SeriesEntity> for Series {
@@ -53,6 +56,9 @@ impl From<SeriesEntity> for Series {
         if let Some(isbn) = value.isbn {
             builder = builder.isbn(isbn);
         }
+        if let Some(cover) = value.cover {
+            builder = builder.cover(cover);
+        }
         builder.build().unwrap()
     }
 }
@@ -60,30 +66,71 @@ impl From<SeriesEntity> for Series {
 #[derive(Insertable)]
 #[diesel(table_name = schema::books::series)]
 pub struct NewSeries<'a> {
+    pub id: Option<i64>,
     pub name: Option<&'a str>,
     pub isbn: Option<&'a str>,
     pub vec: Option<pgvector::Vector>,
+    pub cover: Option<&'a str>,
     pub registered_at : chrono::NaiveDateTime
 }
 
-impl <'a> From<&'a Series> for NewSeries<'a> {
-    fn from(value: &'a Series) -> Self {
+impl <'a> NewSeries<'a> {
+    /// `id`가 `None`인 경우 데이터베이스 시퀀스가 아이디를 발급한다.
+    pub fn new(value: &'a Series, id: Option<u64>, registered_at: chrono::NaiveDateTime) -> Self {
         Self {
+            id: id.map(|id| id as i64),
             name: value.title().as_ref().map(|x| x.as_str()),
             isbn: value.isbn().as_ref().map(|x| x.as_str()),
             vec: value.vec().as_ref().map(|x| pgvector::Vector::from(x.clone())),
-            registered_at: chrono::Local::now().naive_local(),
+            cover: value.cover().as_ref().map(|x| x.as_str()),
+            registered_at,
         }
     }
 }
 
 pub struct SeriesPgStore {
-    pool: Pool<ConnectionManager<PgConnection>>
+    pool: Pool<ConnectionManager<PgConnection>>,
+
+    /// 조회 부하가 큰 쿼리를 실행할 읽기 전용 복제본 (설정 되지 않은 경우 `pool`을 사용한다.)
+    replica: Option<Pool<ConnectionManager<PgConnection>>>,
+
+    /// 등록 시각에 사용할 시계
+    clock: SharedClock,
+
+    /// 유사도 검색 방식
+    vector_search: VectorSearch,
+
+    /// 새 시리즈의 아이디 발급기
+    ids: SharedIdGenerator,
 }
 
 impl SeriesPgStore {
     pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
-        Self { pool }
+        Self { pool, replica: None, clock: system_clock(), vector_search: VectorSearch::default(), ids: database_id_generator() }
+    }
+
+    pub fn with_id_generator(mut self, ids: SharedIdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    pub fn with_vector_search(mut self, vector_search: VectorSearch) -> Self {
+        self.vector_search = vector_search;
+        self
+    }
+
+    pub fn with_replica(mut self, replica: Pool<ConnectionManager<PgConnection>>) -> Self {
+        self.replica = Some(replica);
+        self
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    fn read_pool(&self) -> &Pool<ConnectionManager<PgConnection>> {
+        self.replica.as_ref().unwrap_or(&self.pool)
     }
 }
 
@@ -105,6 +152,48 @@ impl SeriesPgStore {
         Ok(result)
     }
 
+    pub fn find_by_id(&self, ids: &[u64]) -> Result<Vec<SeriesEntity>, Error> {
+        use schema::books::series::dsl::{id, series};
+
+        let ids = ids.iter().map(|i| *i as i64).collect::<Vec<_>>();
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let result = series
+            .filter(id.eq_any(ids))
+            .order_by(id.asc())
+            .select(SeriesEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    /// 출판사의 도서가 속한 시리즈와 시리즈명이 title과 유사한(`pg_trgm`의 `%` 연산자) 시리즈를 최근 등록된 순서로 조회한다.
+    pub fn find_candidates(&self, title: &str, publisher_id: i64, limit: usize) -> Result<Vec<SeriesEntity>, Error> {
+        use schema::books::{book, series};
+        use diesel::dsl::sql;
+        use diesel::sql_types::{Bool, Text};
+
+        let mut connection = self.read_pool().get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let publisher_series = book::table
+            .filter(book::publisher_id.eq(publisher_id))
+            .filter(book::series_id.is_not_null())
+            .select(book::series_id.assume_not_null());
+        let similar_name = sql::<Bool>("books.series.name % ").bind::<Text, _>(title.to_owned());
+
+        series::table
+            .filter(series::vec.is_not_null())
+            .filter(series::id.eq_any(publisher_series).or(similar_name))
+            .order(series::registered_at.desc())
+            .limit(limit as i64)
+            .select(SeriesEntity::as_select())
+            .load::<SeriesEntity>(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
     pub fn cosine_distance(&self, series: &Series, limit: i32) -> Result<Vec<(SeriesEntity, Option<f64>)>, Error> {
         use schema::books::series::dsl::series as db_series;
         use schema::books::series::dsl::vec as db_vec;
@@ -119,20 +208,24 @@ impl SeriesPgStore {
             return Err(Error::InvalidParameter("vector dimension is must be 1024".to_owned()))
         }
 
-        let mut connection = self.pool.get()
+        let mut connection = self.read_pool().get()
             .map_err(|e| Error::ConnectError(e.to_string()))?;
 
-        let cosine_distance_query = QueryDsl::order(db_series, db_vec.cosine_distance(pgvector::Vector::from(vec.clone())));
-        let result = cosine_distance_query
-            .limit(limit as i64)
-            .select((
-                SeriesEntity::as_select(),
-                db_vec.cosine_distance(pgvector::Vector::from(vec.clone()))
-            ))
-            .load::<(SeriesEntity, Option<f64>)>(&mut connection)
-            .map_err(|err| Error::SqlExecuteError(err.to_string()))?;
+        // 검색 방식 설정(`SET LOCAL`)은 트랜잭션 안에서만 유지되므로 검색 쿼리와 같은 트랜잭션에서 실행한다.
+        connection.transaction::<_, diesel::result::Error, _>(|conn| {
+            for setting in self.vector_search.settings() {
+                diesel::sql_query(setting).execute(conn)?;
+            }
 
-        Ok(result)
+            let cosine_distance_query = QueryDsl::order(db_series, db_vec.cosine_distance(pgvector::Vector::from(vec.clone())));
+            cosine_distance_query
+                .limit(limit as i64)
+                .select((
+                    SeriesEntity::as_select(),
+                    db_vec.cosine_distance(pgvector::Vector::from(vec.clone()))
+                ))
+                .load::<(SeriesEntity, Option<f64>)>(conn)
+        }).map_err(|err| Error::SqlExecuteError(err.to_string()))
     }
 
     pub fn new_series<T: AsRef<Series>>(&self, series: &[T]) -> Result<Vec<SeriesEntity>, Error> {
@@ -141,8 +234,9 @@ impl SeriesPgStore {
         let mut connection = self.pool.get()
             .map_err(|e| Error::ConnectError(e.to_string()))?;
 
+        let now = self.clock.now();
         let entities = series.iter()
-            .map(|s| NewSeries::from(s.as_ref()))
+            .map(|s| NewSeries::new(s.as_ref(), self.ids.next_id(), now))
             .collect::<Vec<_>>();
 
         let results = diesel::insert_into(db_series::table)
@@ -170,6 +264,75 @@ impl SeriesPgStore {
 
         Ok(updated_count)
     }
+
+    /// 대표 이미지가 없는 시리즈를 최근 등록된 순서로 `limit` 개수 만큼 조회한다.
+    pub fn find_without_cover(&self, limit: usize) -> Result<Vec<SeriesEntity>, Error> {
+        use schema::books::series::dsl::{cover, id, series};
+
+        let mut connection = self.read_pool().get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let result = series
+            .filter(cover.is_null())
+            .order_by(id.desc())
+            .limit(limit as i64)
+            .select(SeriesEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    pub fn update_series_cover(&self, series_id: u64, cover: &str) -> Result<usize, Error> {
+        use schema::books::series::dsl::series as db_series;
+        use schema::books::series::dsl::{id, modified_at};
+        use schema::books::series::dsl::cover as db_cover;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let updated_count = diesel::update(db_series)
+            .filter(id.eq(series_id as i64))
+            .set((db_cover.eq(cover), modified_at.eq(self.clock.now())))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
+
+        Ok(updated_count)
+    }
+
+    /// 전달 받은 시리즈 중 도서(`book.series_id`), 도서 연결 정보, 운영자 지정 분류에서 참조하지 않는 시리즈를 하나의 트랜잭션으로 삭제하고 삭제된 시리즈 아이디를 반환한다.
+    pub fn delete_empty(&self, ids: &[u64]) -> Result<Vec<i64>, Error> {
+        use schema::books::{book, book_series_link, series, series_override};
+
+        let ids = ids.iter().map(|i| *i as i64).collect::<Vec<_>>();
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        connection.transaction::<_, diesel::result::Error, _>(|conn| {
+            let mut referenced = book::table
+                .filter(book::series_id.eq_any(&ids))
+                .select(book::series_id.assume_not_null())
+                .distinct()
+                .load::<i64>(conn)?;
+            referenced.extend(book_series_link::table
+                .filter(book_series_link::series_id.eq_any(&ids))
+                .select(book_series_link::series_id)
+                .distinct()
+                .load::<i64>(conn)?);
+            referenced.extend(series_override::table
+                .filter(series_override::series_id.eq_any(&ids))
+                .select(series_override::series_id.assume_not_null())
+                .distinct()
+                .load::<i64>(conn)?);
+
+            let empty = ids.iter()
+                .filter(|id| !referenced.contains(id))
+                .collect::<Vec<_>>();
+            diesel::delete(series::table.filter(series::id.eq_any(empty)))
+                .returning(series::id)
+                .get_results::<i64>(conn)
+        }).map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
 }
 
 #[derive(Queryable, Selectable)]
@@ -181,6 +344,8 @@ pub struct BookEntity {
     pub publisher_id: i64,
     pub series_id: Option<i64>,
     pub title: String,
+    pub normalized_title: Option<String>,
+    pub description: Option<String>,
     pub scheduled_pub_date: Option<chrono::NaiveDate>,
     pub actual_pub_date: Option<chrono::NaiveDate>,
 
@@ -200,6 +365,12 @@ impl From<BookEntity> for BookBuilder {
         if let Some(series_id) = value.series_id {
             builder = builder.series_id(series_id as u64);
         }
+        if let Some(normalized_title) = value.normalized_title {
+            builder = builder.normalized_title(normalized_title);
+        }
+        if let Some(description) = value.description {
+            builder = builder.description(description);
+        }
         if let Some(scheduled_pub_date) = value.scheduled_pub_date {
             builder = builder.scheduled_pub_date(scheduled_pub_date);
         }
@@ -215,31 +386,43 @@ impl From<BookEntity> for BookBuilder {
     }
 }
 
+#[derive(Insertable)]
+#[diesel(table_name = schema::books::book_vector)]
+pub struct BookVectorEntity {
+    pub book_id: i64,
+    pub vec: pgvector::Vector,
+    pub modified_at: chrono::NaiveDateTime,
+}
+
 #[derive(Insertable)]
 #[diesel(table_name = schema::books::book)]
 pub struct NewBook<'a> {
+    pub id: Option<i64>,
     pub isbn: &'a str,
     pub publisher_id: i64,
     pub series_id: Option<i64>,
     pub title: &'a str,
+    pub normalized_title: Option<&'a str>,
+    pub description: Option<&'a str>,
     pub scheduled_pub_date: Option<chrono::NaiveDate>,
     pub actual_pub_date: Option<chrono::NaiveDate>,
     pub registered_at : chrono::NaiveDateTime
 }
 
-impl <'a, 'b> From<&'b Book> for NewBook<'a>
-where
-    'b: 'a
-{
-    fn from(value: &'b Book) -> Self {
+impl <'a> NewBook<'a> {
+    /// `id`가 `None`인 경우 데이터베이스 시퀀스가 아이디를 발급한다.
+    pub fn new(value: &'a Book, id: Option<u64>, registered_at: chrono::NaiveDateTime) -> Self {
         Self {
+            id: id.map(|id| id as i64),
             isbn: value.isbn(),
             publisher_id: value.publisher_id() as i64,
             series_id: value.series_id().map(|id| id as i64),
             title: value.title(),
+            normalized_title: value.normalized_title(),
+            description: value.description(),
             scheduled_pub_date: value.scheduled_pub_date(),
             actual_pub_date: value.actual_pub_date(),
-            registered_at: chrono::Local::now().naive_local(),
+            registered_at,
         }
     }
 }
@@ -249,33 +432,62 @@ where
 pub struct BookForm<'a> {
     pub series_id: Option<i64>,
     pub title: &'a str,
+    pub normalized_title: Option<&'a str>,
+    pub description: Option<&'a str>,
     pub scheduled_pub_date: Option<chrono::NaiveDate>,
     pub actual_pub_date: Option<chrono::NaiveDate>,
     pub modified_at: chrono::NaiveDateTime
 }
 
-impl <'a, 'b> From<&'b Book> for BookForm<'a>
-where
-    'b: 'a
-{
-    fn from(value: &'b Book) -> Self {
+impl <'a> BookForm<'a> {
+    pub fn new(value: &'a Book, modified_at: chrono::NaiveDateTime) -> Self {
         Self {
             series_id: value.series_id().map(|id| id as i64),
             title: value.title(),
+            normalized_title: value.normalized_title(),
+            description: value.description(),
             scheduled_pub_date: value.scheduled_pub_date(),
             actual_pub_date: value.actual_pub_date(),
-            modified_at: chrono::Local::now().naive_local(),
+            modified_at,
         }
     }
 }
 
 pub struct BookPgStore {
-    pool: Pool<ConnectionManager<PgConnection>>
+    pool: Pool<ConnectionManager<PgConnection>>,
+
+    /// 조회 부하가 큰 쿼리를 실행할 읽기 전용 복제본 (설정 되지 않은 경우 `pool`을 사용한다.)
+    replica: Option<Pool<ConnectionManager<PgConnection>>>,
+
+    /// 등록, 수정 시각에 사용할 시계
+    clock: SharedClock,
+
+    /// 새 도서의 아이디 발급기
+    ids: SharedIdGenerator,
 }
 
 impl BookPgStore {
     pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
-        Self { pool }
+        Self { pool, replica: None, clock: system_clock(), ids: database_id_generator() }
+    }
+
+    pub fn with_id_generator(mut self, ids: SharedIdGenerator) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    pub fn with_replica(mut self, replica: Pool<ConnectionManager<PgConnection>>) -> Self {
+        self.replica = Some(replica);
+        self
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    fn read_pool(&self) -> &Pool<ConnectionManager<PgConnection>> {
+        self.replica.as_ref().unwrap_or(&self.pool)
     }
 }
 
@@ -284,7 +496,7 @@ impl BookPgStore {
     pub fn find_by_pub_between(&self, from: &chrono::NaiveDate, to: &chrono::NaiveDate) -> Result<Vec<BookEntity>, Error> {
         use schema::books::book::dsl::*;
 
-        let mut connection = self.pool.get()
+        let mut connection = self.read_pool().get()
             .map_err(|e| Error::ConnectError(e.to_string()))?;
         let results = book
             .filter(
@@ -320,8 +532,9 @@ impl BookPgStore {
         let mut connection = self.pool.get()
             .map_err(|e| Error::ConnectError(e.to_string()))?;
 
+        let now = self.clock.now();
         let entities = books.iter()
-            .map(|b| NewBook::from(b.as_ref()))
+            .map(|b| NewBook::new(b.as_ref(), self.ids.next_id(), now))
             .collect::<Vec<_>>();
 
         let results = diesel::insert_into(book::table)
@@ -340,20 +553,61 @@ impl BookPgStore {
             .map_err(|e| Error::ConnectError(e.to_string()))?;
         let updated_count = diesel::update(book::table)
             .filter(book::id.eq(book.id() as i64))
-            .set(BookForm::from(book))
+            .set(BookForm::new(book, self.clock.now()))
             .execute(&mut connection)
             .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
 
         Ok(updated_count)
     }
 
+    /// 전달 받은 도서들을 하나의 트랜잭션으로 업데이트 한다. 하나라도 실패할 경우 모든 업데이트를 롤백한다.
+    pub fn update_books<T: AsRef<Book>>(&self, books: &[T]) -> Result<usize, Error> {
+        use schema::books::book;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let now = self.clock.now();
+        connection.transaction::<_, diesel::result::Error, _>(|conn| {
+            let mut updated_count = 0;
+            for b in books {
+                let b = b.as_ref();
+                updated_count += diesel::update(book::table)
+                    .filter(book::id.eq(b.id() as i64))
+                    .set(BookForm::new(b, now))
+                    .execute(conn)?;
+            }
+            Ok(updated_count)
+        }).map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
     pub fn find_series_unorganized(&self, limit: usize) -> Result<Vec<BookEntity>, Error> {
         use schema::books::book::dsl::*;
 
-        let mut connection = self.pool.get()
+        let mut connection = self.read_pool().get()
             .map_err(|e| Error::ConnectError(e.to_string()))?;
+        // 운영자가 시리즈 분류를 지정한 도서는 자동 분류 대상에서 제외한다.
+        let overridden = schema::books::series_override::table
+            .filter(schema::books::series_override::isbn.eq(isbn));
         let result = book
             .filter(series_id.is_null())
+            .filter(diesel::dsl::not(diesel::dsl::exists(overridden)))
+            .limit(limit as i64)
+            .order_by(id.desc())
+            .select(BookEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    pub fn find_title_unnormalized(&self, limit: usize) -> Result<Vec<BookEntity>, Error> {
+        use schema::books::book::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+        let result = book
+            .filter(normalized_title.is_null())
             .limit(limit as i64)
             .order_by(id.desc())
             .select(BookEntity::as_select())
@@ -363,6 +617,86 @@ impl BookPgStore {
         Ok(result)
     }
 
+    pub fn find_by_registered_between(&self, from: &chrono::NaiveDateTime, to: &chrono::NaiveDateTime) -> Result<Vec<BookEntity>, Error> {
+        use schema::books::book::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+        let results = book
+            .filter(registered_at.ge(from).and(registered_at.lt(to)))
+            .order_by(id.asc())
+            .select(BookEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
+
+        Ok(results)
+    }
+
+    pub fn unlink_series(&self, book_id: u64) -> Result<usize, Error> {
+        use schema::books::book::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+        diesel::update(book)
+            .filter(id.eq(book_id as i64))
+            .set((series_id.eq(None::<i64>), modified_at.eq(self.clock.now())))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn upsert_vector(&self, book_id: u64, vec: &[f32]) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::book_vector;
+
+        if vec.len() != SERIES_VECTOR_DIMENSION {
+            return Err(Error::InvalidParameter("vector dimension is must be 1024".to_owned()))
+        }
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let entity = BookVectorEntity {
+            book_id: book_id as i64,
+            vec: pgvector::Vector::from(vec.to_vec()),
+            modified_at: self.clock.now(),
+        };
+
+        diesel::insert_into(book_vector::table)
+            .values(&entity)
+            .on_conflict(book_vector::book_id)
+            .do_update()
+            .set((
+                book_vector::vec.eq(excluded(book_vector::vec)),
+                book_vector::modified_at.eq(excluded(book_vector::modified_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn cosine_distance(&self, vec: &[f32], limit: i32) -> Result<Vec<(BookEntity, f64)>, Error> {
+        use schema::books::{book, book_vector};
+        use pgvector::VectorExpressionMethods;
+
+        if vec.len() != SERIES_VECTOR_DIMENSION {
+            return Err(Error::InvalidParameter("vector dimension is must be 1024".to_owned()))
+        }
+
+        let mut connection = self.read_pool().get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let vec = pgvector::Vector::from(vec.to_vec());
+        book::table
+            .inner_join(book_vector::table.on(book_vector::book_id.eq(book::id)))
+            .order(book_vector::vec.cosine_distance(vec.clone()))
+            .limit(limit as i64)
+            .select((
+                BookEntity::as_select(),
+                book_vector::vec.cosine_distance(vec),
+            ))
+            .load::<(BookEntity, f64)>(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
     pub fn find_by_series_id(&self, series_id: u64) -> Result<Vec<BookEntity>, Error> {
         use schema::books::book::dsl::{book, id};
         use schema::books::book::dsl::series_id as db_series_id;
@@ -379,6 +713,51 @@ impl BookPgStore {
 
         Ok(result)
     }
+
+    pub fn find_series_linked_by_publisher(&self, publisher_id: u64) -> Result<Vec<BookEntity>, Error> {
+        use schema::books::book::dsl::{book, id, series_id};
+        use schema::books::book::dsl::publisher_id as db_publisher_id;
+
+        let publisher_id = publisher_id as i64;
+        let mut connection = self.read_pool().get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+        let result = book
+            .filter(db_publisher_id.eq(&publisher_id))
+            .filter(series_id.is_not_null())
+            .order_by((series_id.asc(), id.asc()))
+            .select(BookEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    /// 도서와 도서를 참조하는 행을 외래 키 순서에 맞춰 하나의 트랜잭션으로 삭제하고 삭제된 도서 수를 반환한다. 하나라도 실패할 경우 모든 삭제를 롤백한다.
+    ///
+    /// # Description
+    /// 도서 아이디를 참조하는 테이블(원본 데이터, 판매 상태, 제목 백터, 시리즈 연결 정보, 제목 정규화 기록)을 먼저 삭제하며,
+    /// ISBN으로 참조하는 보강 재시도는 도서를 삭제하기 전에 도서의 ISBN으로 삭제한다.
+    pub fn delete_books(&self, book_id_vec: &[i64]) -> Result<usize, Error> {
+        use schema::books::{book, book_availability, book_origin_data, book_series_link, book_vector, enrichment_retry, title_normalization};
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        connection.transaction::<_, diesel::result::Error, _>(|conn| {
+            let isbn_vec = book::table
+                .filter(book::id.eq_any(book_id_vec))
+                .select(book::isbn);
+            diesel::delete(enrichment_retry::table.filter(enrichment_retry::isbn.eq_any(isbn_vec))).execute(conn)?;
+
+            diesel::delete(book_origin_data::table.filter(book_origin_data::book_id.eq_any(book_id_vec))).execute(conn)?;
+            diesel::delete(book_availability::table.filter(book_availability::book_id.eq_any(book_id_vec))).execute(conn)?;
+            diesel::delete(book_vector::table.filter(book_vector::book_id.eq_any(book_id_vec))).execute(conn)?;
+            diesel::delete(book_series_link::table.filter(book_series_link::book_id.eq_any(book_id_vec))).execute(conn)?;
+            diesel::delete(title_normalization::table.filter(title_normalization::book_id.eq_any(book_id_vec))).execute(conn)?;
+
+            diesel::delete(book::table.filter(book::id.eq_any(book_id_vec))).execute(conn)
+        }).map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
 }
 
 #[derive(Queryable, Selectable)]
@@ -506,7 +885,7 @@ impl BookOriginFilterPgStore {
 
 impl BookOriginFilterPgStore {
     pub fn find_by_site(&self, s: &Site) -> Result<Vec<BookOriginFilterEntity>, Error> {
-        use schema::books::book_origin_filter::dsl::book_origin_filter;
+        use schema::books::book_origin_filter::dsl::{book_origin_filter, id};
         use schema::books::book_origin_filter::dsl::site as db_site;
 
         let mut connection = self.pool.get()
@@ -514,6 +893,7 @@ impl BookOriginFilterPgStore {
 
         let results = book_origin_filter
             .filter(db_site.eq(s.to_string()))
+            .order_by(id.asc())
             .select(BookOriginFilterEntity::as_select())
             .load(&mut connection)
             .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
@@ -567,6 +947,7 @@ pub struct NewBookOriginData {
     pub book_id: i64,
     pub site: String,
     pub origin_data: serde_json::Value,
+    pub content_hash: String,
 }
 
 impl NewBookOriginData {
@@ -583,6 +964,7 @@ impl NewBookOriginData {
                 book_id,
                 site: s.to_string(),
                 origin_data: serde_json::to_value(map).unwrap(),
+                content_hash: raw_utils::content_hash(raw),
             };
             v.push(entity)
         }
@@ -602,15 +984,22 @@ impl BookOriginDataPgStore {
 
 impl BookOriginDataPgStore {
 
-    pub fn find_by_book_id(&self, book_id: &[i64]) -> Result<Vec<BookOriginDataEntity>, Error> {
+    /// 도서 아이디들의 원본 데이터를 조회한다. `sites`가 있을 경우 해당 사이트의 원본 데이터만 조회한다.
+    pub fn find_by_book_id(&self, book_id: &[i64], sites: Option<&[String]>) -> Result<Vec<BookOriginDataEntity>, Error> {
         use schema::books::book_origin_data::dsl::book_origin_data;
         use schema::books::book_origin_data::dsl::book_id as db_book_id;
+        use schema::books::book_origin_data::dsl::site as db_site;
 
         let mut connection = self.pool.get()
             .map_err(|e| Error::ConnectError(e.to_string()))?;
 
-        let result = book_origin_data
+        let mut query = book_origin_data
             .filter(db_book_id.eq_any(book_id))
+            .into_boxed();
+        if let Some(sites) = sites {
+            query = query.filter(db_site.eq_any(sites));
+        }
+        let result = query
             .select(BookOriginDataEntity::as_select())
             .load(&mut connection)
             .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
@@ -618,6 +1007,8 @@ impl BookOriginDataPgStore {
         Ok(result)
     }
 
+    /// 원본 데이터를 저장하고 저장된 원본 데이터를 반환한다.
+    /// 도서, 사이트, 내용 해시가 같은 원본 데이터가 이미 있는 경우 저장하지 않으며 반환 값에도 포함하지 않는다.
     pub fn new_original_data(&self, book_id: i64, originals: &Originals) -> Result<Vec<BookOriginDataEntity>, Error> {
         use schema::books::book_origin_data as db_book_origin_data;
 
@@ -628,6 +1019,8 @@ impl BookOriginDataPgStore {
 
         let results = diesel::insert_into(db_book_origin_data::table)
             .values(entities)
+            .on_conflict((db_book_origin_data::book_id, db_book_origin_data::site, db_book_origin_data::content_hash))
+            .do_nothing()
             .returning(BookOriginDataEntity::as_select())
             .get_results(&mut connection)
             .map_err(|e| Error::SqlExecuteError(e.to_string()))?;
@@ -650,4 +1043,1025 @@ impl BookOriginDataPgStore {
             .execute(&mut connection)
             .map_err(|e| Error::SqlExecuteError(e.to_string()))
     }
+
+    pub fn delete_by_book_id(&self, book_id_vec: &[i64]) -> Result<usize, Error> {
+        use schema::books::book_origin_data::dsl::book_id as db_book_id;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::delete(book_origin_data.filter(db_book_id.eq_any(book_id_vec)))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+/// 판매처에서 제공하는 판매 상태 문구의 최대 길이
+const RAW_STATUS_MAX_LENGTH: usize = 64;
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = schema::books::book_availability)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct BookAvailabilityEntity {
+    pub book_id: i64,
+    pub site: String,
+    pub status: String,
+    pub raw_status: String,
+    pub checked_at: chrono::NaiveDateTime,
+    pub changed_at: chrono::NaiveDateTime,
+}
+
+impl BookAvailabilityEntity {
+
+    pub fn to_domain(self) -> Availability {
+        Availability::restore(
+            self.book_id as u64,
+            Site::try_from(self.site.as_str()).unwrap(),
+            SaleStatus::try_from(self.status.as_str()).unwrap_or(SaleStatus::Unknown),
+            self.raw_status,
+            self.checked_at,
+            self.changed_at,
+        )
+    }
+}
+
+impl From<&Availability> for BookAvailabilityEntity {
+    fn from(value: &Availability) -> Self {
+        Self {
+            book_id: value.book_id() as i64,
+            site: value.site().to_string(),
+            status: value.status().to_string(),
+            raw_status: value.raw_status().chars().take(RAW_STATUS_MAX_LENGTH).collect(),
+            checked_at: value.checked_at(),
+            changed_at: value.changed_at(),
+        }
+    }
+}
+
+pub struct BookAvailabilityPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl BookAvailabilityPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl BookAvailabilityPgStore {
+
+    pub fn find_by_book_id(&self, book_id: &[i64]) -> Result<Vec<BookAvailabilityEntity>, Error> {
+        use schema::books::book_availability::dsl::book_availability;
+        use schema::books::book_availability::dsl::book_id as db_book_id;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        book_availability
+            .filter(db_book_id.eq_any(book_id))
+            .select(BookAvailabilityEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn upsert(&self, availability: &[Availability]) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::book_availability::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let entities = availability.iter()
+            .map(BookAvailabilityEntity::from)
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(book_availability)
+            .values(&entities)
+            .on_conflict((book_id, site))
+            .do_update()
+            .set((
+                status.eq(excluded(status)),
+                raw_status.eq(excluded(raw_status)),
+                checked_at.eq(excluded(checked_at)),
+                changed_at.eq(excluded(changed_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = schema::books::enrichment_retry)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct EnrichmentRetryEntity {
+    pub site: String,
+    pub isbn: String,
+    pub error_type: String,
+    pub message: String,
+    pub attempt: i32,
+    pub next_retry_at: chrono::NaiveDateTime,
+    pub last_failed_at: chrono::NaiveDateTime,
+}
+
+impl EnrichmentRetryEntity {
+
+    pub fn to_domain(self) -> EnrichmentRetry {
+        EnrichmentRetry::restore(
+            Site::try_from(self.site.as_str()).unwrap(),
+            self.isbn,
+            self.error_type,
+            self.message,
+            self.attempt as u32,
+            self.next_retry_at,
+            self.last_failed_at,
+        )
+    }
+}
+
+impl From<&EnrichmentRetry> for EnrichmentRetryEntity {
+    fn from(value: &EnrichmentRetry) -> Self {
+        Self {
+            site: value.site().to_string(),
+            isbn: value.isbn().to_owned(),
+            error_type: value.error_type().to_owned(),
+            message: value.message().to_owned(),
+            attempt: value.attempt() as i32,
+            next_retry_at: value.next_retry_at(),
+            last_failed_at: value.last_failed_at(),
+        }
+    }
+}
+
+pub struct EnrichmentRetryPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl EnrichmentRetryPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl EnrichmentRetryPgStore {
+
+    pub fn find_due(&self, s: &Site, now: &chrono::NaiveDateTime, limit: usize) -> Result<Vec<EnrichmentRetryEntity>, Error> {
+        use schema::books::enrichment_retry::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        enrichment_retry
+            .filter(site.eq(s.to_string()))
+            .filter(next_retry_at.le(now))
+            .order_by(next_retry_at.asc())
+            .limit(limit as i64)
+            .select(EnrichmentRetryEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn find_by_isbn(&self, s: &Site, isbn_vec: &[&str]) -> Result<Vec<EnrichmentRetryEntity>, Error> {
+        use schema::books::enrichment_retry::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        enrichment_retry
+            .filter(site.eq(s.to_string()))
+            .filter(isbn.eq_any(isbn_vec))
+            .select(EnrichmentRetryEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn upsert(&self, retry: &[EnrichmentRetry]) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::enrichment_retry::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let entities = retry.iter()
+            .map(EnrichmentRetryEntity::from)
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(enrichment_retry)
+            .values(&entities)
+            .on_conflict((site, isbn))
+            .do_update()
+            .set((
+                error_type.eq(excluded(error_type)),
+                message.eq(excluded(message)),
+                attempt.eq(excluded(attempt)),
+                next_retry_at.eq(excluded(next_retry_at)),
+                last_failed_at.eq(excluded(last_failed_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn delete_by_isbn(&self, s: &Site, isbn_vec: &[&str]) -> Result<usize, Error> {
+        use schema::books::enrichment_retry::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::delete(
+                enrichment_retry
+                    .filter(site.eq(s.to_string()))
+                    .filter(isbn.eq_any(isbn_vec))
+            )
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = schema::books::book_series_link)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct BookSeriesLinkEntity {
+    pub book_id: i64,
+    pub series_id: i64,
+    pub method: String,
+    pub score: Option<f64>,
+    pub linked_at: chrono::NaiveDateTime,
+}
+
+impl BookSeriesLinkEntity {
+
+    pub fn to_domain(self) -> Option<SeriesLink> {
+        let confidence = SeriesLinkConfidence::restore(&self.method, self.score).ok()?;
+        Some(SeriesLink::restore(self.book_id as u64, self.series_id as u64, confidence, self.linked_at))
+    }
+}
+
+impl BookSeriesLinkEntity {
+
+    /// 연결 시각이 없는 연결(새로 연결된 도서)은 `now`를 연결 시각으로 사용한다.
+    pub fn new(value: &SeriesLink, now: chrono::NaiveDateTime) -> Self {
+        Self {
+            book_id: value.book_id() as i64,
+            series_id: value.series_id() as i64,
+            method: value.confidence().method().to_owned(),
+            score: value.confidence().score(),
+            linked_at: value.linked_at().unwrap_or(now),
+        }
+    }
+}
+
+pub struct BookSeriesLinkPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>,
+    clock: SharedClock,
+}
+
+impl BookSeriesLinkPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool, clock: system_clock() }
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl BookSeriesLinkPgStore {
+
+    pub fn upsert(&self, links: &[SeriesLink]) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::book_series_link::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let now = self.clock.now();
+        let entities = links.iter()
+            .map(|link| BookSeriesLinkEntity::new(link, now))
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(book_series_link)
+            .values(&entities)
+            .on_conflict(book_id)
+            .do_update()
+            .set((
+                series_id.eq(excluded(series_id)),
+                method.eq(excluded(method)),
+                score.eq(excluded(score)),
+                linked_at.eq(excluded(linked_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn delete_by_book_id(&self, book_id_vec: &[i64]) -> Result<usize, Error> {
+        use schema::books::book_series_link::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::delete(book_series_link.filter(book_id.eq_any(book_id_vec)))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn find_score_below(&self, threshold: f64, limit: usize) -> Result<Vec<BookSeriesLinkEntity>, Error> {
+        use schema::books::book_series_link::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        book_series_link
+            .filter(score.lt(threshold))
+            .order_by(score.asc())
+            .limit(limit as i64)
+            .select(BookSeriesLinkEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = schema::books::series_override)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SeriesOverrideEntity {
+    pub isbn: String,
+    pub series_id: Option<i64>,
+    pub note: Option<String>,
+}
+
+impl SeriesOverrideEntity {
+
+    pub fn to_domain(self) -> SeriesOverride {
+        let target = match self.series_id {
+            Some(series_id) => SeriesOverrideTarget::Series(series_id as u64),
+            None => SeriesOverrideTarget::Standalone,
+        };
+        SeriesOverride::new(self.isbn, target, self.note)
+    }
+}
+
+pub struct SeriesOverridePgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl SeriesOverridePgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl SeriesOverridePgStore {
+
+    pub fn find_by_isbn(&self, isbn_vec: &[&str]) -> Result<Vec<SeriesOverrideEntity>, Error> {
+        use schema::books::series_override::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        series_override
+            .filter(isbn.eq_any(isbn_vec))
+            .select(SeriesOverrideEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn find_unapplied_isbn(&self, limit: usize) -> Result<Vec<String>, Error> {
+        use schema::books::{book, series_override};
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        series_override::table
+            .inner_join(book::table.on(book::isbn.eq(series_override::isbn)))
+            .filter(book::series_id.is_distinct_from(series_override::series_id))
+            .order_by(series_override::registered_at.asc())
+            .limit(limit as i64)
+            .select(series_override::isbn)
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = schema::books::title_normalization)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TitleNormalizationEntity {
+    pub book_id: i64,
+    pub source_title: String,
+    pub normalized_title: String,
+    pub reason: String,
+    pub prompt_version: Option<String>,
+    pub normalized_at: chrono::NaiveDateTime,
+}
+
+impl TitleNormalizationEntity {
+
+    pub fn to_domain(self) -> TitleNormalization {
+        TitleNormalization::new(
+            self.book_id as u64,
+            self.source_title,
+            self.normalized_title,
+            self.reason,
+            self.prompt_version,
+            self.normalized_at,
+        )
+    }
+}
+
+impl From<&TitleNormalization> for TitleNormalizationEntity {
+    fn from(value: &TitleNormalization) -> Self {
+        Self {
+            book_id: value.book_id() as i64,
+            source_title: value.source_title().to_owned(),
+            normalized_title: value.normalized_title().to_owned(),
+            reason: value.reason().to_owned(),
+            prompt_version: value.prompt_version().map(|v| v.to_owned()),
+            normalized_at: value.normalized_at(),
+        }
+    }
+}
+
+pub struct TitleNormalizationPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl TitleNormalizationPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl TitleNormalizationPgStore {
+
+    pub fn find_by_book_id(&self, book_id_vec: &[i64]) -> Result<Vec<TitleNormalizationEntity>, Error> {
+        use schema::books::title_normalization::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        title_normalization
+            .filter(book_id.eq_any(book_id_vec))
+            .select(TitleNormalizationEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn upsert(&self, normalization: &[TitleNormalization]) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::title_normalization::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let entities = normalization.iter()
+            .map(TitleNormalizationEntity::from)
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(title_normalization)
+            .values(&entities)
+            .on_conflict(book_id)
+            .do_update()
+            .set((
+                source_title.eq(excluded(source_title)),
+                normalized_title.eq(excluded(normalized_title)),
+                reason.eq(excluded(reason)),
+                prompt_version.eq(excluded(prompt_version)),
+                normalized_at.eq(excluded(normalized_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn find_title_changed_isbn(&self, limit: usize) -> Result<Vec<String>, Error> {
+        use schema::books::{book, title_normalization};
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        title_normalization::table
+            .inner_join(book::table.on(book::id.eq(title_normalization::book_id)))
+            .filter(book::title.ne(title_normalization::source_title))
+            .order_by(title_normalization::normalized_at.asc())
+            .limit(limit as i64)
+            .select(book::isbn)
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = schema::books::backfill_progress)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct BackfillProgressEntity {
+    pub site: String,
+    pub publisher_id: i64,
+    pub completed_through: chrono::NaiveDate,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl BackfillProgressEntity {
+
+    pub fn to_domain(self) -> Option<BackfillProgress> {
+        let site = Site::try_from(self.site.as_str()).ok()?;
+        Some(BackfillProgress::new(site, self.publisher_id as u64, self.completed_through, self.updated_at))
+    }
+}
+
+impl From<&BackfillProgress> for BackfillProgressEntity {
+    fn from(value: &BackfillProgress) -> Self {
+        Self {
+            site: value.site().to_string(),
+            publisher_id: value.publisher_id() as i64,
+            completed_through: value.completed_through(),
+            updated_at: value.updated_at(),
+        }
+    }
+}
+
+pub struct BackfillProgressPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl BackfillProgressPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl BackfillProgressPgStore {
+
+    pub fn find(&self, s: &Site, publisher: i64) -> Result<Option<BackfillProgressEntity>, Error> {
+        use schema::books::backfill_progress::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        backfill_progress
+            .filter(site.eq(s.to_string()))
+            .filter(publisher_id.eq(publisher))
+            .select(BackfillProgressEntity::as_select())
+            .first(&mut connection)
+            .optional()
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn upsert(&self, progress: &BackfillProgress) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::backfill_progress::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::insert_into(backfill_progress)
+            .values(BackfillProgressEntity::from(progress))
+            .on_conflict((site, publisher_id))
+            .do_update()
+            .set((
+                completed_through.eq(excluded(completed_through)),
+                updated_at.eq(excluded(updated_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = schema::books::collection_status)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct CollectionStatusEntity {
+    pub site: String,
+    pub publisher_id: i64,
+    pub collected_at: chrono::NaiveDateTime,
+    pub result_count: i32,
+}
+
+impl CollectionStatusEntity {
+
+    pub fn to_domain(self) -> Option<CollectionStatus> {
+        let site = Site::try_from(self.site.as_str()).ok()?;
+        Some(CollectionStatus::new(site, self.publisher_id as u64, self.collected_at, self.result_count as usize))
+    }
+}
+
+impl From<&CollectionStatus> for CollectionStatusEntity {
+    fn from(value: &CollectionStatus) -> Self {
+        Self {
+            site: value.site().to_string(),
+            publisher_id: value.publisher_id() as i64,
+            collected_at: value.collected_at(),
+            result_count: value.result_count() as i32,
+        }
+    }
+}
+
+pub struct CollectionStatusPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl CollectionStatusPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl CollectionStatusPgStore {
+
+    pub fn find_all(&self) -> Result<Vec<CollectionStatusEntity>, Error> {
+        use schema::books::collection_status::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        collection_status
+            .select(CollectionStatusEntity::as_select())
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn upsert(&self, status: &[CollectionStatus]) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::collection_status::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let entities = status.iter()
+            .map(CollectionStatusEntity::from)
+            .collect::<Vec<_>>();
+        diesel::insert_into(collection_status)
+            .values(&entities)
+            .on_conflict((site, publisher_id))
+            .do_update()
+            .set((
+                collected_at.eq(excluded(collected_at)),
+                result_count.eq(excluded(result_count)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable)]
+#[diesel(table_name = schema::books::collection_volume)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct CollectionVolumeEntity {
+    pub job_name: String,
+    pub publisher_id: i64,
+    pub executed_at: chrono::NaiveDateTime,
+    pub read_count: i32,
+    pub write_count: i32,
+}
+
+impl CollectionVolumeEntity {
+
+    pub fn to_domain(self) -> CollectionVolume {
+        CollectionVolume::new(&self.job_name, self.publisher_id as u64, self.executed_at, self.read_count as usize, self.write_count as usize)
+    }
+}
+
+impl From<&CollectionVolume> for CollectionVolumeEntity {
+    fn from(value: &CollectionVolume) -> Self {
+        Self {
+            job_name: value.job_name().to_owned(),
+            publisher_id: value.publisher_id() as i64,
+            executed_at: value.executed_at(),
+            read_count: value.read_count() as i32,
+            write_count: value.write_count() as i32,
+        }
+    }
+}
+
+pub struct CollectionVolumePgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl CollectionVolumePgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl CollectionVolumePgStore {
+
+    pub fn find_recent(&self, name: &str, publisher: u64, limit: usize) -> Result<Vec<CollectionVolumeEntity>, Error> {
+        use schema::books::collection_volume::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        collection_volume
+            .select(CollectionVolumeEntity::as_select())
+            .filter(job_name.eq(name))
+            .filter(publisher_id.eq(publisher as i64))
+            .order(executed_at.desc())
+            .limit(limit as i64)
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn insert(&self, volumes: &[CollectionVolume]) -> Result<usize, Error> {
+        use schema::books::collection_volume::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let entities = volumes.iter()
+            .map(CollectionVolumeEntity::from)
+            .collect::<Vec<_>>();
+        diesel::insert_into(collection_volume)
+            .values(&entities)
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+pub struct ProviderQuotaPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>,
+    clock: SharedClock,
+}
+
+impl ProviderQuotaPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool, clock: system_clock() }
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl ProviderQuotaPgStore {
+
+    pub fn find_used(&self, s: &Site, date: &chrono::NaiveDate) -> Result<Option<i32>, Error> {
+        use schema::books::provider_quota::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        provider_quota
+            .filter(site.eq(s.to_string()))
+            .filter(quota_date.eq(date))
+            .select(used)
+            .first(&mut connection)
+            .optional()
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn add_used(&self, s: &Site, date: &chrono::NaiveDate, count: i32) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::provider_quota::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::insert_into(provider_quota)
+            .values((
+                site.eq(s.to_string()),
+                quota_date.eq(date),
+                used.eq(count),
+                modified_at.eq(self.clock.now()),
+            ))
+            .on_conflict((site, quota_date))
+            .do_update()
+            .set((
+                used.eq(used + excluded(used)),
+                modified_at.eq(excluded(modified_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+pub struct JobExecutionPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>
+}
+
+impl JobExecutionPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl JobExecutionPgStore {
+
+    pub fn insert(&self, name: &str, params: &str, hash: &str, started: &chrono::NaiveDateTime) -> Result<i64, Error> {
+        use schema::books::job_execution::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::insert_into(job_execution)
+            .values((
+                job_name.eq(name),
+                parameters.eq(params),
+                param_hash.eq(hash),
+                status.eq(JobStatus::Started.to_string()),
+                started_at.eq(started),
+            ))
+            .returning(id)
+            .get_result(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn find_id_by_hash_and_status(&self, hash: &str, s: JobStatus) -> Result<Option<i64>, Error> {
+        use schema::books::job_execution::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        job_execution
+            .select(id)
+            .filter(param_hash.eq(hash))
+            .filter(status.eq(s.to_string()))
+            .order(id.desc())
+            .first(&mut connection)
+            .optional()
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn update_finished(&self, execution_id: i64, s: JobStatus, finished: &chrono::NaiveDateTime, path: Option<&str>, counts: &JobExecutionCounts) -> Result<usize, Error> {
+        use schema::books::job_execution::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::update(job_execution.filter(id.eq(execution_id)))
+            .set((
+                status.eq(s.to_string()),
+                finished_at.eq(finished),
+                audit_path.eq(path),
+                read_count.eq(counts.read as i64),
+                processed_count.eq(counts.processed as i64),
+                written_count.eq(counts.written as i64),
+                skipped_count.eq(counts.skipped as i64),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+pub struct DeadLetterPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>,
+    clock: SharedClock,
+}
+
+impl DeadLetterPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool, clock: system_clock() }
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl DeadLetterPgStore {
+
+    pub fn increment(&self, name: &str, class: &str, isbn_vec: &[String]) -> Result<Vec<(String, i32)>, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::dead_letter::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        // 한 번의 upsert에서 같은 행을 두 번 수정할 수 없으므로 중복된 ISBN을 제거한다.
+        let isbn_set = isbn_vec.iter().collect::<std::collections::BTreeSet<_>>();
+        let now = self.clock.now();
+        let values = isbn_set.into_iter()
+            .map(|i| (job_name.eq(name), isbn.eq(i), error_class.eq(class), attempts.eq(1), first_failed_at.eq(now), last_failed_at.eq(now)))
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(dead_letter)
+            .values(values)
+            .on_conflict((job_name, isbn, error_class))
+            .do_update()
+            .set((
+                attempts.eq(attempts + 1),
+                last_failed_at.eq(excluded(last_failed_at)),
+            ))
+            .returning((isbn, attempts))
+            .get_results(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+pub struct JobCheckpointPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>,
+    clock: SharedClock,
+}
+
+impl JobCheckpointPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool, clock: system_clock() }
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl JobCheckpointPgStore {
+
+    pub fn find_chunk_index(&self, key: &str) -> Result<Option<i64>, Error> {
+        use schema::books::job_checkpoint::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        job_checkpoint
+            .select(chunk_index)
+            .filter(checkpoint_key.eq(key))
+            .first(&mut connection)
+            .optional()
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn upsert(&self, key: &str, name: &str, index: i64) -> Result<usize, Error> {
+        use diesel::upsert::excluded;
+        use schema::books::job_checkpoint::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::insert_into(job_checkpoint)
+            .values((
+                checkpoint_key.eq(key),
+                job_name.eq(name),
+                chunk_index.eq(index),
+                updated_at.eq(self.clock.now()),
+            ))
+            .on_conflict(checkpoint_key)
+            .do_update()
+            .set((
+                chunk_index.eq(excluded(chunk_index)),
+                updated_at.eq(excluded(updated_at)),
+            ))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn delete(&self, key: &str) -> Result<usize, Error> {
+        use schema::books::job_checkpoint::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        diesel::delete(job_checkpoint.filter(checkpoint_key.eq(key)))
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+}
+
+pub struct IsbnSetPgStore {
+    pool: Pool<ConnectionManager<PgConnection>>,
+    clock: SharedClock,
+}
+
+impl IsbnSetPgStore {
+    pub fn new(pool: Pool<ConnectionManager<PgConnection>>) -> Self {
+        Self { pool, clock: system_clock() }
+    }
+
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl IsbnSetPgStore {
+
+    pub fn find_by_name(&self, set_name: &str) -> Result<Vec<String>, Error> {
+        use schema::books::isbn_set::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        isbn_set
+            .filter(name.eq(set_name))
+            .order(registered_at.asc())
+            .select(isbn)
+            .load(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
+
+    pub fn insert(&self, set_name: &str, isbn_vec: &[String]) -> Result<usize, Error> {
+        use schema::books::isbn_set::dsl::*;
+
+        let mut connection = self.pool.get()
+            .map_err(|e| Error::ConnectError(e.to_string()))?;
+
+        let now = self.clock.now();
+        let values = isbn_vec.iter()
+            .map(|i| (name.eq(set_name), isbn.eq(i), registered_at.eq(now)))
+            .collect::<Vec<_>>();
+
+        diesel::insert_into(isbn_set)
+            .values(values)
+            .on_conflict_do_nothing()
+            .execute(&mut connection)
+            .map_err(|e| Error::SqlExecuteError(e.to_string()))
+    }
 }
@@ -11,6 +11,9 @@ pub mod books {
             isbn -> Varchar,
             #[max_length = 512]
             title -> Varchar,
+            #[max_length = 512]
+            normalized_title -> Nullable<Varchar>,
+            description -> Nullable<Text>,
             publisher_id -> Int8,
             scheduled_pub_date -> Nullable<Date>,
             actual_pub_date -> Nullable<Date>,
@@ -79,6 +82,8 @@ pub mod books {
             registered_at -> Timestamp,
             modified_at -> Nullable<Timestamp>,
             vec -> Nullable<Vector>,
+            #[max_length = 1024]
+            cover -> Nullable<Varchar>,
         }
     }
 
@@ -90,7 +95,207 @@ pub mod books {
             book_id -> Int8,
             #[max_length = 32]
             site -> Varchar,
-            origin_data -> Json
+            origin_data -> Json,
+            #[max_length = 64]
+            content_hash -> Nullable<Varchar>,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.book_availability (book_id, site) {
+            book_id -> Int8,
+            #[max_length = 32]
+            site -> Varchar,
+            #[max_length = 32]
+            status -> Varchar,
+            #[max_length = 64]
+            raw_status -> Varchar,
+            checked_at -> Timestamp,
+            changed_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.enrichment_retry (site, isbn) {
+            #[max_length = 32]
+            site -> Varchar,
+            #[max_length = 13]
+            isbn -> Varchar,
+            #[max_length = 32]
+            error_type -> Varchar,
+            message -> Text,
+            attempt -> Int4,
+            next_retry_at -> Timestamp,
+            last_failed_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.backfill_progress (site, publisher_id) {
+            #[max_length = 32]
+            site -> Varchar,
+            publisher_id -> Int8,
+            completed_through -> Date,
+            updated_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use pgvector::sql_types::*;
+
+        books.book_vector (book_id) {
+            book_id -> Int8,
+            vec -> Vector,
+            modified_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.book_series_link (book_id) {
+            book_id -> Int8,
+            series_id -> Int8,
+            #[max_length = 32]
+            method -> Varchar,
+            score -> Nullable<Float8>,
+            linked_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.isbn_set (name, isbn) {
+            #[max_length = 128]
+            name -> Varchar,
+            #[max_length = 13]
+            isbn -> Varchar,
+            registered_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.job_execution (id) {
+            id -> Int8,
+            #[max_length = 32]
+            job_name -> Varchar,
+            parameters -> Text,
+            #[max_length = 16]
+            status -> Varchar,
+            started_at -> Timestamp,
+            finished_at -> Nullable<Timestamp>,
+            audit_path -> Nullable<Text>,
+            #[max_length = 64]
+            param_hash -> Nullable<Varchar>,
+            read_count -> Nullable<Int8>,
+            processed_count -> Nullable<Int8>,
+            written_count -> Nullable<Int8>,
+            skipped_count -> Nullable<Int8>,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.dead_letter (job_name, isbn, error_class) {
+            #[max_length = 32]
+            job_name -> Varchar,
+            #[max_length = 64]
+            isbn -> Varchar,
+            #[max_length = 128]
+            error_class -> Varchar,
+            attempts -> Int4,
+            first_failed_at -> Timestamp,
+            last_failed_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.job_checkpoint (checkpoint_key) {
+            #[max_length = 64]
+            checkpoint_key -> Varchar,
+            #[max_length = 32]
+            job_name -> Varchar,
+            chunk_index -> Int8,
+            updated_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.collection_status (site, publisher_id) {
+            #[max_length = 32]
+            site -> Varchar,
+            publisher_id -> Int8,
+            collected_at -> Timestamp,
+            result_count -> Int4,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.collection_volume (id) {
+            id -> Int8,
+            #[max_length = 32]
+            job_name -> Varchar,
+            publisher_id -> Int8,
+            executed_at -> Timestamp,
+            read_count -> Int4,
+            write_count -> Int4,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.provider_quota (site, quota_date) {
+            #[max_length = 32]
+            site -> Varchar,
+            quota_date -> Date,
+            used -> Int4,
+            modified_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.series_override (isbn) {
+            #[max_length = 13]
+            isbn -> Varchar,
+            series_id -> Nullable<Int8>,
+            note -> Nullable<Text>,
+            registered_at -> Timestamp,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        books.title_normalization (book_id) {
+            book_id -> Int8,
+            #[max_length = 512]
+            source_title -> Varchar,
+            #[max_length = 512]
+            normalized_title -> Varchar,
+            reason -> Text,
+            #[max_length = 64]
+            prompt_version -> Nullable<Varchar>,
+            normalized_at -> Timestamp,
         }
     }
 
@@ -99,10 +304,24 @@ pub mod books {
     diesel::joinable!(publisher_keyword -> publisher (publisher_id));
 
     diesel::allow_tables_to_appear_in_same_query!(
+        backfill_progress,
         book,
+        book_availability,
         book_origin_filter,
+        book_series_link,
+        book_vector,
+        collection_status,
+        collection_volume,
+        dead_letter,
+        enrichment_retry,
+        isbn_set,
+        job_checkpoint,
+        job_execution,
+        provider_quota,
         publisher,
         publisher_keyword,
         series,
+        series_override,
+        title_normalization,
     );
 }
@@ -0,0 +1,137 @@
+use chrono::Utc;
+use serde::Deserialize;
+use std::cell::Cell;
+use std::hash::{BuildHasher, RandomState};
+use std::rc::Rc;
+
+/// 시간 순 아이디의 기준 시각 (2025-01-01T00:00:00Z, 유닉스 밀리초)
+const ID_EPOCH_MILLIS: i64 = 1_735_689_600_000;
+
+/// 시간 순 아이디에서 같은 밀리초의 아이디를 구분하는 임의값의 비트 수
+const RANDOM_BITS: u32 = 22;
+
+/// 밀리초가 바뀔 때 새로 뽑는 임의값의 최대값, 같은 밀리초에 발급할 아이디를 위해 임의값의 절반을 남겨둔다.
+const RANDOM_SEED_MASK: u64 = (1 << (RANDOM_BITS - 1)) - 1;
+
+/// 임의값의 최대값
+const RANDOM_MAX: u64 = (1 << RANDOM_BITS) - 1;
+
+/// 도서, 시리즈 아이디 발급 방식
+///
+/// # Description
+/// 도메인 모델의 아이디는 발급 방식과 관계 없이 `u64`를 유지하며, 저장소는 설정된 방식으로 새 도서와 시리즈의 아이디를 정한다.
+/// 여러 데이터베이스에 나누어 저장하거나 시퀀스가 없는 저장소를 사용하는 경우 [`IdStrategy::Ulid`]를 사용한다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdStrategy {
+    /// 데이터베이스 시퀀스(`bigserial`)가 아이디를 발급한다.
+    #[default]
+    Database,
+
+    /// 저장소가 ULID 처럼 시간 순으로 정렬 되는 아이디를 발급한다. ([`UlidIdGenerator`])
+    Ulid,
+}
+
+impl IdStrategy {
+    /// 발급 방식의 아이디 발급기를 생성한다.
+    pub fn generator(&self) -> SharedIdGenerator {
+        match self {
+            IdStrategy::Database => database_id_generator(),
+            IdStrategy::Ulid => SharedIdGenerator::new(Box::new(UlidIdGenerator::new())),
+        }
+    }
+}
+
+/// 새 도서, 시리즈의 아이디 발급기
+pub trait IdGenerator {
+
+    /// 새 아이디를 발급한다. 데이터베이스가 아이디를 발급하는 경우 `None`을 반환한다.
+    fn next_id(&self) -> Option<u64>;
+}
+
+pub type SharedIdGenerator = Rc<Box<dyn IdGenerator>>;
+
+/// 아이디를 발급하지 않고 데이터베이스 시퀀스에 맡기는 발급기
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DatabaseIdGenerator;
+
+impl IdGenerator for DatabaseIdGenerator {
+    fn next_id(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// 데이터베이스 시퀀스로 아이디를 발급하는 공유 발급기를 생성한다.
+pub fn database_id_generator() -> SharedIdGenerator {
+    SharedIdGenerator::new(Box::new(DatabaseIdGenerator))
+}
+
+/// ULID 방식으로 시간 순으로 정렬 되는 아이디 발급기
+///
+/// # Description
+/// ULID(128비트)는 `u64` 아이디에 담을 수 없으므로 같은 구성을 63비트로 줄여 PostgreSQL `bigint`에 저장할 수 있는 양수를 발급한다.
+/// 상위 41비트는 2025-01-01(UTC) 부터의 밀리초, 하위 22비트는 임의값이며 약 69년 동안 시간 순으로 정렬 된다.
+/// ULID의 단조 증가(monotonic) 방식과 같이 같은 밀리초에 발급하는 아이디는 이전 임의값에 1을 더하고, 시스템 시각이 뒤로 가더라도 마지막 밀리초를 유지하여 항상 이전보다 큰 아이디를 발급한다.
+/// 여러 서버가 동시에 발급하는 경우 밀리초마다 새로 뽑는 임의값으로 충돌을 피하며, 시퀀스가 발급한 기존 아이디보다 항상 크다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::item::repo::{IdGenerator, UlidIdGenerator};
+///
+/// let generator = UlidIdGenerator::new();
+/// let first = generator.next_id().unwrap();
+/// let second = generator.next_id().unwrap();
+///
+/// assert!(first < second);
+/// assert!(second <= i64::MAX as u64);
+/// ```
+#[derive(Debug)]
+pub struct UlidIdGenerator {
+    random: RandomState,
+
+    /// 마지막으로 발급한 아이디의 `(밀리초, 임의값)`
+    last: Cell<(u64, u64)>,
+
+    /// 임의값을 뽑은 횟수
+    draws: Cell<u64>,
+}
+
+impl UlidIdGenerator {
+    pub fn new() -> Self {
+        Self {
+            random: RandomState::new(),
+            last: Cell::new((0, 0)),
+            draws: Cell::new(0),
+        }
+    }
+
+    fn draw(&self) -> u64 {
+        let draws = self.draws.get().wrapping_add(1);
+        self.draws.set(draws);
+        self.random.hash_one(draws) & RANDOM_SEED_MASK
+    }
+}
+
+impl Default for UlidIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdGenerator for UlidIdGenerator {
+    fn next_id(&self) -> Option<u64> {
+        let now = Utc::now().timestamp_millis().saturating_sub(ID_EPOCH_MILLIS).max(0) as u64;
+        let (last_millis, last_random) = self.last.get();
+
+        let (millis, random) = if now > last_millis {
+            (now, self.draw())
+        } else if last_random < RANDOM_MAX {
+            (last_millis, last_random + 1)
+        } else {
+            // 같은 밀리초의 임의값을 모두 사용한 경우 다음 밀리초의 아이디를 발급한다.
+            (last_millis + 1, self.draw())
+        };
+        self.last.set((millis, random));
+        Some((millis << RANDOM_BITS) | random)
+    }
+}
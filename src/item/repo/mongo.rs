@@ -0,0 +1,167 @@
+use crate::item::repo::{OriginStore, OriginStoreError};
+use crate::item::{raw_utils, OriginProjection, Originals, Raw, RawValue, Site};
+use mongodb::bson::{doc, Document};
+use mongodb::options::IndexOptions;
+use mongodb::sync::{Client, Collection};
+use mongodb::IndexModel;
+use tracing::{info, warn};
+
+/// MongoDB 컬렉션에 원본 데이터를 저장하는 원본 데이터 저장소
+///
+/// # Description
+/// 원본 데이터는 `{ book_id, site, origin_data, content_hash }` 형식의 문서로 저장하며,
+/// 조회에 사용하는 `(book_id, site)` 인덱스와 중복 저장을 막는 `(book_id, site, content_hash)` 유니크 인덱스는 `MIGRATE` 잡으로 생성한다. ([`MongoOriginStore::create_indexes`])
+pub struct MongoOriginStore {
+    collection: Collection<Document>,
+}
+
+impl MongoOriginStore {
+    /// 원본 데이터를 저장하는 MongoDB 컬렉션 이름
+    pub const COLLECTION: &'static str = "book_origin_data";
+
+    pub fn new(client: &Client, database: &str) -> Self {
+        Self { collection: client.database(database).collection::<Document>(Self::COLLECTION) }
+    }
+
+    /// 원본 데이터 컬렉션에 있어야 하는 인덱스 이름과 키, 옵션
+    ///
+    /// # Description
+    /// 원본 데이터는 `book_id` 또는 `(book_id, site)`로 조회하며, 복합 인덱스의 첫 번째 키가 `book_id`이므로
+    /// `book_id`만으로 조회하는 경우에도 이 인덱스를 사용한다.
+    /// `(book_id, site, content_hash)` 유니크 인덱스는 같은 내용의 원본 데이터가 중복 저장되는 것을 막으며,
+    /// 내용 해시가 없는 이전 문서는 인덱스에서 제외한다.
+    pub fn expected_indexes() -> Vec<(&'static str, Document, IndexOptions)> {
+        vec![
+            ("book_id_site", doc! { "book_id": 1, "site": 1 }, IndexOptions::default()),
+            (
+                "book_id_site_content_hash",
+                doc! { "book_id": 1, "site": 1, "content_hash": 1 },
+                IndexOptions::builder()
+                    .unique(true)
+                    .partial_filter_expression(doc! { "content_hash": { "$exists": true } })
+                    .build(),
+            ),
+        ]
+    }
+
+    /// 원본 데이터 컬렉션에 필요한 인덱스([`MongoOriginStore::expected_indexes`])를 생성한다. 이미 생성된 인덱스는 그대로 유지한다.
+    pub fn create_indexes(&self) -> Result<(), mongodb::error::Error> {
+        for (name, keys, mut options) in Self::expected_indexes() {
+            options.name = Some(name.to_owned());
+            let index = IndexModel::builder()
+                .keys(keys)
+                .options(options)
+                .build();
+            self.collection.create_index(index).run()?;
+            info!("Mongo index created: {}.{} ({})", self.collection.namespace().db, Self::COLLECTION, name);
+        }
+        Ok(())
+    }
+
+    /// 원본 데이터 컬렉션에 없는 인덱스 이름을 반환한다.
+    pub fn missing_indexes(&self) -> Result<Vec<&'static str>, mongodb::error::Error> {
+        let names = self.collection.list_index_names().run()?;
+
+        let missing = Self::expected_indexes().into_iter()
+            .map(|(name, _, _)| name)
+            .filter(|name| !names.iter().any(|n| n == name))
+            .collect();
+        Ok(missing)
+    }
+
+    /// 원본 데이터 컬렉션에 필요한 인덱스가 있는지 확인하고 없는 경우 경고 로그를 남긴다.
+    /// 인덱스가 없으면 원본 데이터 조회가 컬렉션 전체를 탐색하게 되므로 `MIGRATE` 잡으로 인덱스를 생성해야 한다.
+    pub fn verify_indexes(&self) {
+        match self.missing_indexes() {
+            Ok(missing) if !missing.is_empty() => {
+                warn!("Mongo indexes missing on {}: {} (run MIGRATE job to create)", Self::COLLECTION, missing.join(", "));
+            }
+            Ok(_) => {}
+            Err(err) => warn!("Failed to verify mongo indexes: {}", err),
+        }
+    }
+}
+
+impl OriginStore for MongoOriginStore {
+    fn find_by_book_id(&self, book_id: &[i64], projection: Option<&OriginProjection>) -> Result<Vec<(i64, Site, Raw)>, OriginStoreError> {
+        let mut filter = doc! { "book_id": { "$in": book_id } };
+        let mut fields = None;
+        if let Some(projection) = projection {
+            let sites = projection.keys().map(|site| site.to_string()).collect::<Vec<_>>();
+            filter.insert("site", doc! { "$in": sites });
+
+            // 사이트별 키의 합집합만 가져오고 사이트에 지정되지 않은 키는 조회 후 제거한다.
+            let mut projected = doc! { "book_id": 1, "site": 1 };
+            for key in projection.values().flatten() {
+                projected.insert(format!("origin_data.{}", key), 1);
+            }
+            fields = Some(projected);
+        }
+
+        let mut find = self.collection.find(filter);
+        if let Some(fields) = fields {
+            find = find.projection(fields);
+        }
+        let cursor = find.run().map_err(mongo_error)?;
+
+        let mut result = Vec::new();
+        for document in cursor {
+            let document = document.map_err(mongo_error)?;
+            let book_id = document.get_i64("book_id").map_err(mongo_error)?;
+            let site = document.get_str("site")
+                .map_err(mongo_error)
+                .and_then(|site| Site::try_from(site).map_err(|e| OriginStoreError(format!("{:?}", e))))?;
+            let raw = match document.get("origin_data").cloned().map(|v| v.into_relaxed_extjson()) {
+                Some(serde_json::Value::Object(map)) => map.into_iter()
+                    .map(|(k, v)| (k, RawValue::from(v)))
+                    .collect(),
+                _ => Raw::new(),
+            };
+            let raw = match projection.and_then(|p| p.get(&site)) {
+                Some(keys) => raw_utils::project_raw(raw, keys),
+                None => raw,
+            };
+            result.push((book_id, site, raw));
+        }
+        Ok(result)
+    }
+
+    /// 도서, 사이트, 내용 해시가 같은 문서가 없는 경우에만 저장(upsert)하여 다시 실행한 청크가 같은 문서를 중복으로 저장하지 않도록 한다.
+    fn new_original_data(&self, book_id: i64, originals: &Originals) -> Result<usize, OriginStoreError> {
+        let mut inserted = 0;
+        for (site, raw) in originals {
+            let origin_data = raw.iter()
+                .map(|(k, v)| (k.clone(), serde_json::Value::from(v.clone())))
+                .collect::<serde_json::Map<_, _>>();
+            let origin_data = mongodb::bson::to_bson(&origin_data).map_err(mongo_error)?;
+            let key = doc! { "book_id": book_id, "site": site.to_string(), "content_hash": raw_utils::content_hash(raw) };
+
+            let result = self.collection.update_one(key, doc! { "$setOnInsert": { "origin_data": origin_data } })
+                .upsert(true)
+                .run()
+                .map_err(mongo_error)?;
+            if result.upserted_id.is_some() {
+                inserted += 1;
+            }
+        }
+        Ok(inserted)
+    }
+
+    fn delete_by_site(&self, book_id: i64, site: &Site) -> Result<usize, OriginStoreError> {
+        self.collection.delete_many(doc! { "book_id": book_id, "site": site.to_string() })
+            .run()
+            .map(|result| result.deleted_count as usize)
+            .map_err(mongo_error)
+    }
+
+    fn delete_by_book_id(&self, book_id: &[i64]) -> Result<usize, OriginStoreError> {
+        self.collection.delete_many(doc! { "book_id": { "$in": book_id } })
+            .run()
+            .map(|result| result.deleted_count as usize)
+            .map_err(mongo_error)
+    }
+}
+
+fn mongo_error<E: std::fmt::Display>(e: E) -> OriginStoreError {
+    OriginStoreError(e.to_string())
+}
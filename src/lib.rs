@@ -1,4 +1,5 @@
 use crate::batch::JobParameter;
+use crate::clock::Clock;
 use clap::Parser;
 use std::fmt;
 use std::fmt::Formatter;
@@ -8,6 +9,13 @@ pub mod provider;
 pub mod item;
 pub mod batch;
 pub mod prompt;
+pub mod spec;
+pub mod clock;
+pub mod transliterate;
+pub mod runtime;
+pub mod toggle;
+#[cfg(feature = "chaos")]
+pub mod chaos;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum ArgumentError {
@@ -28,7 +36,36 @@ pub enum JobName {
     NLGO,
     KYOBO,
 
-    SERIES
+    SERIES,
+
+    FETCH,
+
+    NORMALIZE,
+
+    STOCK,
+
+    REPORT,
+
+    BACKFILL,
+
+    #[allow(non_camel_case_types)]
+    KYOBO_SEARCH,
+
+    STATUS,
+
+    MIGRATE,
+
+    #[allow(non_camel_case_types)]
+    SERIES_COVER,
+
+    GLOSSARY,
+
+    PURGE,
+
+    SNAPSHOT,
+
+    #[allow(non_camel_case_types)]
+    PUB_DATE_RECHECK,
 }
 
 impl From<&str> for JobName {
@@ -39,6 +76,19 @@ impl From<&str> for JobName {
             "nlgo" => JobName::NLGO,
             "kyobo" => JobName::KYOBO,
             "series" => JobName::SERIES,
+            "fetch" => JobName::FETCH,
+            "normalize" => JobName::NORMALIZE,
+            "stock" => JobName::STOCK,
+            "report" => JobName::REPORT,
+            "backfill" => JobName::BACKFILL,
+            "kyobo_search" => JobName::KYOBO_SEARCH,
+            "status" => JobName::STATUS,
+            "migrate" => JobName::MIGRATE,
+            "series_cover" => JobName::SERIES_COVER,
+            "glossary" => JobName::GLOSSARY,
+            "purge" => JobName::PURGE,
+            "snapshot" => JobName::SNAPSHOT,
+            "pub_date_recheck" => JobName::PUB_DATE_RECHECK,
             _ => panic!("Invalid job name: {}", s),
         }
     }
@@ -50,6 +100,37 @@ pub const PARAM_NAME_PUBLISHER_ID: &str = "publisher_id";
 
 pub const PARAM_NAME_ISBN: &str = "isbn";
 pub const PARAM_NAME_LIMIT: &str = "limit";
+pub const PARAM_NAME_CHUNK_SIZE: &str = "chunk_size";
+pub const PARAM_NAME_SPILL_THRESHOLD: &str = "spill_threshold";
+pub const PARAM_NAME_SKIP_LIMIT: &str = "skip_limit";
+pub const PARAM_NAME_UPSERT: &str = "upsert";
+pub const PARAM_NAME_OUTPUT: &str = "output";
+pub const PARAM_NAME_INPUT: &str = "input";
+pub const PARAM_NAME_SKIP_FILTER: &str = "skip_filter";
+pub const PARAM_NAME_FILTER_SITE: &str = "filter_site";
+pub const PARAM_NAME_DESCRIPTION_SITE: &str = "description_site";
+pub const PARAM_NAME_DESCRIPTION_MIN_LENGTH: &str = "description_min_length";
+pub const PARAM_NAME_DESCRIPTION_MAX_LENGTH: &str = "description_max_length";
+pub const PARAM_NAME_REPORT_DAYS: &str = "report_days";
+pub const PARAM_NAME_SERIES_SAME_PUBLISHER: &str = "series_same_publisher";
+pub const PARAM_NAME_SITE_PRIORITY: &str = "site_priority";
+pub const PARAM_NAME_START_YEAR: &str = "start_year";
+pub const PARAM_NAME_FOLLOW_UP: &str = "follow_up";
+pub const PARAM_NAME_ISBN_SET: &str = "isbn_set";
+pub const PARAM_NAME_ITEM_LIST: &str = "item_list";
+pub const PARAM_NAME_STALE_DAYS: &str = "stale_days";
+pub const PARAM_NAME_NORMALIZE_BATCH: &str = "normalize_batch";
+pub const PARAM_NAME_PROFILE: &str = "profile";
+pub const PARAM_NAME_DRY_RUN: &str = "dry_run";
+pub const PARAM_NAME_ALLOW_DUPLICATE: &str = "allow_duplicate";
+pub const PARAM_NAME_ROMANIZE: &str = "romanize";
+pub const PARAM_NAME_NO_LOGIN: &str = "no_login";
+pub const PARAM_NAME_REPLAY_CHUNK: &str = "replay_chunk";
+pub const PARAM_NAME_RESUME: &str = "resume";
+pub const PARAM_NAME_CONFIRM: &str = "confirm";
+pub const PARAM_NAME_SNAPSHOT_DIR: &str = "snapshot_dir";
+pub const PARAM_NAME_UPDATE_SNAPSHOT: &str = "update_snapshot";
+pub const PARAM_NAME_KEYWORD: &str = "keyword";
 
 #[derive(Debug, Parser)]
 pub struct Argument {
@@ -68,8 +149,42 @@ pub struct Argument {
     /// - `ALADIN`: 알라딘 API를 이용한 도서 데이터 수집
     /// - `KYOBO`: 교보문고 파싱을 통한 도서 데이터 수집
     /// - `SERIES`: 시리즈가 연결되지 않은 도서들의 적잘한 시리즈를 찾아 연결
-    #[arg(short, long)]
-    pub job: String,
+    /// - `FETCH`: 입력 받은 ISBN을 모든 사이트에서 조회하여 저장된 도서와 비교 (`--upsert` 입력시 저장)
+    /// - `NORMALIZE`: 제목이 정규화 되지 않은 도서들의 제목을 정규화 하여 저장
+    /// - `STOCK`: 수집된 원본 데이터로 사이트별 판매 상태를 기록하고 모든 사이트에서 품절된 도서를 알림
+    /// - `REPORT`: 최근 수집된 도서를 표지, 바코드와 함께 인쇄용 HTML/PDF 리포트로 출력
+    /// - `BACKFILL`: 국립중앙도서관 API로 지정한 연도부터 현재까지의 도서를 한 달씩 수집 (중단된 경우 이어서 수집)
+    /// - `KYOBO_SEARCH`: 교보문고 검색을 통한 출판사별 신규 도서(예약 판매 등) 수집
+    /// - `STATUS`: 사이트, 출판사별 마지막 수집 시각과 도서 수를 표로 출력 (오랫동안 수집 되지 않은 조합 표시)
+    /// - `MIGRATE`: 바이너리에 포함된 데이터베이스 마이그레이션과 MongoDB 인덱스를 적용 (새 환경 초기화)
+    /// - `SERIES_COVER`: 대표 이미지가 없는 시리즈에 소속 도서(1권 우선)의 썸네일을 대표 이미지로 저장
+    /// - `GLOSSARY`: 출판사별 시리즈의 정규화된 제목과 소속 도서를 편집팀용 용어집(CSV)으로 출력
+    /// - `PURGE`: ISBN 혹은 출판사와 출판일 기간으로 도서와 원본 데이터를 삭제하고 빈 시리즈를 정리 (`--confirm` 입력시 삭제)
+    /// - `SNAPSHOT`: 기준 ISBN을 모든 사이트에서 조회하여 체크인된 스냅샷과 속성 단위로 비교 (`--update-snapshot` 입력시 스냅샷 갱신)
+    /// - `PUB_DATE_RECHECK`: 출간 예정일이 지났지만 실제 출간일이 없는 도서를 국립중앙도서관에서 다시 조회하고 교보문고 재수집 대상으로 등록 (확인 되지 않을 수록 주기가 길어짐)
+    ///
+    /// `--list-jobs`, `--describe-job`을 입력한 경우 생략할 수 있으며,
+    /// `--replay-chunk`를 입력한 경우 생략하면 격리 파일에 기록된 잡을 실행한다.
+    #[arg(short, long, required_unless_present_any = ["list_jobs", "describe_job", "replay_chunk"])]
+    pub job: Option<String>,
+
+    /// (Optional) 실행할 수 있는 잡 목록을 출력하고 종료
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --list-jobs
+    /// ```
+    #[arg(long)]
+    pub list_jobs: bool,
+
+    /// (Optional) 잡이 사용하는 파라미터와 기본값, 필요한 인증 정보(환경 변수)를 출력하고 종료
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --describe-job BACKFILL
+    /// ```
+    #[arg(long)]
+    pub describe_job: Option<String>,
 
     /// (Optional) 수집할 도서의 출판일 검색 시작 날짜 (YYYY-MM-DD)
     ///
@@ -78,6 +193,8 @@ pub struct Argument {
     /// - NAVER
     /// - NLGO
     /// - KYOBO
+    /// - PURGE
+    /// - PUB_DATE_RECHECK: 재확인할 도서의 출간 예정일 기간 (입력하지 않을 경우 어제까지 180일)
     ///
     /// # Example
     /// ```text
@@ -94,6 +211,8 @@ pub struct Argument {
     /// - NAVER
     /// - NLGO
     /// - KYOBO
+    /// - PURGE
+    /// - PUB_DATE_RECHECK: 재확인할 도서의 출간 예정일 기간 (입력하지 않을 경우 어제까지 180일)
     ///
     /// # Example
     /// ```text
@@ -111,6 +230,11 @@ pub struct Argument {
     /// - NAVER
     /// - NLGO
     /// - KYOBO
+    /// - BACKFILL
+    /// - KYOBO_SEARCH
+    /// - STATUS
+    /// - GLOSSARY
+    /// - PURGE
     ///
     /// # Example
     /// ```text
@@ -134,6 +258,11 @@ pub struct Argument {
     /// # Job Names
     /// - KYOBO: 수집할 도서 ISBN
     /// - SERIES: 시리즈를 분류할 대상 ISBN
+    /// - FETCH: 모든 사이트에서 조회할 도서 ISBN
+    /// - NORMALIZE: 제목을 (다시) 정규화할 도서 ISBN
+    /// - STOCK: 판매 상태를 확인할 도서 ISBN
+    /// - PURGE: 삭제할 도서 ISBN
+    /// - SNAPSHOT: 스냅샷과 비교할 기준 도서 ISBN (입력하지 않을 경우 스냅샷 디렉토리의 `isbn.txt`를 사용)
     ///
     /// # Example
     /// ```text
@@ -151,10 +280,26 @@ pub struct Argument {
     #[arg(short, long, num_args = 1..)]
     pub isbn: Option<Vec<String>>,
 
+    /// (Optional) 처리하고자 하는 도서의 ISBN 집합 이름
+    /// 다른 잡에서 저장한 ISBN 집합(ex: `nlgo:2024-06-01:new`)을 불러와 `--isbn`으로 입력한 것과 같이 처리한다.
+    /// `--isbn`과 함께 입력할 경우 두 ISBN을 모두 처리한다.
+    ///
+    /// # Job Names
+    /// `--isbn`을 사용하는 모든 잡
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job KYOBO --isbn-set nlgo:2024-06-01:new
+    /// ```
+    #[arg(long)]
+    pub isbn_set: Option<String>,
+
     /// (Optional) 잡에서 한번에 처리할 데이터의 개수
     ///
     /// # Supported Job Names
     /// - SERIES
+    /// - NORMALIZE
+    /// - SERIES_COVER
     ///
     /// # Example
     /// ```text
@@ -169,13 +314,473 @@ pub struct Argument {
     /// // 100
     /// println!("{}", argument.limit.unwrap())
     /// ```
-    pub limit: Option<usize>
+    pub limit: Option<usize>,
+
+    /// (Optional) 잡에서 한번에 처리하고 저장할 데이터의 개수(청크 사이즈)
+    /// 1 이상의 값만 입력할 수 있으며 입력하지 않을 경우 각 잡의 기본값을 사용한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NAVER
+    /// - NLGO
+    /// - KYOBO
+    /// - KYOBO_SEARCH
+    /// - SERIES
+    /// - NORMALIZE
+    /// - SERIES_COVER
+    /// - GLOSSARY
+    /// - PURGE
+    /// - PUB_DATE_RECHECK
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --chunk-size 100
+    /// ```
+    #[arg(long)]
+    pub chunk_size: Option<usize>,
+
+    /// (Optional) 읽은 도서를 디스크의 임시 파일로 옮긴 후 처리할 도서 개수 기준
+    /// 읽은 도서가 이 값보다 많을 경우 처리 중 메모리에 모든 도서를 유지하지 않고 임시 파일에서 청크 단위로 읽어 처리한다.
+    /// 1 이상의 값만 입력할 수 있으며 입력하지 않을 경우 모든 도서를 메모리에서 처리한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NLGO
+    /// - KYOBO_SEARCH
+    /// - BACKFILL
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job NLGO --from 2020-01-01 --to 2024-12-31 --spill-threshold 50000
+    /// ```
+    #[arg(long)]
+    pub spill_threshold: Option<usize>,
+
+    /// (Optional) 처리, 저장에 실패한 도서를 잡을 중단하지 않고 건너뛸 최대 개수
+    /// 실패한 도서는 경고 로그를 남기고 건너뛰며(저장에 실패한 청크는 격리 파일로 기록), 건너뛴 도서가 이 값을 넘으면 잡을 중단한다.
+    /// 입력하지 않을 경우 실패한 도서를 건너뛰지 않고 잡을 중단한다.
+    ///
+    /// # Job Names
+    /// `--chunk-size`를 사용하는 모든 잡
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job KYOBO --from 2025-01-01 --to 2025-01-31 --skip-limit 20
+    /// ```
+    #[arg(long)]
+    pub skip_limit: Option<usize>,
+
+    /// (Optional) 조회한 도서를 저장소에 저장할지 여부
+    /// 입력하지 않을 경우 저장소의 도서와 비교한 결과만 출력한다.
+    ///
+    /// # Job Names
+    /// - FETCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job FETCH --isbn 9788966261000 --upsert
+    /// ```
+    #[arg(long)]
+    pub upsert: bool,
+
+    /// (Optional) 수집한 도서를 저장소 대신 출력할 파일 경로
+    /// 확장자가 `.csv`일 경우 CSV로, 그 외에는 JSON Lines로 출력하며 `-`를 입력할 경우 표준 출력으로 출력한다.
+    /// `REPORT` 잡은 HTML로 출력하며 확장자가 `.pdf`일 경우 PDF로 변환하여 출력한다. `GLOSSARY` 잡은 항상 CSV로 출력한다.
+    ///
+    /// # Note
+    /// `ALADIN`, `NLGO`, `KYOBO_SEARCH` 잡은 데이터베이스에 연결하지 않고 실행한다. 수집 대상 출판사는 `--keyword`(또는 `--input` 파일)로 입력하며, 필터 규칙과 요청 한도는 사용하지 않고 실행 기록과 수집 현황도 남기지 않는다.
+    /// 저장된 도서를 보강하는 `NAVER`, `KYOBO` 잡은 기존 도서, 재시도 큐를 데이터베이스에서 읽으므로 데이터베이스 연결이 필요하며 도서의 저장만 파일로 대신한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NAVER
+    /// - NLGO
+    /// - KYOBO
+    /// - KYOBO_SEARCH
+    /// - REPORT
+    /// - GLOSSARY
+    /// - PURGE: 삭제 대상 목록
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job ALADIN --output aladin.jsonl
+    /// $ cargo run -- --job ALADIN -o aladin.csv
+    /// $ cargo run -- --job REPORT -o new-arrivals.pdf
+    /// ```
+    #[arg(short, long)]
+    pub output: Option<String>,
+
+    /// (Optional) API 대신 도서를 읽어올 JSON Lines 파일 경로
+    /// `--output`으로 출력한 파일을 입력하여 필터, 라이터를 다시 실행할 때 사용하며 `-`를 입력할 경우 표준 입력에서 읽는다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NAVER
+    /// - NLGO
+    /// - KYOBO
+    /// - KYOBO_SEARCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job ALADIN --input aladin.jsonl
+    /// ```
+    #[arg(long)]
+    pub input: Option<String>,
+
+    /// (Optional) 저장소의 출판사 대신 사이트에서 검색할 키워드 리스트
+    /// `--output`으로 데이터베이스 없이 실행할 때 사용하며, 입력한 키워드들을 검색 키워드로 가진 출판사 하나를 수집한다.
+    /// 각 키워드는 공백(" ")으로 구분 하며 `--publisher-id`를 함께 입력한 경우 첫 번째 아이디를 출판사 아이디로 사용한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NLGO
+    /// - KYOBO_SEARCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job NLGO --output books.jsonl --keyword 민음사
+    /// ```
+    #[arg(long, num_args = 1..)]
+    pub keyword: Option<Vec<String>>,
+
+    /// (Optional) 원본 데이터 필터(저장소에 등록된 사이트별 필터 규칙)를 사용하지 않고 수집
+    /// 잘못 등록된 필터 규칙을 수정하는 동안 필터 규칙을 삭제하지 않고 수집할 때 사용한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NLGO
+    /// - KYOBO_SEARCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job ALADIN --skip-filter
+    /// ```
+    #[arg(long)]
+    pub skip_filter: bool,
+
+    /// (Optional) 원본 데이터 필터에 사용할 필터 규칙의 사이트 리스트
+    /// 각 사이트는 공백(" ")으로 구분 하며 입력하지 않을 경우 수집하는 사이트의 필터 규칙만 사용한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NLGO
+    /// - KYOBO_SEARCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job ALADIN --filter-site ALADIN NLGO
+    /// ```
+    #[arg(long, num_args = 1..)]
+    pub filter_site: Option<Vec<String>>,
+
+    /// (Optional) 도서 소개글을 선택할 사이트의 우선순위
+    /// 각 사이트는 공백(" ")으로 구분 하며 앞에 입력한 사이트의 소개글을 우선 사용한다.
+    /// 입력하지 않을 경우 KYOBO, ALADIN, NAVER, NLGO 순서를 사용한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NAVER
+    /// - NLGO
+    /// - KYOBO
+    /// - FETCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job FETCH --isbn 9788966261000 --description-site ALADIN KYOBO
+    /// ```
+    #[arg(long, num_args = 1..)]
+    pub description_site: Option<Vec<String>>,
+
+    /// (Optional) 도서 소개글의 최소 길이(문자 수), 이보다 짧은 소개글은 사용하지 않는다. (기본값: 10)
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NAVER
+    /// - NLGO
+    /// - KYOBO
+    /// - FETCH
+    #[arg(long)]
+    pub description_min_length: Option<usize>,
+
+    /// (Optional) 도서 소개글의 최대 길이(문자 수), 이보다 긴 소개글은 잘라서 저장한다. (기본값: 2000)
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NAVER
+    /// - NLGO
+    /// - KYOBO
+    /// - FETCH
+    #[arg(long)]
+    pub description_max_length: Option<usize>,
+
+    /// (Optional) 신간 리포트에 포함할 수집 기간(일), 오늘을 포함하여 지정한 일수 동안 수집된 도서를 출력한다. (기본값: 7)
+    ///
+    /// # Job Names
+    /// - REPORT
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job REPORT --report-days 14 --output report.html
+    /// ```
+    #[arg(long)]
+    pub report_days: Option<u64>,
+
+    /// (Optional) 도서를 같은 출판사의 도서로만 이루어진 시리즈에만 연결
+    /// 제목이 유사하더라도 다른 출판사의 도서가 속한 시리즈는 연결 후보에서 제외하고 새 시리즈를 생성한다.
+    ///
+    /// # Job Names
+    /// - SERIES
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job SERIES --series-same-publisher
+    /// ```
+    #[arg(long)]
+    pub series_same_publisher: bool,
+
+    /// (Optional) LLM 요청에 사용할 사이트별 원본 데이터의 우선순위
+    /// 각 사이트는 공백(" ")으로 구분 하며 앞에 입력한 사이트의 판매처 정보를 먼저 전달하고, 저자 등의 대표값도 앞의 사이트에서 선택한다.
+    /// 입력하지 않을 경우 KYOBO, ALADIN, NAVER, NLGO 순서를 사용한다.
+    ///
+    /// # Job Names
+    /// - SERIES
+    /// - NORMALIZE
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job NORMALIZE --site-priority ALADIN KYOBO
+    /// ```
+    #[arg(long, num_args = 1..)]
+    pub site_priority: Option<Vec<String>>,
+
+    /// (Optional) 과거 도서 수집을 시작할 연도, 해당 연도의 1월부터 현재까지 한 달씩 수집한다.
+    ///
+    /// # Job Names
+    /// - BACKFILL (필수)
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job BACKFILL --start-year 2015 --publisher-id 20050726
+    /// ```
+    #[arg(long)]
+    pub start_year: Option<i32>,
+
+    /// (Optional) 수집 현황에서 마지막 수집 후 지정한 일수가 지난 사이트, 출판사 조합을 오래 된 수집으로 표시한다. (기본값: 7)
+    ///
+    /// # Job Names
+    /// - STATUS
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job STATUS --stale-days 3
+    /// ```
+    #[arg(long)]
+    pub stale_days: Option<i64>,
+
+    /// (Optional) 도서 제목을 한 건씩 정규화 하지 않고 청크 단위로 한번에 정규화 (브릿지 서버의 일괄 정규화 API 사용)
+    /// 청크 사이즈를 입력하지 않을 경우 20건씩 정규화 한다.
+    ///
+    /// # Job Names
+    /// - SERIES
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job SERIES --normalize-batch --chunk-size 30
+    /// ```
+    #[arg(long)]
+    pub normalize_batch: bool,
+
+    /// (Optional) 잡이 끝난 후 잡에 정의된 후속 잡을 이어서 실행
+    /// 후속 잡의 파라미터는 선행 잡의 변경 내역으로 만든다. (ex: NLGO 잡에서 새로 저장된 도서의 ISBN으로 KYOBO 잡 실행)
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NLGO
+    /// - KYOBO_SEARCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job NLGO --follow-up
+    /// ```
+    #[arg(long)]
+    pub follow_up: bool,
+
+    /// (Optional) 출판사 키워드 검색 결과에 사이트에서 선정한 신간 리스트(알라딘 주목할 만한 신간)를 함께 수집
+    /// 신간 리스트의 도서 중 출판사 키워드와 출판사명이 일치하는 도서만 수집한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job ALADIN --item-list
+    /// ```
+    #[arg(long)]
+    pub item_list: bool,
+
+    /// (Optional) 사용할 설정 파일의 프로필 이름
+    /// 프로필에 설정된 데이터베이스, 브릿지 서버에 연결하여 잡을 실행한다. 입력하지 않을 경우 환경 변수와 기본 설정을 사용한다.
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job SERIES --profile comics
+    /// ```
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// (Optional) 저장소의 도서, 시리즈를 변경하지 않고 잡을 미리 실행
+    /// 읽기와 처리는 그대로 실행하고 저장 대신 저장할 아이템 수만 로그로 남긴다. (`--output`으로 파일에 출력하는 경우 파일에는 출력한다.)
+    /// 수집 현황, 신규 ISBN 묶음, 보강 실패 기록, API 요청 수, 체크포인트도 저장하지 않으며 실행 기록만 남긴다.
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job SERIES --dry-run
+    /// ```
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// (Optional) 오늘 같은 파라미터로 정상 종료된 실행이 있어도 잡을 실행
+    /// 입력하지 않을 경우 같은 날 같은 잡을 같은 파라미터로 다시 실행하면 경고 로그를 남기고 실행하지 않는다.
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job NLGO --from 2025-05-01 --to 2025-05-31 --allow-duplicate
+    /// ```
+    #[arg(long)]
+    pub allow_duplicate: bool,
+
+    /// (Optional) 파일로 출력할 때 제목의 로마자 표기(`romanized_title`)를 함께 출력
+    /// `transliteration` 기능으로 빌드한 경우에만 사용할 수 있다.
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run --features transliteration -- --job NLGO --output books.csv --romanize
+    /// ```
+    #[arg(long)]
+    pub romanize: bool,
+
+    /// (Optional) 교보문고에 로그인 하지 않고 공개된 페이지만 조회
+    /// 교보문고 로그인 정보(`KYOBO_ID`, `KYOBO_SECRET`)와 크롬이 없는 로컬 개발 환경에서 사용한다.
+    ///
+    /// # Job Names
+    /// - KYOBO
+    /// - FETCH
+    /// - SNAPSHOT
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job KYOBO --isbn 9791136202093 --no-login
+    /// ```
+    #[arg(long)]
+    pub no_login: bool,
+
+    /// (Optional) 저장에 실패하여 격리된 청크 파일을 수집 없이 라이터로 다시 저장
+    /// 격리 파일은 환경 변수 `QUARANTINE_DIR`(기본값 `quarantine`) 디렉토리에 생성되며 경로는 에러 로그와 잡 실행 기록의 변경 내역에 남는다.
+    /// 저장 실패의 원인(데이터베이스 장애 등)을 해결한 후 사용한다.
+    ///
+    /// # Job Names
+    /// - ALADIN
+    /// - NAVER
+    /// - NLGO
+    /// - KYOBO
+    /// - FETCH
+    /// - KYOBO_SEARCH
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --replay-chunk quarantine/NLGO-20250601000000-42-0.jsonl
+    /// ```
+    #[arg(long)]
+    pub replay_chunk: Option<String>,
+
+    /// (Optional) 실패하거나 취소된 실행을 이어서 실행
+    /// 같은 잡을 같은 파라미터로 실행했던 이전 실행의 체크포인트(마지막으로 저장을 마친 청크)까지의 청크를 처리하지 않고 건너뛴다.
+    /// 체크포인트가 없는 경우 처음부터 실행한다.
+    ///
+    /// # Job Names
+    /// - KYOBO
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job KYOBO --from 2025-05-01 --to 2025-05-31 --resume
+    /// ```
+    #[arg(long)]
+    pub resume: bool,
+
+    /// (Optional) 도서 삭제를 확인
+    /// 입력하지 않거나 `--dry-run`과 함께 입력한 경우 도서를 삭제하지 않고 삭제 대상 목록만 출력한다.
+    ///
+    /// # Job Names
+    /// - PURGE
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job PURGE --publisher-id 20050726 --from 2025-01-01 --to 2025-01-31 --confirm
+    /// ```
+    #[arg(long)]
+    pub confirm: bool,
+
+    /// (Optional) 사이트별 스냅샷(`{사이트}/{ISBN}.json`)과 기준 ISBN 목록(`isbn.txt`)이 있는 디렉토리 (기본값: `snapshots`)
+    ///
+    /// # Job Names
+    /// - SNAPSHOT
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job SNAPSHOT --snapshot-dir snapshots --no-login
+    /// ```
+    #[arg(long)]
+    pub snapshot_dir: Option<String>,
+
+    /// (Optional) 스냅샷과 비교하지 않고 조회한 도서로 스냅샷을 갱신
+    /// 사이트의 변경이나 변환 로직의 수정이 의도된 것임을 확인한 후 사용한다.
+    ///
+    /// # Job Names
+    /// - SNAPSHOT
+    ///
+    /// # Example
+    /// ```text
+    /// $ cargo run -- --job SNAPSHOT --isbn 9791133478427 --update-snapshot
+    /// ```
+    #[arg(long)]
+    pub update_snapshot: bool,
+
+    /// (Optional) 잡 파라미터를 읽을 JSON/YAML/TOML 파일 경로
+    /// 파일의 최상위 키를 파라미터 이름으로 사용하며, `sets` 아래에 이름별 파라미터 세트를 정의할 수 있다.
+    /// 커맨드 라인에 입력한 파라미터가 파일의 파라미터보다 우선한다.
+    ///
+    /// # Example
+    /// ```yaml
+    /// publisher_id: [20050726, 20110708]
+    /// chunk_size: 100
+    /// sets:
+    ///   june:
+    ///     from: 2025-06-01
+    ///     to: 2025-06-30
+    /// ```
+    /// ```text
+    /// $ cargo run -- --job NLGO --params-file params.yaml --params-set june
+    /// ```
+    #[arg(long)]
+    pub params_file: Option<String>,
+
+    /// (Optional) `--params-file`에서 사용할 파라미터 세트 이름
+    /// 세트의 파라미터는 파일 최상위의 파라미터보다 우선한다.
+    #[arg(long, requires = "params_file")]
+    pub params_set: Option<String>,
 }
 
 impl Argument {
 
+    /// 실행할 잡 이름, `--job`을 생략하고 `--replay-chunk`를 입력한 경우 격리 파일에 기록된 잡을 반환한다.
     pub fn get_job(&self) -> JobName {
-        self.job.as_str().into()
+        match (self.job.as_deref(), self.replay_chunk.as_ref()) {
+            (Some(job), _) => job.into(),
+            (None, Some(path)) => batch::quarantine::read_header(std::path::Path::new(path))
+                .expect("Failed to read quarantine file")
+                .job.as_str().into(),
+            (None, None) => panic!("job is required"),
+        }
     }
 
     pub fn get_from(&self) -> Option<chrono::NaiveDate> {
@@ -201,25 +806,23 @@ impl Argument {
 /// - `.1`: 잡에서 사용될 파라미터
 ///
 /// # Note
+/// - `--params-file`이 입력된 경우 파일의 파라미터([`load_parameter_file`]) 중 커맨드 라인에 입력하지 않은 파라미터를 추가한다.
 /// - `from/to`가 입력 되지 않았을 경우 기본값을 사용하며 `from`은 현재일로 부터 -30일, `to`는 현재일로부터 +60일을 시용한다. (총 90일)
 /// - `from`, `to`는 모두 `YYYY-MM-DD` 형식이어야 한다 (ex: 2025-05-01)
 /// - `publisher_id`, `isbn`은 콤마(",")로 연결하여 `String` 타입으로 변환한다.(ex: 20050726 20110708 20111223 -> "20050726,20110708,20111223")
-pub fn command_to_parameter() -> (JobName, JobParameter) {
+/// - `--list-jobs`, `--describe-job`이 입력된 경우 `--help`와 같이 잡 명세([`spec::JobSpec`])를 출력하고 프로그램을 종료한다.
+/// - `from/to`의 기본값은 `clock`의 오늘 날짜를 기준으로 계산한다.
+pub fn command_to_parameter(clock: &dyn Clock) -> (JobName, JobParameter) {
     let argument = Argument::parse();
+    print_job_spec_and_exit(&argument);
 
     let mut parameter = JobParameter::new();
     if let Some(from) = argument.get_from().as_ref() {
         parameter.insert(PARAM_NAME_FROM.to_owned(), from.format("%Y-%m-%d").to_string());
-    } else {
-        let from = default_from_date();
-        parameter.insert(PARAM_NAME_FROM.to_owned(), from.format("%Y-%m-%d").to_string());
     }
 
     if let Some(to) = argument.get_to().as_ref() {
         parameter.insert(PARAM_NAME_TO.to_owned(), to.format("%Y-%m-%d").to_string());
-    } else {
-        let to = default_to_date();
-        parameter.insert(PARAM_NAME_TO.to_owned(), to.format("%Y-%m-%d").to_string());
     }
 
     if let Some(publisher_id) = argument.publisher_id.as_ref() {
@@ -239,13 +842,256 @@ pub fn command_to_parameter() -> (JobName, JobParameter) {
         parameter.insert(PARAM_NAME_LIMIT.to_owned(), limit.to_string());
     }
 
+    if let Some(isbn_set) = argument.isbn_set.as_ref() {
+        parameter.insert(PARAM_NAME_ISBN_SET.to_owned(), isbn_set.to_owned());
+    }
+
+    if let Some(chunk_size) = argument.chunk_size {
+        parameter.insert(PARAM_NAME_CHUNK_SIZE.to_owned(), chunk_size.to_string());
+    }
+
+    if let Some(threshold) = argument.spill_threshold {
+        parameter.insert(PARAM_NAME_SPILL_THRESHOLD.to_owned(), threshold.to_string());
+    }
+
+    if let Some(skip_limit) = argument.skip_limit {
+        parameter.insert(PARAM_NAME_SKIP_LIMIT.to_owned(), skip_limit.to_string());
+    }
+
+    if let Some(output) = argument.output.as_ref() {
+        parameter.insert(PARAM_NAME_OUTPUT.to_owned(), output.to_owned());
+    }
+
+    if let Some(input) = argument.input.as_ref() {
+        parameter.insert(PARAM_NAME_INPUT.to_owned(), input.to_owned());
+    }
+
+    if let Some(keyword) = argument.keyword.as_ref() {
+        parameter.insert(PARAM_NAME_KEYWORD.to_owned(), keyword.join(","));
+    }
+
+    if argument.skip_filter {
+        parameter.insert(PARAM_NAME_SKIP_FILTER.to_owned(), argument.skip_filter.to_string());
+    }
+
+    if let Some(filter_site) = argument.filter_site.as_ref() {
+        parameter.insert(PARAM_NAME_FILTER_SITE.to_owned(), filter_site.join(","));
+    }
+
+    if let Some(description_site) = argument.description_site.as_ref() {
+        parameter.insert(PARAM_NAME_DESCRIPTION_SITE.to_owned(), description_site.join(","));
+    }
+
+    if let Some(min_length) = argument.description_min_length {
+        parameter.insert(PARAM_NAME_DESCRIPTION_MIN_LENGTH.to_owned(), min_length.to_string());
+    }
+
+    if let Some(max_length) = argument.description_max_length {
+        parameter.insert(PARAM_NAME_DESCRIPTION_MAX_LENGTH.to_owned(), max_length.to_string());
+    }
+
+    if let Some(site_priority) = argument.site_priority.as_ref() {
+        parameter.insert(PARAM_NAME_SITE_PRIORITY.to_owned(), site_priority.join(","));
+    }
+
+    if argument.follow_up {
+        parameter.insert(PARAM_NAME_FOLLOW_UP.to_owned(), argument.follow_up.to_string());
+    }
+
+    if argument.item_list {
+        parameter.insert(PARAM_NAME_ITEM_LIST.to_owned(), argument.item_list.to_string());
+    }
+
+    if let Some(start_year) = argument.start_year {
+        parameter.insert(PARAM_NAME_START_YEAR.to_owned(), start_year.to_string());
+    }
+
+    if argument.normalize_batch {
+        parameter.insert(PARAM_NAME_NORMALIZE_BATCH.to_owned(), argument.normalize_batch.to_string());
+    }
+
+    if let Some(stale_days) = argument.stale_days {
+        parameter.insert(PARAM_NAME_STALE_DAYS.to_owned(), stale_days.to_string());
+    }
+
+    if let Some(report_days) = argument.report_days {
+        parameter.insert(PARAM_NAME_REPORT_DAYS.to_owned(), report_days.to_string());
+    }
+
+    if argument.series_same_publisher {
+        parameter.insert(PARAM_NAME_SERIES_SAME_PUBLISHER.to_owned(), argument.series_same_publisher.to_string());
+    }
+
+    if argument.upsert {
+        parameter.insert(PARAM_NAME_UPSERT.to_owned(), argument.upsert.to_string());
+    }
+
+    if let Some(profile) = argument.profile.as_ref() {
+        parameter.insert(PARAM_NAME_PROFILE.to_owned(), profile.to_owned());
+    }
+
+    if argument.dry_run {
+        parameter.insert(PARAM_NAME_DRY_RUN.to_owned(), argument.dry_run.to_string());
+    }
+
+    if argument.romanize {
+        parameter.insert(PARAM_NAME_ROMANIZE.to_owned(), argument.romanize.to_string());
+    }
+
+    if argument.allow_duplicate {
+        parameter.insert(PARAM_NAME_ALLOW_DUPLICATE.to_owned(), argument.allow_duplicate.to_string());
+    }
+
+    if argument.no_login {
+        parameter.insert(PARAM_NAME_NO_LOGIN.to_owned(), argument.no_login.to_string());
+    }
+
+    if let Some(replay_chunk) = argument.replay_chunk.as_ref() {
+        parameter.insert(PARAM_NAME_REPLAY_CHUNK.to_owned(), replay_chunk.to_owned());
+    }
+
+    if argument.resume {
+        parameter.insert(PARAM_NAME_RESUME.to_owned(), argument.resume.to_string());
+    }
+
+    if argument.confirm {
+        parameter.insert(PARAM_NAME_CONFIRM.to_owned(), argument.confirm.to_string());
+    }
+
+    if let Some(snapshot_dir) = argument.snapshot_dir.as_ref() {
+        parameter.insert(PARAM_NAME_SNAPSHOT_DIR.to_owned(), snapshot_dir.to_owned());
+    }
+
+    if argument.update_snapshot {
+        parameter.insert(PARAM_NAME_UPDATE_SNAPSHOT.to_owned(), argument.update_snapshot.to_string());
+    }
+
+    if let Some(path) = argument.params_file.as_ref() {
+        let file_parameter = load_parameter_file(path, argument.params_set.as_deref())
+            .expect("Failed to load parameter file");
+        for (name, value) in file_parameter {
+            parameter.entry(name).or_insert(value);
+        }
+    }
+
+    parameter.entry(PARAM_NAME_FROM.to_owned())
+        .or_insert_with(|| default_from_date(clock).format("%Y-%m-%d").to_string());
+    parameter.entry(PARAM_NAME_TO.to_owned())
+        .or_insert_with(|| default_to_date(clock).format("%Y-%m-%d").to_string());
+
     (argument.get_job(), parameter)
 }
 
-pub fn default_from_date() -> chrono::NaiveDate {
-    chrono::Local::now().checked_sub_days(chrono::Days::new(30)).unwrap().date_naive()
+/// 파라미터 파일에서 이름별 파라미터 세트를 정의하는 키
+const PARAMS_FILE_SETS_KEY: &str = "sets";
+
+/// JSON/YAML/TOML 파일(확장자로 형식을 판단)에서 잡 파라미터를 읽는다.
+///
+/// # Description
+/// 파일의 최상위 키를 파라미터 이름으로 사용하며, `set`이 입력된 경우 `sets.{set}`에 정의된 파라미터로 최상위 파라미터를 덮어쓴다.
+/// 문자열이 아닌 값은 커맨드 라인 파라미터와 같은 형식의 문자열로 변환한다. (숫자, 불리언은 문자열로, 배열은 콤마(",")로 연결)
+///
+/// # Errors
+/// 파일을 읽을 수 없거나, 지정한 세트가 없거나, 파라미터 값이 객체인 경우 `ArgumentError::InvalidArgument`를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::load_parameter_file;
+///
+/// let path = std::env::temp_dir().join(format!("book-batch-params-{}.json", std::process::id()));
+/// std::fs::write(&path, r#"{"publisher_id": [1, 2], "dry_run": true, "sets": {"june": {"from": "2025-06-01"}}}"#).unwrap();
+///
+/// let parameter = load_parameter_file(path.to_str().unwrap(), Some("june")).unwrap();
+/// assert_eq!(parameter.get("publisher_id").unwrap(), "1,2");
+/// assert_eq!(parameter.get("dry_run").unwrap(), "true");
+/// assert_eq!(parameter.get("from").unwrap(), "2025-06-01");
+/// assert!(load_parameter_file(path.to_str().unwrap(), Some("july")).is_err());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn load_parameter_file(path: &str, set: Option<&str>) -> Result<JobParameter, ArgumentError> {
+    let mut values = config::Config::builder()
+        .add_source(config::File::from(std::path::Path::new(path)))
+        .build()
+        .and_then(|c| c.try_deserialize::<serde_json::Map<String, serde_json::Value>>())
+        .map_err(|e| ArgumentError::InvalidArgument(format!("{}: {}", path, e)))?;
+
+    let sets = values.remove(PARAMS_FILE_SETS_KEY);
+    if let Some(set) = set {
+        let selected = sets.as_ref()
+            .and_then(|sets| sets.get(set))
+            .and_then(|set| set.as_object())
+            .ok_or_else(|| ArgumentError::InvalidArgument(format!("{}: parameter set {} is not defined", path, set)))?;
+        values.extend(selected.clone());
+    }
+
+    let mut parameter = JobParameter::new();
+    for (name, value) in values {
+        if let Some(value) = parameter_value_to_string(&value)
+            .map_err(|_| ArgumentError::InvalidArgument(format!("{}: {} must be a scalar or an array", path, name)))? {
+            parameter.insert(name, value);
+        }
+    }
+    Ok(parameter)
+}
+
+/// 파라미터 파일의 값을 파라미터 문자열로 변환한다. `null`은 `None`을, 객체는 에러를 반환한다.
+fn parameter_value_to_string(value: &serde_json::Value) -> Result<Option<String>, ()> {
+    match value {
+        serde_json::Value::Null => Ok(None),
+        serde_json::Value::String(s) => Ok(Some(s.to_owned())),
+        serde_json::Value::Bool(b) => Ok(Some(b.to_string())),
+        serde_json::Value::Number(n) => Ok(Some(n.to_string())),
+        serde_json::Value::Array(values) => {
+            let values = values.iter()
+                .filter_map(|v| parameter_value_to_string(v).transpose())
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Some(values.join(",")))
+        }
+        serde_json::Value::Object(_) => Err(()),
+    }
+}
+
+/// `--list-jobs`, `--describe-job`이 입력된 경우 잡 명세를 출력하고 프로그램을 종료한다.
+fn print_job_spec_and_exit(argument: &Argument) {
+    if argument.list_jobs {
+        println!("{}", spec::render_job_list());
+        std::process::exit(0);
+    }
+
+    if let Some(name) = argument.describe_job.as_ref() {
+        match spec::find_job_spec(name) {
+            Some(job_spec) => {
+                println!("{}", spec::render_job_description(job_spec));
+                std::process::exit(0);
+            }
+            None => {
+                eprintln!("Unknown job: {}", name);
+                eprintln!();
+                eprintln!("{}", spec::render_job_list());
+                std::process::exit(2);
+            }
+        }
+    }
+}
+
+/// `from`의 기본값 (오늘로 부터 -30일)
+///
+/// # Example
+/// ```
+/// use book_batch_rust::clock::FixedClock;
+/// use book_batch_rust::{default_from_date, default_to_date};
+/// use chrono::NaiveDate;
+///
+/// let clock = FixedClock::new(NaiveDate::from_ymd_opt(2025, 5, 1).unwrap().and_hms_opt(9, 0, 0).unwrap());
+///
+/// assert_eq!(default_from_date(&clock), NaiveDate::from_ymd_opt(2025, 4, 1).unwrap());
+/// assert_eq!(default_to_date(&clock), NaiveDate::from_ymd_opt(2025, 6, 30).unwrap());
+/// ```
+pub fn default_from_date(clock: &dyn Clock) -> chrono::NaiveDate {
+    clock.today().checked_sub_days(chrono::Days::new(30)).unwrap()
 }
 
-pub fn default_to_date() -> chrono::NaiveDate {
-    chrono::Local::now().checked_add_days(chrono::Days::new(60)).unwrap().date_naive()
+/// `to`의 기본값 (오늘로 부터 +60일)
+pub fn default_to_date(clock: &dyn Clock) -> chrono::NaiveDate {
+    clock.today().checked_add_days(chrono::Days::new(60)).unwrap()
 }
\ No newline at end of file
@@ -1,71 +1,48 @@
-use book_batch_rust::item::repo::{ComposeBookRepository, DieselFilterRepository, DieselPublisherRepository, DieselSeriesRepository};
-use book_batch_rust::item::{SharedBookRepository, SharedFilterRepository, SharedPublisherRepository, SharedSeriesRepository};
-use book_batch_rust::prompt::bridge::{BridgeClient, BridgeServer};
-use book_batch_rust::prompt::SharedPrompt;
-use book_batch_rust::provider::api::{aladin, naver, nlgo};
-use book_batch_rust::provider::html::kyobo;
-use book_batch_rust::{batch, command_to_parameter, configs, JobName};
-use std::rc::Rc;
+use book_batch_rust::batch::cancel::cancel_on_signal;
+use book_batch_rust::clock::system_clock;
+use book_batch_rust::runtime::{self, Runtime, RuntimeError};
+use book_batch_rust::{command_to_parameter, configs, JobName, PARAM_NAME_PROFILE};
 
 fn main() {
     configs::load_dotenv();
     configs::set_global_logging_config().expect("Failed to set global logging config");
 
-    let connection = configs::connect_to_postgres();
+    let clock = system_clock();
+    let (job, parameter) = command_to_parameter(&**clock);
 
-    let pub_repo = SharedPublisherRepository::new(Box::new(DieselPublisherRepository::new(connection.clone())));
-    let book_repo = SharedBookRepository::new(Box::new(ComposeBookRepository::with_origin(connection.clone())));
-    let filter_repo = SharedFilterRepository::new(Box::new(DieselFilterRepository::new(connection.clone())));
+    let mut config = configs::Config::load().expect("Failed to load config");
+    let profile = parameter.get(PARAM_NAME_PROFILE)
+        .map(|name| config.select_profile(name).expect("Failed to select profile"));
 
-    let (job, parameter) = command_to_parameter();
-    match job {
-        JobName::ALADIN => {
-            let job = batch::book::aladin::create_job(
-                Rc::new(aladin::Client::new_with_env().unwrap()),
-                pub_repo.clone(),
-                book_repo.clone(),
-                filter_repo.clone(),
-            );
-            job.run(&parameter).expect("Job running failed");
+    // 데이터베이스 없이 파일로 출력하는 수집 잡은 데이터베이스에 연결하지 않는다.
+    let runtime = if runtime::is_offline_run(&job, &parameter) {
+        Runtime::offline(config)
+    } else {
+        match Runtime::connect(config, profile) {
+            Ok(runtime) => runtime,
+            Err(e) => exit_with_error(job, e),
         }
-        JobName::NAVER => {
-            let job = batch::book::naver::create_job(
-                Rc::new(naver::Client::new_with_env().unwrap()),
-                book_repo.clone(),
-            );
-            job.run(&parameter).expect("Job running failed");
-        }
-        JobName::NLGO => {
-            let job = batch::book::nlgo::create_job(
-                Rc::new(nlgo::Client::new_with_env().unwrap()),
-                pub_repo.clone(),
-                book_repo.clone(),
-                filter_repo.clone(),
-            );
-            job.run(&parameter).expect("Job running failed");
-        }
-        JobName::KYOBO => {
-            let job = batch::book::kyobo::create_job(
-                Rc::new(kyobo::Client::new(kyobo::chrome::new_provider().unwrap())),
-                book_repo.clone(),
-            );
-            job.run(&parameter).expect("Job running failed");
+    };
+    let runtime = runtime.with_clock(clock);
+
+    // 마이그레이션은 실행 기록 테이블이 없는 새 환경에서도 실행할 수 있어야 하므로 실행 기록을 남기지 않는다.
+    if job == JobName::MIGRATE {
+        if let Err(e) = runtime.migrate() {
+            exit_with_error(job, e);
         }
-        JobName::SERIES => {
-            let bridge_server = BridgeServer::new_with_env();
+        return;
+    }
 
-            let book_repo = ComposeBookRepository::new(connection.clone(), true, false, false);
-            let book_repo = SharedBookRepository::new(Box::new(book_repo));
-            
-            let series_repo = SharedSeriesRepository::new(Box::new(DieselSeriesRepository::new(connection.clone())));
-            let prompt = SharedPrompt::new(Box::new(BridgeClient::new(bridge_server)));
+    runtime.verify_origin_indexes();
 
-            let job = batch::series::create_job(
-                book_repo.clone(),
-                series_repo.clone(),
-                prompt.clone(),
-            );
-            job.run(&parameter).expect("Job running failed");
-        }
-    };
+    cancel_on_signal(runtime.cancellation());
+    if let Err(e) = runtime.execute(job, &parameter) {
+        exit_with_error(job, e);
+    }
+}
+
+/// 실행 환경의 에러를 로그로 남기고 실패 코드로 종료한다.
+fn exit_with_error(job: JobName, e: RuntimeError) -> ! {
+    tracing::error!("{:?} => {}", job, e);
+    std::process::exit(1);
 }
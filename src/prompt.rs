@@ -5,7 +5,7 @@ use std::fmt::{Display, Formatter};
 use std::rc::Rc;
 
 /// 프롬프트 사용 중 발생한 에러 열거
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Error {
     /// LLM과 연동 중 에러가 발생함
     ConnectFailed(String),
@@ -40,7 +40,14 @@ pub struct Normalized {
     pub title: String,
 
     /// 제목에서 제거된 요소에 대한 설명
-    pub reason: String
+    pub reason: String,
+
+    /// 정규화에 사용된 프롬프트의 버전
+    ///
+    /// # Note
+    /// 응답에 버전이 없을 경우 [`Prompt::prompt_version`]을 사용한다.
+    #[serde(default)]
+    pub prompt_version: Option<String>,
 }
 
 /// 도서 판매처별 상세 정보
@@ -112,7 +119,7 @@ impl NormalizeRequest {
 ///
 /// # Description
 /// 시리즈 소속 확인시 참고할 도서의 상세 정보를 저장한다.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeriesSimilarRequestBookInfo {
 
     /// 도서 제목
@@ -139,6 +146,48 @@ pub struct SeriesSimilarRequest {
     pub series: Vec<SeriesSimilarRequestBookInfo>
 }
 
+/// 시리즈 선택 요청의 후보 시리즈
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesSelectCandidate {
+
+    /// 후보 시리즈 아이디
+    pub series_id: u64,
+
+    /// 후보 시리즈명
+    pub title: Option<String>,
+
+    /// 후보 시리즈의 도서 목록
+    pub books: Vec<SeriesSimilarRequestBookInfo>,
+}
+
+/// 시리즈 선택 프롬프트 요청 폼
+///
+/// # Description
+/// 신간 정보와 신간이 속할 수 있는 후보 시리즈들을 저장한다. 후보는 신간과 관련이 높은 순서(유사도 등)로 정렬한다.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SeriesSelectRequest {
+
+    /// 속할 시리즈를 선택하고 싶은 신간 도서 정보
+    pub new: SeriesSimilarRequestBookInfo,
+
+    /// 관련이 높은 순서로 정렬된 후보 시리즈 목록
+    pub candidates: Vec<SeriesSelectCandidate>,
+}
+
+/// 후보 시리즈마다 시리즈 소속 여부([`Prompt::series_similar`])를 순서대로 확인하여 처음으로 속한다고 판단된 시리즈의 아이디를 반환한다.
+///
+/// # Description
+/// 여러 후보 중 하나를 한번에 선택하는 API가 없는 프롬프트의 [`Prompt::series_select`] 구현으로 사용한다.
+pub fn select_by_series_similar<P: Prompt + ?Sized>(prompt: &P, request: &SeriesSelectRequest) -> Result<Option<u64>, Error> {
+    for candidate in &request.candidates {
+        let similar = SeriesSimilarRequest { new: request.new.clone(), series: candidate.books.clone() };
+        if prompt.series_similar(&similar)? {
+            return Ok(Some(candidate.series_id));
+        }
+    }
+    Ok(None)
+}
+
 /// 같은 프롬프트 객체를 여러곳에서 사용 할 수 있도록 하는 [`Rc`] 형태의 공유 프롬프트 타입
 pub type SharedPrompt = Rc<Box<dyn Prompt>>;
 
@@ -157,6 +206,23 @@ pub trait Prompt {
     /// - `Normlized`: 정규화된 도서명과 처리 내역을 담은 객체
     fn normalize(&self, request: &NormalizeRequest) -> Result<Normalized, Error>;
 
+    /// 입력 받은 도서명들을 한번에 정규화 한다.
+    ///
+    /// # Parameter
+    /// - `request`: 정규화할 도서 제목과 참고할 판매처 정보를 담은 요청 객체 리스트
+    ///
+    /// # Returns
+    /// 도서명별 정규화 결과를 입력된 순서와 동일한 순서로 반환한다. 일부 도서명의 정규화가 실패하더라도 나머지 결과는 반환된다.
+    ///
+    /// # Note
+    /// 기본 구현은 [`Prompt::normalize`]를 도서명마다 호출한다.
+    /// 여러 도서명을 한번에 정규화 할 수 있는 프롬프트는 이 함수를 재정의 하여 요청 수를 줄인다.
+    fn normalize_batch(&self, request: &[NormalizeRequest]) -> Vec<Result<Normalized, Error>> {
+        request.iter()
+            .map(|r| self.normalize(r))
+            .collect()
+    }
+
     /// 입력 받은 텍스트들을 임베딩 한다.
     ///
     /// # Parameter
@@ -183,4 +249,30 @@ pub trait Prompt {
     /// # Returns
     /// 신간이 시리즈에 속하는지 여부 (True: 속함/False: 속하지 않음)
     fn series_similar(&self, request: &SeriesSimilarRequest) -> Result<bool, Error>;
+
+    /// 입력 받은 신간 정보와 후보 시리즈들의 도서 목록을 프롬프트에 요청해 신간이 속하는 시리즈를 하나 선택한다.
+    ///
+    /// # Description
+    /// 후보 시리즈 전체를 한번의 요청으로 비교하므로 후보마다 소속 여부를 묻는 것보다 요청 수가 적고, 후보 사이의 차이를 함께 고려할 수 있다.
+    ///
+    /// # Paramter
+    /// - request: 신간 정보와 관련이 높은 순서로 정렬된 후보 시리즈들의 도서 목록 정보를 담은 요청 객체
+    ///
+    /// # Returns
+    /// 신간이 속하는 후보 시리즈의 아이디, 어느 후보에도 속하지 않는 경우 `None`
+    ///
+    /// # Note
+    /// 기본 구현은 후보마다 [`Prompt::series_similar`]를 호출한다. ([`select_by_series_similar`])
+    fn series_select(&self, request: &SeriesSelectRequest) -> Result<Option<u64>, Error> {
+        select_by_series_similar(self, request)
+    }
+
+    /// 현재 사용하는 제목 정규화 프롬프트의 버전
+    ///
+    /// # Description
+    /// 정규화 결과와 함께 저장하여 프롬프트가 바뀌었을 때 다시 정규화 해야 하는 도서를 구분하는데 사용한다.
+    /// 버전을 알 수 없을 경우 `None`을 반환한다.
+    fn prompt_version(&self) -> Option<String> {
+        None
+    }
 }
\ No newline at end of file
@@ -1,12 +1,15 @@
-use crate::prompt::{Error, NormalizeRequest, Normalized, Prompt, SeriesSimilarRequest};
+use crate::batch::trace::{current_trace_id, TRACE_ID_HEADER};
+use crate::prompt::{select_by_series_similar, Error, NormalizeRequest, Normalized, Prompt, SeriesSelectRequest, SeriesSimilarRequest};
+use crate::provider::http::{shared_client, TARGET_BRIDGE};
 use reqwest::{blocking, Url};
 use serde::{Deserialize, Serialize};
-use std::env::var;
 
 const DEFAULT_BRIDGE_HOST: &str = "http://localhost:5000";
 const DEFAULT_BRIDGE_NORMALIZE_ENDPOINT: &str = "/normalize";
+const DEFAULT_BRIDGE_NORMALIZE_BATCH_ENDPOINT: &str = "/normalize-batch";
 const DEFAULT_BRIDGE_EMBEDDING_ENDPOINT: &str = "/embedding";
 const DEFAULT_BRIDGE_SERIES_SIMILAR_ENDPOINT: &str = "/series-similar";
+const DEFAULT_BRIDGE_SERIES_SELECT_ENDPOINT: &str = "/series-select";
 
 const DEFAULT_BRIDGE_TIMEOUT: usize = 30000;
 
@@ -14,6 +17,9 @@ const DEFAULT_BRIDGE_TIMEOUT: usize = 30000;
 ///
 /// # Description
 /// 특정 LLM과 연동 되어 있는 서버의 연결 정보를 저장한다.
+/// 설정 파일의 `prompt` 섹션([`crate::configs::Config`])에서 읽으며, 입력하지 않은 값은 기본값을 사용한다.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct BridgeServer {
     /// API 서버의 호스트
     ///
@@ -27,25 +33,62 @@ pub struct BridgeServer {
     /// 도서 제목 정규화 API의 엔드포인트
     pub normalize_endpoint: String,
 
+    /// 도서 제목 일괄 정규화 API의 엔드포인트
+    pub normalize_batch_endpoint: String,
+
     /// 텍스트 임베딩 API의 엔드포인트
     pub embedding_endpoint: String,
 
     /// 시리즈 소속 판단 API의 엔드 포인트
-    pub series_similar_endpoint: String
+    pub series_similar_endpoint: String,
+
+    /// 여러 후보 중 신간이 속하는 시리즈를 선택하는 API의 엔드 포인트
+    ///
+    /// # Note
+    /// 빈 문자열을 입력한 경우 선택 API 대신 후보마다 시리즈 소속 판단 API를 호출한다.
+    pub series_select_endpoint: String,
+
+    /// 제목 정규화 프롬프트의 버전 (서버의 응답에 버전이 없을 경우 사용한다.)
+    pub prompt_version: Option<String>,
 }
 
-impl BridgeServer {
-    pub fn new_with_env() -> Self {
+impl Default for BridgeServer {
+    fn default() -> Self {
         Self {
-            host: var("BRIDGE_HOST").unwrap_or_else(|_| DEFAULT_BRIDGE_HOST.to_owned()),
-            timeout: var("BRIDGE_TIMEOUT").map(|v| v.parse::<usize>().unwrap()).unwrap_or_else(|_| DEFAULT_BRIDGE_TIMEOUT),
-            normalize_endpoint: var("BRIDGE_NORMALIZE_ENDPOINT").unwrap_or_else(|_| DEFAULT_BRIDGE_NORMALIZE_ENDPOINT.to_owned()),
-            embedding_endpoint: var("BRIDGE_EMBEDDING_ENDPOINT").unwrap_or_else(|_| DEFAULT_BRIDGE_EMBEDDING_ENDPOINT.to_owned()),
-            series_similar_endpoint: var("BRIDGE_SERIES_SIMILAR_ENDPOINT").unwrap_or_else(|_| DEFAULT_BRIDGE_SERIES_SIMILAR_ENDPOINT.to_owned()),
+            host: DEFAULT_BRIDGE_HOST.to_owned(),
+            timeout: DEFAULT_BRIDGE_TIMEOUT,
+            normalize_endpoint: DEFAULT_BRIDGE_NORMALIZE_ENDPOINT.to_owned(),
+            normalize_batch_endpoint: DEFAULT_BRIDGE_NORMALIZE_BATCH_ENDPOINT.to_owned(),
+            embedding_endpoint: DEFAULT_BRIDGE_EMBEDDING_ENDPOINT.to_owned(),
+            series_similar_endpoint: DEFAULT_BRIDGE_SERIES_SIMILAR_ENDPOINT.to_owned(),
+            series_select_endpoint: DEFAULT_BRIDGE_SERIES_SELECT_ENDPOINT.to_owned(),
+            prompt_version: None,
         }
     }
 }
 
+/// 일괄 정규화 요청 폼
+#[derive(Debug, Serialize)]
+struct NormalizeBatchRequest<'a> {
+    pub requests: &'a [NormalizeRequest],
+}
+
+/// 일괄 정규화 결과 중 도서명 하나의 결과
+///
+/// # Description
+/// 정규화에 성공한 경우 `normalized`에, 실패한 경우 `error`에 값이 설정된다.
+#[derive(Debug, Deserialize)]
+struct NormalizeBatchItem {
+    pub normalized: Option<Normalized>,
+    pub error: Option<String>,
+}
+
+/// 일괄 정규화 응답 형태
+#[derive(Debug, Deserialize)]
+struct NormalizedBatch {
+    pub results: Vec<NormalizeBatchItem>,
+}
+
 /// 임베딩 요청 폼
 #[derive(Debug, Serialize, Deserialize)]
 struct EmbeddingRequest {
@@ -83,6 +126,13 @@ struct SeriesSimilar {
     pub reason: Option<String>,
 }
 
+/// 시리즈 선택 응답 형태 (어느 후보에도 속하지 않는 경우 `series_id`는 `null`)
+#[derive(Debug, Serialize, Deserialize)]
+struct SeriesSelected {
+    pub series_id: Option<u64>,
+    pub reason: Option<String>,
+}
+
 /// 브릿지 API 서버 클라이언트
 ///
 /// # Description
@@ -99,13 +149,14 @@ impl BridgeClient {
 
 impl Prompt for BridgeClient {
     fn normalize(&self, request: &NormalizeRequest) -> Result<Normalized, Error> {
-        let client = create_blocking_client(&self.server);
+        let client = create_blocking_client()?;
 
         let url = create_request_url(&self.server.host, &self.server.normalize_endpoint);
         let body = serde_json::to_string(request)
             .map_err(|err| Error::ConnectFailed(format!("Failed to serialize request: {}", err)))?;
 
-        let response = client.post(url)
+        let response = with_trace_header(client.post(url))
+            .timeout(std::time::Duration::from_millis(self.server.timeout as u64))
             .header("Content-Type", "application/json")
             .body(body)
             .send()
@@ -114,21 +165,42 @@ impl Prompt for BridgeClient {
         let response_text = response.text()
             .map_err(|err| Error::ResponseParsingFailed(format!("Failed to read response: {}", err)))?;
 
-        let response = serde_json::from_str::<Normalized>(&response_text)
+        let mut response = serde_json::from_str::<Normalized>(&response_text)
             .map_err(|err| Error::ResponseParsingFailed(format!("Failed to parse response: {}", err)))?;
+        if response.prompt_version.is_none() {
+            response.prompt_version = self.prompt_version();
+        }
 
         Ok(response)
     }
 
+    /// 도서명들을 일괄 정규화 API로 한번에 정규화 한다.
+    ///
+    /// # Note
+    /// 요청 자체가 실패하거나 응답의 결과 수가 요청 수와 다를 경우 모든 도서명의 결과를 에러로 반환한다.
+    fn normalize_batch(&self, request: &[NormalizeRequest]) -> Vec<Result<Normalized, Error>> {
+        if request.is_empty() {
+            return Vec::new();
+        }
+
+        match self.send_normalize_batch(request) {
+            Ok(results) => results,
+            Err(err) => request.iter()
+                .map(|_| Err(err.clone()))
+                .collect(),
+        }
+    }
+
     fn embedding(&self, request: &[String]) -> Result<Vec<Vec<f32>>, Error> {
-        let client = create_blocking_client(&self.server);
+        let client = create_blocking_client()?;
 
         let url = create_request_url(&self.server.host, &self.server.embedding_endpoint);
         let body = EmbeddingRequest::new(request);
         let body = serde_json::to_string(&body)
             .map_err(|err| Error::ConnectFailed(format!("Failed to serialize request: {}", err)))?;
 
-        let response = client.post(url)
+        let response = with_trace_header(client.post(url))
+            .timeout(std::time::Duration::from_millis(self.server.timeout as u64))
             .header("Content-Type", "application/json")
             .body(body)
             .send()
@@ -148,13 +220,14 @@ impl Prompt for BridgeClient {
     }
 
     fn series_similar(&self, request: &SeriesSimilarRequest) -> Result<bool, Error> {
-        let client = create_blocking_client(&self.server);
+        let client = create_blocking_client()?;
 
         let url = create_request_url(&self.server.host, &self.server.series_similar_endpoint);
         let body = serde_json::to_string(request)
             .map_err(|err| Error::ConnectFailed(format!("Failed to serialize request: {}", err)))?;
 
-        let response = client.post(url)
+        let response = with_trace_header(client.post(url))
+            .timeout(std::time::Duration::from_millis(self.server.timeout as u64))
             .header("Content-Type", "application/json")
             .body(body)
             .send()
@@ -168,12 +241,90 @@ impl Prompt for BridgeClient {
 
         Ok(response.result)
     }
+
+    fn series_select(&self, request: &SeriesSelectRequest) -> Result<Option<u64>, Error> {
+        if self.server.series_select_endpoint.is_empty() {
+            return select_by_series_similar(self, request);
+        }
+        let client = create_blocking_client()?;
+
+        let url = create_request_url(&self.server.host, &self.server.series_select_endpoint);
+        let body = serde_json::to_string(request)
+            .map_err(|err| Error::ConnectFailed(format!("Failed to serialize request: {}", err)))?;
+
+        let response = with_trace_header(client.post(url))
+            .timeout(std::time::Duration::from_millis(self.server.timeout as u64))
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .map_err(|err| Error::ConnectFailed(format!("Failed to send request: {}", err)))?;
+
+        let response_text = response.text()
+            .map_err(|err| Error::ResponseParsingFailed(format!("Failed to read response: {}", err)))?;
+
+        let response = serde_json::from_str::<SeriesSelected>(&response_text)
+            .map_err(|err| Error::ResponseParsingFailed(format!("Failed to parse response: {}", err)))?;
+
+        Ok(response.series_id)
+    }
+
+    fn prompt_version(&self) -> Option<String> {
+        self.server.prompt_version.clone()
+    }
 }
 
-fn create_blocking_client(server: &BridgeServer) -> blocking::Client {
-    blocking::Client::builder()
-        .timeout(std::time::Duration::from_millis(server.timeout as u64))
-        .build().unwrap()
+impl BridgeClient {
+
+    fn send_normalize_batch(&self, request: &[NormalizeRequest]) -> Result<Vec<Result<Normalized, Error>>, Error> {
+        let client = create_blocking_client()?;
+
+        let url = create_request_url(&self.server.host, &self.server.normalize_batch_endpoint);
+        let body = serde_json::to_string(&NormalizeBatchRequest { requests: request })
+            .map_err(|err| Error::ConnectFailed(format!("Failed to serialize request: {}", err)))?;
+
+        let response = with_trace_header(client.post(url))
+            .timeout(std::time::Duration::from_millis(self.server.timeout as u64))
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .map_err(|err| Error::ConnectFailed(format!("Failed to send request: {}", err)))?;
+
+        let response_text = response.text()
+            .map_err(|err| Error::ResponseParsingFailed(format!("Failed to read response: {}", err)))?;
+
+        let response = serde_json::from_str::<NormalizedBatch>(&response_text)
+            .map_err(|err| Error::ResponseParsingFailed(format!("Failed to parse response: {}", err)))?;
+        if response.results.len() != request.len() {
+            return Err(Error::ResponseParsingFailed(format!("Expected {} results but got {}", request.len(), response.results.len())));
+        }
+
+        let results = response.results.into_iter()
+            .map(|item| match (item.normalized, item.error) {
+                (Some(mut normalized), _) => {
+                    if normalized.prompt_version.is_none() {
+                        normalized.prompt_version = self.prompt_version();
+                    }
+                    Ok(normalized)
+                }
+                (None, error) => Err(Error::ResponseParsingFailed(error.unwrap_or_else(|| "Empty result".to_owned()))),
+            })
+            .collect();
+        Ok(results)
+    }
+}
+
+/// 브릿지 API 서버 요청에 사용할 공유 클라이언트를 반환한다. 타임아웃은 요청마다 서버 설정의 값을 사용한다.
+fn create_blocking_client() -> Result<blocking::Client, Error> {
+    shared_client(TARGET_BRIDGE)
+        .map_err(|err| Error::ConnectFailed(format!("Failed to create client: {}", err)))
+}
+
+/// 처리 중인 아이템이 있는 경우 추적 아이디 헤더([`TRACE_ID_HEADER`])를 요청에 추가한다.
+fn with_trace_header(request: blocking::RequestBuilder) -> blocking::RequestBuilder {
+    match current_trace_id() {
+        Some(trace_id) => request.header(TRACE_ID_HEADER, trace_id),
+        None => request,
+    }
 }
 
 fn create_request_url(host: &str, endpoint: &str) -> Url {
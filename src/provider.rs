@@ -1,2 +1,5 @@
 pub mod api;
-pub mod html;
\ No newline at end of file
+pub mod archive;
+pub mod credential;
+pub mod html;
+pub mod http;
\ No newline at end of file
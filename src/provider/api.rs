@@ -1,5 +1,5 @@
 use crate::item::{BookBuilder, Site};
-use chrono::NaiveDate;
+use crate::provider::http::ResponseBodyError;
 
 pub mod nlgo;
 pub mod aladin;
@@ -11,131 +11,130 @@ pub enum ClientError {
     InvalidBaseUrl,
     RequestFailed(String),
     ResponseTextExtractionFailed(String),
+    ResponseTooLarge(String), // 응답 본문이 허용된 최대 크기를 초과함
     ResponseParseFailed(String),
+    NotFound(String), // 조회한 도서가 없음 (장애가 아닌 정상적인 "데이터 없음" 응답)
+    QuotaExceeded(String), // API 키의 요청 한도를 초과함
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum RequestError {
-    InvalidParameter(String),         // 유효하지 않은 매개변수
+impl From<ResponseBodyError> for ClientError {
+    fn from(err: ResponseBodyError) -> Self {
+        match err {
+            ResponseBodyError::TooLarge { .. } => ClientError::ResponseTooLarge(err.to_string()),
+            ResponseBodyError::ReadFailed(message) => ClientError::ResponseTextExtractionFailed(message),
+        }
+    }
 }
 
 #[derive(Debug)]
-pub struct Request {
-    page: i32,
-    size: i32,
-    query: String,
-    start_date:Option<NaiveDate>,
-    end_date:Option<NaiveDate>,
+pub struct Response {
+    pub total_count: i32,
+    pub page_no: i32,
+    pub site: Site,
+    pub books: Vec<BookBuilder>,
 }
 
-impl Request {
-    pub fn builder() -> RequestBuilder {
-        RequestBuilder::new()
-    }
-
-    pub fn page(&self) -> i32 {
-        self.page
-    }
-
-    pub fn size(&self) -> i32 {
-        self.size
+impl Response {
+    pub fn empty(site: Site) -> Self {
+        Response {
+            total_count: 0,
+            page_no: 0,
+            site,
+            books: Vec::new(),
+        }
     }
 
-    pub fn query(&self) -> &str {
-        &self.query
+    /// 한 페이지의 크기가 `size`일 때 전체 페이지 수를 반환한다.
+    pub fn total_pages(&self, size: u32) -> u32 {
+        if size == 0 {
+            return 0;
+        }
+        (self.total_count.max(0) as u32).div_ceil(size)
     }
 
-    pub fn start_date(&self) -> Option<NaiveDate> {
-        self.start_date
+    /// 응답이 마지막 페이지인지 여부
+    ///
+    /// # Description
+    /// 조회된 도서가 없거나 한 페이지의 크기(`size`)보다 적은 경우, 혹은 응답의 페이지 번호가 전체 페이지 수 이상인 경우 마지막 페이지로 본다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::item::{BookBuilder, Site};
+    /// use book_batch_rust::provider::api::Response;
+    ///
+    /// let books = (0..10).map(|_| BookBuilder::new()).collect::<Vec<_>>();
+    /// let response = Response { total_count: 25, page_no: 2, site: Site::Aladin, books };
+    ///
+    /// assert_eq!(response.total_pages(10), 3);
+    /// assert!(!response.is_last_page(10));
+    /// assert!(Response::empty(Site::Aladin).is_last_page(10));
+    /// ```
+    pub fn is_last_page(&self, size: u32) -> bool {
+        self.books.is_empty()
+            || self.books.len() < size as usize
+            || self.page_no.max(0) as u32 >= self.total_pages(size)
     }
 
-    pub fn end_date(&self) -> Option<NaiveDate> {
-        self.end_date
+    /// 응답의 다음 페이지를 조회하는 요청을 반환한다. 마지막 페이지인 경우 `None`을 반환한다.
+    pub fn next_page_request<R: PagedRequest>(&self, request: &R) -> Option<R> {
+        if self.is_last_page(request.size()) {
+            None
+        } else {
+            Some(request.with_page(request.page() + 1))
+        }
     }
 }
 
-#[derive(Default)]
-pub struct RequestBuilder {
-    page: Option<i32>,
-    size: Option<i32>,
-    query: Option<String>,
-    start_date: Option<NaiveDate>,
-    end_date: Option<NaiveDate>,
-}
-
-impl RequestBuilder {
-    pub fn new() -> Self {
-        RequestBuilder::default()
-    }
+/// 페이지 단위로 조회하는 요청
+///
+/// # Description
+/// 페이지를 넘겨가며 조회하는 잡은 [`Response::next_page_request`]로 다음 페이지 요청을 생성한다.
+pub trait PagedRequest: Sized {
 
-    pub fn page(mut self, page: i32) -> Self {
-        self.page = Some(page);
-        self
-    }
+    /// 조회할 페이지 (1부터 시작)
+    fn page(&self) -> u32;
 
-    pub fn size(mut self, size: i32) -> Self {
-        self.size = Some(size);
-        self
-    }
+    /// 한 페이지의 조회 결과 수
+    fn size(&self) -> u32;
 
-    pub fn query<S: Into<String>>(mut self, query: S) -> Self {
-        self.query = Some(query.into());
-        self
-    }
+    /// 페이지만 `page`로 바꾼 요청을 반환한다.
+    fn with_page(&self, page: u32) -> Self;
+}
 
-    pub fn start_date(mut self, start_date: NaiveDate) -> Self {
-        self.start_date = Some(start_date);
-        self
-    }
+/// 도서 검색 클라이언트
+///
+/// # Description
+/// 사이트마다 검색에 필요한 값(페이지, 기간, ISBN 등)이 다르므로 요청 타입은 클라이언트별로 정의한다.
+/// 잘못된 조합(ex: 기간이 없는 국립중앙도서관 검색)은 요청을 생성하는 시점에 컴파일 에러가 된다.
+pub trait Client {
 
-    pub fn end_date(mut self, end_date: NaiveDate) -> Self {
-        self.end_date = Some(end_date);
-        self
-    }
+    /// 검색 요청 타입
+    type Request;
 
-    pub fn build(self) -> Result<Request, RequestError> {
-        let query = self.query.ok_or_else(|| 
-            RequestError::InvalidParameter("query is required".to_string()))?;
+    fn get_books(&self, request: &Self::Request) -> Result<Response, ClientError>;
+}
 
-        let page = self.page.unwrap_or(0);
-        if page < 0 {
-            return Err(RequestError::InvalidParameter("page must be greater than or equal to 0".to_string()));
-        }
+/// ISBN 단건 조회 클라이언트
+///
+/// # Description
+/// 출판사/기간 검색이 아닌 ISBN 하나로 도서를 조회할 수 있는 API 클라이언트
+pub trait LookupClient {
 
-        let size = self.size.unwrap_or(0);
-        if size < 0 {
-            return Err(RequestError::InvalidParameter("size must be greater than or equal to 0".to_string()));
-        }
+    /// 조회 대상 사이트
+    fn site(&self) -> Site;
 
-        Ok(Request {
-            page,
-            size,
-            query,
-            start_date: self.start_date,
-            end_date: self.end_date,
-        })
-    }
+    /// ISBN으로 도서를 조회한다. 도서를 찾을 수 없는 경우 [`ClientError::NotFound`]를 반환한다.
+    fn lookup(&self, isbn: &str) -> Result<Response, ClientError>;
 }
 
-#[derive(Debug)]
-pub struct Response {
-    pub total_count: i32,
-    pub page_no: i32,
-    pub site: Site,
-    pub books: Vec<BookBuilder>,
-}
+/// 상품 리스트 조회 클라이언트
+///
+/// # Description
+/// 키워드 검색이 아닌 사이트에서 선정한 상품 리스트(ex: 알라딘 주목할 만한 신간 리스트)를 조회할 수 있는 API 클라이언트
+pub trait ItemListClient {
 
-impl Response {
-    pub fn empty(site: Site) -> Self {
-        Response {
-            total_count: 0,
-            page_no: 0,
-            site,
-            books: Vec::new(),
-        }
-    }
-}
+    /// 상품 리스트 조회 요청 타입
+    type Request;
 
-pub trait Client {
-    fn get_books(&self, request: &Request) -> Result<Response, ClientError>;
+    fn item_list(&self, request: &Self::Request) -> Result<Response, ClientError>;
 }
\ No newline at end of file
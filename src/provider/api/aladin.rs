@@ -1,16 +1,27 @@
 use crate::item::{BookBuilder, Raw, RawDataKind, RawKeyDict, Site};
 use crate::provider;
-use crate::provider::api::{ClientError, Request};
+use crate::provider::api::{ClientError, PagedRequest};
+use crate::provider::archive::{attach_payload, ArchivedPayload, ResponseArchive};
+use crate::provider::credential::{keys_from_env, KeyRing};
+use crate::provider::http::{read_body, send_with_retry, shared_client, TARGET_ALADIN};
 use chrono::NaiveDate;
-use reqwest::{blocking, Url};
+use reqwest::Url;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
-use std::env;
 use std::env::VarError;
 
 /// 알라딘 API 엔드포인트 URL
 const ALADIN_API_ENDPOINT: &'static str = "https://www.aladin.co.kr/ttb/api/ItemSearch.aspx";
-/// API 요청의 기본 타임아웃 시간(초)
-const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+/// 알라딘 상품 조회 API 엔드포인트 URL
+const ALADIN_LOOKUP_API_ENDPOINT: &'static str = "https://www.aladin.co.kr/ttb/api/ItemLookUp.aspx";
+/// 알라딘 상품 리스트 API 엔드포인트 URL
+const ALADIN_ITEM_LIST_API_ENDPOINT: &str = "https://www.aladin.co.kr/ttb/api/ItemList.aspx";
+
+/// 일일 요청 한도 초과 에러 코드
+const ERROR_CODE_QUOTA_EXCEEDED: i32 = 10;
+
+/// 상품 리스트 종류: 주목할 만한 신간 리스트
+pub const QUERY_TYPE_ITEM_NEW_SPECIAL: &str = "ItemNewSpecial";
 
 /// 알라딘 API 응답을 표현하는 구조체
 #[derive(Debug, Deserialize)]
@@ -50,6 +61,37 @@ pub struct AladinResponse {
 
 }
 
+/// 알라딘 상품 조회(ItemLookUp) API 응답을 표현하는 구조체
+///
+/// # Note
+/// 상품 조회 API는 검색 관련 속성(query, searchCategoryId 등)을 반환하지 않으므로 도서 아이템 목록만 사용한다.
+#[derive(Debug, Deserialize)]
+pub struct AladinLookUpResponse {
+    /// 총 결과 수
+    #[serde(rename = "totalResults", default)]
+    pub total_results: i32,
+    /// 도서 아이템 목록
+    #[serde(rename = "item", default)]
+    pub items: Vec<BookItem>,
+}
+
+/// 알라딘 상품 리스트(ItemList) API 응답을 표현하는 구조체
+///
+/// # Note
+/// 상품 리스트 API는 검색어(query)가 없으므로 검색 관련 속성은 사용하지 않는다.
+#[derive(Debug, Deserialize)]
+pub struct AladinItemListResponse {
+    /// 총 결과 수
+    #[serde(rename = "totalResults", default)]
+    pub total_results: i32,
+    /// 시작 인덱스
+    #[serde(rename = "startIndex", default)]
+    pub start_index: i32,
+    /// 도서 아이템 목록
+    #[serde(rename = "item", default)]
+    pub items: Vec<BookItem>,
+}
+
 /// 개별 도서 정보를 표현하는 구조체
 #[derive(Debug, Deserialize)]
 pub struct BookItem {
@@ -92,6 +134,9 @@ pub struct BookItem {
     /// 재고상태
     #[serde(rename = "stockStatus")]
     pub stock_status: String,
+    /// 표지 이미지 URL
+    #[serde(rename = "cover", default)]
+    pub cover: String,
 }
 
 impl BookItem {
@@ -111,6 +156,7 @@ impl BookItem {
         map.insert("publisher".to_string(), self.publisher.as_str().into());
         map.insert("categoryId".to_string(), self.category_id.into());
         map.insert("stockStatus".to_string(), self.stock_status.as_str().into());
+        map.insert("cover".to_string(), self.cover.as_str().into());
 
         map
     }
@@ -134,46 +180,226 @@ pub fn load_raw_key_dict() -> RawKeyDict {
         (RawDataKind::SalePrice, "salePrice".to_owned()),
         (RawDataKind::Description, "description".to_owned()),
         (RawDataKind::Author, "author".to_owned()),
+        (RawDataKind::SaleStatus, "stockStatus".to_owned()),
+        (RawDataKind::Thumbnail, "cover".to_owned()),
     ])
 }
 
+/// 알라딘 출판사 검색 요청
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchRequest {
+    /// 검색할 출판사 키워드
+    query: String,
+    /// 검색 결과 시작 페이지 (1부터 시작)
+    page: u32,
+    /// 한 페이지의 검색 결과 수
+    size: u32,
+}
+
+impl SearchRequest {
+    pub fn builder<S: Into<String>>(query: S) -> SearchRequestBuilder {
+        SearchRequestBuilder { query: query.into(), page: 1, size: 10 }
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+impl PagedRequest for SearchRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn size(&self) -> u32 {
+        self.size
+    }
+
+    fn with_page(&self, page: u32) -> Self {
+        Self { page, ..self.clone() }
+    }
+}
+
+pub struct SearchRequestBuilder {
+    query: String,
+    page: u32,
+    size: u32,
+}
+
+impl SearchRequestBuilder {
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn build(self) -> SearchRequest {
+        SearchRequest { query: self.query, page: self.page, size: self.size }
+    }
+}
+
+/// 알라딘 상품 리스트 조회 요청
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemListRequest {
+    /// 조회할 리스트의 종류 (ex: [`QUERY_TYPE_ITEM_NEW_SPECIAL`])
+    query_type: String,
+    /// 조회 결과 시작 페이지 (1부터 시작)
+    page: u32,
+    /// 한 페이지의 조회 결과 수
+    size: u32,
+}
+
+impl ItemListRequest {
+    pub fn builder<S: Into<String>>(query_type: S) -> ItemListRequestBuilder {
+        ItemListRequestBuilder { query_type: query_type.into(), page: 1, size: 10 }
+    }
+
+    pub fn query_type(&self) -> &str {
+        &self.query_type
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+impl PagedRequest for ItemListRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn size(&self) -> u32 {
+        self.size
+    }
+
+    fn with_page(&self, page: u32) -> Self {
+        Self { page, ..self.clone() }
+    }
+}
+
+pub struct ItemListRequestBuilder {
+    query_type: String,
+    page: u32,
+    size: u32,
+}
+
+impl ItemListRequestBuilder {
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn build(self) -> ItemListRequest {
+        ItemListRequest { query_type: self.query_type, page: self.page, size: self.size }
+    }
+}
+
+/// 알라딘 API 에러 응답
+#[derive(Debug, Deserialize)]
+struct AladinErrorResponse {
+    #[serde(rename = "errorCode")]
+    error_code: i32,
+    #[serde(rename = "errorMessage", default)]
+    error_message: String,
+}
+
 /// 알라딘 API 클라이언트
+///
+/// # Description
+/// 환경 변수 `ALADIN_KEY`에 TTB 키를 쉼표로 구분하여 여러개 설정할 수 있으며, 요청마다 키를 돌아가며 사용하고
+/// 일일 요청 한도를 초과한 키는 건너뛴다. ([`KeyRing`])
 pub struct Client {
-    /// 알라딘 API TTB 키
-    ttb_key: String,
+    /// 알라딘 API TTB 키 목록
+    ttb_keys: KeyRing<String>,
+    /// 원본 응답 보관소 (설정 되지 않은 경우 응답을 보관하지 않는다.)
+    archive: Option<ResponseArchive>,
 }
 
 impl Client {
     pub fn new_with_env() -> Result<Self, VarError> {
-        let key = env::var("ALADIN_KEY")?;
-        Ok(Self { ttb_key: key })
+        let keys = KeyRing::new(keys_from_env("ALADIN_KEY")?).ok_or(VarError::NotPresent)?;
+        Ok(Self { ttb_keys: keys, archive: ResponseArchive::from_env(TARGET_ALADIN) })
     }
 }
 
 impl provider::api::Client for Client {
-    fn get_books(&self, request: &Request) -> Result<provider::api::Response, ClientError> {
-        let client = blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(DEFAULT_TIMEOUT_SECONDS))
-            .build()
-            .map_err(|e| ClientError::RequestFailed(format!("클라이언트 생성 실패: {}", e)))?;
-
-        let url = build_search_url(&self.ttb_key, request)?;
-        let response = client.get(url)
-            .send()
-            .map_err(|err| ClientError::RequestFailed(err.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(ClientError::RequestFailed(format!("HTTP 오류: {}", response.status())));
+    type Request = SearchRequest;
+
+    fn get_books(&self, request: &SearchRequest) -> Result<provider::api::Response, ClientError> {
+        let (parsed_response, payload) = self.ttb_keys.call(|key| {
+            send_request::<AladinResponse>(build_search_url(key, request)?, self.archive.as_ref())
+        })?;
+
+        let books = parsed_response.items.iter()
+            .map(|item| attach_payload(item.to_book_builder(), Site::Aladin, payload.as_ref()))
+            .collect();
+
+        Ok(provider::api::Response {
+            total_count: parsed_response.total_results,
+            page_no: parsed_response.start_index,
+            site: Site::Aladin,
+            books,
+        })
+    }
+}
+
+impl provider::api::LookupClient for Client {
+    fn site(&self) -> Site {
+        Site::Aladin
+    }
+
+    fn lookup(&self, isbn: &str) -> Result<provider::api::Response, ClientError> {
+        let (parsed_response, payload) = self.ttb_keys.call(|key| {
+            send_request::<AladinLookUpResponse>(build_lookup_url(key, isbn)?, self.archive.as_ref())
+        })?;
+        if parsed_response.items.is_empty() {
+            return Err(ClientError::NotFound(format!("ISBN: {}", isbn)));
         }
 
-        let text = response.text()
-            .map_err(|err| ClientError::ResponseTextExtractionFailed(err.to_string()))?;
+        let books = parsed_response.items.iter()
+            .map(|item| attach_payload(item.to_book_builder(), Site::Aladin, payload.as_ref()))
+            .collect();
+
+        Ok(provider::api::Response {
+            total_count: parsed_response.total_results,
+            page_no: 1,
+            site: Site::Aladin,
+            books,
+        })
+    }
+}
+
+impl provider::api::ItemListClient for Client {
+    type Request = ItemListRequest;
 
-        let parsed_response = serde_json::from_str::<AladinResponse>(&text)
-            .map_err(|err| ClientError::ResponseParseFailed(err.to_string()))?;
+    fn item_list(&self, request: &ItemListRequest) -> Result<provider::api::Response, ClientError> {
+        let (parsed_response, payload) = self.ttb_keys.call(|key| {
+            send_request::<AladinItemListResponse>(build_item_list_url(key, request)?, self.archive.as_ref())
+        })?;
 
         let books = parsed_response.items.iter()
-            .map(|item| item.to_book_builder())
+            .map(|item| attach_payload(item.to_book_builder(), Site::Aladin, payload.as_ref()))
             .collect();
 
         Ok(provider::api::Response {
@@ -185,16 +411,73 @@ impl provider::api::Client for Client {
     }
 }
 
-fn build_search_url(ttb_key: &str, request: &Request) -> Result<Url, ClientError> {
+fn send_request<T: DeserializeOwned>(url: Url, archive: Option<&ResponseArchive>) -> Result<(T, Option<ArchivedPayload>), ClientError> {
+    let client = shared_client(TARGET_ALADIN)
+        .map_err(|e| ClientError::RequestFailed(format!("클라이언트 생성 실패: {}", e)))?;
+
+    let response = send_with_retry(TARGET_ALADIN, client.get(url))
+        .map_err(|err| ClientError::RequestFailed(err.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(ClientError::RequestFailed(format!("HTTP 오류: {}", response.status())));
+    }
+
+    let text = read_body(TARGET_ALADIN, response)?;
+    // 알라딘은 요청이 실패한 경우에도 HTTP 200으로 에러 코드를 응답한다.
+    if let Ok(error) = serde_json::from_str::<AladinErrorResponse>(&text) {
+        let message = format!("{}: {}", error.error_code, error.error_message);
+        return Err(match error.error_code {
+            ERROR_CODE_QUOTA_EXCEEDED => ClientError::QuotaExceeded(message),
+            _ => ClientError::RequestFailed(message),
+        });
+    }
+    let parsed = serde_json::from_str::<T>(&text)
+        .map_err(|err| ClientError::ResponseParseFailed(err.to_string()))?;
+
+    let payload = archive.and_then(|archive| archive.store(text.as_bytes()));
+    Ok((parsed, payload))
+}
+
+fn build_lookup_url(ttb_key: &str, isbn: &str) -> Result<Url, ClientError> {
+    Url::parse(ALADIN_LOOKUP_API_ENDPOINT)
+        .map_err(|_| ClientError::InvalidBaseUrl)
+        .map(|mut url| {
+            url.query_pairs_mut()
+                .append_pair("ttbkey", ttb_key)
+                .append_pair("ItemId", isbn)
+                .append_pair("ItemIdType", "ISBN13")
+                .append_pair("output", "js") // JS로 고정
+                .append_pair("Version", "20131101");
+            url
+        })
+}
+
+fn build_item_list_url(ttb_key: &str, request: &ItemListRequest) -> Result<Url, ClientError> {
+    Url::parse(ALADIN_ITEM_LIST_API_ENDPOINT)
+        .map_err(|_| ClientError::InvalidBaseUrl)
+        .map(|mut url| {
+            url.query_pairs_mut()
+                .append_pair("ttbkey", ttb_key)
+                .append_pair("QueryType", request.query_type())
+                .append_pair("start", &request.page().to_string())
+                .append_pair("MaxResults", &request.size().to_string())
+                .append_pair("SearchTarget", "Book")  // Book으로 고정
+                .append_pair("output", "js") // JS로 고정
+                .append_pair("Version", "20131101");
+            url
+        })
+}
+
+fn build_search_url(ttb_key: &str, request: &SearchRequest) -> Result<Url, ClientError> {
     Url::parse(ALADIN_API_ENDPOINT)
         .map_err(|_| ClientError::InvalidBaseUrl)
         .map(|mut url| {
             url.query_pairs_mut()
                 .append_pair("ttbkey", ttb_key)
-                .append_pair("Query", &request.query.clone())
+                .append_pair("Query", request.query())
                 .append_pair("QueryType", "Publisher")  // Publisher로 고정
-                .append_pair("start", &request.page.to_string())
-                .append_pair("MaxResults", &request.size.to_string())
+                .append_pair("start", &request.page().to_string())
+                .append_pair("MaxResults", &request.size().to_string())
                 .append_pair("SearchTarget", "Book")  // Book으로 고정
                 .append_pair("output", "js") // JS로 고정
                 .append_pair("Version", "20131101")
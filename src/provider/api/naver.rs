@@ -1,9 +1,14 @@
 use crate::item::{Book, BookBuilder, Raw, RawDataKind, RawKeyDict, Site};
 use crate::provider;
-use crate::provider::api::{ClientError, Request, Response};
+use crate::provider::api::{ClientError, Response};
+use crate::provider::archive::{attach_payload, ResponseArchive};
+use crate::provider::credential::{keys_from_env, KeyRing};
+use crate::provider::http::{read_body, send_with_retry, shared_client, TARGET_NAVER};
 use serde::Deserialize;
+use reqwest::StatusCode;
 use serde_with::serde_as;
 use std::env::VarError;
+use tracing::warn;
 
 const BOOK_SEARCH_ENDPOINT: &'static str = "https://openapi.naver.com/v1/search/book_adv.xml";
 
@@ -106,58 +111,121 @@ pub fn load_raw_key_dict() -> RawKeyDict {
         (RawDataKind::SalePrice, "discount".to_owned()),
         (RawDataKind::Description, "description".to_owned()),
         (RawDataKind::Author, "author".to_owned()),
+        (RawDataKind::Thumbnail, "image".to_owned()),
     ])
 }
 
-#[derive(Clone)]
-pub struct Client {
+/// 네이버 도서 상세 검색 요청
+///
+/// # Description
+/// 네이버 상세 검색은 ISBN(`d_isbn`)으로만 사용 하므로 페이지, 기간 등은 입력 받지 않는다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsbnRequest {
+    isbn: String,
+}
+
+impl IsbnRequest {
+    pub fn new<S: Into<String>>(isbn: S) -> Self {
+        Self { isbn: isbn.into() }
+    }
+
+    pub fn isbn(&self) -> &str {
+        &self.isbn
+    }
+}
+
+/// 네이버 검색 API 인증 정보
+#[derive(Debug, Clone)]
+struct Credential {
     client_id: String,
     client_secret: String,
 }
 
+/// 네이버 검색 API 클라이언트
+///
+/// # Description
+/// 환경 변수 `NAVER_KEY`, `NAVER_SECRET`에 인증 정보를 같은 순서로 쉼표로 구분하여 여러개 설정할 수 있으며,
+/// 요청마다 인증 정보를 돌아가며 사용하고 일일 요청 한도를 초과한 인증 정보는 건너뛴다. ([`KeyRing`])
+#[derive(Clone)]
+pub struct Client {
+    credentials: KeyRing<Credential>,
+    /// 원본 응답 보관소 (설정 되지 않은 경우 응답을 보관하지 않는다.)
+    archive: Option<ResponseArchive>,
+}
+
 impl Client {
     pub fn new_with_env() -> Result<Client, VarError> {
-        let client_id = std::env::var("NAVER_KEY")?;
-        let client_secret = std::env::var("NAVER_SECRET")?;
+        let client_ids = keys_from_env("NAVER_KEY")?;
+        let client_secrets = keys_from_env("NAVER_SECRET")?;
+        if client_ids.len() != client_secrets.len() {
+            warn!("NAVER_KEY({}) and NAVER_SECRET({}) have different number of keys, unpaired keys are ignored", client_ids.len(), client_secrets.len());
+        }
 
-        Ok(Self { client_id, client_secret })
+        let credentials = client_ids.into_iter().zip(client_secrets)
+            .map(|(client_id, client_secret)| Credential { client_id, client_secret })
+            .collect();
+        let credentials = KeyRing::new(credentials).ok_or(VarError::NotPresent)?;
+        Ok(Self { credentials, archive: ResponseArchive::from_env(TARGET_NAVER) })
     }
 }
 
 impl provider::api::Client for Client {
+    type Request = IsbnRequest;
 
-    fn get_books(&self, request: &Request) -> Result<Response, ClientError> {
+    fn get_books(&self, request: &IsbnRequest) -> Result<Response, ClientError> {
         let mut url = reqwest::Url::parse(BOOK_SEARCH_ENDPOINT).unwrap();
         url.query_pairs_mut()
-            .append_pair("d_isbn", request.query.as_str());
+            .append_pair("d_isbn", request.isbn());
+
+        let client = shared_client(TARGET_NAVER)
+            .map_err(|e| ClientError::RequestFailed(format!("클라이언트 생성 실패: {}", e)))?;
+        let response_text = self.credentials.call(|credential| {
+            let request_builder = client.get(url.clone())
+                .header("X-Naver-Client-Id", credential.client_id.as_str())
+                .header("X-Naver-Client-Secret", credential.client_secret.as_str());
+
+            let response = send_with_retry(TARGET_NAVER, request_builder)
+                .map_err(|e| ClientError::RequestFailed(format!("ISBN: {}, ERROR: {:?}", request.isbn(), e)))?;
+            // 일일 요청 한도를 초과한 경우 429 상태 코드를 응답한다.
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                return Err(ClientError::QuotaExceeded(format!("ISBN: {}, HTTP {}", request.isbn(), response.status())));
+            }
+            Ok(read_body(TARGET_NAVER, response)?)
+        })?;
+        let parsed_response: RssResponse = serde_xml_rs::from_str(&response_text)
+            .map_err(|e| ClientError::ResponseParseFailed(format!("ISBN: {}, ERROR: {:?}", request.isbn(), e)))?;
 
-        let client = reqwest::blocking::Client::new()
-            .get(url)
-            .header("X-Naver-Client-Id", self.client_id.as_str())
-            .header("X-Naver-Client-Secret", self.client_secret.as_str());
+        // 검색 결과가 없을 경우 채널이나 아이템이 비어 있는 응답을 반환하므로 다른 검색 클라이언트와 같이 빈 응답으로 반환한다.
+        let Some(channel) = parsed_response.channel else {
+            return Ok(Response::empty(Site::Naver));
+        };
+        let items = channel.item.unwrap_or_else(|| vec![]);
+
+        let payload = self.archive.as_ref().and_then(|archive| archive.store(response_text.as_bytes()));
+        let books = items.into_iter()
+            .map(|item| attach_payload(item.to_book_builder(), Site::Naver, payload.as_ref()))
+            .collect::<Vec<BookBuilder>>();
+
+        Ok(Response {
+            total_count: channel.total,
+            page_no: channel.start,
+            site: Site::Naver,
+            books,
+        })
+    }
+}
 
-        let response = client.send()
-            .map_err(|e| ClientError::RequestFailed(format!("ISBN: {}, ERROR: {:?}", request.query, e)))?;
-        let response_text = response.text()
-            .map_err(|e| ClientError::ResponseTextExtractionFailed(format!("ISBN: {}, ERROR: {:?}", request.query, e)))?;
-        let parsed_response: RssResponse = serde_xml_rs::from_str(&response_text)
-            .map_err(|e| ClientError::ResponseParseFailed(format!("ISBN: {}, ERROR: {:?}", request.query, e)))?;
-
-        let response = parsed_response.channel
-            .map(|channel| {
-                let books = channel.item.unwrap_or_else(|| vec![]).into_iter()
-                    .map(|item| item.to_book_builder())
-                    .collect::<Vec<BookBuilder>>();
-
-                Response {
-                    total_count: channel.total,
-                    page_no: channel.start,
-                    site: Site::Naver,
-                    books,
-                }
-            })
-            .unwrap_or_else(|| Response::empty(Site::Naver));
+impl provider::api::LookupClient for Client {
+    fn site(&self) -> Site {
+        Site::Naver
+    }
 
+    fn lookup(&self, isbn: &str) -> Result<Response, ClientError> {
+        // 네이버 상세 검색 API는 ISBN(d_isbn)으로 검색 하므로 검색 API를 그대로 사용한다.
+        let response = provider::api::Client::get_books(self, &IsbnRequest::new(isbn))?;
+        if response.books.is_empty() {
+            return Err(ClientError::NotFound(format!("ISBN: {}", isbn)));
+        }
         Ok(response)
     }
 }
\ No newline at end of file
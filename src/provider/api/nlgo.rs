@@ -1,9 +1,13 @@
 use crate::item::{Book, BookBuilder, Raw, RawDataKind, RawKeyDict, Site};
 use crate::provider;
-use crate::provider::api::{ClientError, Request};
+use crate::provider::api::{ClientError, PagedRequest};
+use crate::provider::archive::{attach_payload, ResponseArchive};
+use crate::provider::credential::{keys_from_env, KeyRing};
+use crate::provider::http::{read_body_to_file, send_with_retry, shared_client, TARGET_NLGO};
+use chrono::NaiveDate;
+use reqwest::StatusCode;
 use serde::Deserialize;
 use serde_with::serde_as;
-use std::env;
 use std::env::VarError;
 
 /// 국립중앙도서관 ISBN 도서정보 검색 API 엔드포인트 URL
@@ -127,55 +131,180 @@ pub struct Response {
     pub docs: Vec<Doc>,
 }
 
+/// 국립중앙도서관 출판사 검색 요청
+///
+/// # Description
+/// 국립중앙도서관 검색 API는 출판 예정일 기간이 반드시 필요 하므로 기간은 요청을 생성할 때 입력 받는다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchRequest {
+    /// 검색할 출판사 키워드
+    publisher: String,
+    /// 검색 기간 시작일
+    start_date: NaiveDate,
+    /// 검색 기간 종료일
+    end_date: NaiveDate,
+    /// 검색 결과 페이지 (1부터 시작)
+    page: u32,
+    /// 한 페이지의 검색 결과 수
+    size: u32,
+}
+
+impl SearchRequest {
+    pub fn builder<S: Into<String>>(publisher: S, start_date: NaiveDate, end_date: NaiveDate) -> SearchRequestBuilder {
+        SearchRequestBuilder { publisher: publisher.into(), start_date, end_date, page: 1, size: 10 }
+    }
+
+    pub fn publisher(&self) -> &str {
+        &self.publisher
+    }
+
+    pub fn start_date(&self) -> NaiveDate {
+        self.start_date
+    }
+
+    pub fn end_date(&self) -> NaiveDate {
+        self.end_date
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+impl PagedRequest for SearchRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn size(&self) -> u32 {
+        self.size
+    }
+
+    fn with_page(&self, page: u32) -> Self {
+        Self { page, ..self.clone() }
+    }
+}
+
+pub struct SearchRequestBuilder {
+    publisher: String,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    page: u32,
+    size: u32,
+}
+
+impl SearchRequestBuilder {
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn build(self) -> SearchRequest {
+        SearchRequest {
+            publisher: self.publisher,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            page: self.page,
+            size: self.size,
+        }
+    }
+}
+
 /// 국립중앙도서관 API 클라이언트
+///
+/// # Description
+/// 환경 변수 `NLGO_KEY`에 인증 키를 쉼표로 구분하여 여러개 설정할 수 있으며, 요청마다 키를 돌아가며 사용하고
+/// 요청 한도를 초과한 키는 건너뛴다. ([`KeyRing`])
 #[derive(Clone)]
 pub struct Client {
-    /// API 인증 키
-    key: String
+    /// API 인증 키 목록
+    keys: KeyRing<String>,
+    /// 원본 응답 보관소 (설정 되지 않은 경우 응답을 보관하지 않는다.)
+    archive: Option<ResponseArchive>,
 }
 
 impl Client {
 
     pub fn new_with_env() -> Result<Self, VarError> {
-        let key = env::var("NLGO_KEY")?;
-        Ok(Self { key })
+        let keys = KeyRing::new(keys_from_env("NLGO_KEY")?).ok_or(VarError::NotPresent)?;
+        Ok(Self { keys, archive: ResponseArchive::from_env(TARGET_NLGO) })
     }
 }
 
 impl provider::api::Client for Client {
-    fn get_books(&self, request: &Request) -> Result<provider::api::Response, ClientError> {
-        let url = build_search_url(&self.key, &request)?;
-        let response = reqwest::blocking::get(url)
-            .map_err(|e| ClientError::RequestFailed(e.to_string()))?;
-        let response_text = response.text()
-            .map_err(|e| ClientError::ResponseTextExtractionFailed(e.to_string()))?;
-        let parsed_response: Response = serde_json::from_str(&response_text)
-            .map_err(|e| ClientError::ResponseParseFailed(e.to_string()))?;
-
-        let books = parsed_response.docs.iter()
-            .map(|doc| doc.to_book_builder())
-            .collect();
-
-        Ok(provider::api::Response {
-            total_count: parsed_response.total_count,
-            page_no: parsed_response.page_no,
-            site: Site::NLGO,
-            books,
-        })
+    type Request = SearchRequest;
+
+    fn get_books(&self, request: &SearchRequest) -> Result<provider::api::Response, ClientError> {
+        self.keys.call(|key| send_request(build_search_url(key, request)?, self.archive.as_ref()))
+    }
+}
+
+impl provider::api::LookupClient for Client {
+    fn site(&self) -> Site {
+        Site::NLGO
     }
+
+    fn lookup(&self, isbn: &str) -> Result<provider::api::Response, ClientError> {
+        let response = self.keys.call(|key| send_request(build_lookup_url(key, isbn)?, self.archive.as_ref()))?;
+        if response.books.is_empty() {
+            return Err(ClientError::NotFound(format!("ISBN: {}", isbn)));
+        }
+        Ok(response)
+    }
+}
+
+fn send_request(url: reqwest::Url, archive: Option<&ResponseArchive>) -> Result<provider::api::Response, ClientError> {
+    let client = shared_client(TARGET_NLGO)
+        .map_err(|e| ClientError::RequestFailed(format!("클라이언트 생성 실패: {}", e)))?;
+    let response = send_with_retry(TARGET_NLGO, client.get(url))
+        .map_err(|e| ClientError::RequestFailed(e.to_string()))?;
+    if response.status() == StatusCode::TOO_MANY_REQUESTS {
+        return Err(ClientError::QuotaExceeded(format!("HTTP {}", response.status())));
+    }
+    // 한 페이지의 도서가 많은 경우 응답이 매우 커질 수 있으므로 임시 파일에 저장한 후 스트림으로 파싱한다.
+    let body = read_body_to_file(TARGET_NLGO, response)?;
+    let payload = archive.and_then(|archive| archive.store_file(body.path()));
+    let parsed_response: Response = serde_json::from_reader(body.into_reader())
+        .map_err(|e| ClientError::ResponseParseFailed(e.to_string()))?;
+
+    let books = parsed_response.docs.iter()
+        .map(|doc| attach_payload(doc.to_book_builder(), Site::NLGO, payload.as_ref()))
+        .collect();
+
+    Ok(provider::api::Response {
+        total_count: parsed_response.total_count,
+        page_no: parsed_response.page_no,
+        site: Site::NLGO,
+        books,
+    })
+}
+
+fn build_lookup_url(key: &str, isbn: &str) -> Result<reqwest::Url, ClientError> {
+    let mut url = reqwest::Url::parse(ISBN_SEARCH_ENDPOINT)
+        .map_err(|_| ClientError::InvalidBaseUrl)?;
+
+    url.query_pairs_mut()
+        .append_pair("cert_key", key)
+        .append_pair("isbn", isbn)
+        .append_pair("result_style", "json")
+        .append_pair("page_no", "1")
+        .append_pair("page_size", "10");
+
+    Ok(url)
 }
 
-fn build_search_url(key: &str, request: &Request) -> Result<reqwest::Url, ClientError> {
-    let from = if let Some(date) = request.start_date {
-        date.format("%Y%m%d").to_string()
-    } else {
-        return Err(ClientError::MissingRequiredParameter("시작일은 반드시 입력 되어야 합니다.".to_string()))
-    };
-    let to = if let Some(date) = request.end_date {
-        date.format("%Y%m%d").to_string()
-    } else {
-        return Err(ClientError::MissingRequiredParameter("종료일은 반드시 입력 되어야 합니다.".to_string()))
-    };
+fn build_search_url(key: &str, request: &SearchRequest) -> Result<reqwest::Url, ClientError> {
+    let from = request.start_date().format("%Y%m%d").to_string();
+    let to = request.end_date().format("%Y%m%d").to_string();
 
     // URL 생성
     let mut url = reqwest::Url::parse(ISBN_SEARCH_ENDPOINT)
@@ -186,10 +315,10 @@ fn build_search_url(key: &str, request: &Request) -> Result<reqwest::Url, Client
         .append_pair("cert_key", key)
         .append_pair("start_publish_date", &from)
         .append_pair("end_publish_date", &to)
-        .append_pair("publisher", &request.query)
+        .append_pair("publisher", request.publisher())
         .append_pair("result_style", "json")
-        .append_pair("page_no", &request.page.to_string())
-        .append_pair("page_size", &request.size.to_string())
+        .append_pair("page_no", &request.page().to_string())
+        .append_pair("page_size", &request.size().to_string())
         .append_pair("ebook_yn", "N")
         .append_pair("sort", "INDEX_PUBLISHER")
         .append_pair("order_by", "ASC");
@@ -0,0 +1,106 @@
+use crate::item::{BookBuilder, RawValue, Site};
+use crate::provider::http::read_target_env;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// 원본 데이터에 기록할 응답 본문 해시 키
+pub const RAW_PAYLOAD_SHA256: &str = "_payload_sha256";
+/// 원본 데이터에 기록할 응답 본문 저장 위치 키
+pub const RAW_PAYLOAD_LOCATION: &str = "_payload_location";
+
+/// 보관된 응답 본문 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedPayload {
+    /// 응답 본문의 SHA-256 해시 (16진수 소문자)
+    pub sha256: String,
+    /// 응답 본문이 저장된 위치
+    pub location: String,
+}
+
+impl ArchivedPayload {
+    /// 도서의 사이트 원본 데이터에 응답 본문의 해시와 저장 위치를 기록한다.
+    pub fn attach(&self, builder: BookBuilder, site: Site) -> BookBuilder {
+        builder
+            .add_original_raw(site.clone(), RAW_PAYLOAD_SHA256, RawValue::Text(self.sha256.clone()))
+            .add_original_raw(site, RAW_PAYLOAD_LOCATION, RawValue::Text(self.location.clone()))
+    }
+}
+
+/// 응답 본문이 보관된 경우 도서의 사이트 원본 데이터에 본문의 해시와 저장 위치를 기록한다.
+pub fn attach_payload(builder: BookBuilder, site: Site, payload: Option<&ArchivedPayload>) -> BookBuilder {
+    match payload {
+        Some(payload) => payload.attach(builder, site),
+        None => builder,
+    }
+}
+
+/// 도서 정보 제공 사이트의 원본 응답 보관소
+///
+/// # Description
+/// 수집 시점에 사이트가 실제로 반환한 응답을 확인할 수 있도록 응답 본문을 그대로 파일로 저장하고,
+/// 응답에서 만든 도서의 원본 데이터에 본문의 해시([`RAW_PAYLOAD_SHA256`])와 저장 위치([`RAW_PAYLOAD_LOCATION`])를 기록한다.
+/// 원본 데이터는 도서와 함께 원본 데이터 저장소에 저장 되므로 데이터가 이상해 보일 때 해시로 저장된 본문을 찾아 검증할 수 있다.
+/// 대상별 설정(`{대상}_{이름}`)이 없을 경우 전체 설정을 사용한다.
+/// - `RAW_ARCHIVE_DIR`: 응답 본문을 저장할 디렉토리, 설정 하지 않을 경우 응답을 보관하지 않는다.
+///
+/// # Note
+/// 본문은 `{디렉토리}/{대상}/{해시 앞 두글자}/{해시}.body`에 저장 되며 같은 본문은 한번만 저장된다.
+/// 오브젝트 스토리지를 사용하는 경우 스토리지를 마운트한 경로를 디렉토리로 지정한다.
+/// 보관에 실패 하더라도 수집은 중단하지 않고 경고 로그만 남긴다.
+#[derive(Debug, Clone)]
+pub struct ResponseArchive {
+    target: String,
+    dir: PathBuf,
+}
+
+impl ResponseArchive {
+    pub fn new<P: Into<PathBuf>>(target: &str, dir: P) -> Self {
+        Self { target: target.to_lowercase(), dir: dir.into() }
+    }
+
+    /// 환경 변수에서 대상의 응답 보관소를 읽는다. 디렉토리가 설정 되지 않은 경우 `None`을 반환한다.
+    pub fn from_env(target: &str) -> Option<Self> {
+        read_target_env(target, "RAW_ARCHIVE_DIR")
+            .filter(|v| !v.trim().is_empty())
+            .map(|dir| Self::new(target, dir.trim()))
+    }
+
+    /// 응답 본문을 보관하고 보관된 본문 정보를 반환한다.
+    pub fn store(&self, body: &[u8]) -> Option<ArchivedPayload> {
+        let sha256 = hex(&Sha256::digest(body));
+        self.write(&sha256, |path| fs::write(path, body))
+    }
+
+    /// 파일에 저장된 응답 본문을 보관하고 보관된 본문 정보를 반환한다. 본문이 큰 경우 메모리에 모두 올리지 않고 보관할 때 사용한다.
+    pub fn store_file(&self, source: &Path) -> Option<ArchivedPayload> {
+        let mut hasher = Sha256::new();
+        let hashed = fs::File::open(source).and_then(|mut file| io::copy(&mut file, &mut hasher));
+        if let Err(e) = hashed {
+            warn!("{} => Failed to hash response body({}): {}", self.target, source.display(), e);
+            return None;
+        }
+        let sha256 = hex(&hasher.finalize());
+        self.write(&sha256, |path| fs::copy(source, path).map(|_| ()))
+    }
+
+    fn write(&self, sha256: &str, write: impl FnOnce(&Path) -> io::Result<()>) -> Option<ArchivedPayload> {
+        let dir = self.dir.join(&self.target).join(&sha256[..2]);
+        let path = dir.join(format!("{}.body", sha256));
+
+        if !path.exists() {
+            let result = fs::create_dir_all(&dir).and_then(|_| write(&path));
+            if let Err(e) = result {
+                warn!("{} => Failed to archive response body({}): {}", self.target, path.display(), e);
+                return None;
+            }
+        }
+        Some(ArchivedPayload { sha256: sha256.to_owned(), location: path.display().to_string() })
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
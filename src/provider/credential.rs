@@ -0,0 +1,128 @@
+use crate::provider::api::ClientError;
+use std::cell::Cell;
+use std::env;
+use std::env::VarError;
+use tracing::warn;
+
+/// 환경 변수에 여러 키를 설정할 때 사용하는 구분자
+pub const KEY_SEPARATOR: char = ',';
+
+/// 구분자([`KEY_SEPARATOR`])로 나열된 키를 나눈다. 앞뒤 공백은 제거하며 빈 키는 무시한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::provider::credential::split_keys;
+///
+/// assert_eq!(split_keys("ttb-first"), vec!["ttb-first"]);
+/// assert_eq!(split_keys("ttb-first, ttb-second,"), vec!["ttb-first", "ttb-second"]);
+/// assert!(split_keys(" ").is_empty());
+/// ```
+pub fn split_keys(value: &str) -> Vec<String> {
+    value.split(KEY_SEPARATOR)
+        .map(|key| key.trim())
+        .filter(|key| !key.is_empty())
+        .map(|key| key.to_owned())
+        .collect()
+}
+
+/// 환경 변수 `name`에 설정된 키 목록을 반환한다. 설정 되지 않았거나 키가 하나도 없는 경우 `VarError::NotPresent`를 반환한다.
+pub fn keys_from_env(name: &str) -> Result<Vec<String>, VarError> {
+    let keys = split_keys(&env::var(name)?);
+    if keys.is_empty() {
+        return Err(VarError::NotPresent);
+    }
+    Ok(keys)
+}
+
+/// 환경 변수 `name`에 설정된 키의 수, 설정 되지 않은 경우 0을 반환한다.
+pub fn configured_key_count(name: &str) -> usize {
+    env::var(name).map(|value| split_keys(&value).len()).unwrap_or(0)
+}
+
+/// 사이트 API 키 순환 목록
+///
+/// # Description
+/// 같은 사이트의 API 키를 여러개 설정한 경우 요청마다 키를 돌아가며 사용한다.
+/// 요청이 요청 한도 초과([`ClientError::QuotaExceeded`])로 실패하면 해당 키를 소진된 것으로 표시하고 다음 키로 같은 요청을 다시 보내며,
+/// 소진된 키는 이번 실행에서 더 이상 사용하지 않는다. 모든 키가 소진된 경우 요청 한도 초과를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::provider::api::ClientError;
+/// use book_batch_rust::provider::credential::KeyRing;
+///
+/// let ring = KeyRing::new(vec!["first", "second"]).unwrap();
+/// assert_eq!(ring.call(|key| Ok::<_, ClientError>(*key)), Ok("first"));
+/// assert_eq!(ring.call(|key| Ok::<_, ClientError>(*key)), Ok("second"));
+///
+/// // 한도를 초과한 키는 건너뛰고 다음 키로 다시 요청한다.
+/// let result = ring.call(|key| match *key {
+///     "first" => Err(ClientError::QuotaExceeded("daily limit".to_owned())),
+///     key => Ok(key),
+/// });
+/// assert_eq!(result, Ok("second"));
+/// assert_eq!(ring.available(), 1);
+///
+/// let result = ring.call(|_| Err::<&str, _>(ClientError::QuotaExceeded("daily limit".to_owned())));
+/// assert!(matches!(result, Err(ClientError::QuotaExceeded(_))));
+/// assert_eq!(ring.available(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyRing<K> {
+    keys: Vec<K>,
+    /// 다음 요청에 사용할 키의 위치
+    cursor: Cell<usize>,
+    /// 키별 소진 여부
+    exhausted: Vec<Cell<bool>>,
+}
+
+impl<K> KeyRing<K> {
+    /// 키 목록으로 생성한다. 키가 하나도 없는 경우 `None`을 반환한다.
+    pub fn new(keys: Vec<K>) -> Option<Self> {
+        if keys.is_empty() {
+            return None;
+        }
+        let exhausted = keys.iter().map(|_| Cell::new(false)).collect();
+        Some(Self { keys, cursor: Cell::new(0), exhausted })
+    }
+
+    /// 설정된 키의 수
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// 소진되지 않은 키의 수
+    pub fn available(&self) -> usize {
+        self.exhausted.iter().filter(|exhausted| !exhausted.get()).count()
+    }
+
+    /// 순서가 된 키로 요청을 보낸다. 요청 한도 초과로 실패한 경우 소진되지 않은 다음 키로 다시 요청한다.
+    pub fn call<T, F>(&self, mut request: F) -> Result<T, ClientError>
+    where
+        F: FnMut(&K) -> Result<T, ClientError>,
+    {
+        let start = self.cursor.get();
+        self.cursor.set((start + 1) % self.keys.len());
+
+        let mut last_error = None;
+        for offset in 0..self.keys.len() {
+            let index = (start + offset) % self.keys.len();
+            if self.exhausted[index].get() {
+                continue;
+            }
+            match request(&self.keys[index]) {
+                Err(ClientError::QuotaExceeded(message)) => {
+                    self.exhausted[index].set(true);
+                    warn!("API key #{} exhausted({}), {} key(s) left", index + 1, message, self.available());
+                    last_error = Some(ClientError::QuotaExceeded(message));
+                }
+                result => return result,
+            }
+        }
+        Err(last_error.unwrap_or_else(|| ClientError::QuotaExceeded(format!("all {} API key(s) are exhausted", self.keys.len()))))
+    }
+}
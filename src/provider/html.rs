@@ -1,6 +1,9 @@
+pub mod crawl;
 pub mod kyobo;
+pub mod robots;
 
 use crate::item::BookBuilder;
+use crate::provider::http::ResponseBodyError;
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -12,7 +15,9 @@ pub enum ParsingError {
     UnknownError(String),
     RequestFailed(String),
     ResponseTextExtractionFailed(String),
+    ResponseTooLarge(String), // 응답 본문이 허용된 최대 크기를 초과함
     ItemNotFound,
+    Disallowed(String), // robots.txt에서 허용 되지 않은 경로
 }
 
 impl fmt::Display for ParsingError {
@@ -21,6 +26,15 @@ impl fmt::Display for ParsingError {
     }
 }
 
+impl From<ResponseBodyError> for ParsingError {
+    fn from(err: ResponseBodyError) -> Self {
+        match err {
+            ResponseBodyError::TooLarge { .. } => ParsingError::ResponseTooLarge(err.to_string()),
+            ResponseBodyError::ReadFailed(message) => ParsingError::ResponseTextExtractionFailed(message),
+        }
+    }
+}
+
 pub trait Client {
     fn get(&self, isbn: &str) -> Result<BookBuilder, ParsingError>;
 }
\ No newline at end of file
@@ -0,0 +1,154 @@
+use crate::provider::http::read_target_env;
+use reqwest::Url;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// 기본으로 사용할 User-Agent 목록
+const DEFAULT_USER_AGENTS: [&str; 3] = [
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.5 Safari/605.1.15",
+];
+/// 같은 도메인에 요청을 보낼 때 기다릴 기본 최소 간격(밀리초)
+const DEFAULT_MIN_DELAY_MILLIS: u64 = 1000;
+/// 같은 도메인에 동시에 보낼 수 있는 기본 최대 요청 수
+const DEFAULT_MAX_CONCURRENCY: usize = 1;
+/// 환경 변수에서 여러 User-Agent를 구분하는 구분자 (User-Agent에는 콤마, 세미콜론이 포함 되므로 `|`를 사용한다.)
+const USER_AGENT_SEPARATOR: char = '|';
+
+/// HTML 제공 사이트 크롤링 정책
+///
+/// # Description
+/// 사이트에 차단 되지 않고 과도한 부하를 주지 않도록 요청마다 User-Agent를 순환하여 사용하고,
+/// 같은 도메인에 대한 요청은 최소 간격과 동시 요청 수를 제한한다.
+/// 대상(교보문고 등)별 설정(`{대상}_{이름}`)이 없을 경우 전체 설정을 사용한다.
+/// - `CRAWL_USER_AGENTS`: 순환하여 사용할 User-Agent 목록 (`|`로 구분)
+/// - `CRAWL_MIN_DELAY_MS`: 같은 도메인에 요청을 보낼 때 기다릴 최소 간격 (단위는 밀리세컨드(ms), 기본값 1000)
+/// - `CRAWL_MAX_CONCURRENCY`: 같은 도메인에 동시에 보낼 수 있는 최대 요청 수 (기본값 1)
+///
+/// # Example
+/// ```
+/// use book_batch_rust::provider::html::crawl::CrawlPolicy;
+/// use std::time::Duration;
+///
+/// let policy = CrawlPolicy::new(vec!["agent-a".to_owned(), "agent-b".to_owned()], Duration::ZERO, 1);
+///
+/// assert_eq!(policy.next_user_agent(), "agent-a");
+/// assert_eq!(policy.next_user_agent(), "agent-b");
+/// assert_eq!(policy.next_user_agent(), "agent-a");
+/// ```
+pub struct CrawlPolicy {
+    user_agents: Vec<String>,
+    min_delay: Duration,
+    max_concurrency: usize,
+
+    next_agent: AtomicUsize,
+    domains: Mutex<HashMap<String, DomainState>>,
+    released: Condvar,
+}
+
+/// 도메인별 요청 상태
+#[derive(Default)]
+struct DomainState {
+    last_request_at: Option<Instant>,
+    in_flight: usize,
+}
+
+impl CrawlPolicy {
+    pub fn new(user_agents: Vec<String>, min_delay: Duration, max_concurrency: usize) -> Self {
+        let user_agents = if user_agents.is_empty() {
+            DEFAULT_USER_AGENTS.iter().map(|a| a.to_string()).collect()
+        } else {
+            user_agents
+        };
+
+        Self {
+            user_agents,
+            min_delay,
+            max_concurrency: max_concurrency.max(1),
+            next_agent: AtomicUsize::new(0),
+            domains: Mutex::new(HashMap::new()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// 환경 변수에서 대상의 크롤링 정책을 읽는다.
+    pub fn from_env(target: &str) -> Self {
+        let user_agents = read_target_env(target, "CRAWL_USER_AGENTS")
+            .map(|v| {
+                v.split(USER_AGENT_SEPARATOR)
+                    .map(|a| a.trim().to_owned())
+                    .filter(|a| !a.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let min_delay = read_target_env(target, "CRAWL_MIN_DELAY_MS")
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_MIN_DELAY_MILLIS);
+        let max_concurrency = read_target_env(target, "CRAWL_MAX_CONCURRENCY")
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .unwrap_or(DEFAULT_MAX_CONCURRENCY);
+
+        Self::new(user_agents, Duration::from_millis(min_delay), max_concurrency)
+    }
+
+    /// 다음 요청에 사용할 User-Agent를 반환한다. User-Agent 목록을 순서대로 순환한다.
+    pub fn next_user_agent(&self) -> &str {
+        let index = self.next_agent.fetch_add(1, Ordering::Relaxed) % self.user_agents.len();
+        &self.user_agents[index]
+    }
+
+    /// URL의 도메인에 요청을 보낼 수 있을 때까지 기다린다.
+    ///
+    /// # Description
+    /// 같은 도메인에 진행 중인 요청 수가 최대 동시 요청 수 보다 적어질 때까지, 그리고 마지막 요청 후 최소 간격이 지날 때까지 기다린 후
+    /// 요청 허가를 반환한다. 요청 허가가 해제(drop) 되면 진행 중인 요청 수가 줄어든다.
+    pub fn acquire(&self, url: &Url) -> CrawlPermit<'_> {
+        let domain = url.host_str().unwrap_or_default().to_owned();
+
+        let mut domains = self.domains.lock().unwrap();
+        loop {
+            let state = domains.entry(domain.clone()).or_default();
+            if state.in_flight >= self.max_concurrency {
+                domains = self.released.wait(domains).unwrap();
+                continue;
+            }
+
+            let wait = state.last_request_at
+                .map(|at| self.min_delay.saturating_sub(at.elapsed()))
+                .unwrap_or(Duration::ZERO);
+            if wait.is_zero() {
+                state.in_flight += 1;
+                state.last_request_at = Some(Instant::now());
+                break;
+            }
+            domains = self.released.wait_timeout(domains, wait).unwrap().0;
+        }
+
+        CrawlPermit { policy: self, domain }
+    }
+
+    fn release(&self, domain: &str) {
+        if let Some(state) = self.domains.lock().unwrap().get_mut(domain) {
+            state.in_flight = state.in_flight.saturating_sub(1);
+        }
+        self.released.notify_all();
+    }
+}
+
+/// 도메인에 대한 요청 허가
+///
+/// # Description
+/// [`CrawlPolicy::acquire`]로 얻은 요청 허가로 요청이 끝날 때까지 유지하며, 해제(drop) 되면 같은 도메인에 대기 중인 요청이 진행된다.
+pub struct CrawlPermit<'a> {
+    policy: &'a CrawlPolicy,
+    domain: String,
+}
+
+impl Drop for CrawlPermit<'_> {
+    fn drop(&mut self) {
+        self.policy.release(&self.domain);
+    }
+}
@@ -1,21 +1,21 @@
 pub mod chrome;
+pub mod product;
+pub mod search;
+pub mod session;
 mod utils;
 
 use crate::item::{Book, BookBuilder, Raw, RawDataKind, RawKeyDict, RawValue, Site};
 use crate::provider::html;
+use crate::provider::html::kyobo::session::KyoboSession;
 use crate::provider::html::ParsingError;
-use reqwest::cookie::Jar;
 use reqwest::Url;
 use scraper::Html;
 use std::collections::HashMap;
-use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
-const AGENT: &'static str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/80.0.3987.149 Safari/537.36";
-
-const KYOBO_DOMAIN: &'static str = "https://www.kyobobook.co.kr";
+pub(crate) const KYOBO_DOMAIN: &'static str = "https://www.kyobobook.co.kr";
 const ISBN_SEARCH_ENDPOINT: &'static str = "https://www.kyobobook.co.kr/product/detailViewKor.laf";
 
 /// 교보문고 로그인 제공 트레이트
@@ -39,11 +39,43 @@ pub trait LoginProvider {
     fn get_cookies(&self) -> Result<Vec<Self::CookieValue>, ParsingError>;
 }
 
+/// 로그인 하지 않는 로그인 제공자
+///
+/// # Description
+/// 공개된 페이지만 조회하는 클라이언트([`Client::public`])의 타입 파라미터로 사용하며 쿠키를 제공하지 않는다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoLoginProvider;
+
+impl LoginProvider for NoLoginProvider {
+    type CookieValue = String;
+
+    fn login(&mut self) -> Result<(), ParsingError> {
+        Ok(())
+    }
+
+    fn get_cookies(&self) -> Result<Vec<Self::CookieValue>, ParsingError> {
+        Ok(Vec::new())
+    }
+}
+
+/// 교보문고 도서 검색 클라이언트
+///
+/// # Description
+/// 교보문고 도서 상세 페이지를 ISBN으로 검색하여 도서 정보를 가져온다.
+/// 도서 상세 페이지와 시리즈 목록 API 모두 같은 세션([`KyoboSession`])으로 요청하므로 로그인한 경우 회원 쿠키가 함께 전달된다.
+///
+/// # Product API
+/// 도서 상세 페이지에서는 상품 아이디를 찾고, 상품 정보는 상품 API([`product::get_product`])의 JSON 응답을 우선 사용한다.
+/// 상품 API에 없는 항목(판매 상태 등)이나 상품 API 요청이 실패한 경우 도서 상세 페이지에서 가져온 값을 사용하므로
+/// 페이지 구조가 바뀌더라도 상품 아이디만 찾을 수 있으면 대부분의 정보를 가져올 수 있다.
 pub struct Client<P>
 where
     P: LoginProvider,
 {
-    login_provider: P,
+    session: KyoboSession<P>,
+
+    /// 상품 API 사용 여부 ([`product::is_enabled`])
+    product_api: bool,
 }
 
 impl <P> Client<P>
@@ -51,7 +83,18 @@ where
     P: LoginProvider,
 {
     pub fn new(login_provider: P) -> Self {
-        Self { login_provider }
+        Self { session: KyoboSession::new(Some(login_provider)), product_api: product::is_enabled() }
+    }
+}
+
+impl Client<NoLoginProvider> {
+    /// 로그인 하지 않고 공개된 페이지만 조회하는 클라이언트를 생성한다.
+    ///
+    /// # Description
+    /// 로그인 제공자를 사용하지 않으므로 로그인 정보와 크롬이 필요 없다.
+    /// 회원 전용 정보(회원가 등)는 페이지에 표시 되지 않으므로 원본 데이터에서 생략된다.
+    pub fn public() -> Self {
+        Self { session: KyoboSession::new(None), product_api: product::is_enabled() }
     }
 }
 
@@ -63,42 +106,27 @@ where
         let mut url = Url::parse(ISBN_SEARCH_ENDPOINT).unwrap();
         url.query_pairs_mut().append_pair("barcode", isbn);
 
-        let cookie_store = Jar::default();
-        let cookies = self.login_provider.get_cookies()?;
+        let text = self.session.get(&url)?;
+        let (item_id, mut origin_data) = html_to_raw(&Html::parse_document(&text))?;
 
-        for cookie in cookies {
-            cookie_store.add_cookie_str(cookie.as_ref(), &KYOBO_DOMAIN.parse().unwrap());
+        if self.product_api {
+            match product::get_product(&self.session, &item_id) {
+                Ok(product) => origin_data = merge_origin_data(product.to_raw(), origin_data),
+                Err(err) => warn!("Failed to get product, use detail page only: {}({}) {:?}", item_id, isbn, err),
+            }
         }
+        let book_builder = raw_to_book(origin_data)?;
 
-        let client = reqwest::blocking::Client::builder()
-            .cookie_provider(Arc::new(cookie_store))
-            .user_agent(AGENT)
-            .build()
-            .unwrap();
-
-        let request = client.get(url).build().unwrap();
-        let response = client
-            .execute(request)
-            .map_err(|err| ParsingError::RequestFailed(format!("ISBN: {}, ERROR: {:?}", isbn, err)))?;
-
-        let text = response.text().unwrap();
-        let parse = html_to_book(&Html::parse_document(&text));
-
-        if let Ok((item_id, mut book_builder)) = parse {
-            let series_list = get_series_list(&item_id);
-            if let Ok(series_list) = series_list {
-                let series = series_list.into_iter()
-                    .map(|b| b.to_raw_val())
-                    .collect::<Vec<_>>();
-
-                book_builder = book_builder.add_original_raw(Site::KyoboBook, "series", RawValue::Array(series));
-                Ok(book_builder)
-            } else {
-                warn!("Failed to get series list: {}({})", item_id, isbn);
-                Ok(book_builder)
-            }
+        let series_list = get_series_list(&self.session, &item_id);
+        if let Ok(series_list) = series_list {
+            let series = series_list.into_iter()
+                .map(|b| b.to_raw_val())
+                .collect::<Vec<_>>();
+
+            Ok(book_builder.add_original_raw(Site::KyoboBook, "series", RawValue::Array(series)))
         } else {
-            Err(parse.unwrap_err())
+            warn!("Failed to get series list: {}({})", item_id, isbn);
+            Ok(book_builder)
         }
     }
 }
@@ -158,24 +186,11 @@ impl BookItem {
     }
 }
 
-fn get_series_list(item_id: &str) -> Result<Vec<BookItem>, ParsingError> {
+/// 도서의 시리즈 목록을 조회한다. 일부 시리즈는 회원 쿠키가 필요하므로 도서 상세 페이지와 같은 세션으로 요청한다.
+fn get_series_list<P: LoginProvider>(session: &KyoboSession<P>, item_id: &str) -> Result<Vec<BookItem>, ParsingError> {
     let url = format!("https://product.kyobobook.co.kr/api/gw/pdt/product/{}/series", item_id);
     let url = Url::parse(&url).unwrap();
-
-    let client = reqwest::blocking::Client::builder()
-        .user_agent(AGENT)
-        .build()
-        .unwrap();
-
-    let response = client
-        .get(url)
-        .send();
-    if response.is_err() {
-        return Err(ParsingError::RequestFailed(format!("ERROR: {:?}", response)));
-    }
-    let response = response.unwrap();
-    let text = response.text()
-        .map_err(|err| ParsingError::ResponseTextExtractionFailed(format!("ERROR: {:?}", err)))?;
+    let text = session.get(&url)?;
 
     let response: KyoboResponse = serde_json::from_str(&text)
         .map_err(|err| ParsingError::ResponseTextExtractionFailed(format!("ERROR: {:?}", err)))?;
@@ -188,25 +203,29 @@ fn get_series_list(item_id: &str) -> Result<Vec<BookItem>, ParsingError> {
     Ok(data.list)
 }
 
-fn html_to_book(document: &Html) -> Result<(String, BookBuilder), ParsingError> {
+/// 도서 상세 페이지에서 상품 아이디와 원본 데이터를 가져온다. 상품 아이디를 제외한 항목은 페이지에 없는 경우 생략한다.
+fn html_to_raw(document: &Html) -> Result<(String, Raw), ParsingError> {
     let item_id = utils::retrieve_item_id(document)
         .ok_or_else(|| ParsingError::ItemNotFound)?;
-    let isbn = utils::retrieve_isbn(document)
-        .ok_or_else(|| ParsingError::ItemNotFound)?;
-    let title = utils::retrieve_title(document)
-        .ok_or_else(|| ParsingError::ElementNotFound("title is not found".to_owned()))?;
 
+    let isbn = utils::retrieve_isbn(document);
+    let title = utils::retrieve_title(document);
     let thumbnail_url = utils::retrieve_thumbnail(document);
     let prod_img_url = utils::retrieve_desc_img(document);
     let prod_desc = utils::retrieve_prod_desc(document);
     let (sale_price, standard_price) = utils::retrieve_price(document);
     let author = utils::retrieve_author(document);
+    let sale_status = utils::retrieve_sale_status(document);
 
     let mut origin_data = Raw::new();
     origin_data.insert("item_id".to_owned(), item_id.as_str().into());
-    origin_data.insert("isbn".to_owned(), isbn.as_str().into());
-    origin_data.insert("title".to_owned(), title.as_str().into());
-    
+
+    if let Some(s) = isbn {
+        origin_data.insert("isbn".to_owned(), s.as_str().into());
+    }
+    if let Some(s) = title {
+        origin_data.insert("title".to_owned(), s.as_str().into());
+    }
     if let Some(s) = thumbnail_url {
         origin_data.insert("thumbnail_url".to_owned(), s.as_str().into());
     }
@@ -225,13 +244,36 @@ fn html_to_book(document: &Html) -> Result<(String, BookBuilder), ParsingError>
     if let Some(s) = author {
         origin_data.insert("author".to_owned(), s.as_str().into());
     }
+    if let Some(s) = sale_status {
+        origin_data.insert("sale_status".to_owned(), s.as_str().into());
+    }
+
+    Ok((item_id, origin_data))
+}
+
+/// 상품 API의 원본 데이터를 우선 사용하고, 상품 API에 없는 항목은 도서 상세 페이지의 원본 데이터로 채운다.
+fn merge_origin_data(product: Raw, page: Raw) -> Raw {
+    let mut merged = product;
+    for (key, value) in page {
+        merged.entry(key).or_insert(value);
+    }
+    merged
+}
+
+fn raw_to_book(origin_data: Raw) -> Result<BookBuilder, ParsingError> {
+    let isbn = origin_data.get("isbn")
+        .map(|v| v.to_string())
+        .ok_or_else(|| ParsingError::ItemNotFound)?;
+    let title = origin_data.get("title")
+        .map(|v| v.to_string())
+        .ok_or_else(|| ParsingError::ElementNotFound("title is not found".to_owned()))?;
 
     let builder = Book::builder()
-        .isbn(isbn.to_owned())
-        .title(title.clone())
+        .isbn(isbn)
+        .title(title)
         .add_original(Site::KyoboBook, origin_data);
 
-    Ok((item_id, builder))
+    Ok(builder)
 }
 
 pub fn load_raw_key_dict() -> RawKeyDict {
@@ -241,5 +283,7 @@ pub fn load_raw_key_dict() -> RawKeyDict {
         (RawDataKind::Description, "prod_description".to_owned()),
         (RawDataKind::SeriesList, "series".to_owned()),
         (RawDataKind::Author, "author".to_owned()),
+        (RawDataKind::SaleStatus, "sale_status".to_owned()),
+        (RawDataKind::Thumbnail, "thumbnail_url".to_owned()),
     ])
 }
\ No newline at end of file
@@ -1,23 +1,81 @@
 use std::any::Any;
+use crate::configs::proxy::ProxyConfig;
+use crate::provider::http::TARGET_KYOBO;
 use crate::provider::html::kyobo::LoginProvider;
 use crate::provider::html::ParsingError;
 use headless_chrome::{Browser, LaunchOptions};
 use std::env::VarError;
+use std::path::PathBuf;
 use std::{env, thread};
 use std::ops::Add;
 use headless_chrome::browser::tab::point::Point;
 
-const AGENT: &'static str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36";
-
+/// 실행 중인 운영체제의 크롬과 같은 User-Agent (로그인 페이지의 자동화 탐지를 피하기 위해 실제 브라우저와 운영체제를 일치시킨다.)
+#[cfg(target_os = "macos")]
+const DEFAULT_AGENT: &'static str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36";
+#[cfg(target_os = "windows")]
+const DEFAULT_AGENT: &'static str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const DEFAULT_AGENT: &'static str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/147.0.0.0 Safari/537.36";
+
+/// 크롬 실행 파일 경로를 설정하지 않았을 때 찾아볼 운영체제별 기본 설치 경로
+/// 목록에 없는 운영체제(리눅스 등)는 `PATH`에서 크롬을 찾는다.
+#[cfg(target_os = "macos")]
+const DEFAULT_CHROME_PATHS: &[&str] = &[
+    "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+    "/Applications/Chromium.app/Contents/MacOS/Chromium",
+];
+#[cfg(target_os = "windows")]
+const DEFAULT_CHROME_PATHS: &[&str] = &[
+    r"C:\Program Files\Google\Chrome\Application\chrome.exe",
+    r"C:\Program Files (x86)\Google\Chrome\Application\chrome.exe",
+];
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const DEFAULT_CHROME_PATHS: &[&str] = &[];
+
+/// 로그인 쿠키의 기본 도메인 (로그인 후 받은 쿠키에 도메인이 없는 경우 사용한다.)
 const COOKIE_DOMAIN: &'static str = ".kyobobook.co.kr";
 const LOGIN_URL: &'static str = "https://mmbr.kyobobook.co.kr/login";
 
+/// 로그인에 사용할 크롬 실행 옵션
+///
+/// # Description
+/// 환경 변수로 설정할 수 있으며 설정 하지 않을 경우 실행 중인 운영체제에 맞는 기본값을 사용한다.
+/// - `CHROME_PATH`: 크롬 실행 파일 경로 (기본값: 운영체제별 기본 설치 경로, 없으면 `PATH`에서 찾음)
+/// - `KYOBO_USER_AGENT`: 로그인에 사용할 User-Agent (기본값: 운영체제별 크롬 User-Agent)
+/// - `KYOBO_CHROME_HEADLESS`: `false`로 설정한 경우 크롬 창을 띄워 로그인 과정을 확인할 수 있다. (기본값: `true`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChromeOptions {
+    pub binary_path: Option<PathBuf>,
+    pub user_agent: String,
+    pub headless: bool,
+}
+
+impl ChromeOptions {
+    pub fn from_env() -> Self {
+        let binary_path = env::var("CHROME_PATH").ok()
+            .filter(|v| !v.trim().is_empty())
+            .map(PathBuf::from)
+            .or_else(|| DEFAULT_CHROME_PATHS.iter().map(PathBuf::from).find(|path| path.exists()));
+        let user_agent = env::var("KYOBO_USER_AGENT").ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_AGENT.to_owned());
+        let headless = env::var("KYOBO_CHROME_HEADLESS").ok()
+            .map(|v| !v.trim().eq_ignore_ascii_case("false"))
+            .unwrap_or(true);
+        Self { binary_path, user_agent, headless }
+    }
+}
+
 pub struct ChromeDriverLoginProvider {
-    server_url: String,
+    /// ChromeDriver 서버 주소 (선택)
+    server_url: Option<String>,
     id: String,
     pw: String,
+    options: ChromeOptions,
 
     access_token: Option<String>,
+    cookie_domain: String,
     last_login_at: Option<chrono::NaiveDateTime>,
 }
 
@@ -25,13 +83,15 @@ pub fn new_provider() -> Result<ChromeDriverLoginProvider, VarError> {
     let id = env::var("KYOBO_ID")?;
     let pw = env::var("KYOBO_SECRET")?;
 
-    let server_url = env::var("CHROMEDRIVER_URL")?;
+    let server_url = env::var("CHROMEDRIVER_URL").ok().filter(|v| !v.trim().is_empty());
 
     let mut provider = ChromeDriverLoginProvider {
         server_url,
         id,
         pw,
+        options: ChromeOptions::from_env(),
         access_token: None,
+        cookie_domain: COOKIE_DOMAIN.to_owned(),
         last_login_at: None,
     };
     provider.login().unwrap();
@@ -42,9 +102,13 @@ impl LoginProvider for ChromeDriverLoginProvider {
     type CookieValue = String;
 
     fn login(&mut self) -> Result<(), ParsingError> {
-        let user_agent = format!("--user-agent={}", AGENT);
+        let user_agent = format!("--user-agent={}", self.options.user_agent);
+        let proxy_args = ProxyConfig::from_env(TARGET_KYOBO)
+            .map(|config| config.chrome_args())
+            .unwrap_or_default();
         let options = LaunchOptions {
-            headless: true,
+            headless: self.options.headless,
+            path: self.options.binary_path.clone(),
             args: vec![
                 user_agent.as_str(),
                 "--disable-blink-features=AutomationControlled", // 자동화 플래그 비활성화
@@ -52,7 +116,9 @@ impl LoginProvider for ChromeDriverLoginProvider {
                 "--disable-dev-shm-usage",
                 "--disable-renderer-backgrounding",
                 "--disable-background-timer-throttling"
-            ].into_iter().map(std::ffi::OsStr::new).collect(),
+            ].into_iter()
+                .chain(proxy_args.iter().map(|arg| arg.as_str()))
+                .map(std::ffi::OsStr::new).collect(),
             ..Default::default()
         };
 
@@ -82,15 +148,18 @@ impl LoginProvider for ChromeDriverLoginProvider {
             .map_err(|_| ParsingError::ElementNotFound("login complete tag cannot found".to_owned()))?;
 
         let access_token = match tab.get_cookies() {
-            Ok(cookies) => cookies.iter().find(|cookie| cookie.name == "accessToken").map(|cookie| cookie.value.to_string()),
+            Ok(cookies) => cookies.iter().find(|cookie| cookie.name == "accessToken").map(|cookie| (cookie.value.to_string(), cookie.domain.to_string())),
             Err(err) => {
                 return Err(ParsingError::UnknownError(err.to_string()));
             }
         };
 
         match access_token {
-            Some(token) => {
+            Some((token, domain)) => {
                 self.access_token = Some(token);
+                if !domain.is_empty() {
+                    self.cookie_domain = domain;
+                }
                 self.last_login_at = Some(chrono::Local::now().naive_local());
                 Ok(())
             }
@@ -100,7 +169,7 @@ impl LoginProvider for ChromeDriverLoginProvider {
 
     fn get_cookies(&self) -> Result<Vec<Self::CookieValue>, ParsingError> {
         if let Some(token) = self.access_token.as_ref() {
-            let access_token = format!("accessToken={}; Domain={}; Path=/; Secure", token, COOKIE_DOMAIN);
+            let access_token = format!("accessToken={}; Domain={}; Path=/; Secure", token, self.cookie_domain);
             Ok(vec![access_token])
         } else {
             Err(ParsingError::UnknownError("Access token is None".to_owned()))
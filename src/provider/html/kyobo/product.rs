@@ -0,0 +1,111 @@
+use crate::item::{Raw, RawValue};
+use crate::provider::html::kyobo::session::KyoboSession;
+use crate::provider::html::kyobo::LoginProvider;
+use crate::provider::html::ParsingError;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+const PRODUCT_API_ENDPOINT: &'static str = "https://product.kyobobook.co.kr/api/gw/pdt/product";
+
+/// 상품 API 응답
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProductResponse {
+    pub data: Option<ProductData>,
+    #[serde(rename = "statusCode")]
+    pub status_code: i32,
+}
+
+/// 상품 API의 상품 상세 정보
+///
+/// # Description
+/// 응답에 없는 항목은 `None`으로 처리하며, 도서 상세 페이지에서 가져온 값으로 채운다.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProductData {
+    #[serde(rename = "saleCmdtId")]
+    pub sale_cmdt_id: Option<String>,
+    #[serde(rename = "cmdtCode")]
+    pub cmdt_code: Option<String>,
+    #[serde(rename = "cmdtName")]
+    pub cmdt_name: Option<String>,
+    #[serde(rename = "autrName")]
+    pub autr_name: Option<String>,
+    #[serde(rename = "sellPrice")]
+    pub sell_price: Option<usize>,
+    #[serde(rename = "price")]
+    pub price: Option<usize>,
+    #[serde(rename = "imgUrl")]
+    pub img_url: Option<String>,
+    #[serde(rename = "cmdtIntrCntt")]
+    pub cmdt_intr_cntt: Option<String>,
+}
+
+impl ProductData {
+
+    /// 상품 정보를 교보문고 원본 데이터 키([`crate::provider::html::kyobo::load_raw_key_dict`])로 변환한다. 값이 없거나 빈 문자열인 항목은 생략한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::provider::html::kyobo::product::ProductData;
+    ///
+    /// let data = ProductData {
+    ///     cmdt_code: Some("9791133478427".to_owned()),
+    ///     cmdt_name: Some("원피스 1".to_owned()),
+    ///     autr_name: Some(" ".to_owned()),
+    ///     sell_price: Some(5400),
+    ///     ..Default::default()
+    /// };
+    /// let raw = data.to_raw();
+    /// assert_eq!(raw.get("isbn").unwrap().to_string(), "9791133478427");
+    /// assert_eq!(raw.get("title").unwrap().to_string(), "원피스 1");
+    /// assert!(raw.get("author").is_none());
+    /// assert!(raw.contains_key("sale_price"));
+    /// ```
+    pub fn to_raw(&self) -> Raw {
+        let mut raw = Raw::new();
+        let texts = [
+            ("item_id", &self.sale_cmdt_id),
+            ("isbn", &self.cmdt_code),
+            ("title", &self.cmdt_name),
+            ("author", &self.autr_name),
+            ("thumbnail_url", &self.img_url),
+            ("prod_description", &self.cmdt_intr_cntt),
+        ];
+        for (key, value) in texts {
+            if let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+                raw.insert(key.to_owned(), value.into());
+            }
+        }
+        if let Some(v) = self.sell_price {
+            raw.insert("sale_price".to_owned(), RawValue::from(v));
+        }
+        if let Some(v) = self.price {
+            raw.insert("standard_price".to_owned(), RawValue::from(v));
+        }
+        raw
+    }
+}
+
+/// 상품 API 사용 여부
+///
+/// # Description
+/// 환경 변수 `KYOBO_PRODUCT_API`를 `false`로 설정한 경우 상품 API를 사용하지 않고 도서 상세 페이지만 사용한다. (기본값: `true`)
+pub fn is_enabled() -> bool {
+    env::var("KYOBO_PRODUCT_API").ok()
+        .map(|v| !v.trim().eq_ignore_ascii_case("false"))
+        .unwrap_or(true)
+}
+
+/// 상품 아이디로 상품 API에서 상품 상세 정보를 조회한다.
+pub fn get_product<P: LoginProvider>(session: &KyoboSession<P>, item_id: &str) -> Result<ProductData, ParsingError> {
+    let url = Url::parse(&format!("{}/{}", PRODUCT_API_ENDPOINT, item_id)).unwrap();
+    let text = session.get(&url)?;
+
+    let response: ProductResponse = serde_json::from_str(&text)
+        .map_err(|err| ParsingError::ResponseTextExtractionFailed(format!("ERROR: {:?}", err)))?;
+    if response.status_code != 0 {
+        return Err(ParsingError::ItemNotFound);
+    }
+    response.data.ok_or(ParsingError::ItemNotFound)
+}
@@ -0,0 +1,214 @@
+use crate::item::{Book, BookBuilder, Raw, Site};
+use crate::provider::api::{ClientError, PagedRequest, Response};
+use crate::provider::archive::{attach_payload, ResponseArchive};
+use crate::provider::html::crawl::CrawlPolicy;
+use crate::provider::html::robots::RobotsGate;
+use crate::provider::http::{read_body, send_with_retry, shared_client, TARGET_KYOBO};
+use crate::provider;
+use chrono::NaiveDate;
+use reqwest::header::USER_AGENT;
+use reqwest::Url;
+use scraper::{ElementRef, Html, Selector};
+
+/// 교보문고 통합 검색 페이지 URL
+const SEARCH_ENDPOINT: &str = "https://search.kyobobook.co.kr/search";
+
+/// 교보문고 키워드 검색 요청
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchRequest {
+    /// 검색 키워드
+    keyword: String,
+    /// 검색 결과 페이지 (1부터 시작)
+    page: u32,
+    /// 한 페이지의 검색 결과 수
+    size: u32,
+}
+
+impl SearchRequest {
+    pub fn builder<S: Into<String>>(keyword: S) -> SearchRequestBuilder {
+        SearchRequestBuilder { keyword: keyword.into(), page: 1, size: 20 }
+    }
+
+    pub fn keyword(&self) -> &str {
+        &self.keyword
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+impl PagedRequest for SearchRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn size(&self) -> u32 {
+        self.size
+    }
+
+    fn with_page(&self, page: u32) -> Self {
+        Self { page, ..self.clone() }
+    }
+}
+
+pub struct SearchRequestBuilder {
+    keyword: String,
+    page: u32,
+    size: u32,
+}
+
+impl SearchRequestBuilder {
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn build(self) -> SearchRequest {
+        SearchRequest { keyword: self.keyword, page: self.page, size: self.size }
+    }
+}
+
+/// 교보문고 키워드 검색 클라이언트
+///
+/// # Description
+/// 교보문고 통합 검색 페이지를 키워드로 검색하여 검색 결과의 ISBN, 제목, 출판사, 출판일을 가져온다.
+/// 상세 페이지([`super::Client`])와 달리 로그인이 필요 없으며, 출판사 키워드로 새로 등록된(예약 판매 등) 도서를 찾는 용도로 사용한다.
+/// 요청은 상세 페이지와 같은 크롤링 정책([`CrawlPolicy`])과 robots.txt([`RobotsGate`]) 확인을 따른다.
+///
+/// # Note
+/// 검색 결과는 출판사뿐 아니라 제목, 저자 등에 키워드가 포함된 도서도 함께 반환 하므로
+/// 출판사명이 키워드와 일치하는지는 사용하는 쪽에서 확인 해야 한다. (원본 데이터의 `publisher`)
+pub struct SearchClient {
+    crawl_policy: CrawlPolicy,
+    robots: RobotsGate,
+    archive: Option<ResponseArchive>,
+}
+
+impl SearchClient {
+    pub fn new() -> Self {
+        Self {
+            crawl_policy: CrawlPolicy::from_env(TARGET_KYOBO),
+            robots: RobotsGate::from_env(TARGET_KYOBO),
+            archive: ResponseArchive::from_env(TARGET_KYOBO),
+        }
+    }
+}
+
+impl Default for SearchClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl provider::api::Client for SearchClient {
+    type Request = SearchRequest;
+
+    fn get_books(&self, request: &SearchRequest) -> Result<Response, ClientError> {
+        let url = build_search_url(request)?;
+        self.robots.check(&url)
+            .map_err(|err| ClientError::RequestFailed(err.to_string()))?;
+
+        let client = shared_client(TARGET_KYOBO)
+            .map_err(|err| ClientError::RequestFailed(format!("Failed to build client: {:?}", err)))?;
+
+        let permit = self.crawl_policy.acquire(&url);
+        let response = send_with_retry(TARGET_KYOBO, client.get(url).header(USER_AGENT, self.crawl_policy.next_user_agent()))
+            .map_err(|err| ClientError::RequestFailed(err.to_string()))?;
+        drop(permit);
+
+        if !response.status().is_success() {
+            return Err(ClientError::RequestFailed(format!("HTTP 오류: {}", response.status())));
+        }
+
+        let text = read_body(TARGET_KYOBO, response)?;
+        let payload = self.archive.as_ref().and_then(|archive| archive.store(text.as_bytes()));
+        let books = html_to_books(&Html::parse_document(&text)).into_iter()
+            .map(|book| attach_payload(book, Site::KyoboBook, payload.as_ref()))
+            .collect::<Vec<_>>();
+
+        // 검색 결과 페이지로는 전체 건수를 알 수 없으므로 현재 페이지가 가득 찬 경우 다음 페이지가 있는 것으로 본다.
+        let fetched = (request.page().saturating_sub(1) * request.size()) as usize + books.len();
+        let total_count = if books.len() < request.size() as usize { fetched } else { fetched + 1 };
+
+        Ok(Response {
+            total_count: total_count as i32,
+            page_no: request.page() as i32,
+            site: Site::KyoboBook,
+            books,
+        })
+    }
+}
+
+fn build_search_url(request: &SearchRequest) -> Result<Url, ClientError> {
+    Url::parse(SEARCH_ENDPOINT)
+        .map_err(|_| ClientError::InvalidBaseUrl)
+        .map(|mut url| {
+            url.query_pairs_mut()
+                .append_pair("keyword", request.keyword())
+                .append_pair("gbCode", "TOT") // 통합 검색으로 고정
+                .append_pair("target", "total")
+                .append_pair("page", &request.page().to_string())
+                .append_pair("len", &request.size().to_string());
+            url
+        })
+}
+
+/// 검색 결과 페이지의 도서 리스트를 파싱한다. ISBN이나 제목을 찾을 수 없는 검색 결과는 제외한다.
+fn html_to_books(document: &Html) -> Vec<BookBuilder> {
+    let item_selector = Selector::parse("li.prod_item").unwrap();
+    document.select(&item_selector)
+        .filter_map(item_to_book)
+        .collect()
+}
+
+fn item_to_book(item: ElementRef) -> Option<BookBuilder> {
+    let checkbox_selector = Selector::parse("input.result_checkbox").unwrap();
+    let title_selector = Selector::parse(".prod_info span[id^=\"cmdtName_\"]").unwrap();
+    let publisher_selector = Selector::parse(".prod_publish .text").unwrap();
+    let date_selector = Selector::parse(".prod_publish .date").unwrap();
+
+    let checkbox = item.select(&checkbox_selector).next()?;
+    let isbn = checkbox.attr("data-bid").map(|s| s.trim().to_owned()).filter(|s| !s.is_empty())?;
+    let item_id = checkbox.attr("data-pid").unwrap_or_default().trim().to_owned();
+    let title = item.select(&title_selector).next()
+        .map(|e| e.text().collect::<String>().trim().to_owned())
+        .filter(|s| !s.is_empty())?;
+    let publisher = item.select(&publisher_selector).next()
+        .map(|e| e.text().collect::<String>().trim().to_owned());
+    let pub_date = item.select(&date_selector).next()
+        .map(|e| e.text().collect::<String>())
+        .and_then(|s| parse_pub_date(&s));
+
+    let mut origin_data = Raw::new();
+    origin_data.insert("item_id".to_owned(), item_id.as_str().into());
+    origin_data.insert("isbn".to_owned(), isbn.as_str().into());
+    origin_data.insert("title".to_owned(), title.as_str().into());
+    if let Some(publisher) = publisher.as_ref() {
+        origin_data.insert("publisher".to_owned(), publisher.as_str().into());
+    }
+
+    let mut builder = Book::builder()
+        .isbn(isbn)
+        .title(title)
+        .add_original(Site::KyoboBook, origin_data);
+    if let Some(date) = pub_date {
+        builder = builder.actual_pub_date(date);
+    }
+    Some(builder)
+}
+
+/// 검색 결과의 출판일(ex: ` · 2024년 06월 01일`)을 파싱한다.
+fn parse_pub_date(text: &str) -> Option<NaiveDate> {
+    let text = text.trim_matches(|c: char| c.is_whitespace() || c == '·');
+    NaiveDate::parse_from_str(text, "%Y년 %m월 %d일").ok()
+}
@@ -0,0 +1,85 @@
+use crate::provider::html::crawl::CrawlPolicy;
+use crate::provider::html::kyobo::{LoginProvider, KYOBO_DOMAIN};
+use crate::provider::html::robots::RobotsGate;
+use crate::provider::html::ParsingError;
+use crate::provider::http::{read_body, send_with_retry, shared_client, TARGET_KYOBO};
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::{COOKIE, SET_COOKIE, USER_AGENT};
+use reqwest::Url;
+use std::cell::Cell;
+
+/// 교보문고 요청 세션
+///
+/// # Description
+/// 로그인 제공자가 제공한 쿠키와 응답으로 받은 쿠키를 세션의 쿠키 저장소에 보관하여 교보문고의 모든 요청(도서 상세 페이지, 시리즈 목록 API 등)에 함께 전달한다.
+/// 로그인 쿠키는 첫 요청 전에 한번만 가져오며, 쿠키의 도메인(`.kyobobook.co.kr`)에 따라 하위 도메인(`product.kyobobook.co.kr` 등) 요청에도 전달된다.
+/// 요청은 크롤링 정책([`CrawlPolicy`])과 robots.txt([`RobotsGate`])를 따른다.
+///
+/// # Note
+/// 공유 클라이언트는 여러 요청에서 함께 사용 하므로 쿠키는 클라이언트가 아닌 요청 헤더에 설정한다.
+pub struct KyoboSession<P>
+where
+    P: LoginProvider,
+{
+    /// 로그인 제공자, 공개 페이지만 조회하는 세션은 `None`
+    login_provider: Option<P>,
+    cookies: Jar,
+    logged_in: Cell<bool>,
+    crawl_policy: CrawlPolicy,
+    robots: RobotsGate,
+}
+
+impl <P> KyoboSession<P>
+where
+    P: LoginProvider,
+{
+    pub fn new(login_provider: Option<P>) -> Self {
+        Self {
+            login_provider,
+            cookies: Jar::default(),
+            logged_in: Cell::new(false),
+            crawl_policy: CrawlPolicy::from_env(TARGET_KYOBO),
+            robots: RobotsGate::from_env(TARGET_KYOBO),
+        }
+    }
+
+    /// 세션의 쿠키와 함께 GET 요청을 보내고 응답 본문을 반환한다.
+    ///
+    /// # Errors
+    /// robots.txt에서 허용 되지 않은 경로이거나 로그인 쿠키를 가져오지 못한 경우, 요청이 실패한 경우 에러를 반환한다.
+    pub fn get(&self, url: &Url) -> Result<String, ParsingError> {
+        self.robots.check(url)?;
+        self.load_login_cookies()?;
+
+        let client = shared_client(TARGET_KYOBO)
+            .map_err(|err| ParsingError::RequestFailed(format!("Failed to build client: {:?}", err)))?;
+
+        let mut request = client.get(url.clone())
+            .header(USER_AGENT, self.crawl_policy.next_user_agent());
+        if let Some(cookie) = self.cookies.cookies(url) {
+            request = request.header(COOKIE, cookie);
+        }
+        let permit = self.crawl_policy.acquire(url);
+        let response = send_with_retry(TARGET_KYOBO, request)
+            .map_err(|err| ParsingError::RequestFailed(format!("URL: {}, ERROR: {:?}", url, err)))?;
+        drop(permit);
+
+        self.cookies.set_cookies(&mut response.headers().get_all(SET_COOKIE).iter(), url);
+        Ok(read_body(TARGET_KYOBO, response)?)
+    }
+
+    /// 로그인 제공자의 쿠키를 세션의 쿠키 저장소에 추가한다. 이미 추가한 경우 다시 가져오지 않는다.
+    fn load_login_cookies(&self) -> Result<(), ParsingError> {
+        if self.logged_in.get() {
+            return Ok(());
+        }
+        if let Some(login_provider) = self.login_provider.as_ref() {
+            let domain = KYOBO_DOMAIN.parse().unwrap();
+            for cookie in login_provider.get_cookies()? {
+                self.cookies.add_cookie_str(cookie.as_ref(), &domain);
+            }
+        }
+        self.logged_in.set(true);
+        Ok(())
+    }
+}
@@ -73,8 +73,11 @@ pub fn retrieve_price(doc: &Html) -> (Option<usize>, Option<usize>) {
         let parent = e.parent_element().unwrap();
         let value = e.text().collect::<String>();
 
+        // 로그인 하지 않은 경우 회원 전용 가격은 숫자 대신 안내 문구로 표시 되므로 숫자가 아닌 값은 무시한다.
         let clean = regex.replace_all(&value, "");
-        let value = clean.parse::<usize>().unwrap();
+        let Ok(value) = clean.parse::<usize>() else {
+            continue;
+        };
 
         if parent.has_class(&sale_price_css, CaseSensitivity::CaseSensitive) {
             sale_price = value;
@@ -110,3 +113,20 @@ pub fn retrieve_author(doc: &Html) -> Option<String> {
         None
     }
 }
+
+/// 상품의 판매 상태(품절, 절판, 예약판매 등) 문구를 가져온다.
+/// 정상 판매중인 상품은 판매 상태 문구가 표시 되지 않으므로 빈 문자열을 반환한다.
+pub fn retrieve_sale_status(doc: &Html) -> Option<String> {
+    let price_selector = Selector::parse(".prod_price_box").unwrap();
+    if doc.select(&price_selector).next().is_none() {
+        return None;
+    }
+
+    let selector = Selector::parse(".prod_price_box .prod_sale_status, .prod_info_wrap .badge_sale_status").unwrap();
+    let status = doc.select(&selector)
+        .map(|e| e.text().collect::<String>().trim().to_owned())
+        .find(|text| !text.is_empty())
+        .unwrap_or_default();
+
+    Some(status)
+}
@@ -0,0 +1,248 @@
+use crate::provider::html::ParsingError;
+use crate::provider::http::{read_target_env, shared_client};
+use regex::Regex;
+use reqwest::Url;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+/// robots.txt에서 우리 크롤러를 식별할 기본 이름 (설정 하지 않을 경우 `*` 그룹의 규칙만 따른다.)
+const DEFAULT_ROBOTS_AGENT: &str = "*";
+
+/// robots.txt의 경로 규칙
+#[derive(Debug, Clone)]
+struct RobotsRule {
+    allow: bool,
+    pattern: String,
+    regex: Regex,
+}
+
+/// robots.txt의 User-agent 그룹
+#[derive(Debug, Clone, Default)]
+struct RobotsGroup {
+    agents: Vec<String>,
+    rules: Vec<RobotsRule>,
+}
+
+/// robots.txt 규칙
+///
+/// # Description
+/// robots.txt의 `User-agent`, `Allow`, `Disallow` 항목을 읽어 경로의 크롤링 허용 여부를 판단한다.
+/// 크롤러 이름과 일치하는 그룹이 있을 경우 해당 그룹의 규칙을, 없을 경우 `*` 그룹의 규칙을 사용하며
+/// 경로와 일치하는 규칙 중 가장 긴 규칙을 따른다. 길이가 같을 경우 `Allow` 규칙을 우선한다.
+///
+/// # Note
+/// 경로 패턴의 `*`(임의의 문자열)과 `$`(경로의 끝) 표기를 지원하며, `Crawl-delay`, `Sitemap` 등 그 외의 항목은 무시한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::provider::html::robots::RobotsRules;
+///
+/// let rules = RobotsRules::parse("
+/// User-agent: *
+/// Disallow: /private/
+/// Allow: /private/public
+/// Disallow: /*.laf$
+///
+/// User-agent: book-batch
+/// Disallow: /
+/// ");
+///
+/// assert!(rules.is_allowed("*", "/product/detail"));
+/// assert!(!rules.is_allowed("*", "/private/secret"));
+/// assert!(rules.is_allowed("*", "/private/public/page"));
+/// assert!(!rules.is_allowed("*", "/product/detailViewKor.laf"));
+/// assert!(rules.is_allowed("*", "/product/detailViewKor.laf?barcode=9791136202093"));
+/// assert!(!rules.is_allowed("book-batch", "/product/detail"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RobotsRules {
+    groups: Vec<RobotsGroup>,
+}
+
+impl RobotsRules {
+    /// 모든 경로를 허용하는 규칙 (robots.txt가 없는 경우)
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// 모든 경로를 허용하지 않는 규칙 (robots.txt를 확인할 수 없는 경우)
+    pub fn disallow_all() -> Self {
+        Self::parse("User-agent: *\nDisallow: /")
+    }
+
+    /// robots.txt 본문을 읽어 규칙으로 변환한다.
+    pub fn parse(text: &str) -> Self {
+        let mut groups: Vec<RobotsGroup> = Vec::new();
+        let mut current: Option<RobotsGroup> = None;
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim();
+
+            match key.as_str() {
+                "user-agent" => {
+                    // 규칙이 나온 후의 User-agent는 새로운 그룹의 시작이다.
+                    if current.as_ref().is_some_and(|g| !g.rules.is_empty()) {
+                        groups.extend(current.take());
+                    }
+                    current.get_or_insert_with(RobotsGroup::default)
+                        .agents.push(value.to_lowercase());
+                }
+                "allow" | "disallow" if !value.is_empty() => {
+                    if let (Some(group), Some(regex)) = (current.as_mut(), pattern_to_regex(value)) {
+                        group.rules.push(RobotsRule { allow: key == "allow", pattern: value.to_owned(), regex });
+                    }
+                }
+                _ => {}
+            }
+        }
+        groups.extend(current);
+
+        Self { groups }
+    }
+
+    /// 크롤러 이름으로 경로(쿼리 포함)의 크롤링 허용 여부를 반환한다.
+    pub fn is_allowed(&self, agent: &str, path: &str) -> bool {
+        let agent = agent.to_lowercase();
+        let group = self.groups.iter()
+            .find(|g| agent != "*" && g.agents.iter().any(|a| a != "*" && agent.contains(a.as_str())))
+            .or_else(|| self.groups.iter().find(|g| g.agents.iter().any(|a| a == "*")));
+
+        let Some(group) = group else {
+            return true;
+        };
+
+        group.rules.iter()
+            .filter(|rule| rule.regex.is_match(path))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .map(|rule| rule.allow)
+            .unwrap_or(true)
+    }
+}
+
+/// robots.txt 확인 게이트
+///
+/// # Description
+/// HTML 제공 사이트에 요청을 보내기 전 해당 사이트의 robots.txt를 확인하여 허용 되지 않은 경로의 요청을 거부한다.
+/// robots.txt는 사이트(스킴, 호스트, 포트)별로 한번만 요청하여 캐시한다.
+/// 대상(교보문고 등)별 설정(`{대상}_{이름}`)이 없을 경우 전체 설정을 사용한다.
+/// - `ROBOTS_AGENT`: robots.txt에서 우리 크롤러를 식별할 이름 (기본값 `*`)
+/// - `ROBOTS_OVERRIDE`: `true`로 설정 하면 허용 되지 않은 경로도 경고 로그를 남기고 요청한다.
+///
+/// # Note
+/// robots.txt가 없는 경우(4xx)는 모든 경로를 허용하며, 서버 에러(5xx)나 요청 실패로 robots.txt를 확인할 수 없는 경우는
+/// 모든 경로를 허용하지 않는다. 확인할 수 없었던 robots.txt는 캐시하지 않고 다음 요청에서 다시 확인한다.
+pub struct RobotsGate {
+    target: String,
+    agent: String,
+    overridden: bool,
+    cache: Mutex<HashMap<String, RobotsRules>>,
+}
+
+impl RobotsGate {
+    pub fn new(target: &str, agent: &str, overridden: bool) -> Self {
+        Self {
+            target: target.to_owned(),
+            agent: agent.to_owned(),
+            overridden,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 환경 변수에서 대상의 robots.txt 확인 설정을 읽는다.
+    pub fn from_env(target: &str) -> Self {
+        let agent = read_target_env(target, "ROBOTS_AGENT")
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_ROBOTS_AGENT.to_owned());
+        let overridden = read_target_env(target, "ROBOTS_OVERRIDE")
+            .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"));
+
+        if overridden {
+            warn!("{} => robots.txt override is enabled, disallowed paths will be crawled", target);
+        }
+        Self::new(target, &agent, overridden)
+    }
+
+    /// URL의 크롤링 허용 여부를 확인한다. 허용 되지 않은 경우 [`ParsingError::Disallowed`]를 반환한다.
+    pub fn check(&self, url: &Url) -> Result<(), ParsingError> {
+        let rules = self.load_rules(url);
+
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_owned(),
+        };
+        if rules.is_allowed(&self.agent, &path) {
+            return Ok(());
+        }
+
+        if self.overridden {
+            warn!("{} => Disallowed by robots.txt but crawled by override: {}", self.target, url);
+            Ok(())
+        } else {
+            warn!("{} => Disallowed by robots.txt: {}", self.target, url);
+            Err(ParsingError::Disallowed(url.to_string()))
+        }
+    }
+
+    fn load_rules(&self, url: &Url) -> RobotsRules {
+        let origin = url.origin().ascii_serialization();
+        if let Some(rules) = self.cache.lock().unwrap().get(&origin) {
+            return rules.clone();
+        }
+
+        match self.fetch_rules(&origin) {
+            Some(rules) => {
+                self.cache.lock().unwrap().insert(origin, rules.clone());
+                rules
+            }
+            None => RobotsRules::disallow_all(),
+        }
+    }
+
+    /// robots.txt를 요청한다. robots.txt를 확인할 수 없는 경우 `None`을 반환한다.
+    fn fetch_rules(&self, origin: &str) -> Option<RobotsRules> {
+        let url = format!("{}/robots.txt", origin);
+        let response = shared_client(&self.target).ok()?
+            .get(&url)
+            .send();
+
+        match response {
+            Ok(response) if response.status().is_success() => {
+                info!("{} => robots.txt loaded: {}", self.target, url);
+                response.text().ok().map(|text| RobotsRules::parse(&text))
+            }
+            Ok(response) if response.status().is_client_error() => {
+                info!("{} => robots.txt is not found({}), all paths are allowed: {}", self.target, response.status(), url);
+                Some(RobotsRules::allow_all())
+            }
+            Ok(response) => {
+                warn!("{} => Failed to load robots.txt({}): {}", self.target, response.status(), url);
+                None
+            }
+            Err(err) => {
+                warn!("{} => Failed to load robots.txt({}): {}", self.target, err, url);
+                None
+            }
+        }
+    }
+}
+
+/// robots.txt의 경로 패턴을 정규식으로 변환한다.
+fn pattern_to_regex(pattern: &str) -> Option<Regex> {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+
+    let escaped = pattern.split('*')
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(".*");
+    let regex = if anchored { format!("^{}$", escaped) } else { format!("^{}", escaped) };
+    Regex::new(&regex).ok()
+}
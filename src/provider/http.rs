@@ -0,0 +1,329 @@
+use crate::configs::proxy::ProxyConfig;
+use reqwest::blocking::{Client, ClientBuilder, RequestBuilder, Response};
+use reqwest::Certificate;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use std::{env, fmt, fs, io, process, thread};
+use tracing::warn;
+
+/// 알라딘 API 요청 대상
+pub const TARGET_ALADIN: &str = "ALADIN";
+/// 네이버 API 요청 대상
+pub const TARGET_NAVER: &str = "NAVER";
+/// 국립중앙도서관 API 요청 대상
+pub const TARGET_NLGO: &str = "NLGO";
+/// 교보문고 요청 대상 (크롬 브라우저 포함)
+pub const TARGET_KYOBO: &str = "KYOBO";
+/// 브릿지 API 서버 요청 대상
+pub const TARGET_BRIDGE: &str = "BRIDGE";
+
+/// 요청의 기본 타임아웃 시간(초)
+const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+/// 연결의 기본 타임아웃 시간(초)
+const DEFAULT_CONNECT_TIMEOUT_SECONDS: u64 = 5;
+/// 호스트당 유지할 기본 유휴 커넥션 수
+const DEFAULT_POOL_MAX_IDLE: u64 = 8;
+/// 유휴 커넥션을 유지할 시간(초)
+const DEFAULT_POOL_IDLE_TIMEOUT_SECONDS: u64 = 90;
+/// 요청 실패시 기본 재시도 횟수
+const DEFAULT_RETRY: u64 = 2;
+/// 재시도 대기 시간의 기준값(밀리초)
+const RETRY_BASE_DELAY_MILLIS: u64 = 500;
+
+/// 응답 본문의 기본 최대 크기(바이트, 10MiB)
+const DEFAULT_MAX_RESPONSE_BYTES: u64 = 10 * 1024 * 1024;
+/// 임시 파일로 받는 응답 본문의 기본 최대 크기(바이트, 200MiB)
+const DEFAULT_MAX_FILE_RESPONSE_BYTES: u64 = 200 * 1024 * 1024;
+
+/// 대상별로 생성된 공유 클라이언트
+static CLIENTS: OnceLock<Mutex<HashMap<String, Client>>> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HttpClientError {
+    InvalidProxy(String),
+    InvalidCertificate(String),
+    BuildFailed(String),
+}
+
+impl fmt::Display for HttpClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// 응답 본문을 읽는 중 발생한 에러
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseBodyError {
+    /// 응답 본문이 허용된 최대 크기를 초과함 (`size`는 `Content-Length`로 알 수 있는 경우에만 설정된다.)
+    TooLarge { limit: u64, size: Option<u64> },
+    ReadFailed(String),
+}
+
+impl fmt::Display for ResponseBodyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResponseBodyError::TooLarge { limit, size: Some(size) } =>
+                write!(f, "response body is too large: {} bytes (limit: {} bytes)", size, limit),
+            ResponseBodyError::TooLarge { limit, size: None } =>
+                write!(f, "response body is too large: exceeds {} bytes", limit),
+            ResponseBodyError::ReadFailed(message) => write!(f, "failed to read response body: {}", message),
+        }
+    }
+}
+
+/// 외부 요청에 사용할 TLS 설정
+///
+/// # Description
+/// 대상(알라딘, 브릿지 등)별로 신뢰할 CA 인증서를 환경 변수에서 읽는다. 대상별 설정이 없을 경우 전체 설정을 사용한다.
+/// - `TLS_CA_BUNDLE`, `{대상}_TLS_CA_BUNDLE`: 추가로 신뢰할 CA 인증서 번들(PEM) 파일 경로
+/// - `TLS_BUILT_IN_ROOTS`, `{대상}_TLS_BUILT_IN_ROOTS`: 시스템(내장) 루트 인증서 사용 여부 (기본값 `true`)
+///
+/// # Note
+/// 사설 CA를 사용하는 내부 브릿지 서버 등은 `BRIDGE_TLS_CA_BUNDLE`에 CA 인증서를 지정하고
+/// `BRIDGE_TLS_BUILT_IN_ROOTS=false`로 설정 하여 해당 CA로 발급된 인증서만 신뢰하도록 할 수 있다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsConfig {
+    /// 추가로 신뢰할 CA 인증서 번들(PEM) 파일 경로
+    pub ca_bundle: Option<String>,
+    /// 시스템(내장) 루트 인증서 사용 여부
+    pub built_in_roots: bool,
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self { ca_bundle: None, built_in_roots: true }
+    }
+}
+
+impl TlsConfig {
+    /// 환경 변수에서 대상의 TLS 설정을 읽는다.
+    pub fn from_env(target: &str) -> Result<Self, HttpClientError> {
+        let ca_bundle = read_target_env(target, "TLS_CA_BUNDLE")
+            .filter(|v| !v.trim().is_empty());
+        let built_in_roots = match read_target_env(target, "TLS_BUILT_IN_ROOTS") {
+            Some(v) => v.trim().parse::<bool>()
+                .map_err(|_| HttpClientError::InvalidCertificate(format!("TLS_BUILT_IN_ROOTS must be true or false: {}", v)))?,
+            None => true,
+        };
+
+        Ok(Self { ca_bundle, built_in_roots })
+    }
+
+    /// TLS 설정을 reqwest 클라이언트 빌더에 적용한다.
+    pub fn apply(&self, builder: ClientBuilder) -> Result<ClientBuilder, HttpClientError> {
+        let mut builder = builder.tls_built_in_root_certs(self.built_in_roots);
+        if let Some(path) = &self.ca_bundle {
+            let pem = fs::read(path)
+                .map_err(|e| HttpClientError::InvalidCertificate(format!("{}: {}", path, e)))?;
+            let certificates = Certificate::from_pem_bundle(&pem)
+                .map_err(|e| HttpClientError::InvalidCertificate(format!("{}: {}", path, e)))?;
+            if certificates.is_empty() {
+                return Err(HttpClientError::InvalidCertificate(format!("{}: certificate is not found", path)));
+            }
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        Ok(builder)
+    }
+}
+
+/// 대상의 프록시, TLS 설정이 적용된 reqwest 클라이언트 빌더를 생성한다.
+///
+/// # Description
+/// 외부로 요청을 보내는 모든 클라이언트(도서 정보 제공 사이트, 브릿지 API 서버 등)는 이 함수로 빌더를 생성하여
+/// 타임아웃, 쿠키 등 클라이언트별 설정을 추가한 후 사용한다.
+pub fn new_client_builder(target: &str) -> Result<ClientBuilder, HttpClientError> {
+    let mut builder = Client::builder();
+    if let Some(proxy) = ProxyConfig::from_env(target) {
+        builder = proxy.apply(builder)
+            .map_err(|e| HttpClientError::InvalidProxy(e.to_string()))?;
+    }
+    TlsConfig::from_env(target)?.apply(builder)
+}
+
+/// 대상의 공유 reqwest 클라이언트를 반환한다.
+///
+/// # Description
+/// 대상별로 한번만 클라이언트를 생성하고 이후에는 생성된 클라이언트를 복제하여 반환하므로
+/// 같은 대상에 대한 요청은 하나의 커넥션 풀을 공유하여 TLS 핸드셰이크 등 연결 비용을 줄일 수 있다.
+/// 클라이언트에는 프록시, TLS 설정과 함께 아래 환경 변수의 설정이 적용된다. 대상별 설정(`{대상}_{이름}`)이 없을 경우 전체 설정을 사용한다.
+/// - `HTTP_TIMEOUT`: 요청 타임아웃 (단위는 초(s), 기본값 10)
+/// - `HTTP_CONNECT_TIMEOUT`: 연결 타임아웃 (단위는 초(s), 기본값 5)
+/// - `HTTP_USER_AGENT`: 요청에 사용할 User-Agent
+/// - `HTTP_POOL_MAX_IDLE`: 호스트당 유지할 최대 유휴 커넥션 수 (기본값 8)
+///
+/// # Note
+/// reqwest 클라이언트는 내부적으로 `Arc`로 상태를 공유 하므로 복제 비용이 적고 복제된 클라이언트들은 같은 커넥션 풀을 사용한다.
+/// 요청마다 다른 타임아웃이 필요한 경우 [`reqwest::blocking::RequestBuilder::timeout`]을 사용한다.
+pub fn shared_client(target: &str) -> Result<Client, HttpClientError> {
+    let clients = CLIENTS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut clients = clients.lock()
+        .map_err(|e| HttpClientError::BuildFailed(e.to_string()))?;
+
+    if let Some(client) = clients.get(target) {
+        return Ok(client.clone());
+    }
+
+    let mut builder = new_client_builder(target)?
+        .timeout(Duration::from_secs(read_target_env_number(target, "HTTP_TIMEOUT", DEFAULT_TIMEOUT_SECONDS)))
+        .connect_timeout(Duration::from_secs(read_target_env_number(target, "HTTP_CONNECT_TIMEOUT", DEFAULT_CONNECT_TIMEOUT_SECONDS)))
+        .pool_max_idle_per_host(read_target_env_number(target, "HTTP_POOL_MAX_IDLE", DEFAULT_POOL_MAX_IDLE) as usize)
+        .pool_idle_timeout(Duration::from_secs(DEFAULT_POOL_IDLE_TIMEOUT_SECONDS));
+    if let Some(user_agent) = read_target_env(target, "HTTP_USER_AGENT") {
+        builder = builder.user_agent(user_agent);
+    }
+
+    let client = builder.build()
+        .map_err(|e| HttpClientError::BuildFailed(e.to_string()))?;
+    clients.insert(target.to_owned(), client.clone());
+    Ok(client)
+}
+
+/// 요청을 전송하고 연결 실패, 타임아웃, 서버 에러(5xx)가 발생한 경우 재시도 한다.
+///
+/// # Description
+/// 대상의 `HTTP_RETRY` 환경 변수(기본값 2)만큼 재시도 하며, 재시도 사이의 대기 시간은 0.5초 부터 두배씩 늘어난다.
+/// 재시도 횟수를 모두 사용한 경우 마지막 응답 혹은 에러를 그대로 반환한다.
+///
+/// # Note
+/// 본문을 복제할 수 없는 요청(스트림 본문 등)은 재시도 하지 않는다. 조회(GET) 처럼 여러번 전송해도 안전한 요청에만 사용한다.
+pub fn send_with_retry(target: &str, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+    let retry = read_target_env_number(target, "HTTP_RETRY", DEFAULT_RETRY);
+
+    let mut attempt = 0;
+    loop {
+        let current = match request.try_clone() {
+            Some(current) if attempt < retry => current,
+            _ => return request.send(),
+        };
+
+        match current.send() {
+            Ok(response) if response.status().is_server_error() => {
+                warn!("{} => Server error({}), retry {}/{}", target, response.status(), attempt + 1, retry);
+            }
+            Err(err) if err.is_connect() || err.is_timeout() => {
+                warn!("{} => Request failed({}), retry {}/{}", target, err, attempt + 1, retry);
+            }
+            result => return result,
+        }
+
+        thread::sleep(Duration::from_millis(RETRY_BASE_DELAY_MILLIS << attempt));
+        attempt += 1;
+    }
+}
+
+/// 응답 본문을 최대 크기 이내에서 문자열로 읽는다.
+///
+/// # Description
+/// 최대 크기는 대상의 `HTTP_MAX_RESPONSE_BYTES` 환경 변수(기본값 10MiB)로 설정한다.
+/// `Content-Length`가 최대 크기를 초과할 경우 본문을 읽지 않고 바로 에러를 반환하며,
+/// `Content-Length`가 없는 응답(chunked 등)은 최대 크기 까지만 읽고 초과분이 있을 경우 에러를 반환한다.
+pub fn read_body(target: &str, response: Response) -> Result<String, ResponseBodyError> {
+    let limit = read_target_env_number(target, "HTTP_MAX_RESPONSE_BYTES", DEFAULT_MAX_RESPONSE_BYTES);
+    check_content_length(limit, &response)?;
+
+    let mut buffer = Vec::new();
+    response.take(limit + 1).read_to_end(&mut buffer)
+        .map_err(|e| ResponseBodyError::ReadFailed(e.to_string()))?;
+    if buffer.len() as u64 > limit {
+        return Err(ResponseBodyError::TooLarge { limit, size: None });
+    }
+
+    String::from_utf8(buffer)
+        .map_err(|e| ResponseBodyError::ReadFailed(e.to_string()))
+}
+
+/// 응답 본문을 임시 파일에 저장하고 저장된 본문을 반환한다.
+///
+/// # Description
+/// 국립중앙도서관의 대량 페이지 처럼 크기가 큰 응답을 메모리에 모두 올리지 않고 파싱하기 위해 사용한다.
+/// 최대 크기는 대상의 `HTTP_MAX_FILE_RESPONSE_BYTES` 환경 변수(기본값 200MiB)로 설정하며 크기 검사 방식은 [`read_body`]와 같다.
+/// 임시 파일은 `TMPDIR`(기본 임시 디렉토리) 하위에 생성 되며 반환된 [`TempBody`]가 해제될 때 삭제된다.
+pub fn read_body_to_file(target: &str, response: Response) -> Result<TempBody, ResponseBodyError> {
+    let limit = read_target_env_number(target, "HTTP_MAX_FILE_RESPONSE_BYTES", DEFAULT_MAX_FILE_RESPONSE_BYTES);
+    check_content_length(limit, &response)?;
+
+    let mut body = TempBody::create(target)
+        .map_err(|e| ResponseBodyError::ReadFailed(e.to_string()))?;
+    let written = io::copy(&mut response.take(limit + 1), &mut body.file)
+        .map_err(|e| ResponseBodyError::ReadFailed(e.to_string()))?;
+    if written > limit {
+        return Err(ResponseBodyError::TooLarge { limit, size: None });
+    }
+
+    body.file.seek(SeekFrom::Start(0))
+        .map_err(|e| ResponseBodyError::ReadFailed(e.to_string()))?;
+    Ok(body)
+}
+
+fn check_content_length(limit: u64, response: &Response) -> Result<(), ResponseBodyError> {
+    match response.content_length() {
+        Some(size) if size > limit => Err(ResponseBodyError::TooLarge { limit, size: Some(size) }),
+        _ => Ok(()),
+    }
+}
+
+/// 임시 파일에 저장된 응답 본문
+///
+/// # Description
+/// [`TempBody::into_reader`]로 본문을 읽을 수 있으며 값이 해제될 때 임시 파일을 삭제한다.
+pub struct TempBody {
+    path: PathBuf,
+    file: File,
+}
+
+impl TempBody {
+    fn create(target: &str) -> io::Result<Self> {
+        static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+        let name = format!("book-batch-{}-{}-{}.body",
+            target.to_lowercase(), process::id(), SEQUENCE.fetch_add(1, Ordering::Relaxed));
+        let path = env::temp_dir().join(name);
+        let file = File::options().read(true).write(true).create_new(true).open(&path)?;
+        Ok(Self { path, file })
+    }
+
+    /// 본문이 저장된 임시 파일 경로
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 저장된 본문을 읽는 버퍼 리더를 반환한다.
+    pub fn into_reader(self) -> BufReader<TempBody> {
+        BufReader::new(self)
+    }
+}
+
+impl Read for TempBody {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.file.read(buf)
+    }
+}
+
+impl Drop for TempBody {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            warn!("Failed to remove temporary response file({}): {}", self.path.display(), e);
+        }
+    }
+}
+
+/// 대상별 환경 변수(`{대상}_{이름}`)를 우선 읽고 없을 경우 전체 환경 변수(`{이름}`)를 읽는다.
+pub(crate) fn read_target_env(target: &str, name: &str) -> Option<String> {
+    env::var(format!("{}_{}", target, name))
+        .or_else(|_| env::var(name))
+        .ok()
+}
+
+/// 대상별 환경 변수를 숫자로 읽는다. 환경 변수가 없거나 숫자가 아닐 경우 기본값을 반환한다.
+fn read_target_env_number(target: &str, name: &str, default: u64) -> u64 {
+    read_target_env(target, name)
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(default)
+}
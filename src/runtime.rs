@@ -0,0 +1,976 @@
+//! 배치 실행 환경
+//!
+//! 설정([`Config`])으로 데이터베이스에 연결하고 잡 이름([`JobName`])과 파라미터로 잡을 실행한다.
+//! 배치 바이너리(`main.rs`)도 이 모듈을 사용하므로, 다른 서비스에서 수집 잡을 실행할 때 저장소, 사이트 클라이언트, 프롬프트 구성을 복사할 필요 없이 [`Runtime`]을 사용한다.
+//!
+//! # Example
+//! ```no_run
+//! use book_batch_rust::configs::Config;
+//! use book_batch_rust::runtime::Runtime;
+//! use book_batch_rust::{JobName, PARAM_NAME_PUBLISHER_ID};
+//! use std::collections::HashMap;
+//!
+//! let config = Config::load().unwrap();
+//! let runtime = Runtime::connect(config, None).unwrap();
+//!
+//! let parameter = HashMap::from([(PARAM_NAME_PUBLISHER_ID.to_owned(), "1".to_owned())]);
+//! match runtime.execute(JobName::NLGO, &parameter) {
+//!     Ok(status) => println!("{:?}", status),
+//!     Err(e) => eprintln!("{}", e),
+//! }
+//! ```
+use crate::batch::cancel::CancellationToken;
+use crate::batch::error::{JobBuildError, JobRuntimeError};
+use crate::batch::{Job, JobParameter, JobResult};
+use crate::clock::{system_clock, SharedClock};
+use crate::configs::{Config, Profile};
+use crate::item::offline::{EmptyBookRepository, EmptyFilterRepository, EmptyQuotaRepository, ParameterPublisherRepository};
+use crate::item::readonly::{ReadOnlyBookRepository, ReadOnlyQuotaRepository, ReadOnlyRetryRepository, ReadOnlySeriesRepository};
+use crate::item::repo::{ComposeBookRepository, MongoOriginStore, DieselAvailabilityRepository, DieselBackfillRepository, DieselCheckpointRepository, DieselCollectionStatusRepository, DieselDeadLetterRepository, DieselFilterRepository, DieselIsbnSetRepository, DieselJobExecutionRepository, DieselPublisherRepository, DieselQuotaRepository, DieselRetryRepository, DieselSeriesOverrideRepository, DieselSeriesRepository, DieselTitleNormalizationRepository, DieselVolumeRepository, SharedIdGenerator, VectorSearch};
+use crate::item::{raw_utils, Book, Site};
+use crate::item::{JobStatus, SharedAvailabilityRepository, SharedBackfillRepository, SharedBookRepository, SharedCheckpointRepository, SharedCollectionStatusRepository, SharedDeadLetterRepository, SharedFilterRepository, SharedIsbnSetRepository, SharedJobExecutionRepository, SharedPublisherRepository, SharedQuotaRepository, SharedRetryRepository, SharedSeriesOverrideRepository, SharedSeriesRepository, SharedTitleNormalizationRepository, SharedVolumeRepository};
+use crate::prompt::bridge::BridgeClient;
+use crate::prompt::SharedPrompt;
+use crate::provider::api::{aladin, naver, nlgo, LookupClient};
+use crate::provider::html;
+use crate::provider::html::kyobo;
+use crate::provider::http::{TARGET_ALADIN, TARGET_KYOBO, TARGET_NAVER, TARGET_NLGO};
+use crate::{batch, configs, spec, ArgumentError, JobName, PARAM_NAME_INPUT, PARAM_NAME_KEYWORD, PARAM_NAME_NO_LOGIN, PARAM_NAME_OUTPUT, PARAM_NAME_REPLAY_CHUNK, PARAM_NAME_RESUME};
+use diesel::r2d2::ConnectionManager;
+use diesel::PgConnection;
+use r2d2::Pool;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::env::VarError;
+use std::fmt;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::rc::Rc;
+
+/// 배치 실행 환경
+///
+/// # Description
+/// 설정과 프로필로 PostgreSQL(읽기 전용 복제본 포함), MongoDB(원본 데이터 저장소로 설정한 경우)에 연결하고,
+/// 잡 이름으로 저장소, 사이트 클라이언트, 프롬프트를 구성하여 잡을 실행한다.
+/// 실행 환경은 여러 잡을 실행하는 동안 같은 연결 풀과 시계, 취소 토큰을 공유한다.
+///
+/// # Errors
+/// 잡에 필요한 인증 정보가 없거나 잡 구성, 실행에 실패한 경우 [`RuntimeError`]를 반환한다. 프로세스 종료 여부는 호출하는 쪽에서 정한다.
+pub struct Runtime {
+    config: Config,
+    profile: Option<Profile>,
+
+    /// PostgreSQL 연결 풀, 데이터베이스 없이 실행하는 경우([`Runtime::offline`]) `None`
+    connection: Option<Pool<ConnectionManager<PgConnection>>>,
+    databases: BookDatabases,
+    cancel: CancellationToken,
+}
+
+impl Runtime {
+    /// 설정과 프로필(선택한 경우)로 데이터베이스에 연결한다. 프로필은 [`Config::select_profile`]로 선택한다.
+    ///
+    /// # Errors
+    /// 연결 주소가 설정 되지 않았거나 PostgreSQL(읽기 전용 복제본 포함), MongoDB(원본 데이터 저장소로 설정한 경우)에 연결할 수 없는 경우 [`RuntimeError::DatabaseUnavailable`]을 반환한다.
+    pub fn connect(config: Config, profile: Option<Profile>) -> Result<Self, RuntimeError> {
+        let connection = configs::connect_to_postgres(profile.as_ref())
+            .map_err(RuntimeError::DatabaseUnavailable)?;
+        let replica = configs::connect_to_replica(profile.as_ref())
+            .map_err(RuntimeError::DatabaseUnavailable)?;
+        let mongo = if config.origin_store == configs::OriginStoreKind::Mongo {
+            Some(configs::connect_to_mongo(profile.as_ref()).map_err(RuntimeError::DatabaseUnavailable)?)
+        } else {
+            None
+        };
+        let databases = BookDatabases {
+            replica,
+            mongo,
+            clock: system_clock(),
+            vector_search: config.vector_search,
+            ids: config.id_strategy.generator(),
+        };
+        Ok(Self { config, profile, connection: Some(connection), databases, cancel: CancellationToken::new() })
+    }
+
+    /// 데이터베이스에 연결하지 않는 실행 환경을 만든다.
+    ///
+    /// # Description
+    /// 파일로 출력하는 수집 잡([`is_offline_run`])만 실행할 수 있으며, 출판사는 `keyword` 파라미터로 만들고 필터 규칙과 요청 한도는 사용하지 않는다.
+    /// 실행 기록, 수집 현황, 변경 내역을 남기지 않으며 후속 잡도 실행하지 않는다. 그 외의 잡은 [`RuntimeError::InvalidParameter`]를 반환한다.
+    pub fn offline(config: Config) -> Self {
+        let databases = BookDatabases {
+            replica: None,
+            mongo: None,
+            clock: system_clock(),
+            vector_search: config.vector_search,
+            ids: config.id_strategy.generator(),
+        };
+        Self { config, profile: None, connection: None, databases, cancel: CancellationToken::new() }
+    }
+
+    /// 등록, 수정 시각과 실행 기록에 사용할 시계를 변경한다. (기본값: [`system_clock`])
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.databases.clock = clock;
+        self
+    }
+
+    /// 실행 중인 잡을 취소할 때 사용할 취소 토큰을 변경한다.
+    pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn clock(&self) -> &SharedClock {
+        &self.databases.clock
+    }
+
+    /// 실행 중인 잡을 취소할 때 사용하는 취소 토큰
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancel
+    }
+
+    /// 원본 데이터 저장소로 MongoDB를 사용하는 경우(`origin_store = mongo`) 원본 데이터 인덱스가 모두 생성 되어 있는지 확인하고, 없는 인덱스를 경고 로그로 남긴다.
+    /// 인덱스는 실행 환경이 연결한 MongoDB 클라이언트로 확인한다.
+    pub fn verify_origin_indexes(&self) {
+        if self.config.origin_store != configs::OriginStoreKind::Mongo {
+            return;
+        }
+        if let Some(client) = self.databases.mongo.as_ref() {
+            MongoOriginStore::new(client, &configs::migrate::mongo_database()).verify_indexes();
+        }
+    }
+
+    /// 데이터베이스 마이그레이션을 적용하고, MongoDB가 설정된 경우 원본 데이터 인덱스를 생성한다.
+    pub fn migrate(&self) -> Result<(), RuntimeError> {
+        migrate(self.connection(JobName::MIGRATE)?, self.profile.as_ref())
+    }
+
+    /// 잡을 실행하고 실행 기록과 변경 내역을 남긴다. 후속 잡이 설정된 경우 이어서 실행한다.
+    ///
+    /// # Description
+    /// `MIGRATE` 잡은 실행 기록 테이블이 없는 새 환경에서도 실행할 수 있어야 하므로 실행 기록을 남기지 않고 마이그레이션만 적용한다.
+    /// 오늘 같은 파라미터로 정상 종료된 실행이 있어 실행하지 않은 경우 `None`을 반환한다.
+    pub fn execute(&self, job: JobName, parameter: &JobParameter) -> Result<Option<JobStatus>, RuntimeError> {
+        if job == JobName::MIGRATE {
+            self.migrate()?;
+            return Ok(Some(JobStatus::Completed));
+        }
+        if self.connection.is_none() {
+            return run_offline(job, parameter, &self.config, &self.databases, &self.cancel).map(Some);
+        }
+        execute(job, parameter, &self.config, self.connection(job)?, &self.databases, &self.cancel)
+    }
+
+    /// 실행 기록, 변경 내역, 후속 잡 없이 잡만 실행하고 실행 결과 상태를 반환한다.
+    pub fn run_job(&self, job: JobName, parameter: &JobParameter) -> Result<JobStatus, RuntimeError> {
+        if job == JobName::MIGRATE {
+            self.migrate()?;
+            return Ok(JobStatus::Completed);
+        }
+        if self.connection.is_none() {
+            return run_offline(job, parameter, &self.config, &self.databases, &self.cancel);
+        }
+        let parameter = apply_kyobo_config(&self.config, parameter);
+        run_job(job, &parameter, &self.config, self.connection(job)?, &self.databases, &self.cancel)
+    }
+
+    /// 데이터베이스 연결 풀을 반환한다. 데이터베이스 없이 실행하는 경우 [`RuntimeError::InvalidParameter`]를 반환한다.
+    fn connection(&self, job: JobName) -> Result<&Pool<ConnectionManager<PgConnection>>, RuntimeError> {
+        self.connection.as_ref()
+            .ok_or_else(|| RuntimeError::InvalidParameter(format!("{:?} job requires a database connection", job)))
+    }
+}
+
+/// 잡 실행 환경의 에러
+#[derive(Debug)]
+pub enum RuntimeError {
+    /// 잡에 필요한 인증 정보(환경 변수)가 설정 되지 않음
+    MissingCredentials(String),
+
+    /// 잡이 지원하지 않거나 잘못 입력된 파라미터
+    InvalidParameter(String),
+
+    /// 사이트 클라이언트 생성 실패 (API 키, 로그인 정보 등의 환경 변수 누락)
+    ClientUnavailable(String),
+
+    /// 잡 구성 실패
+    JobBuildFailed(JobBuildError),
+
+    /// 잡 실행 실패 (취소 제외)
+    JobFailed(String),
+
+    /// 데이터베이스 마이그레이션 또는 MongoDB 인덱스 생성 실패
+    MigrationFailed(String),
+
+    /// 잡 결과 출력 실패 (PDF 변환 등)
+    OutputFailed(String),
+
+    /// 데이터베이스 연결 실패 (연결 주소 누락 포함)
+    DatabaseUnavailable(String),
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeError::MissingCredentials(message) => write!(f, "Missing credentials, {}", message),
+            RuntimeError::InvalidParameter(message) => write!(f, "Invalid parameter, {}", message),
+            RuntimeError::ClientUnavailable(message) => write!(f, "Client unavailable, {}", message),
+            RuntimeError::JobBuildFailed(e) => write!(f, "Job build failed, {}", e),
+            RuntimeError::JobFailed(message) => write!(f, "Job running failed, {}", message),
+            RuntimeError::MigrationFailed(message) => write!(f, "Migration failed, {}", message),
+            RuntimeError::OutputFailed(message) => write!(f, "Output failed, {}", message),
+            RuntimeError::DatabaseUnavailable(message) => write!(f, "Database unavailable, {}", message),
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
+
+impl From<JobBuildError> for RuntimeError {
+    fn from(e: JobBuildError) -> Self {
+        RuntimeError::JobBuildFailed(e)
+    }
+}
+
+impl From<ArgumentError> for RuntimeError {
+    fn from(e: ArgumentError) -> Self {
+        match e {
+            ArgumentError::InvalidCredentials(message) => RuntimeError::MissingCredentials(message),
+            ArgumentError::InvalidArgument(message) => RuntimeError::InvalidParameter(message),
+        }
+    }
+}
+
+/// 잡을 실행하고 실행 기록과 변경 내역을 남긴다.
+/// 실행 전 잡에 필요한 인증 정보(환경 변수)가 모두 설정 되어 있는지 확인하며, 없을 경우 누락된 환경 변수 이름과 함께 [`RuntimeError::MissingCredentials`]를 반환한다.
+/// `follow_up` 파라미터가 `true`일 경우 잡의 변경 내역으로 후속 잡의 파라미터를 만들어 이어서 실행한다.
+/// 정상 종료된 경우 출판사별 수집량을 최근 실행들과 비교하여 평균에서 크게 벗어난 경우 알린다.
+/// 오늘 같은 파라미터로 정상 종료된 실행이 있는 경우 `allow_duplicate` 파라미터가 `true`가 아니면 경고 로그를 남기고 실행하지 않는다.
+/// 실행 기록에는 잡이 읽고, 처리하고, 저장하고, 건너뛴 아이템 수를 함께 남긴다.
+/// `dry_run` 파라미터가 `true`인 경우 수집 현황과 신규 ISBN 묶음은 저장하지 않는다.
+/// 실행 중 취소된 경우 그때까지의 실행 기록과 변경 내역을 남기고 취소 상태로 종료하며, 후속 잡은 실행하지 않는다.
+/// 복구할 수 없는 에러로 실패한 경우 그때까지의 변경 내역(격리된 청크 파일 경로 포함)과 실패 상태를 실행 기록에 남긴 후 에러를 반환한다. (패닉인 경우 패닉을 다시 발생시킨다.)
+fn execute(job: JobName, parameter: &JobParameter, config: &configs::Config, connection: &Pool<ConnectionManager<PgConnection>>, databases: &BookDatabases, cancel: &CancellationToken) -> Result<Option<JobStatus>, RuntimeError> {
+    let parameter = &apply_kyobo_config(config, parameter);
+    let parameter = &config.publisher_profile.apply_to_parameter(parameter);
+    spec::check_credentials(&job, parameter)?;
+
+    let execution_repo = SharedJobExecutionRepository::new(Box::new(DieselJobExecutionRepository::new(connection.clone())));
+    let isbn_set_repo = SharedIsbnSetRepository::new(Box::new(DieselIsbnSetRepository::new(connection.clone()).with_clock(databases.clock.clone())));
+    let parameter = &batch::book::resolve_isbn_set_in_parameter(parameter, &isbn_set_repo)?;
+    let job_name = format!("{:?}", job);
+    let param_hash = batch::duplicate::parameter_hash(&job_name, parameter, &databases.clock.today());
+    if let Some(completed_id) = execution_repo.find_completed(&param_hash) {
+        if !batch::duplicate::is_duplicate_allowed(parameter) {
+            tracing::warn!("{} => Identical run already completed today (execution {}), skipped. Use --allow-duplicate to run again", job_name, completed_id);
+            return Ok(None);
+        }
+        tracing::warn!("{} => Identical run already completed today (execution {}), running again", job_name, completed_id);
+    }
+
+    let sorted_parameter = parameter.iter().collect::<BTreeMap<_, _>>();
+    let execution_id = execution_repo.start(
+        &job_name,
+        &serde_json::to_string(&sorted_parameter).unwrap_or_default(),
+        &param_hash,
+        &databases.clock.now(),
+    );
+
+    let status = match panic::catch_unwind(AssertUnwindSafe(|| run_job(job, parameter, config, connection, databases, cancel))) {
+        Ok(Ok(status)) => status,
+        Ok(Err(e)) => {
+            record_failure(&job_name, execution_id, &execution_repo, &databases.clock);
+            return Err(e);
+        }
+        Err(cause) => {
+            record_failure(&job_name, execution_id, &execution_repo, &databases.clock);
+            panic::resume_unwind(cause);
+        }
+    };
+    batch::metrics::log_query_stats(&job_name, &batch::metrics::take_query_stats());
+    let counts = batch::metrics::take_item_counts();
+    tracing::info!("{} => read {}, processed {}, written {}, skipped {}", job_name, counts.read, counts.processed, counts.written, counts.skipped);
+
+    let status_repo = SharedCollectionStatusRepository::new(Box::new(DieselCollectionStatusRepository::new(connection.clone())));
+    let collected = batch::status::take(databases.clock.now());
+    if !batch::is_dry_run(parameter) {
+        status_repo.save_status(&collected);
+    }
+
+    let mut audit = batch::audit::take();
+    if !audit.created_isbn.is_empty() && !batch::is_dry_run(parameter) {
+        let set_name = format!("{}:{}:new", job_name.to_lowercase(), databases.clock.today().format("%Y-%m-%d"));
+        isbn_set_repo.save_isbn(&set_name, &audit.created_isbn);
+        tracing::info!("{} => {} created isbn published to set {}", job_name, audit.created_isbn.len(), set_name);
+        audit.created_isbn_set = Some(set_name);
+    }
+    let audit_path = batch::audit::export(&audit, &job_name, execution_id);
+    if status == JobStatus::Completed && !batch::is_dry_run(parameter) {
+        let volume_repo = SharedVolumeRepository::new(Box::new(DieselVolumeRepository::new(connection.clone())));
+        let volumes = batch::volume::collect_volumes(&job_name, &collected, &audit, databases.clock.now());
+        batch::volume::check_and_record(&volume_repo, &volumes, &batch::volume::VolumePolicy::from_env());
+    }
+    if let Some(id) = execution_id {
+        execution_repo.finish(id, status, &databases.clock.now(), audit_path.as_deref(), &counts);
+    }
+    if status == JobStatus::Cancelled {
+        tracing::warn!("{} => Job cancelled, follow up jobs are skipped", job_name);
+        return Ok(Some(status));
+    }
+
+    if batch::follow_up::is_follow_up_enabled(parameter)? {
+        for follow_up in batch::follow_up::follow_ups(&job) {
+            if let Some(follow_up_parameter) = (follow_up.derive)(&audit, parameter) {
+                tracing::info!("{} => Follow up job {:?} triggered", job_name, follow_up.job);
+                execute(follow_up.job, &follow_up_parameter, config, connection, databases, cancel)?;
+            }
+        }
+    }
+    Ok(Some(status))
+}
+
+/// 실패한 잡의 변경 내역을 내보내고 실행 기록을 실패 상태로 종료한다. 수집 현황은 저장하지 않으며 아이템 수는 실패 전까지의 수를 기록한다.
+fn record_failure(job_name: &str, execution_id: Option<u64>, execution_repo: &SharedJobExecutionRepository, clock: &SharedClock) {
+    batch::metrics::log_query_stats(job_name, &batch::metrics::take_query_stats());
+    let counts = batch::metrics::take_item_counts();
+    batch::status::take(clock.now());
+
+    let audit = batch::audit::take();
+    let audit_path = batch::audit::export(&audit, job_name, execution_id);
+    if let Some(id) = execution_id {
+        execution_repo.finish(id, JobStatus::Failed, &clock.now(), audit_path.as_deref(), &counts);
+    }
+    tracing::error!("{} => Job failed, {} chunk(s) quarantined", job_name, audit.quarantined_files.len());
+}
+
+/// 잡을 실행하고 실행 결과 상태를 반환한다. 실행 전 아이템의 추적 아이디에 사용할 실행 아이디([`batch::trace::start_run`])를 만든다.
+/// `replay_chunk` 파라미터가 입력된 경우 수집하지 않고 격리 파일의 아이템을 다시 저장한다.
+fn run_job(job: JobName, parameter: &JobParameter, config: &configs::Config, connection: &Pool<ConnectionManager<PgConnection>>, databases: &BookDatabases, cancel: &CancellationToken) -> Result<JobStatus, RuntimeError> {
+    batch::trace::start_run(&format!("{:?}", job), &databases.clock.now());
+    if let Some(path) = parameter.get(PARAM_NAME_REPLAY_CHUNK) {
+        check_replay(job, path)?;
+    }
+    if batch::checkpoint::is_resume(parameter) && !batch::checkpoint::is_checkpoint_supported(&job) {
+        return Err(RuntimeError::InvalidParameter(format!("{:?} job does not support {}", job, PARAM_NAME_RESUME)));
+    }
+    let pub_repo = SharedPublisherRepository::new(Box::new(DieselPublisherRepository::new(connection.clone())));
+    let book_repo = inject::book_repo(SharedBookRepository::new(Box::new(databases.book_repo(ComposeBookRepository::with_origin(connection.clone())))));
+    let book_repo = guard_book_repo(book_repo, parameter);
+    let filter_repo = SharedFilterRepository::new(Box::new(DieselFilterRepository::new(connection.clone())));
+
+    match job {
+        JobName::ALADIN => {
+            let client = Rc::new(inject::client(client_from_env(aladin::Client::new_with_env(), TARGET_ALADIN)?, TARGET_ALADIN));
+            let job = batch::book::aladin::create_job(
+                client.clone(),
+                client.clone(),
+                pub_repo.clone(),
+                book_repo.clone(),
+                filter_repo.clone(),
+                guard_quota_repo(SharedQuotaRepository::new(Box::new(DieselQuotaRepository::new(connection.clone()))), parameter),
+                config.upsert_mode,
+                &config.publisher_profile,
+                parameter,
+            )?;
+            let job = with_dead_letter(job, JobName::ALADIN, connection, databases);
+            let job = with_dry_run(job, JobName::ALADIN, parameter);
+            if is_cancelled(run_or_replay(&job, parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::NAVER => {
+            let job = batch::book::naver::create_job(
+                Rc::new(inject::client(client_from_env(naver::Client::new_with_env(), TARGET_NAVER)?, TARGET_NAVER)),
+                book_repo.clone(),
+                guard_retry_repo(SharedRetryRepository::new(Box::new(DieselRetryRepository::new(connection.clone()))), parameter),
+                guard_quota_repo(SharedQuotaRepository::new(Box::new(DieselQuotaRepository::new(connection.clone()))), parameter),
+                config.upsert_mode,
+                &config.publisher_profile,
+                parameter,
+            )?;
+            let job = with_dead_letter(job, JobName::NAVER, connection, databases);
+            let job = with_dry_run(job, JobName::NAVER, parameter);
+            if is_cancelled(run_or_replay(&job, parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::NLGO => {
+            let job = batch::book::nlgo::create_job(
+                Rc::new(inject::client(client_from_env(nlgo::Client::new_with_env(), TARGET_NLGO)?, TARGET_NLGO)),
+                pub_repo.clone(),
+                book_repo.clone(),
+                filter_repo.clone(),
+                &config.publisher_profile,
+                parameter,
+            )?;
+            let job = with_dead_letter(job, JobName::NLGO, connection, databases);
+            let job = with_dry_run(job, JobName::NLGO, parameter);
+            if is_cancelled(run_or_replay(&job, parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::KYOBO => {
+            let retry_repo = guard_retry_repo(SharedRetryRepository::new(Box::new(DieselRetryRepository::new(connection.clone()))), parameter);
+            let result = if batch::book::kyobo::is_no_login(parameter) {
+                let job = batch::book::kyobo::create_job(
+                    Rc::new(inject::client(kyobo::Client::public(), TARGET_KYOBO)),
+                    book_repo.clone(),
+                    retry_repo,
+                    config.upsert_mode,
+                    &config.publisher_profile,
+                    parameter,
+                )?;
+                let job = with_dead_letter(job, JobName::KYOBO, connection, databases);
+                let job = with_dry_run(job, JobName::KYOBO, parameter);
+                let job = with_checkpoint(job, JobName::KYOBO, parameter, connection, databases);
+                run_or_replay(&job, parameter, cancel)
+            } else {
+                let job = batch::book::kyobo::create_job(
+                    Rc::new(inject::client(kyobo::Client::new(client_from_env(kyobo::chrome::new_provider(), TARGET_KYOBO)?), TARGET_KYOBO)),
+                    book_repo.clone(),
+                    retry_repo,
+                    config.upsert_mode,
+                    &config.publisher_profile,
+                    parameter,
+                )?;
+                let job = with_dead_letter(job, JobName::KYOBO, connection, databases);
+                let job = with_dry_run(job, JobName::KYOBO, parameter);
+                let job = with_checkpoint(job, JobName::KYOBO, parameter, connection, databases);
+                run_or_replay(&job, parameter, cancel)
+            };
+            if is_cancelled(result)? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::SERIES => {
+
+            let book_repo = databases.book_repo(ComposeBookRepository::read_only_origin(connection.clone()))
+                .with_origin_projection(raw_utils::origin_projection(&batch::series::SERIES_ORIGIN_KINDS));
+            let book_repo = inject::book_repo(SharedBookRepository::new(Box::new(book_repo)));
+            let book_repo = guard_book_repo(book_repo, parameter);
+            
+            let series_repo = SharedSeriesRepository::new(Box::new(databases.series_repo(DieselSeriesRepository::new(connection.clone()))));
+            let series_repo = guard_series_repo(series_repo, parameter);
+            let override_repo = SharedSeriesOverrideRepository::new(Box::new(DieselSeriesOverrideRepository::new(connection.clone())));
+            let prompt = inject::prompt(SharedPrompt::new(Box::new(BridgeClient::new(config.prompt.clone()))));
+
+            let job = batch::series::create_job(
+                book_repo.clone(),
+                series_repo.clone(),
+                override_repo.clone(),
+                prompt.clone(),
+                &config.series,
+                &config.toggles,
+                &config.publisher_profile,
+                parameter,
+            )?;
+            let job = with_dry_run(job, JobName::SERIES, parameter);
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::FETCH => {
+            let (api_clients, html_clients) = lookup_clients(parameter)?;
+            let job = batch::book::fetch::create_job(
+                api_clients,
+                html_clients,
+                book_repo.clone(),
+                config.upsert_mode,
+                &config.publisher_profile,
+                parameter,
+            )?;
+            let job = with_dead_letter(job, JobName::FETCH, connection, databases);
+            let job = with_dry_run(job, JobName::FETCH, parameter);
+            if is_cancelled(run_or_replay(&job, parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::PUB_DATE_RECHECK => {
+            let job = batch::book::recheck::create_job(
+                Rc::new(inject::client(client_from_env(nlgo::Client::new_with_env(), TARGET_NLGO)?, TARGET_NLGO)),
+                book_repo.clone(),
+                guard_retry_repo(SharedRetryRepository::new(Box::new(DieselRetryRepository::new(connection.clone()))), parameter),
+                parameter,
+            )?;
+            let job = with_dry_run(job, JobName::PUB_DATE_RECHECK, parameter);
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::SNAPSHOT => {
+            let (api_clients, html_clients) = lookup_clients(parameter)?;
+            let job = batch::snapshot::create_job(api_clients, html_clients, parameter)?;
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::NORMALIZE => {
+
+            let book_repo = databases.book_repo(ComposeBookRepository::read_only_origin(connection.clone()))
+                .with_origin_projection(raw_utils::origin_projection(&batch::normalize::NORMALIZE_ORIGIN_KINDS));
+            let book_repo = inject::book_repo(SharedBookRepository::new(Box::new(book_repo)));
+            let book_repo = guard_book_repo(book_repo, parameter);
+            let normalization_repo = SharedTitleNormalizationRepository::new(Box::new(DieselTitleNormalizationRepository::new(connection.clone())));
+            let prompt = inject::prompt(SharedPrompt::new(Box::new(BridgeClient::new(config.prompt.clone()))));
+
+            let job = batch::normalize::create_job(
+                book_repo.clone(),
+                normalization_repo.clone(),
+                prompt.clone(),
+                parameter,
+            )?;
+            let job = with_dry_run(job, JobName::NORMALIZE, parameter);
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::STOCK => {
+            let book_repo = databases.book_repo(ComposeBookRepository::read_only_origin(connection.clone()));
+            let book_repo = inject::book_repo(SharedBookRepository::new(Box::new(book_repo)));
+            let book_repo = guard_book_repo(book_repo, parameter);
+            let availability_repo = SharedAvailabilityRepository::new(Box::new(DieselAvailabilityRepository::new(connection.clone())));
+
+            let job = batch::availability::create_job(
+                book_repo.clone(),
+                availability_repo.clone(),
+                parameter,
+            )?;
+            let job = with_dry_run(job, JobName::STOCK, parameter);
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::SERIES_COVER => {
+            let book_repo = databases.book_repo(ComposeBookRepository::read_only_origin(connection.clone()))
+                .with_origin_projection(raw_utils::origin_projection(&batch::cover::COVER_ORIGIN_KINDS));
+            let book_repo = inject::book_repo(SharedBookRepository::new(Box::new(book_repo)));
+            let book_repo = guard_book_repo(book_repo, parameter);
+
+            let series_repo = SharedSeriesRepository::new(Box::new(databases.series_repo(DieselSeriesRepository::new(connection.clone()))));
+            let series_repo = guard_series_repo(series_repo, parameter);
+
+            let job = batch::cover::create_job(
+                book_repo.clone(),
+                series_repo.clone(),
+                parameter,
+            )?;
+            let job = with_dry_run(job, JobName::SERIES_COVER, parameter);
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::GLOSSARY => {
+            let book_repo = databases.book_repo(ComposeBookRepository::without_origin(connection.clone()));
+            let book_repo = inject::book_repo(SharedBookRepository::new(Box::new(book_repo)));
+            let series_repo = SharedSeriesRepository::new(Box::new(databases.series_repo(DieselSeriesRepository::new(connection.clone()))));
+
+            let job = batch::glossary::create_job(
+                book_repo.clone(),
+                series_repo.clone(),
+                pub_repo.clone(),
+                parameter,
+            )?;
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::PURGE => {
+            // 삭제 대상 목록에는 원본 데이터가 필요 없으며, 원본 데이터는 원본 데이터 사용 방식과 관계 없이 도서와 함께 삭제된다.
+            let book_repo = databases.book_repo(ComposeBookRepository::without_origin(connection.clone()));
+            let book_repo = inject::book_repo(SharedBookRepository::new(Box::new(book_repo)));
+            let book_repo = guard_book_repo(book_repo, parameter);
+
+            let series_repo = SharedSeriesRepository::new(Box::new(databases.series_repo(DieselSeriesRepository::new(connection.clone()))));
+            let series_repo = guard_series_repo(series_repo, parameter);
+
+            let job = batch::purge::create_job(
+                book_repo.clone(),
+                series_repo.clone(),
+                parameter,
+            )?;
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::REPORT => {
+            let job = batch::report::create_job(
+                book_repo.clone(),
+                pub_repo.clone(),
+                parameter,
+            )?;
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+
+            if batch::report::is_pdf_output(parameter) {
+                let pdf_path = parameter.get(PARAM_NAME_OUTPUT)
+                    .ok_or_else(|| RuntimeError::InvalidParameter(format!("{} is required for pdf report", PARAM_NAME_OUTPUT)))?;
+                let html_path = batch::report::html_path_for_pdf(pdf_path);
+                batch::report::export_pdf(&html_path, pdf_path).map_err(RuntimeError::OutputFailed)?;
+            }
+        }
+        JobName::BACKFILL => {
+            let job = batch::book::backfill::create_job(
+                Rc::new(inject::client(client_from_env(nlgo::Client::new_with_env(), TARGET_NLGO)?, TARGET_NLGO)),
+                pub_repo.clone(),
+                book_repo.clone(),
+                filter_repo.clone(),
+                SharedBackfillRepository::new(Box::new(DieselBackfillRepository::new(connection.clone()))),
+                &config.publisher_profile,
+                parameter,
+            )?.with_clock(databases.clock.clone());
+            if is_cancelled(job.run(parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+        JobName::STATUS => {
+            batch::status::print_status(
+                pub_repo.clone(),
+                SharedCollectionStatusRepository::new(Box::new(DieselCollectionStatusRepository::new(connection.clone()))),
+                parameter,
+            )?;
+        }
+        JobName::MIGRATE => unreachable!("MIGRATE job runs in Runtime::migrate without job execution record"),
+        JobName::KYOBO_SEARCH => {
+            let job = batch::book::kyobo_search::create_job(
+                Rc::new(inject::client(kyobo::search::SearchClient::new(), TARGET_KYOBO)),
+                pub_repo.clone(),
+                book_repo.clone(),
+                filter_repo.clone(),
+                &config.publisher_profile,
+                parameter,
+            )?;
+            let job = with_dead_letter(job, JobName::KYOBO_SEARCH, connection, databases);
+            let job = with_dry_run(job, JobName::KYOBO_SEARCH, parameter);
+            if is_cancelled(run_or_replay(&job, parameter, cancel))? {
+                return Ok(JobStatus::Cancelled);
+            }
+        }
+    };
+    Ok(JobStatus::Completed)
+}
+
+/// 데이터베이스 없이 실행할 수 있는 잡
+///
+/// 출판사 키워드로 사이트를 검색하여 수집하는 잡으로, 저장된 도서를 읽지 않으므로 파일로 출력할 경우 데이터베이스가 필요 없다.
+pub const OFFLINE_JOBS: [JobName; 3] = [JobName::ALADIN, JobName::NLGO, JobName::KYOBO_SEARCH];
+
+/// 잡을 데이터베이스 없이 실행할 수 있는지 여부
+/// 데이터베이스 없이 실행할 수 있는 잡([`OFFLINE_JOBS`])을 저장소 대신 파일로 출력(`output`)하는 경우 `true`를 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::batch::JobParameter;
+/// use book_batch_rust::runtime::is_offline_run;
+/// use book_batch_rust::JobName;
+///
+/// let parameter = JobParameter::from([("output".to_owned(), "books.jsonl".to_owned())]);
+/// assert!(is_offline_run(&JobName::NLGO, &parameter));
+/// assert!(!is_offline_run(&JobName::KYOBO, &parameter));
+/// assert!(!is_offline_run(&JobName::NLGO, &JobParameter::new()));
+/// ```
+pub fn is_offline_run(job: &JobName, parameter: &JobParameter) -> bool {
+    OFFLINE_JOBS.contains(job) && parameter.contains_key(PARAM_NAME_OUTPUT)
+}
+
+/// 데이터베이스 없이 잡을 실행하고 실행 결과 상태를 반환한다.
+/// 출판사는 `keyword` 파라미터로 만들며([`ParameterPublisherRepository`]) 필터 규칙과 요청 한도는 사용하지 않는다.
+/// 데이터베이스 없이 실행할 수 없는 잡이거나, `input`, `replay_chunk` 없이 키워드를 입력하지 않은 경우 [`RuntimeError::InvalidParameter`]를 반환한다.
+fn run_offline(job: JobName, parameter: &JobParameter, config: &configs::Config, databases: &BookDatabases, cancel: &CancellationToken) -> Result<JobStatus, RuntimeError> {
+    if !is_offline_run(&job, parameter) {
+        return Err(RuntimeError::InvalidParameter(format!("{:?} job requires a database connection", job)));
+    }
+    let has_items = parameter.contains_key(PARAM_NAME_INPUT) || parameter.contains_key(PARAM_NAME_REPLAY_CHUNK);
+    if !has_items && !parameter.contains_key(PARAM_NAME_KEYWORD) {
+        return Err(RuntimeError::InvalidParameter(format!("{} is required to run {:?} job without database", PARAM_NAME_KEYWORD, job)));
+    }
+    let parameter = &config.publisher_profile.apply_to_parameter(parameter);
+    spec::check_credentials(&job, parameter)?;
+
+    let job_name = format!("{:?}", job);
+    batch::trace::start_run(&job_name, &databases.clock.now());
+    tracing::info!("{} => Running without database, books are written to {}", job_name, parameter[PARAM_NAME_OUTPUT]);
+
+    let pub_repo = SharedPublisherRepository::new(Box::new(ParameterPublisherRepository::from_parameter(parameter)));
+    let book_repo = SharedBookRepository::new(Box::new(EmptyBookRepository));
+    let filter_repo = SharedFilterRepository::new(Box::new(EmptyFilterRepository));
+    let job = match job {
+        JobName::ALADIN => {
+            let client = Rc::new(inject::client(client_from_env(aladin::Client::new_with_env(), TARGET_ALADIN)?, TARGET_ALADIN));
+            batch::book::aladin::create_job(
+                client.clone(),
+                client.clone(),
+                pub_repo,
+                book_repo,
+                filter_repo,
+                SharedQuotaRepository::new(Box::new(EmptyQuotaRepository)),
+                config.upsert_mode,
+                &config.publisher_profile,
+                parameter,
+            )?
+        }
+        JobName::NLGO => batch::book::nlgo::create_job(
+            Rc::new(inject::client(client_from_env(nlgo::Client::new_with_env(), TARGET_NLGO)?, TARGET_NLGO)),
+            pub_repo,
+            book_repo,
+            filter_repo,
+            &config.publisher_profile,
+            parameter,
+        )?,
+        JobName::KYOBO_SEARCH => batch::book::kyobo_search::create_job(
+            Rc::new(inject::client(kyobo::search::SearchClient::new(), TARGET_KYOBO)),
+            pub_repo,
+            book_repo,
+            filter_repo,
+            &config.publisher_profile,
+            parameter,
+        )?,
+        _ => unreachable!("{:?} job is not in OFFLINE_JOBS", job),
+    };
+    let cancelled = is_cancelled(run_or_replay(&job, parameter, cancel))?;
+
+    batch::metrics::log_query_stats(&job_name, &batch::metrics::take_query_stats());
+    let counts = batch::metrics::take_item_counts();
+    tracing::info!("{} => read {}, processed {}, written {}, skipped {}", job_name, counts.read, counts.processed, counts.written, counts.skipped);
+    batch::status::take(databases.clock.now());
+    batch::audit::take();
+    if cancelled {
+        return Ok(JobStatus::Cancelled);
+    }
+    Ok(JobStatus::Completed)
+}
+
+/// 설정에서 교보문고 로그인을 사용하지 않도록 한 경우(`kyobo.login = false`) 파라미터에 `no_login`을 추가한다.
+fn apply_kyobo_config(config: &configs::Config, parameter: &JobParameter) -> JobParameter {
+    let mut parameter = parameter.clone();
+    if !config.kyobo.login {
+        parameter.insert(PARAM_NAME_NO_LOGIN.to_owned(), true.to_string());
+    }
+    parameter
+}
+
+/// `replay_chunk` 파라미터가 입력된 경우 격리 파일의 아이템을 다시 저장하고, 그렇지 않은 경우 잡을 실행한다.
+fn run_or_replay<I, O: Serialize + DeserializeOwned + 'static>(job: &Job<I, O>, parameter: &JobParameter, cancel: &CancellationToken) -> Result<JobResult<I, O>, JobRuntimeError<I, O>> {
+    match parameter.get(PARAM_NAME_REPLAY_CHUNK) {
+        Some(path) => job.replay(Path::new(path), cancel),
+        None => job.run(parameter, cancel),
+    }
+}
+
+/// 도서 잡에서 저장에 실패한 도서를 격리하기 전 격리 횟수를 기록하여, 여러 실행에서 같은 도서가 같은 에러로 최대 횟수(`DEAD_LETTER_MAX_ATTEMPTS`)를 넘게 격리 되지 않도록 설정한다.
+fn with_dead_letter<I>(job: Job<I, Book>, job_name: JobName, connection: &Pool<ConnectionManager<PgConnection>>, databases: &BookDatabases) -> Job<I, Book> {
+    let repo = SharedDeadLetterRepository::new(Box::new(DieselDeadLetterRepository::new(connection.clone()).with_clock(databases.clock.clone())));
+    let dead_letter = batch::dead_letter::DeadLetter::new(repo, &job_name)
+        .with_max_attempts(batch::dead_letter::max_attempts_from_env());
+    job.with_dead_letter(dead_letter, batch::trace::book_trace_key)
+}
+
+/// 체크포인트를 지원하는 잡([`batch::checkpoint::CHECKPOINT_JOBS`])인 경우 저장을 마친 청크의 순번을 체크포인트로 기록하도록 설정한다.
+/// `dry_run` 파라미터가 `true`인 경우 저장하지 않은 청크를 기록하지 않도록 체크포인트를 사용하지 않는다.
+fn with_checkpoint<I, O>(job: Job<I, O>, job_name: JobName, parameter: &JobParameter, connection: &Pool<ConnectionManager<PgConnection>>, databases: &BookDatabases) -> Job<I, O> {
+    if !batch::checkpoint::is_checkpoint_supported(&job_name) || batch::is_dry_run(parameter) {
+        return job;
+    }
+    let repo = SharedCheckpointRepository::new(Box::new(DieselCheckpointRepository::new(connection.clone()).with_clock(databases.clock.clone())));
+    job.with_checkpoint(batch::checkpoint::Checkpoint::new(repo, &job_name))
+}
+
+/// 잡이 격리 파일을 다시 저장할 수 있는지, 격리 파일이 같은 잡에서 만들어졌는지 확인한다. 그렇지 않은 경우 [`RuntimeError::InvalidParameter`]를 반환한다.
+fn check_replay(job: JobName, path: &str) -> Result<(), RuntimeError> {
+    if !batch::quarantine::is_replayable(&job) {
+        return Err(RuntimeError::InvalidParameter(format!("{:?} job does not support {}", job, PARAM_NAME_REPLAY_CHUNK)));
+    }
+    let header = batch::quarantine::read_header(Path::new(path))
+        .map_err(|e| RuntimeError::InvalidParameter(format!("Failed to read quarantine file {}: {}", path, e)))?;
+    if header.job != format!("{:?}", job) {
+        return Err(RuntimeError::InvalidParameter(format!("{} was quarantined by {} job, not {:?}", path, header.job, job)));
+    }
+    Ok(())
+}
+
+/// 환경 변수로 생성한 사이트 클라이언트를 반환한다. 환경 변수가 설정 되지 않은 경우 [`RuntimeError::ClientUnavailable`]을 반환한다.
+fn client_from_env<C>(client: Result<C, VarError>, target: &str) -> Result<C, RuntimeError> {
+    client.map_err(|e| RuntimeError::ClientUnavailable(format!("{}: {}", target, e)))
+}
+
+/// ISBN 단건 조회에 사용할 모든 사이트(API, HTML)의 클라이언트를 생성한다. (FETCH, SNAPSHOT)
+///
+/// 교보문고는 로그인 정보가 설정 되어 있거나 로그인 하지 않고 조회하는 경우에만 조회한다.
+fn lookup_clients(parameter: &JobParameter) -> Result<(Vec<Rc<dyn LookupClient>>, Vec<(Site, Rc<dyn html::Client>)>), RuntimeError> {
+    let api_clients: Vec<Rc<dyn LookupClient>> = vec![
+        Rc::new(inject::client(client_from_env(nlgo::Client::new_with_env(), TARGET_NLGO)?, TARGET_NLGO)),
+        Rc::new(inject::client(client_from_env(aladin::Client::new_with_env(), TARGET_ALADIN)?, TARGET_ALADIN)),
+        Rc::new(inject::client(client_from_env(naver::Client::new_with_env(), TARGET_NAVER)?, TARGET_NAVER)),
+    ];
+
+    let mut html_clients: Vec<(Site, Rc<dyn html::Client>)> = Vec::new();
+    if batch::book::kyobo::is_no_login(parameter) {
+        html_clients.push((Site::KyoboBook, Rc::new(inject::client(kyobo::Client::public(), TARGET_KYOBO))));
+    } else {
+        match kyobo::chrome::new_provider() {
+            Ok(provider) => html_clients.push((Site::KyoboBook, Rc::new(inject::client(kyobo::Client::new(provider), TARGET_KYOBO)))),
+            Err(err) => tracing::warn!("Kyobo lookup skipped: {}", err),
+        }
+    }
+    Ok((api_clients, html_clients))
+}
+
+/// 잡 실행 결과가 취소인지 확인한다. 취소 외의 오류는 복구할 수 없으므로 [`RuntimeError::JobFailed`]를 반환한다.
+fn is_cancelled<T, I: Debug, O: Debug>(result: Result<T, JobRuntimeError<I, O>>) -> Result<bool, RuntimeError> {
+    match result {
+        Ok(_) => Ok(false),
+        Err(JobRuntimeError::Cancelled) => Ok(true),
+        Err(e) => Err(RuntimeError::JobFailed(format!("{:?}", e))),
+    }
+}
+
+/// 데이터베이스 마이그레이션을 적용하고, MongoDB가 설정된 경우 원본 데이터 인덱스를 생성한다.
+fn migrate(connection: &Pool<ConnectionManager<PgConnection>>, profile: Option<&configs::Profile>) -> Result<(), RuntimeError> {
+    let versions = configs::migrate::run_pending_migrations(connection).map_err(RuntimeError::MigrationFailed)?;
+    tracing::info!("{:?} => {} migrations applied", JobName::MIGRATE, versions.len());
+
+    if configs::is_mongo_configured(profile) {
+        let client = configs::connect_to_mongo(profile).map_err(RuntimeError::DatabaseUnavailable)?;
+        MongoOriginStore::new(&client, &configs::migrate::mongo_database()).create_indexes()
+            .map_err(|e| RuntimeError::MigrationFailed(format!("Mongo index creation failed: {}", e)))?;
+    } else {
+        tracing::info!("{:?} => Mongo index creation skipped: MONGO_URL is not set", JobName::MIGRATE);
+    }
+    Ok(())
+}
+
+/// `--dry-run`으로 실행한 경우 잡의 라이터를 저장하지 않는 라이터([`batch::DryRunWriter`])로 바꾼다.
+/// 저장소 대신 파일로 출력하는 잡(`output`)은 저장소를 변경하지 않으므로 파일 라이터를 그대로 사용한다.
+fn with_dry_run<I, O: 'static>(job: Job<I, O>, job_name: JobName, parameter: &JobParameter) -> Job<I, O> {
+    let is_file_output = parameter.contains_key(PARAM_NAME_OUTPUT)
+        && spec::job_spec(&job_name).parameters.iter().any(|p| p.name == PARAM_NAME_OUTPUT);
+    if !batch::is_dry_run(parameter) || is_file_output {
+        return job;
+    }
+    job.with_dry_run()
+}
+
+/// `--dry-run`으로 실행한 경우 도서 저장소를 읽기 전용 저장소로 감싼다.
+fn guard_book_repo(repo: SharedBookRepository, parameter: &JobParameter) -> SharedBookRepository {
+    if batch::is_dry_run(parameter) {
+        SharedBookRepository::new(Box::new(ReadOnlyBookRepository::new(repo)))
+    } else {
+        repo
+    }
+}
+
+/// `--dry-run`으로 실행한 경우 시리즈 저장소를 읽기 전용 저장소로 감싼다.
+fn guard_series_repo(repo: SharedSeriesRepository, parameter: &JobParameter) -> SharedSeriesRepository {
+    if batch::is_dry_run(parameter) {
+        SharedSeriesRepository::new(Box::new(ReadOnlySeriesRepository::new(repo)))
+    } else {
+        repo
+    }
+}
+
+/// `--dry-run`으로 실행한 경우 보강 실패 기록 저장소를 기록하지 않는 읽기 전용 저장소로 감싼다.
+fn guard_retry_repo(repo: SharedRetryRepository, parameter: &JobParameter) -> SharedRetryRepository {
+    if batch::is_dry_run(parameter) {
+        SharedRetryRepository::new(Box::new(ReadOnlyRetryRepository::new(repo)))
+    } else {
+        repo
+    }
+}
+
+/// `--dry-run`으로 실행한 경우 API 일일 요청 수 저장소를 기록하지 않는 읽기 전용 저장소로 감싼다.
+fn guard_quota_repo(repo: SharedQuotaRepository, parameter: &JobParameter) -> SharedQuotaRepository {
+    if batch::is_dry_run(parameter) {
+        SharedQuotaRepository::new(Box::new(ReadOnlyQuotaRepository::new(repo)))
+    } else {
+        repo
+    }
+}
+
+/// 도서, 시리즈 저장소가 기본 연결 외에 사용하는 연결과 시계
+struct BookDatabases {
+    /// 조회 부하가 큰 쿼리를 실행할 읽기 전용 복제본
+    replica: Option<Pool<ConnectionManager<PgConnection>>>,
+
+    /// 원본 데이터 저장소로 MongoDB를 사용하는 경우의 클라이언트
+    mongo: Option<mongodb::sync::Client>,
+
+    /// 등록, 수정 시각과 실행 기록에 사용할 시계
+    clock: SharedClock,
+
+    /// 시리즈 유사도 검색 방식
+    vector_search: VectorSearch,
+
+    /// 새 도서, 시리즈의 아이디 발급기 (도서와 시리즈 저장소가 같은 발급기를 공유한다.)
+    ids: SharedIdGenerator,
+}
+
+impl BookDatabases {
+    /// 도서 저장소에 시계, 아이디 발급기, 읽기 전용 복제본과 설정된 원본 데이터 저장소를 적용한다.
+    fn book_repo(&self, repo: ComposeBookRepository) -> ComposeBookRepository {
+        let mut repo = repo.with_clock(self.clock.clone()).with_id_generator(self.ids.clone());
+        if let Some(replica) = self.replica.as_ref() {
+            repo = repo.with_replica(replica.clone());
+        }
+        if let Some(mongo) = self.mongo.as_ref() {
+            repo = repo.with_origin_store(Box::new(MongoOriginStore::new(mongo, &configs::migrate::mongo_database())));
+        }
+        repo
+    }
+
+    /// 시리즈 저장소에 시계, 아이디 발급기와 유사도 검색 방식을 적용하고, 읽기 전용 복제본이 설정된 경우 시리즈 유사도 검색을 복제본으로 보낸다.
+    fn series_repo(&self, repo: DieselSeriesRepository) -> DieselSeriesRepository {
+        let repo = repo.with_clock(self.clock.clone()).with_id_generator(self.ids.clone()).with_vector_search(self.vector_search);
+        match self.replica.as_ref() {
+            Some(replica) => repo.with_replica(replica.clone()),
+            None => repo,
+        }
+    }
+}
+
+/// `chaos` 기능이 활성화 된 경우 사이트 클라이언트, 도서 저장소, 프롬프트를 장애 주입 객체로 감싼다.
+#[cfg(feature = "chaos")]
+mod inject {
+    use crate::chaos::{Chaos, ChaosBookRepository, ChaosClient, ChaosPrompt, SharedChaos};
+    use crate::item::SharedBookRepository;
+    use crate::prompt::SharedPrompt;
+    use std::rc::Rc;
+
+    thread_local! {
+        static CHAOS: SharedChaos = Rc::new(Chaos::with_env());
+    }
+
+    pub fn client<C>(client: C, target: &str) -> ChaosClient<C> {
+        CHAOS.with(|chaos| ChaosClient::new(client, target, chaos.clone()))
+    }
+
+    pub fn book_repo(repo: SharedBookRepository) -> SharedBookRepository {
+        CHAOS.with(|chaos| SharedBookRepository::new(Box::new(ChaosBookRepository::new(repo, chaos.clone()))))
+    }
+
+    pub fn prompt(prompt: SharedPrompt) -> SharedPrompt {
+        CHAOS.with(|chaos| SharedPrompt::new(Box::new(ChaosPrompt::new(prompt, chaos.clone()))))
+    }
+}
+
+#[cfg(not(feature = "chaos"))]
+mod inject {
+    use crate::item::SharedBookRepository;
+    use crate::prompt::SharedPrompt;
+
+    pub fn client<C>(client: C, _: &str) -> C {
+        client
+    }
+
+    pub fn book_repo(repo: SharedBookRepository) -> SharedBookRepository {
+        repo
+    }
+
+    pub fn prompt(prompt: SharedPrompt) -> SharedPrompt {
+        prompt
+    }
+}
@@ -0,0 +1,530 @@
+use crate::batch::book::kyobo::is_no_login;
+use crate::batch::JobParameter;
+use crate::configs::{required_env, EnvSpec, KYOBO_LOGIN_ENV};
+use crate::{ArgumentError, JobName, PARAM_NAME_CHUNK_SIZE, PARAM_NAME_CONFIRM, PARAM_NAME_DESCRIPTION_MAX_LENGTH, PARAM_NAME_DESCRIPTION_MIN_LENGTH, PARAM_NAME_DESCRIPTION_SITE, PARAM_NAME_DRY_RUN, PARAM_NAME_FILTER_SITE, PARAM_NAME_FOLLOW_UP, PARAM_NAME_FROM, PARAM_NAME_INPUT, PARAM_NAME_ISBN, PARAM_NAME_ISBN_SET, PARAM_NAME_ITEM_LIST, PARAM_NAME_KEYWORD, PARAM_NAME_LIMIT, PARAM_NAME_NO_LOGIN, PARAM_NAME_NORMALIZE_BATCH, PARAM_NAME_OUTPUT, PARAM_NAME_PUBLISHER_ID, PARAM_NAME_REPLAY_CHUNK, PARAM_NAME_REPORT_DAYS, PARAM_NAME_RESUME, PARAM_NAME_ROMANIZE, PARAM_NAME_SERIES_SAME_PUBLISHER, PARAM_NAME_SITE_PRIORITY, PARAM_NAME_SKIP_FILTER, PARAM_NAME_SKIP_LIMIT, PARAM_NAME_SNAPSHOT_DIR, PARAM_NAME_SPILL_THRESHOLD, PARAM_NAME_STALE_DAYS, PARAM_NAME_START_YEAR, PARAM_NAME_TO, PARAM_NAME_UPDATE_SNAPSHOT, PARAM_NAME_UPSERT};
+
+/// 잡에서 사용하는 파라미터 명세
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParameterSpec {
+    /// [`crate::batch::JobParameter`]의 키 (커맨드 라인에서는 `_`를 `-`로 바꾼 `--{name}`으로 입력한다.)
+    pub name: &'static str,
+
+    /// 필수 파라미터 여부
+    pub required: bool,
+
+    /// 입력하지 않았을 때 사용하는 기본값 (기본값이 없을 경우 `None`)
+    pub default: Option<&'static str>,
+
+    /// 파라미터 설명
+    pub description: &'static str,
+}
+
+impl ParameterSpec {
+
+    /// 커맨드 라인에서 입력할 때 사용하는 옵션 이름 (ex: `--publisher-id`)
+    pub fn flag(&self) -> String {
+        format!("--{}", self.name.replace('_', "-"))
+    }
+}
+
+/// 잡 명세
+///
+/// # Description
+/// 잡이 하는 일과 사용하는 파라미터를 정의한다. 실행에 필요한 인증 정보(환경 변수)는 [`crate::configs::required_env`]에서 정의한다.
+/// `--list-jobs`, `--describe-job` 옵션으로 출력한다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JobSpec {
+    pub job: JobName,
+
+    /// 잡 설명
+    pub description: &'static str,
+
+    /// 잡에서 사용하는 파라미터
+    pub parameters: &'static [ParameterSpec],
+}
+
+impl JobSpec {
+
+    /// 커맨드 라인에서 `--job`으로 입력하는 잡 이름
+    pub fn name(&self) -> String {
+        format!("{:?}", self.job)
+    }
+
+    /// 잡 실행에 사용하는 인증 정보 환경 변수 명세
+    pub fn credentials(&self) -> Vec<EnvSpec> {
+        required_env(self.job)
+    }
+}
+
+const FROM: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_FROM,
+    required: false,
+    default: Some("오늘 - 30일"),
+    description: "수집할 도서의 출판일 검색 시작 날짜 (YYYY-MM-DD)",
+};
+
+const TO: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_TO,
+    required: false,
+    default: Some("오늘 + 60일"),
+    description: "수집할 도서의 출판일 검색 종료 날짜 (YYYY-MM-DD)",
+};
+
+const PUBLISHER_ID: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_PUBLISHER_ID,
+    required: false,
+    default: Some("키워드가 있는 모든 출판사"),
+    description: "처리할 출판사 아이디 리스트 (공백으로 구분)",
+};
+
+const ISBN: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_ISBN,
+    required: false,
+    default: None,
+    description: "처리할 도서의 ISBN 리스트 (공백으로 구분)",
+};
+
+const ISBN_SET: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_ISBN_SET,
+    required: false,
+    default: None,
+    description: "처리할 도서의 ISBN 집합 이름 (--isbn과 함께 입력할 경우 모두 처리)",
+};
+
+const LIMIT: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_LIMIT,
+    required: false,
+    default: Some("50"),
+    description: "한번에 처리할 도서 수",
+};
+
+const CHUNK_SIZE: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_CHUNK_SIZE,
+    required: false,
+    default: Some("잡 기본값"),
+    description: "한번에 처리하고 저장할 데이터의 개수",
+};
+
+const ROMANIZE: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_ROMANIZE,
+    required: false,
+    default: Some("false"),
+    description: "파일로 출력할 때 제목의 로마자 표기를 함께 출력 (transliteration 기능 필요)",
+};
+
+const SKIP_LIMIT: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_SKIP_LIMIT,
+    required: false,
+    default: Some("0"),
+    description: "처리, 저장에 실패한 데이터를 잡을 중단하지 않고 건너뛸 최대 개수",
+};
+
+const SPILL_THRESHOLD: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_SPILL_THRESHOLD,
+    required: false,
+    default: None,
+    description: "읽은 도서를 임시 파일로 옮긴 후 처리할 도서 개수 기준",
+};
+
+const UPSERT: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_UPSERT,
+    required: false,
+    default: Some("false"),
+    description: "조회한 도서를 저장소에 저장",
+};
+
+const OUTPUT: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_OUTPUT,
+    required: false,
+    default: None,
+    description: "저장소 대신 출력할 파일 경로 (`-`: 표준 출력)",
+};
+
+const INPUT: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_INPUT,
+    required: false,
+    default: None,
+    description: "API 대신 도서를 읽어올 JSON Lines 파일 경로 (`-`: 표준 입력)",
+};
+
+const KEYWORD: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_KEYWORD,
+    required: false,
+    default: None,
+    description: "데이터베이스 없이 파일로 출력할 때 출판사 대신 검색할 키워드 리스트",
+};
+
+const SKIP_FILTER: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_SKIP_FILTER,
+    required: false,
+    default: Some("false"),
+    description: "원본 데이터 필터를 사용하지 않고 수집",
+};
+
+const FILTER_SITE: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_FILTER_SITE,
+    required: false,
+    default: Some("수집하는 사이트"),
+    description: "원본 데이터 필터에 사용할 필터 규칙의 사이트 리스트",
+};
+
+const DESCRIPTION_SITE: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_DESCRIPTION_SITE,
+    required: false,
+    default: Some("KYOBO ALADIN NAVER NLGO"),
+    description: "도서 소개글을 선택할 사이트의 우선순위",
+};
+
+const DESCRIPTION_MIN_LENGTH: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_DESCRIPTION_MIN_LENGTH,
+    required: false,
+    default: Some("10"),
+    description: "도서 소개글의 최소 길이(문자 수)",
+};
+
+const DESCRIPTION_MAX_LENGTH: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_DESCRIPTION_MAX_LENGTH,
+    required: false,
+    default: Some("2000"),
+    description: "도서 소개글의 최대 길이(문자 수)",
+};
+
+const REPORT_DAYS: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_REPORT_DAYS,
+    required: false,
+    default: Some("7"),
+    description: "리포트에 포함할 수집 기간(일)",
+};
+
+const SERIES_SAME_PUBLISHER: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_SERIES_SAME_PUBLISHER,
+    required: false,
+    default: Some("false"),
+    description: "같은 출판사의 도서로만 이루어진 시리즈에만 연결",
+};
+
+const SITE_PRIORITY: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_SITE_PRIORITY,
+    required: false,
+    default: Some("KYOBO ALADIN NAVER NLGO"),
+    description: "LLM 요청에 사용할 사이트별 원본 데이터의 우선순위",
+};
+
+const START_YEAR: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_START_YEAR,
+    required: true,
+    default: None,
+    description: "과거 도서 수집을 시작할 연도",
+};
+
+const STALE_DAYS: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_STALE_DAYS,
+    required: false,
+    default: Some("7"),
+    description: "오래 된 수집으로 표시할 마지막 수집 후 경과 일수",
+};
+
+const NORMALIZE_BATCH: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_NORMALIZE_BATCH,
+    required: false,
+    default: Some("false"),
+    description: "도서 제목을 청크 단위로 한번에 정규화",
+};
+
+const FOLLOW_UP: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_FOLLOW_UP,
+    required: false,
+    default: Some("false"),
+    description: "잡이 끝난 후 후속 잡을 이어서 실행",
+};
+
+const ITEM_LIST: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_ITEM_LIST,
+    required: false,
+    default: Some("false"),
+    description: "사이트에서 선정한 신간 리스트를 함께 수집",
+};
+
+const DRY_RUN: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_DRY_RUN,
+    required: false,
+    default: Some("false"),
+    description: "도서, 시리즈 저장소를 변경하지 않고 미리 실행",
+};
+
+const NO_LOGIN: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_NO_LOGIN,
+    required: false,
+    default: Some("false"),
+    description: "교보문고에 로그인 하지 않고 공개된 페이지만 조회",
+};
+
+const REPLAY_CHUNK: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_REPLAY_CHUNK,
+    required: false,
+    default: None,
+    description: "저장에 실패하여 격리된 청크 파일을 수집 없이 다시 저장",
+};
+
+const RESUME: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_RESUME,
+    required: false,
+    default: Some("false"),
+    description: "이전 실행의 체크포인트까지 저장된 청크를 건너뛰고 이어서 실행",
+};
+
+const PURGE_FROM: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_FROM,
+    required: false,
+    default: None,
+    description: "삭제할 도서의 출판일 검색 시작 날짜 (YYYY-MM-DD, ISBN 없이 출판사로 삭제할 경우 필수)",
+};
+
+const PURGE_TO: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_TO,
+    required: false,
+    default: None,
+    description: "삭제할 도서의 출판일 검색 종료 날짜 (YYYY-MM-DD, ISBN 없이 출판사로 삭제할 경우 필수)",
+};
+
+const CONFIRM: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_CONFIRM,
+    required: false,
+    default: Some("false"),
+    description: "삭제 대상 목록만 출력하지 않고 도서를 삭제",
+};
+
+const RECHECK_FROM: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_FROM,
+    required: false,
+    default: Some("어제부터 180일 전"),
+    description: "재확인할 도서의 출간 예정일 검색 시작 날짜 (YYYY-MM-DD, `to`와 함께 입력)",
+};
+
+const RECHECK_TO: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_TO,
+    required: false,
+    default: Some("어제"),
+    description: "재확인할 도서의 출간 예정일 검색 종료 날짜 (YYYY-MM-DD, `from`과 함께 입력)",
+};
+
+const SNAPSHOT_ISBN: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_ISBN,
+    required: false,
+    default: None,
+    description: "스냅샷과 비교할 기준 도서 ISBN (입력하지 않을 경우 스냅샷 디렉토리의 isbn.txt를 사용)",
+};
+
+const SNAPSHOT_DIR: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_SNAPSHOT_DIR,
+    required: false,
+    default: Some("snapshots"),
+    description: "사이트별 스냅샷과 기준 ISBN 목록(isbn.txt)이 있는 디렉토리",
+};
+
+const UPDATE_SNAPSHOT: ParameterSpec = ParameterSpec {
+    name: PARAM_NAME_UPDATE_SNAPSHOT,
+    required: false,
+    default: Some("false"),
+    description: "스냅샷과 비교하지 않고 조회한 도서로 스냅샷을 갱신",
+};
+
+/// 등록된 모든 잡의 명세
+pub const JOB_SPECS: [JobSpec; 18] = [
+    JobSpec {
+        job: JobName::NLGO,
+        description: "국립중앙도서관 API를 이용한 도서 데이터 수집",
+        parameters: &[FROM, TO, PUBLISHER_ID, CHUNK_SIZE, SKIP_LIMIT, OUTPUT, ROMANIZE, INPUT, KEYWORD, SKIP_FILTER, FILTER_SITE, DESCRIPTION_SITE, DESCRIPTION_MIN_LENGTH, DESCRIPTION_MAX_LENGTH, FOLLOW_UP, SPILL_THRESHOLD, REPLAY_CHUNK, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::NAVER,
+        description: "네이버 도서 API를 이용한 도서 데이터 수집",
+        parameters: &[FROM, TO, PUBLISHER_ID, CHUNK_SIZE, SKIP_LIMIT, OUTPUT, ROMANIZE, INPUT, DESCRIPTION_SITE, DESCRIPTION_MIN_LENGTH, DESCRIPTION_MAX_LENGTH, REPLAY_CHUNK, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::ALADIN,
+        description: "알라딘 API를 이용한 도서 데이터 수집",
+        parameters: &[FROM, TO, PUBLISHER_ID, CHUNK_SIZE, SKIP_LIMIT, OUTPUT, ROMANIZE, INPUT, KEYWORD, SKIP_FILTER, FILTER_SITE, DESCRIPTION_SITE, DESCRIPTION_MIN_LENGTH, DESCRIPTION_MAX_LENGTH, FOLLOW_UP, ITEM_LIST, SPILL_THRESHOLD, REPLAY_CHUNK, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::KYOBO,
+        description: "교보문고 파싱을 통한 도서 데이터 수집",
+        parameters: &[FROM, TO, ISBN, ISBN_SET, CHUNK_SIZE, SKIP_LIMIT, OUTPUT, ROMANIZE, INPUT, DESCRIPTION_SITE, DESCRIPTION_MIN_LENGTH, DESCRIPTION_MAX_LENGTH, NO_LOGIN, REPLAY_CHUNK, RESUME, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::SERIES,
+        description: "시리즈가 연결되지 않은 도서들의 적절한 시리즈를 찾아 연결",
+        parameters: &[ISBN, ISBN_SET, LIMIT, CHUNK_SIZE, SKIP_LIMIT, SERIES_SAME_PUBLISHER, SITE_PRIORITY, NORMALIZE_BATCH, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::FETCH,
+        description: "입력 받은 ISBN을 모든 사이트에서 조회하여 저장된 도서와 비교",
+        parameters: &[ISBN, ISBN_SET, PUBLISHER_ID, CHUNK_SIZE, SKIP_LIMIT, UPSERT, DESCRIPTION_SITE, DESCRIPTION_MIN_LENGTH, DESCRIPTION_MAX_LENGTH, NO_LOGIN, REPLAY_CHUNK, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::NORMALIZE,
+        description: "제목이 정규화 되지 않은 도서들의 제목을 정규화 하여 저장",
+        parameters: &[ISBN, ISBN_SET, LIMIT, CHUNK_SIZE, SKIP_LIMIT, SITE_PRIORITY, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::STOCK,
+        description: "수집된 원본 데이터로 사이트별 판매 상태를 기록하고 모든 사이트에서 품절된 도서를 알림",
+        parameters: &[FROM, TO, ISBN, ISBN_SET, CHUNK_SIZE, SKIP_LIMIT],
+    },
+    JobSpec {
+        job: JobName::REPORT,
+        description: "최근 수집된 도서를 표지, 바코드와 함께 인쇄용 HTML/PDF 리포트로 출력",
+        parameters: &[OUTPUT, REPORT_DAYS],
+    },
+    JobSpec {
+        job: JobName::BACKFILL,
+        description: "국립중앙도서관 API로 지정한 연도부터 현재까지의 도서를 한 달씩 수집",
+        parameters: &[START_YEAR, PUBLISHER_ID, SKIP_FILTER, FILTER_SITE, SPILL_THRESHOLD, SKIP_LIMIT, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::KYOBO_SEARCH,
+        description: "교보문고 검색을 통한 출판사별 신규 도서(예약 판매 등) 수집",
+        parameters: &[PUBLISHER_ID, CHUNK_SIZE, SKIP_LIMIT, OUTPUT, ROMANIZE, INPUT, KEYWORD, SKIP_FILTER, FILTER_SITE, FOLLOW_UP, SPILL_THRESHOLD, REPLAY_CHUNK, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::STATUS,
+        description: "사이트, 출판사별 마지막 수집 시각과 도서 수를 표로 출력",
+        parameters: &[PUBLISHER_ID, STALE_DAYS],
+    },
+    JobSpec {
+        job: JobName::MIGRATE,
+        description: "바이너리에 포함된 데이터베이스 마이그레이션과 MongoDB 인덱스를 적용",
+        parameters: &[],
+    },
+    JobSpec {
+        job: JobName::SERIES_COVER,
+        description: "대표 이미지가 없는 시리즈에 소속 도서(1권 우선)의 썸네일을 대표 이미지로 저장",
+        parameters: &[LIMIT, CHUNK_SIZE, SKIP_LIMIT, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::GLOSSARY,
+        description: "출판사별 시리즈의 정규화된 제목과 소속 도서를 편집팀용 용어집(CSV)으로 출력",
+        parameters: &[PUBLISHER_ID, CHUNK_SIZE, SKIP_LIMIT, OUTPUT],
+    },
+    JobSpec {
+        job: JobName::PURGE,
+        description: "ISBN 혹은 출판사와 출판일 기간으로 도서와 원본 데이터를 삭제하고 빈 시리즈를 정리",
+        parameters: &[ISBN, ISBN_SET, PUBLISHER_ID, PURGE_FROM, PURGE_TO, CHUNK_SIZE, SKIP_LIMIT, OUTPUT, CONFIRM, DRY_RUN],
+    },
+    JobSpec {
+        job: JobName::SNAPSHOT,
+        description: "기준 ISBN을 모든 사이트에서 조회하여 체크인된 스냅샷과 속성 단위로 비교",
+        parameters: &[SNAPSHOT_ISBN, SNAPSHOT_DIR, UPDATE_SNAPSHOT, NO_LOGIN],
+    },
+    JobSpec {
+        job: JobName::PUB_DATE_RECHECK,
+        description: "출간 예정일이 지났지만 실제 출간일이 없는 도서를 국립중앙도서관에서 다시 조회하고 교보문고 재수집 대상으로 등록",
+        parameters: &[RECHECK_FROM, RECHECK_TO, CHUNK_SIZE, SKIP_LIMIT, DRY_RUN],
+    },
+];
+
+/// 잡 이름(대소문자 구분 없음)으로 잡 명세를 찾는다. 등록 되지 않은 잡일 경우 `None`을 반환한다.
+pub fn find_job_spec(name: &str) -> Option<&'static JobSpec> {
+    JOB_SPECS.iter().find(|spec| spec.name().eq_ignore_ascii_case(name.trim()))
+}
+
+/// 잡의 명세를 반환한다.
+pub fn job_spec(job: &JobName) -> &'static JobSpec {
+    JOB_SPECS.iter()
+        .find(|spec| spec.job == *job)
+        .expect("every job must have a spec")
+}
+
+/// 잡 실행에 필요한 인증 정보 중 설정 되지 않은 필수 환경 변수 이름을 반환한다.
+/// 교보문고에 로그인 하지 않고 실행(`no_login`)한 경우 교보문고 로그인 정보([`KYOBO_LOGIN_ENV`])는 요구하지 않는다.
+pub fn missing_credentials(job: &JobName, params: &JobParameter) -> Vec<&'static str> {
+    let no_login = is_no_login(params);
+    required_env(*job).into_iter()
+        .filter(|env| env.required && !env.is_set())
+        .filter(|env| !(no_login && KYOBO_LOGIN_ENV.contains(&env.name)))
+        .map(|env| env.name)
+        .collect()
+}
+
+/// 잡 실행 전 필요한 인증 정보가 모두 설정 되어 있는지 확인한다.
+///
+/// # Description
+/// 선택한 잡의 인증 정보([`crate::configs::required_env`])에 정의된 필수 환경 변수만 확인하며, 잡에서 사용하지 않는 사이트의 인증 정보는 요구하지 않는다.
+/// 설정 되지 않은 환경 변수가 있을 경우 그 목록을 담은 [`ArgumentError::InvalidCredentials`]를 반환한다.
+pub fn check_credentials(job: &JobName, params: &JobParameter) -> Result<(), ArgumentError> {
+    let missing = missing_credentials(job, params);
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(ArgumentError::InvalidCredentials(format!(
+            "{} job requires environment variables: {}",
+            job_spec(job).name(),
+            missing.join(", ")
+        )))
+    }
+}
+
+/// 등록된 모든 잡의 이름과 설명을 출력할 문자열로 만든다.
+pub fn render_job_list() -> String {
+    let width = JOB_SPECS.iter().map(|spec| spec.name().len()).max().unwrap_or(0);
+    JOB_SPECS.iter()
+        .map(|spec| format!("{:width$}  {}", spec.name(), spec.description, width = width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 잡이 사용하는 파라미터와 기본값, 필요한 인증 정보를 출력할 문자열로 만든다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::spec::{find_job_spec, render_job_description};
+///
+/// let spec = find_job_spec("backfill").unwrap();
+/// let description = render_job_description(spec);
+///
+/// assert!(description.starts_with("BACKFILL"));
+/// assert!(description.contains("--start-year (required)"));
+/// assert!(description.contains("NLGO_KEY"));
+/// ```
+pub fn render_job_description(spec: &JobSpec) -> String {
+    let mut lines = vec![
+        format!("{}: {}", spec.name(), spec.description),
+        String::new(),
+        "Parameters:".to_owned(),
+    ];
+
+    let width = spec.parameters.iter().map(|p| flag_with_requirement(p).len()).max().unwrap_or(0);
+    for parameter in spec.parameters {
+        let default = parameter.default
+            .map(|default| format!(" (default: {})", default))
+            .unwrap_or_default();
+        lines.push(format!("  {:width$}  {}{}", flag_with_requirement(parameter), parameter.description, default, width = width));
+    }
+
+    lines.push(String::new());
+    lines.push("Credentials:".to_owned());
+    let credentials = spec.credentials();
+    if credentials.is_empty() {
+        lines.push("  (none)".to_owned());
+    } else {
+        let width = credentials.iter().map(|env| env_with_requirement(env).len()).max().unwrap_or(0);
+        lines.extend(credentials.iter().map(|env| format!("  {:width$}  {}", env_with_requirement(env), env.description, width = width)));
+    }
+    lines.join("\n")
+}
+
+fn flag_with_requirement(parameter: &ParameterSpec) -> String {
+    if parameter.required {
+        format!("{} (required)", parameter.flag())
+    } else {
+        parameter.flag()
+    }
+}
+
+fn env_with_requirement(env: &EnvSpec) -> String {
+    if env.required {
+        format!("{} (required)", env.name)
+    } else {
+        env.name.to_owned()
+    }
+}
@@ -0,0 +1,129 @@
+//! 잡 기능 토글
+//!
+//! 실험 중인 처리 단계(ex: 제목 정규화 전 LLM 시리즈 선택)를 일부 아이템이나 특정 출판사에만 적용하여 기존 처리 흐름과 비교할 때 사용한다.
+//! 토글은 설정 파일의 `toggles` 섹션([`crate::configs::Config`])에서 읽으며 잡을 구성할 때 확인한다.
+//!
+//! # Example
+//! ```toml
+//! [toggles.series_select_first]
+//! percentage = 10
+//! publishers = [20050726]
+//! ```
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// 시리즈 잡의 제목 정규화 전 LLM 시리즈 선택([`crate::batch::series::SeriesConfig::select_first`])
+pub const TOGGLE_SERIES_SELECT_FIRST: &str = "series_select_first";
+
+/// 기능 토글
+///
+/// # Description
+/// `publishers`에 포함된 출판사의 아이템과, 토글 이름과 아이템 키(ISBN 등)로 나눈 100개의 버킷 중 `percentage`개의 버킷에 속한 아이템에 기능을 적용한다.
+/// 버킷은 토글 이름과 아이템 키의 해시로 결정 되므로 같은 아이템은 실행할 때마다 항상 같은 그룹에 속한다.
+/// `enabled`가 `false`인 경우 다른 설정과 관계 없이 기능을 적용하지 않는다.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct FeatureToggle {
+    /// 토글 사용 여부 (기본값: true)
+    pub enabled: bool,
+
+    /// 기능을 적용할 아이템 비율 0 ~ 100 (기본값: 0)
+    pub percentage: u8,
+
+    /// 비율과 관계 없이 기능을 적용할 출판사 아이디
+    pub publishers: Vec<u64>,
+}
+
+impl Default for FeatureToggle {
+    fn default() -> Self {
+        Self { enabled: true, percentage: 0, publishers: Vec::new() }
+    }
+}
+
+impl FeatureToggle {
+
+    /// 모든 아이템에 기능을 적용하는 토글
+    pub fn all() -> Self {
+        Self { percentage: 100, ..Default::default() }
+    }
+
+    /// 아이템에 기능을 적용할지 여부를 반환한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::toggle::FeatureToggle;
+    ///
+    /// let toggle = FeatureToggle { publishers: vec![1], ..Default::default() };
+    /// assert!(toggle.is_enabled_for("select", "9791133478427", 1));
+    /// assert!(!toggle.is_enabled_for("select", "9791133478427", 2));
+    ///
+    /// assert!(FeatureToggle::all().is_enabled_for("select", "9791133478427", 2));
+    ///
+    /// let disabled = FeatureToggle { enabled: false, ..FeatureToggle::all() };
+    /// assert!(!disabled.is_enabled_for("select", "9791133478427", 1));
+    /// ```
+    pub fn is_enabled_for(&self, name: &str, key: &str, publisher_id: u64) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        self.publishers.contains(&publisher_id) || bucket(name, key) < self.percentage.min(100)
+    }
+}
+
+/// 토글 이름과 아이템 키로 아이템이 속할 버킷(0 ~ 99)을 반환한다.
+///
+/// # Example
+/// ```
+/// use book_batch_rust::toggle::bucket;
+///
+/// let bucket_of = bucket("series_select_first", "9791133478427");
+/// assert!(bucket_of < 100);
+/// assert_eq!(bucket_of, bucket("series_select_first", "9791133478427"));
+/// ```
+pub fn bucket(name: &str, key: &str) -> u8 {
+    let digest = Sha256::digest(format!("{}:{}", name, key).as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(bytes) % 100) as u8
+}
+
+/// 이름별 기능 토글
+///
+/// # Description
+/// 정의 되지 않은 토글은 기능을 적용하지 않는다.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct FeatureToggles(HashMap<String, FeatureToggle>);
+
+impl FeatureToggles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 이름에 해당하는 토글을 설정한다.
+    pub fn with_toggle(mut self, name: &str, toggle: FeatureToggle) -> Self {
+        self.0.insert(name.to_owned(), toggle);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&FeatureToggle> {
+        self.0.get(name)
+    }
+
+    /// 이름에 해당하는 토글로 아이템에 기능을 적용할지 여부를 반환한다. 토글이 정의 되지 않은 경우 `false`를 반환한다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::toggle::{FeatureToggle, FeatureToggles};
+    ///
+    /// let toggles = FeatureToggles::new().with_toggle("select", FeatureToggle::all());
+    /// assert!(toggles.is_enabled_for("select", "9791133478427", 1));
+    /// assert!(!toggles.is_enabled_for("unknown", "9791133478427", 1));
+    /// ```
+    pub fn is_enabled_for(&self, name: &str, key: &str, publisher_id: u64) -> bool {
+        self.get(name)
+            .map(|toggle| toggle.is_enabled_for(name, key, publisher_id))
+            .unwrap_or(false)
+    }
+}
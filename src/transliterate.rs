@@ -0,0 +1,120 @@
+//! 도서 제목 로마자 표기
+//!
+//! 한국어를 읽지 못하는 외부 파트너에게 전달하는 내보내기 파일에 제목의 로마자 표기를 함께 출력할 때 사용한다.
+//! 표기 방식은 [`Transliterator`] 구현체로 교체할 수 있으며, 기본 구현체([`HangulRomanizer`])는
+//! `transliteration` 기능(feature)을 활성화 했을 때만 컴파일 된다.
+//!
+//! ```text
+//! $ cargo run --features transliteration -- --job NLGO --output books.csv --romanize
+//! ```
+use crate::batch::error::JobBuildError;
+use crate::batch::JobParameter;
+use crate::PARAM_NAME_ROMANIZE;
+use std::rc::Rc;
+
+/// 문자열을 로마자로 표기하는 변환기
+pub trait Transliterator {
+
+    /// 문자열을 로마자 표기로 변환한다. 변환할 수 없는 문자는 그대로 유지한다.
+    fn romanize(&self, text: &str) -> String;
+}
+
+pub type SharedTransliterator = Rc<Box<dyn Transliterator>>;
+
+/// [`JobParameter`]에 `romanize`가 `true`로 설정된 경우 기본 로마자 변환기를 생성한다. 설정 되지 않은 경우 `None`을 반환한다.
+///
+/// # Errors
+/// `transliteration` 기능이 활성화 되지 않은 상태에서 로마자 표기를 요청한 경우 `JobBuildError`를 반환한다.
+pub fn retrieve_transliterator_in_parameter(params: &JobParameter) -> Result<Option<SharedTransliterator>, JobBuildError> {
+    let enabled = params.get(PARAM_NAME_ROMANIZE)
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"));
+    if !enabled {
+        return Ok(None);
+    }
+    default_transliterator()
+        .map(Some)
+        .ok_or_else(|| JobBuildError::InvalidParameter(format!("{} requires transliteration feature", PARAM_NAME_ROMANIZE)))
+}
+
+#[cfg(feature = "transliteration")]
+fn default_transliterator() -> Option<SharedTransliterator> {
+    Some(SharedTransliterator::new(Box::new(HangulRomanizer)))
+}
+
+#[cfg(not(feature = "transliteration"))]
+fn default_transliterator() -> Option<SharedTransliterator> {
+    None
+}
+
+#[cfg(feature = "transliteration")]
+pub use hangul::HangulRomanizer;
+
+#[cfg(feature = "transliteration")]
+mod hangul {
+    use crate::transliterate::Transliterator;
+
+    /// 한글 음절의 시작 코드 (가)
+    const SYLLABLE_BASE: u32 = 0xAC00;
+    /// 한글 음절의 마지막 코드 (힣)
+    const SYLLABLE_LAST: u32 = 0xD7A3;
+
+    const INITIALS: [&str; 19] = [
+        "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+    ];
+    const MEDIALS: [&str; 21] = [
+        "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "eu", "ui", "i",
+    ];
+    const FINALS: [&str; 28] = [
+        "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p", "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+    ];
+
+    /// 국어의 로마자 표기법(문화관광부 고시)의 자모 표기를 따르는 로마자 변환기
+    ///
+    /// # Description
+    /// 한글 음절을 초성, 중성, 종성으로 나누어 자모별 표기를 이어 붙이며, 한글로 시작하는 단어의 첫 글자는 대문자로 표기한다.
+    /// 한글이 아닌 문자(숫자, 영문, 기호)는 그대로 유지한다.
+    ///
+    /// # Note
+    /// 자음 동화 등 발음에 따른 표기 변화는 적용하지 않으므로 사람이 표기한 결과와 다를 수 있다.
+    ///
+    /// # Example
+    /// ```
+    /// use book_batch_rust::transliterate::{HangulRomanizer, Transliterator};
+    ///
+    /// let romanizer = HangulRomanizer;
+    /// assert_eq!(romanizer.romanize("한국"), "Hanguk");
+    /// assert_eq!(romanizer.romanize("원피스 1"), "Wonpiseu 1");
+    /// assert_eq!(romanizer.romanize("ONE PIECE"), "ONE PIECE");
+    /// ```
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct HangulRomanizer;
+
+    impl Transliterator for HangulRomanizer {
+        fn romanize(&self, text: &str) -> String {
+            let mut result = String::with_capacity(text.len() * 2);
+            let mut word_start = true;
+            for c in text.chars() {
+                let code = c as u32;
+                if !(SYLLABLE_BASE..=SYLLABLE_LAST).contains(&code) {
+                    word_start = c.is_whitespace();
+                    result.push(c);
+                    continue;
+                }
+
+                let index = (code - SYLLABLE_BASE) as usize;
+                let syllable = format!("{}{}{}", INITIALS[index / (21 * 28)], MEDIALS[(index % (21 * 28)) / 28], FINALS[index % 28]);
+                if word_start {
+                    let mut chars = syllable.chars();
+                    if let Some(first) = chars.next() {
+                        result.extend(first.to_uppercase());
+                        result.push_str(chars.as_str());
+                    }
+                } else {
+                    result.push_str(&syllable);
+                }
+                word_start = false;
+            }
+            result
+        }
+    }
+}